@@ -24,6 +24,14 @@ import logging
 
 logger = logging.getLogger(__name__)
 
+# Domain separator for internal nodes (agent_registry::merkle::NODE_PREFIX)
+NODE_PREFIX = b"\xff"
+
+
+def hash_pair(left: bytes, right: bytes) -> bytes:
+    """Hash two sibling nodes into their parent: SHA256(0xff || left || right)"""
+    return hashlib.sha256(NODE_PREFIX + left + right).digest()
+
 
 class ActionType(str, Enum):
     """Types of auditable actions"""
@@ -97,7 +105,7 @@ def compute_merkle_root(hashes: List[str]) -> str:
             right = current_level[i + 1] if i + 1 < len(current_level) else left
 
             # Hash pair together
-            combined = hashlib.sha256(left + right).digest()
+            combined = hash_pair(left, right)
             next_level.append(combined)
 
         current_level = next_level
@@ -140,7 +148,7 @@ def compute_merkle_proof(hashes: List[str], index: int) -> List[dict]:
                         "hash": left.hex()
                     })
 
-            combined = hashlib.sha256(left + right).digest()
+            combined = hash_pair(left, right)
             next_level.append(combined)
 
         current_level = next_level
@@ -156,9 +164,9 @@ def verify_merkle_proof(entry_hash: str, proof: List[dict], root: str) -> bool:
     for step in proof:
         sibling = bytes.fromhex(step["hash"])
         if step["position"] == "left":
-            current = hashlib.sha256(sibling + current).digest()
+            current = hash_pair(sibling, current)
         else:
-            current = hashlib.sha256(current + sibling).digest()
+            current = hash_pair(current, sibling)
 
     return current.hex() == root
 
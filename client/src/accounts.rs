@@ -0,0 +1,134 @@
+//! Account fetch and deserialize helpers
+//!
+//! Fetching goes through [`AccountFetcher`] so any RPC client (blocking, async
+//! wrapper, test bank) can be plugged in without this crate depending on one.
+
+use std::fmt;
+
+use agent_registry::state::{
+    AgentAccount, AgentAuditSummary, AgentStatus, AuditMerkleTree, Challenge, MerkleAuditSummary,
+    RegistryConfig, RegistryState,
+};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountDeserialize;
+
+use crate::pda;
+
+/// Errors returned by the fetch helpers
+#[derive(Debug)]
+pub enum ClientError {
+    /// No account exists at the address
+    AccountNotFound(Pubkey),
+    /// Account exists but is not the expected registry account
+    Deserialize(anchor_lang::error::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::AccountNotFound(address) => write!(f, "account not found: {address}"),
+            ClientError::Deserialize(err) => write!(f, "failed to deserialize account: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// Source of raw account data (implement for your RPC client)
+pub trait AccountFetcher {
+    /// Return the account's data, or `None` if it does not exist
+    fn get_account_data(&self, address: &Pubkey) -> Option<Vec<u8>>;
+}
+
+/// Deserialize a registry account from raw data (checks the Anchor discriminator)
+pub fn deserialize<T: AccountDeserialize>(data: &[u8]) -> Result<T, ClientError> {
+    let mut slice = data;
+    T::try_deserialize(&mut slice).map_err(ClientError::Deserialize)
+}
+
+/// Fetch and deserialize any registry account
+pub fn fetch<T: AccountDeserialize>(
+    fetcher: &impl AccountFetcher,
+    address: &Pubkey,
+) -> Result<T, ClientError> {
+    let data = fetcher
+        .get_account_data(address)
+        .ok_or(ClientError::AccountNotFound(*address))?;
+    deserialize(&data)
+}
+
+pub fn fetch_registry(fetcher: &impl AccountFetcher) -> Result<RegistryState, ClientError> {
+    fetch(fetcher, &pda::registry().0)
+}
+
+pub fn fetch_config(fetcher: &impl AccountFetcher) -> Result<RegistryConfig, ClientError> {
+    fetch(fetcher, &pda::config().0)
+}
+
+pub fn fetch_agent(
+    fetcher: &impl AccountFetcher,
+    owner: &Pubkey,
+    agent_id: u64,
+) -> Result<AgentAccount, ClientError> {
+    fetch(fetcher, &pda::agent(owner, agent_id).0)
+}
+
+pub fn fetch_challenge(
+    fetcher: &impl AccountFetcher,
+    agent: &Pubkey,
+    challenger: &Pubkey,
+    nonce: u64,
+) -> Result<Challenge, ClientError> {
+    fetch(fetcher, &pda::challenge(agent, challenger, nonce).0)
+}
+
+pub fn fetch_audit_summary(
+    fetcher: &impl AccountFetcher,
+    agent: &Pubkey,
+) -> Result<AgentAuditSummary, ClientError> {
+    fetch(fetcher, &pda::audit_summary(agent).0)
+}
+
+pub fn fetch_merkle_summary(
+    fetcher: &impl AccountFetcher,
+    agent: &Pubkey,
+) -> Result<MerkleAuditSummary, ClientError> {
+    fetch(fetcher, &pda::merkle_summary(agent).0)
+}
+
+pub fn fetch_audit_tree(
+    fetcher: &impl AccountFetcher,
+    agent: &Pubkey,
+) -> Result<AuditMerkleTree, ClientError> {
+    fetch(fetcher, &pda::audit_tree(agent).0)
+}
+
+/// getProgramAccounts memcmp filter on the AgentAccount index header
+/// `bytes()` is the (offset, bytes) pair to pass to your RPC client's memcmp filter;
+/// combine with a discriminator filter at offset 0 to select agents only
+#[derive(Clone, Copy, Debug)]
+pub enum AgentFilter {
+    Owner(Pubkey),
+    Status(AgentStatus),
+    VerificationLevel(u8),
+    Tier(u8),
+    CapabilityFlags(u64),
+    Namespace([u8; 32]),
+}
+
+impl AgentFilter {
+    pub fn bytes(&self) -> (usize, Vec<u8>) {
+        match self {
+            AgentFilter::Owner(owner) => (AgentAccount::OWNER_OFFSET, owner.to_bytes().to_vec()),
+            AgentFilter::Status(status) => (AgentAccount::STATUS_OFFSET, vec![*status as u8]),
+            AgentFilter::VerificationLevel(level) => {
+                (AgentAccount::VERIFICATION_LEVEL_OFFSET, vec![*level])
+            }
+            AgentFilter::Tier(tier) => (AgentAccount::TIER_OFFSET, vec![*tier]),
+            AgentFilter::CapabilityFlags(flags) => {
+                (AgentAccount::CAPABILITY_FLAGS_OFFSET, flags.to_le_bytes().to_vec())
+            }
+            AgentFilter::Namespace(hash) => (AgentAccount::NAMESPACE_OFFSET, hash.to_vec()),
+        }
+    }
+}
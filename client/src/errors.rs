@@ -0,0 +1,37 @@
+//! Decoding of the registry's structured error context
+//!
+//! Failing limit checks log an [`ErrorContext`] event just before the error, so a
+//! failed transaction's (or simulation's) logs say which limit was hit and by how much.
+
+use agent_registry::error_context::ErrorField;
+use agent_registry::events::ErrorContext;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::Engine;
+
+/// Log prefix under which `emit!` writes event data
+const PROGRAM_DATA: &str = "Program data: ";
+
+/// The last ErrorContext event in a transaction's log messages, if any
+pub fn error_context<S: AsRef<str>>(logs: &[S]) -> Option<ErrorContext> {
+    logs.iter().rev().find_map(|line| {
+        let encoded = line.as_ref().strip_prefix(PROGRAM_DATA)?;
+        let data = base64::engine::general_purpose::STANDARD.decode(encoded).ok()?;
+        let body = data.strip_prefix(ErrorContext::DISCRIMINATOR)?;
+        ErrorContext::deserialize(&mut &body[..]).ok()
+    })
+}
+
+/// Human-readable description of an error context, e.g. "name is 80 bytes, max 64"
+pub fn describe(context: &ErrorContext) -> String {
+    let (actual, limit) = (context.actual, context.limit);
+    let length = |field: &str| format!("{field} is {actual} bytes, max {limit}");
+    match context.field {
+        ErrorField::Name => length("name"),
+        ErrorField::Capabilities => length("capabilities"),
+        ErrorField::Question => length("question"),
+        ErrorField::Uri => length("URI"),
+        ErrorField::Reason => length("reason"),
+        ErrorField::Hooks => format!("{actual} reputation hooks, max {limit}"),
+        ErrorField::SummaryAgents => format!("{actual} agents in a summary, max {limit}"),
+    }
+}
@@ -0,0 +1,2736 @@
+//! Typed instruction builders
+//!
+//! Each builder derives the PDAs it needs, so callers only pass signers,
+//! identifiers and instruction arguments.
+
+use agent_registry::instructions::{
+    AuditEntryProof, ChallengeTemplateParams, InitializeParams, UpdateConfigParams, UpdateNamespaceParams, UpdateServiceListingParams,
+};
+use agent_registry::state::{
+    ActionType, AttestationKind, BadgeKind, ChallengeType, EvidenceKind, Groth16Proof, MultisigAction,
+    PricingModel, RegistrantWhitelist, VoteWeighting, ZkVerifyingKey, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
+};
+use agent_registry::{accounts, instruction, ID};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+
+use crate::pda;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn initialize(admin: Pubkey, params: InitializeParams) -> Instruction {
+    let moderator = params.moderator != Pubkey::default();
+    let attester = params.attester != Pubkey::default();
+    build(
+        accounts::Initialize {
+            admin,
+            registry: pda::registry().0,
+            config: pda::config().0,
+            admin_log: pda::admin_log().0,
+            program_data: pda::program_data().0,
+            moderator: moderator.then(|| pda::moderator(&params.moderator).0),
+            attester: attester.then(|| pda::attester(&params.attester).0),
+            system_program: system_program::ID,
+        },
+        instruction::Initialize { params },
+    )
+}
+
+pub fn initialize_config(admin: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeConfig {
+            admin,
+            registry: pda::registry().0,
+            config: pda::config().0,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeConfig {},
+    )
+}
+
+pub fn init_admin_log(admin: Pubkey) -> Instruction {
+    build(
+        accounts::InitAdminLog {
+            admin,
+            registry: pda::registry().0,
+            admin_log: pda::admin_log().0,
+            system_program: system_program::ID,
+        },
+        instruction::InitAdminLog {},
+    )
+}
+
+pub fn update_config(admin: Pubkey, params: UpdateConfigParams) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            admin,
+            registry: pda::registry().0,
+            admin_log: pda::admin_log().0,
+            config: pda::config().0,
+        },
+        instruction::UpdateConfig { params },
+    )
+}
+
+pub fn set_reputation_bounds(admin: Pubkey, floor: u32, ceiling: u32) -> Instruction {
+    build(
+        accounts::SetReputationBounds {
+            admin,
+            registry: pda::registry().0,
+            admin_log: pda::admin_log().0,
+            config: pda::config().0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        instruction::SetReputationBounds { floor, ceiling },
+    )
+}
+
+/// `agent_id` must be the registry's current `total_agents`; set `allowlisted` to pass
+/// the owner's allowlist entry (required while the registry is allowlist-only),
+/// `gate_token_account` to the owner's gate-mint token account (required while the
+/// registry is token-gated), `unique_model` to claim the model record (required
+/// while models must be unique), `index_owner` to append the agent to the owner's index
+/// and `rate_limited` to pass the owner's registration counter (required while
+/// registrations are rate limited)
+#[allow(clippy::too_many_arguments)]
+pub fn register_agent(
+    owner: Pubkey,
+    agent_id: u64,
+    nft_mint: Pubkey,
+    price_feed: Option<Pubkey>,
+    allowlisted: bool,
+    gate_token_account: Option<Pubkey>,
+    unique_model: bool,
+    index_owner: bool,
+    rate_limited: bool,
+    referrer: Option<Pubkey>,
+    name: String,
+    model_hash: String,
+    capabilities: String,
+) -> Instruction {
+    build(
+        accounts::RegisterAgent {
+            owner,
+            registry: pda::registry().0,
+            config: pda::config().0,
+            treasury: pda::treasury().0,
+            price_feed,
+            allowlist_entry: allowlisted.then(|| pda::allowlist_entry(&owner).0),
+            gate_token_account,
+            model_record: unique_model.then(|| pda::model_record(&model_hash).0),
+            referral: referrer.map(|referrer| pda::referrer(&referrer).0),
+            agent: pda::agent(&owner, agent_id).0,
+            owner_index: index_owner.then(|| pda::owner_index(&owner).0),
+            owner_counter: rate_limited.then(|| pda::owner_counter(&owner).0),
+            nft_mint,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterAgent {
+            name,
+            model_hash,
+            capabilities,
+        },
+    )
+}
+
+/// `agent_id` must be the registry's current `total_agents`; optional accounts as in `register_agent`
+#[allow(clippy::too_many_arguments)]
+pub fn fork_agent(
+    owner: Pubkey,
+    agent_id: u64,
+    parent: Pubkey,
+    nft_mint: Pubkey,
+    price_feed: Option<Pubkey>,
+    allowlisted: bool,
+    gate_token_account: Option<Pubkey>,
+    unique_model: bool,
+    index_owner: bool,
+    rate_limited: bool,
+    name: String,
+    model_hash: String,
+    capabilities: String,
+) -> Instruction {
+    build(
+        accounts::ForkAgent {
+            owner,
+            registry: pda::registry().0,
+            config: pda::config().0,
+            treasury: pda::treasury().0,
+            price_feed,
+            allowlist_entry: allowlisted.then(|| pda::allowlist_entry(&owner).0),
+            gate_token_account,
+            model_record: unique_model.then(|| pda::model_record(&model_hash).0),
+            parent,
+            agent: pda::agent(&owner, agent_id).0,
+            owner_index: index_owner.then(|| pda::owner_index(&owner).0),
+            owner_counter: rate_limited.then(|| pda::owner_counter(&owner).0),
+            nft_mint,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&parent).0,
+        },
+        instruction::ForkAgent {
+            name,
+            model_hash,
+            capabilities,
+        },
+    )
+}
+
+pub fn register_referrer(referrer: Pubkey) -> Instruction {
+    build(
+        accounts::RegisterReferrer {
+            referrer,
+            referral: pda::referrer(&referrer).0,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterReferrer {},
+    )
+}
+
+pub fn claim_referral_rewards(referrer: Pubkey) -> Instruction {
+    build(
+        accounts::ClaimReferralRewards {
+            referrer,
+            referral: pda::referrer(&referrer).0,
+            config: pda::config().0,
+            treasury: pda::treasury().0,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimReferralRewards {},
+    )
+}
+
+pub fn update_agent(
+    owner: Pubkey,
+    agent_id: u64,
+    name: Option<String>,
+    capabilities: Option<String>,
+) -> Instruction {
+    let agent = pda::agent(&owner, agent_id).0;
+    build(
+        accounts::UpdateAgent {
+            owner,
+            agent,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+            unstake_request: pda::unstake_request(&agent).0,
+        },
+        instruction::UpdateAgent { name, capabilities },
+    )
+}
+
+/// Pair with [`ed25519_verify`] over `update_agent_message(agent, nonce, ..)`, placed immediately before
+pub fn update_agent_with_signature(
+    relayer: Pubkey,
+    agent: Pubkey,
+    name: Option<String>,
+    capabilities: Option<String>,
+    nonce: u64,
+) -> Instruction {
+    build(
+        accounts::UpdateAgentWithSignature {
+            relayer,
+            agent,
+            instructions_sysvar: solana_sdk_ids::sysvar::instructions::ID,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+            unstake_request: pda::unstake_request(&agent).0,
+        },
+        instruction::UpdateAgentWithSignature {
+            name,
+            capabilities,
+            nonce,
+        },
+    )
+}
+
+/// Ed25519 program instruction verifying one signature, with all data inline
+pub fn ed25519_verify(signer: &Pubkey, signature: &[u8; 64], message: &[u8]) -> Instruction {
+    // Layout: [count, padding, offsets (7 x u16), pubkey, signature, message]
+    const HEADER_LEN: u16 = 2 + 14;
+    let pubkey_offset = HEADER_LEN;
+    let signature_offset = pubkey_offset + 32;
+    let message_offset = signature_offset + 64;
+
+    let mut data = vec![1u8, 0];
+    for field in [
+        signature_offset,
+        u16::MAX,
+        pubkey_offset,
+        u16::MAX,
+        message_offset,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: solana_sdk_ids::ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+pub fn verify_agent(admin: Pubkey, agent: Pubkey) -> Instruction {
+    build(
+        accounts::VerifyAgent {
+            admin,
+            registry: pda::registry().0,
+            admin_log: pda::admin_log().0,
+            agent,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::VerifyAgent {},
+    )
+}
+
+pub fn retire_agent(owner: Pubkey, agent: Pubkey) -> Instruction {
+    build(
+        accounts::RetireAgent {
+            owner,
+            agent,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::RetireAgent {},
+    )
+}
+
+pub fn deactivate_agent(owner: Pubkey, agent: Pubkey, until: Option<i64>) -> Instruction {
+    build(
+        accounts::DeactivateAgent {
+            owner,
+            agent,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::DeactivateAgent { until },
+    )
+}
+
+pub fn reactivate_agent(caller: Pubkey, agent: Pubkey) -> Instruction {
+    build(
+        accounts::ReactivateAgent {
+            caller,
+            agent,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::ReactivateAgent {},
+    )
+}
+
+/// Set `indexed` to add the agent to the new owner's index, and `has_hooks` / `watched` /
+/// `has_history` for each of the agent's ReputationHooks, AgentWatchers and ReputationHistory
+/// accounts that exist, so they move to the successor
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_agent(
+    owner: Pubkey,
+    new_owner: Pubkey,
+    agent: Pubkey,
+    agent_id: u64,
+    indexed: bool,
+    has_hooks: bool,
+    watched: bool,
+    has_history: bool,
+) -> Instruction {
+    let successor = pda::agent(&new_owner, agent_id).0;
+    build(
+        accounts::TransferAgent {
+            owner,
+            new_owner,
+            config: pda::config().0,
+            agent,
+            successor,
+            transfer: pda::agent_transfer(&agent).0,
+            owner_index: indexed.then(|| pda::owner_index(&new_owner).0),
+            system_program: system_program::ID,
+            hooks: pda::reputation_hooks(&agent).0,
+            successor_hooks: has_hooks.then(|| pda::reputation_hooks(&successor).0),
+            watchers: pda::agent_watchers(&agent).0,
+            successor_watchers: watched.then(|| pda::agent_watchers(&successor).0),
+            history: pda::reputation_history(&agent).0,
+            successor_history: has_history.then(|| pda::reputation_history(&successor).0),
+            counters: pda::agent_counters(&agent).0,
+        },
+        instruction::TransferAgent {},
+    )
+}
+
+/// Set `indexed` when the agent is in the owner's index, to drop it from there
+pub fn close_agent(owner: Pubkey, agent: Pubkey, indexed: bool) -> Instruction {
+    build(
+        accounts::CloseAgent {
+            owner,
+            agent,
+            unstake_request: pda::unstake_request(&agent).0,
+            owner_index: indexed.then(|| pda::owner_index(&owner).0),
+        },
+        instruction::CloseAgent {},
+    )
+}
+
+/// `indexed` as in `close_agent`
+pub fn archive_agent(owner: Pubkey, agent: Pubkey, indexed: bool) -> Instruction {
+    build(
+        accounts::ArchiveAgent {
+            owner,
+            agent,
+            unstake_request: pda::unstake_request(&agent).0,
+            owner_index: indexed.then(|| pda::owner_index(&owner).0),
+            archive: pda::agent_archive(&agent).0,
+            system_program: system_program::ID,
+        },
+        instruction::ArchiveAgent {},
+    )
+}
+
+/// `owner` is the agent's owner; `indexed` as in `close_agent`
+pub fn garbage_collect_agent(caller: Pubkey, owner: Pubkey, agent: Pubkey, indexed: bool) -> Instruction {
+    build(
+        accounts::GarbageCollectAgent {
+            caller,
+            config: pda::config().0,
+            treasury: pda::treasury().0,
+            agent,
+            owner_index: indexed.then(|| pda::owner_index(&owner).0),
+        },
+        instruction::GarbageCollectAgent {},
+    )
+}
+
+pub fn set_moderator(admin: Pubkey, moderator_authority: Pubkey, active: bool) -> Instruction {
+    build(
+        accounts::SetModerator {
+            admin,
+            registry: pda::registry().0,
+            admin_log: pda::admin_log().0,
+            moderator: pda::moderator(&moderator_authority).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetModerator {
+            moderator_authority,
+            active,
+        },
+    )
+}
+
+pub fn set_reputation_authority(
+    admin: Pubkey,
+    reputation_authority: Pubkey,
+    active: bool,
+    epoch_budget: u32,
+) -> Instruction {
+    build(
+        accounts::SetReputationAuthority {
+            admin,
+            registry: pda::registry().0,
+            admin_log: pda::admin_log().0,
+            authority: pda::reputation_authority(&reputation_authority).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetReputationAuthority {
+            reputation_authority,
+            active,
+            epoch_budget,
+        },
+    )
+}
+
+/// `authority` is the admin or a moderator; the moderator PDA is only passed for the latter
+fn moderate_agent(authority: Pubkey, is_admin: bool, agent: Pubkey) -> accounts::ModerateAgent {
+    accounts::ModerateAgent {
+        authority,
+        registry: pda::registry().0,
+        admin_log: pda::admin_log().0,
+        moderator: (!is_admin).then(|| pda::moderator(&authority).0),
+        agent,
+        watchers: pda::agent_watchers(&agent).0,
+    }
+}
+
+pub fn suspend_agent(
+    authority: Pubkey,
+    is_admin: bool,
+    agent: Pubkey,
+    reason_code: u16,
+    duration_secs: Option<i64>,
+) -> Instruction {
+    build(
+        moderate_agent(authority, is_admin, agent),
+        instruction::SuspendAgent {
+            reason_code,
+            duration_secs,
+        },
+    )
+}
+
+pub fn ban_agent(
+    authority: Pubkey,
+    is_admin: bool,
+    agent: Pubkey,
+    reason_code: u16,
+) -> Instruction {
+    build(
+        moderate_agent(authority, is_admin, agent),
+        instruction::BanAgent { reason_code },
+    )
+}
+
+pub fn reinstate_agent(authority: Pubkey, is_admin: bool, agent: Pubkey) -> Instruction {
+    build(
+        moderate_agent(authority, is_admin, agent),
+        instruction::ReinstateAgent {},
+    )
+}
+
+/// `audit_accounts` passes the agent's audit summaries and tree so their totals are
+/// recorded in the quarantine event
+pub fn quarantine_audit_history(
+    authority: Pubkey,
+    is_admin: bool,
+    agent: Pubkey,
+    reason_code: u16,
+    audit_accounts: bool,
+) -> Instruction {
+    build(
+        accounts::QuarantineAuditHistory {
+            authority,
+            registry: pda::registry().0,
+            admin_log: pda::admin_log().0,
+            moderator: (!is_admin).then(|| pda::moderator(&authority).0),
+            agent,
+            audit_summary: audit_accounts.then(|| pda::audit_summary(&agent).0),
+            merkle_summary: audit_accounts.then(|| pda::merkle_summary(&agent).0),
+            audit_tree: audit_accounts.then(|| pda::audit_tree(&agent).0),
+            event_authority: pda::event_authority().0,
+            program: ID,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::QuarantineAuditHistory { reason_code },
+    )
+}
+
+pub fn add_allowlist_entry(admin: Pubkey, registrant: Pubkey) -> Instruction {
+    build(
+        accounts::AddAllowlistEntry {
+            admin,
+            registry: pda::registry().0,
+            entry: pda::allowlist_entry(&registrant).0,
+            system_program: system_program::ID,
+        },
+        instruction::AddAllowlistEntry { registrant },
+    )
+}
+
+pub fn remove_allowlist_entry(admin: Pubkey, registrant: Pubkey) -> Instruction {
+    build(
+        accounts::RemoveAllowlistEntry {
+            admin,
+            registry: pda::registry().0,
+            entry: pda::allowlist_entry(&registrant).0,
+        },
+        instruction::RemoveAllowlistEntry {},
+    )
+}
+
+pub fn set_credential_issuer(admin: Pubkey, issuer_authority: Pubkey, active: bool) -> Instruction {
+    build(
+        accounts::SetCredentialIssuer {
+            admin,
+            registry: pda::registry().0,
+            issuer: pda::credential_issuer(&issuer_authority).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetCredentialIssuer {
+            issuer_authority,
+            active,
+        },
+    )
+}
+
+/// Pair with a preceding `ed25519_verify` of the issuer's signature over
+/// `agent_registry::state::credential_message(..)`
+pub fn record_credential(
+    relayer: Pubkey,
+    issuer_authority: Pubkey,
+    subject: Pubkey,
+    credential_type: u16,
+    credential_hash: [u8; 32],
+    issued_at: i64,
+    expires_at: i64,
+) -> Instruction {
+    build(
+        accounts::RecordCredential {
+            relayer,
+            issuer: pda::credential_issuer(&issuer_authority).0,
+            credential: pda::credential(&subject, &issuer_authority, credential_type).0,
+            instructions_sysvar: solana_sdk_ids::sysvar::instructions::ID,
+            system_program: system_program::ID,
+        },
+        instruction::RecordCredential {
+            subject,
+            credential_type,
+            credential_hash,
+            issued_at,
+            expires_at,
+        },
+    )
+}
+
+pub fn revoke_credential(issuer: Pubkey, subject: Pubkey, credential_type: u16) -> Instruction {
+    build(
+        accounts::RevokeCredential {
+            issuer,
+            credential: pda::credential(&subject, &issuer, credential_type).0,
+        },
+        instruction::RevokeCredential {},
+    )
+}
+
+pub fn set_benchmark_evaluator(admin: Pubkey, evaluator_authority: Pubkey, active: bool) -> Instruction {
+    build(
+        accounts::SetBenchmarkEvaluator {
+            admin,
+            registry: pda::registry().0,
+            evaluator: pda::benchmark_evaluator(&evaluator_authority).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetBenchmarkEvaluator {
+            evaluator_authority,
+            active,
+        },
+    )
+}
+
+pub fn define_benchmark(
+    admin: Pubkey,
+    benchmark_id: u32,
+    suite_hash: [u8; 32],
+    max_score: u32,
+    active: bool,
+) -> Instruction {
+    build(
+        accounts::DefineBenchmark {
+            admin,
+            registry: pda::registry().0,
+            benchmark: pda::benchmark(benchmark_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::DefineBenchmark {
+            benchmark_id,
+            suite_hash,
+            max_score,
+            active,
+        },
+    )
+}
+
+/// Pair with a preceding `ed25519_verify` of the evaluator's signature over
+/// `agent_registry::state::benchmark_message(..)`
+pub fn record_benchmark_score(
+    relayer: Pubkey,
+    evaluator_authority: Pubkey,
+    agent: Pubkey,
+    benchmark_id: u32,
+    score: u32,
+    evaluated_at: i64,
+) -> Instruction {
+    build(
+        accounts::RecordBenchmarkScore {
+            relayer,
+            evaluator: pda::benchmark_evaluator(&evaluator_authority).0,
+            benchmark: pda::benchmark(benchmark_id).0,
+            agent,
+            score: pda::benchmark_score(&agent, benchmark_id).0,
+            instructions_sysvar: solana_sdk_ids::sysvar::instructions::ID,
+            system_program: system_program::ID,
+        },
+        instruction::RecordBenchmarkScore { score, evaluated_at },
+    )
+}
+
+pub fn anchor_did(
+    owner: Pubkey,
+    agent: Pubkey,
+    did_uri: String,
+    did_doc_hash: [u8; 32],
+    controller: Pubkey,
+) -> Instruction {
+    build(
+        accounts::AnchorDid {
+            owner,
+            agent,
+            did: pda::agent_did(&agent).0,
+            system_program: system_program::ID,
+        },
+        instruction::AnchorDid {
+            did_uri,
+            did_doc_hash,
+            controller,
+        },
+    )
+}
+
+pub fn update_did(
+    controller: Pubkey,
+    agent: Pubkey,
+    did_uri: Option<String>,
+    did_doc_hash: [u8; 32],
+    new_controller: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::UpdateDid {
+            controller,
+            did: pda::agent_did(&agent).0,
+        },
+        instruction::UpdateDid {
+            did_uri,
+            did_doc_hash,
+            new_controller,
+        },
+    )
+}
+
+pub fn set_reputation_oracle(
+    admin: Pubkey,
+    oracle_authority: Pubkey,
+    source_id: u8,
+    active: bool,
+) -> Instruction {
+    build(
+        accounts::SetReputationOracle {
+            admin,
+            registry: pda::registry().0,
+            oracle: pda::reputation_oracle(&oracle_authority).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetReputationOracle {
+            oracle_authority,
+            source_id,
+            active,
+        },
+    )
+}
+
+pub fn import_external_reputation(authority: Pubkey, agent: Pubkey, score: u32) -> Instruction {
+    build(
+        accounts::ImportExternalReputation {
+            authority,
+            oracle: pda::reputation_oracle(&authority).0,
+            agent,
+            external_scores: pda::external_scores(&agent).0,
+            system_program: system_program::ID,
+        },
+        instruction::ImportExternalReputation { score },
+    )
+}
+
+pub fn init_reputation_history(payer: Pubkey, agent: Pubkey) -> Instruction {
+    build(
+        accounts::InitReputationHistory {
+            payer,
+            agent,
+            history: pda::reputation_history(&agent).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitReputationHistory {},
+    )
+}
+
+pub fn init_agent_counters(payer: Pubkey, agent: Pubkey) -> Instruction {
+    build(
+        accounts::InitAgentCounters {
+            payer,
+            agent,
+            counters: pda::agent_counters(&agent).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitAgentCounters {},
+    )
+}
+
+pub fn sync_agent_counters(agent: Pubkey) -> Instruction {
+    build(
+        accounts::SyncAgentCounters {
+            agent,
+            counters: pda::agent_counters(&agent).0,
+        },
+        instruction::SyncAgentCounters {},
+    )
+}
+
+/// `model_hash` must be the agent's registered model hash
+pub fn index_agent_model(payer: Pubkey, agent: Pubkey, model_hash: &str) -> Instruction {
+    build(
+        accounts::IndexAgentModel {
+            payer,
+            agent,
+            model_index: pda::model_index(model_hash).0,
+            system_program: system_program::ID,
+        },
+        instruction::IndexAgentModel {},
+    )
+}
+
+pub fn prune_model_index(caller: Pubkey, model_hash: &str, agent: Pubkey) -> Instruction {
+    build(
+        accounts::PruneModelIndex {
+            caller,
+            model_index: pda::model_index(model_hash).0,
+            agent,
+        },
+        instruction::PruneModelIndex {},
+    )
+}
+
+pub fn index_owner_agent(payer: Pubkey, owner: Pubkey, agent: Pubkey) -> Instruction {
+    build(
+        accounts::IndexOwnerAgent {
+            payer,
+            agent,
+            owner_index: pda::owner_index(&owner).0,
+            system_program: system_program::ID,
+        },
+        instruction::IndexOwnerAgent {},
+    )
+}
+
+pub fn prune_owner_index(caller: Pubkey, owner: Pubkey, agent_id: u64) -> Instruction {
+    build(
+        accounts::PruneOwnerIndex {
+            caller,
+            owner_index: pda::owner_index(&owner).0,
+            agent: pda::agent(&owner, agent_id).0,
+        },
+        instruction::PruneOwnerIndex { agent_id },
+    )
+}
+
+/// `sync_tags` names the tag indexes to bring in line: typically the agent's old
+/// and new tags whose indexes exist
+pub fn set_agent_tags(owner: Pubkey, agent: Pubkey, tags: Vec<String>, sync_tags: &[&str]) -> Instruction {
+    let mut ix = build(
+        accounts::SetAgentTags {
+            owner,
+            agent,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::SetAgentTags { tags },
+    );
+    ix.accounts
+        .extend(sync_tags.iter().map(|tag| AccountMeta::new(pda::tag_index(tag).0, false)));
+    ix
+}
+
+pub fn index_agent_tag(payer: Pubkey, agent: Pubkey, tag: String) -> Instruction {
+    build(
+        accounts::IndexAgentTag {
+            payer,
+            agent,
+            tag_index: pda::tag_index(&tag).0,
+            system_program: system_program::ID,
+        },
+        instruction::IndexAgentTag { tag },
+    )
+}
+
+pub fn prune_tag_index(caller: Pubkey, tag: &str, agent: Pubkey) -> Instruction {
+    build(
+        accounts::PruneTagIndex {
+            caller,
+            tag_index: pda::tag_index(tag).0,
+            agent,
+        },
+        instruction::PruneTagIndex {},
+    )
+}
+
+pub fn set_encryption_pubkey(
+    owner: Pubkey,
+    agent: Pubkey,
+    encryption_pubkey: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::SetEncryptionPubkey {
+            owner,
+            agent,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::SetEncryptionPubkey { encryption_pubkey },
+    )
+}
+
+/// The hook program's authority PDA must sign, so on chain this is CPI'd by the hook
+/// program (see `interface::reputation_hooks`)
+pub fn register_reputation_hook(payer: Pubkey, agent: Pubkey, hook_program: Pubkey) -> Instruction {
+    build(
+        accounts::RegisterReputationHook {
+            payer,
+            hook_program,
+            hook_authority: pda::hook_authority(&hook_program).0,
+            agent,
+            hooks: pda::reputation_hooks(&agent).0,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterReputationHook {},
+    )
+}
+
+pub fn remove_reputation_hook(agent: Pubkey, hook_program: Pubkey) -> Instruction {
+    build(
+        accounts::RemoveReputationHook {
+            hook_authority: pda::hook_authority(&hook_program).0,
+            agent,
+            hooks: pda::reputation_hooks(&agent).0,
+        },
+        instruction::RemoveReputationHook { hook_program },
+    )
+}
+
+pub fn evict_reputation_hook(
+    authority: Pubkey,
+    is_admin: bool,
+    agent: Pubkey,
+    hook_program: Pubkey,
+) -> Instruction {
+    build(
+        accounts::EvictReputationHook {
+            authority,
+            registry: pda::registry().0,
+            admin_log: pda::admin_log().0,
+            moderator: (!is_admin).then(|| pda::moderator(&authority).0),
+            agent,
+            hooks: pda::reputation_hooks(&agent).0,
+        },
+        instruction::EvictReputationHook { hook_program },
+    )
+}
+
+pub fn dispatch_reputation_hook(agent: Pubkey, hook_program: Pubkey) -> Instruction {
+    build(
+        accounts::DispatchReputationHook {
+            agent,
+            hooks: pda::reputation_hooks(&agent).0,
+            hook_program,
+        },
+        instruction::DispatchReputationHook {},
+    )
+}
+
+/// `mask` is a set of AgentWatchers::FLAG_* bits
+pub fn watch_agent(watcher: Pubkey, agent: Pubkey, mask: u16) -> Instruction {
+    build(
+        accounts::WatchAgent {
+            watcher,
+            agent,
+            watchers: pda::agent_watchers(&agent).0,
+            system_program: system_program::ID,
+        },
+        instruction::WatchAgent { mask },
+    )
+}
+
+/// `caller` is the watcher or the agent owner
+pub fn unwatch_agent(caller: Pubkey, agent: Pubkey, watcher: Pubkey) -> Instruction {
+    build(
+        accounts::UnwatchAgent {
+            caller,
+            agent,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::UnwatchAgent { watcher },
+    )
+}
+
+pub fn clear_watch_flags(watcher: Pubkey, agent: Pubkey, flags: u16) -> Instruction {
+    build(
+        accounts::ClearWatchFlags {
+            watcher,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::ClearWatchFlags { flags },
+    )
+}
+
+pub fn create_badge_mint(admin: Pubkey, kind: BadgeKind) -> Instruction {
+    build(
+        accounts::CreateBadgeMint {
+            admin,
+            registry: pda::registry().0,
+            badge_mint: pda::badge_mint(kind).0,
+            token_program: TOKEN_2022_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreateBadgeMint { kind },
+    )
+}
+
+/// Set `audit_accounts` to pass the agent's audit summary, Merkle summary and audit
+/// tree (all must exist) so their counters count toward `BadgeKind::MillionAudits`
+pub fn claim_badge(
+    owner: Pubkey,
+    agent: Pubkey,
+    agent_id: u64,
+    kind: BadgeKind,
+    audit_accounts: bool,
+) -> Instruction {
+    build(
+        accounts::ClaimBadge {
+            owner,
+            agent,
+            audit_summary: audit_accounts.then(|| pda::audit_summary(&agent).0),
+            merkle_summary: audit_accounts.then(|| pda::merkle_summary(&agent).0),
+            audit_tree: audit_accounts.then(|| pda::audit_tree(&agent).0),
+            badge_claim: pda::badge_claim(agent_id, kind).0,
+            badge_mint: pda::badge_mint(kind).0,
+            owner_token_account: pda::badge_token_account(&owner, kind).0,
+            token_program: TOKEN_2022_PROGRAM_ID,
+            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimBadge { kind },
+    )
+}
+
+pub fn create_namespace(admin: Pubkey, name: &str, namespace_admin: Pubkey) -> Instruction {
+    build(
+        accounts::CreateNamespace {
+            admin,
+            registry: pda::registry().0,
+            namespace: pda::namespace(name).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateNamespace {
+            name: name.to_string(),
+            namespace_admin,
+        },
+    )
+}
+
+pub fn update_namespace(admin: Pubkey, name: &str, params: UpdateNamespaceParams) -> Instruction {
+    build(
+        accounts::UpdateNamespace {
+            admin,
+            namespace: pda::namespace(name).0,
+        },
+        instruction::UpdateNamespace { params },
+    )
+}
+
+/// Set `approved` when the namespace admin co-signs (required while it is approval-only)
+pub fn join_namespace(
+    owner: Pubkey,
+    agent: Pubkey,
+    name: &str,
+    namespace_admin: Pubkey,
+    approved: bool,
+) -> Instruction {
+    let mut ix = build(
+        accounts::JoinNamespace {
+            owner,
+            agent,
+            namespace: pda::namespace(name).0,
+            namespace_admin,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::JoinNamespace {},
+    );
+    if approved {
+        if let Some(meta) = ix.accounts.iter_mut().find(|meta| meta.pubkey == namespace_admin) {
+            meta.is_signer = true;
+        }
+    }
+    ix
+}
+
+pub fn leave_namespace(owner: Pubkey, agent: Pubkey, name: &str) -> Instruction {
+    build(
+        accounts::LeaveNamespace {
+            owner,
+            agent,
+            namespace: pda::namespace(name).0,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::LeaveNamespace {},
+    )
+}
+
+/// Pass `namespace` to deprecate it (successor = the successor namespace's address);
+/// omit it to deprecate the deployment (successor = the successor program ID)
+pub fn deprecate_registry(authority: Pubkey, namespace: Option<&str>, successor: Pubkey) -> Instruction {
+    build(
+        accounts::DeprecateRegistry {
+            authority,
+            registry: pda::registry().0,
+            admin_log: pda::admin_log().0,
+            config: pda::config().0,
+            namespace: namespace.map(|name| pda::namespace(name).0),
+        },
+        instruction::DeprecateRegistry { successor },
+    )
+}
+
+/// `namespaces` = (deprecated namespace, its successor); `None` migrates out of the deployment
+pub fn migrate_agent_to_registry(
+    owner: Pubkey,
+    agent: Pubkey,
+    namespaces: Option<(&str, &str)>,
+) -> Instruction {
+    let namespace = namespaces.map(|(from, _)| pda::namespace(from).0);
+    build(
+        accounts::MigrateAgentToRegistry {
+            owner,
+            agent,
+            config: pda::config().0,
+            namespace,
+            successor_namespace: namespaces.map(|(_, to)| pda::namespace(to).0),
+            migration: pda::registry_migration(&agent, &namespace.unwrap_or(ID)).0,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::MigrateAgentToRegistry {},
+    )
+}
+
+/// Set `queued` to order the request in the verification queue by stake plus fee
+pub fn request_verification(owner: Pubkey, agent: Pubkey, queued: bool) -> Instruction {
+    build(
+        accounts::RequestVerification {
+            owner,
+            config: pda::config().0,
+            agent,
+            request: pda::verification_request(&agent).0,
+            treasury: pda::treasury().0,
+            queue: queued.then(|| pda::verification_queue().0),
+            system_program: system_program::ID,
+        },
+        instruction::RequestVerification {},
+    )
+}
+
+fn process_verification(
+    admin: Pubkey,
+    agent: Pubkey,
+    requester: Pubkey,
+    queued: bool,
+) -> accounts::ProcessVerification {
+    accounts::ProcessVerification {
+        admin,
+        registry: pda::registry().0,
+        admin_log: pda::admin_log().0,
+        agent,
+        request: pda::verification_request(&agent).0,
+        config: pda::config().0,
+        requester,
+        queue: queued.then(|| pda::verification_queue().0),
+        watchers: pda::agent_watchers(&agent).0,
+    }
+}
+
+/// `queued` must match how the request was made; queued requests must be the queue head
+pub fn approve_verification(
+    admin: Pubkey,
+    agent: Pubkey,
+    requester: Pubkey,
+    queued: bool,
+    reason: String,
+) -> Instruction {
+    build(
+        process_verification(admin, agent, requester, queued),
+        instruction::ApproveVerification { reason },
+    )
+}
+
+pub fn reject_verification(
+    admin: Pubkey,
+    agent: Pubkey,
+    requester: Pubkey,
+    queued: bool,
+    reason: String,
+) -> Instruction {
+    build(
+        process_verification(admin, agent, requester, queued),
+        instruction::RejectVerification { reason },
+    )
+}
+
+pub fn release_verification_bond(caller: Pubkey, agent: Pubkey, requester: Pubkey) -> Instruction {
+    build(
+        accounts::ReleaseVerificationBond {
+            caller,
+            request: pda::verification_request(&agent).0,
+            requester,
+        },
+        instruction::ReleaseVerificationBond {},
+    )
+}
+
+pub fn revoke_verification(
+    admin: Pubkey,
+    agent: Pubkey,
+    requester: Pubkey,
+    fraud: bool,
+    reason: String,
+) -> Instruction {
+    build(
+        accounts::RevokeVerification {
+            admin,
+            registry: pda::registry().0,
+            admin_log: pda::admin_log().0,
+            agent,
+            request: pda::verification_request(&agent).0,
+            requester,
+            treasury: pda::treasury().0,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::RevokeVerification { fraud, reason },
+    )
+}
+
+pub fn init_verification_queue(admin: Pubkey) -> Instruction {
+    build(
+        accounts::InitVerificationQueue {
+            admin,
+            registry: pda::registry().0,
+            queue: pda::verification_queue().0,
+            system_program: system_program::ID,
+        },
+        instruction::InitVerificationQueue {},
+    )
+}
+
+pub fn prune_verification_queue(caller: Pubkey, agent: Pubkey) -> Instruction {
+    build(
+        accounts::PruneVerificationQueue {
+            caller,
+            queue: pda::verification_queue().0,
+            agent,
+        },
+        instruction::PruneVerificationQueue {},
+    )
+}
+
+pub fn set_attester(
+    admin: Pubkey,
+    attester_authority: Pubkey,
+    kind: AttestationKind,
+    active: bool,
+) -> Instruction {
+    build(
+        accounts::SetAttester {
+            admin,
+            registry: pda::registry().0,
+            attester: pda::attester(&attester_authority).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetAttester {
+            attester_authority,
+            kind,
+            active,
+        },
+    )
+}
+
+pub fn attest_agent(
+    authority: Pubkey,
+    agent: Pubkey,
+    report_hash: [u8; 32],
+    expires_at: i64,
+) -> Instruction {
+    build(
+        accounts::AttestAgent {
+            authority,
+            attester: pda::attester(&authority).0,
+            agent,
+            attestation: pda::attestation(&agent, &authority).0,
+            system_program: system_program::ID,
+        },
+        instruction::AttestAgent {
+            report_hash,
+            expires_at,
+        },
+    )
+}
+
+pub fn auto_verify(agent: Pubkey, attester_authority: Pubkey) -> Instruction {
+    build(
+        accounts::AutoVerify {
+            config: pda::config().0,
+            agent,
+            attester: pda::attester(&attester_authority).0,
+            attestation: pda::attestation(&agent, &attester_authority).0,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::AutoVerify {},
+    )
+}
+
+/// `authority` is the admin or a reputation authority; its PDA is only passed for the latter.
+/// Pass `cosigner` for deltas above the config's co-sign threshold; set `record_history`
+/// once the agent's reputation history account exists; pass the agent's registered
+/// hook programs to call them in this transaction (the other reputation builders pass
+/// none, leaving the change to `dispatch_reputation_hook`)
+pub fn update_reputation(
+    authority: Pubkey,
+    is_admin: bool,
+    cosigner: Option<Pubkey>,
+    agent: Pubkey,
+    delta: i32,
+    record_history: bool,
+    hook_programs: &[Pubkey],
+) -> Instruction {
+    let mut ix = build(
+        accounts::UpdateReputation {
+            authority,
+            cosigner,
+            registry: pda::registry().0,
+            reputation_authority: (!is_admin).then(|| pda::reputation_authority(&authority).0),
+            config: pda::config().0,
+            agent,
+            history: record_history.then(|| pda::reputation_history(&agent).0),
+            hooks: pda::reputation_hooks(&agent).0,
+            watchers: pda::agent_watchers(&agent).0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        instruction::UpdateReputation { delta },
+    );
+    ix.accounts
+        .extend(hook_programs.iter().map(|key| AccountMeta::new_readonly(*key, false)));
+    ix
+}
+
+/// Hot-path variant of `update_reputation`; the agent needs AgentCounters in sync
+pub fn update_reputation_fast(
+    authority: Pubkey,
+    is_admin: bool,
+    cosigner: Option<Pubkey>,
+    agent: Pubkey,
+    delta: i32,
+    record_history: bool,
+    hook_programs: &[Pubkey],
+) -> Instruction {
+    let mut ix = build(
+        accounts::UpdateReputationFast {
+            authority,
+            cosigner,
+            registry: pda::registry().0,
+            reputation_authority: (!is_admin).then(|| pda::reputation_authority(&authority).0),
+            config: pda::config().0,
+            agent,
+            counters: pda::agent_counters(&agent).0,
+            history: record_history.then(|| pda::reputation_history(&agent).0),
+            hooks: pda::reputation_hooks(&agent).0,
+            watchers: pda::agent_watchers(&agent).0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        instruction::UpdateReputationFast { delta },
+    );
+    ix.accounts
+        .extend(hook_programs.iter().map(|key| AccountMeta::new_readonly(*key, false)));
+    ix
+}
+
+/// As the challenge program would CPI it; `program_authority` must sign (its PDA)
+pub fn update_reputation_by_program(
+    program_authority: Pubkey,
+    agent: Pubkey,
+    delta: i32,
+    record_history: bool,
+) -> Instruction {
+    build(
+        accounts::UpdateReputationByProgram {
+            program_authority,
+            config: pda::config().0,
+            agent,
+            history: record_history.then(|| pda::reputation_history(&agent).0),
+            hooks: pda::reputation_hooks(&agent).0,
+            watchers: pda::agent_watchers(&agent).0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        instruction::UpdateReputationByProgram { delta },
+    )
+}
+
+pub fn stake_agent(owner: Pubkey, agent_id: u64, amount: u64) -> Instruction {
+    let agent = pda::agent(&owner, agent_id).0;
+    build(
+        accounts::StakeAgent {
+            owner,
+            agent,
+            config: pda::config().0,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::StakeAgent { amount },
+    )
+}
+
+pub fn unstake_agent(owner: Pubkey, agent_id: u64, amount: u64) -> Instruction {
+    let agent = pda::agent(&owner, agent_id).0;
+    build(
+        accounts::UnstakeAgent {
+            owner,
+            agent,
+            config: pda::config().0,
+            watchers: pda::agent_watchers(&agent).0,
+            unstake_request: pda::unstake_request(&agent).0,
+        },
+        instruction::UnstakeAgent { amount },
+    )
+}
+
+pub fn request_unstake(owner: Pubkey, agent_id: u64, amount: u64) -> Instruction {
+    let agent = pda::agent(&owner, agent_id).0;
+    build(
+        accounts::RequestUnstake {
+            owner,
+            agent,
+            config: pda::config().0,
+            unstake_request: pda::unstake_request(&agent).0,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::RequestUnstake { amount },
+    )
+}
+
+pub fn withdraw_unstake(owner: Pubkey, agent_id: u64) -> Instruction {
+    let agent = pda::agent(&owner, agent_id).0;
+    build(
+        accounts::WithdrawUnstake {
+            owner,
+            agent,
+            unstake_request: pda::unstake_request(&agent).0,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::WithdrawUnstake {},
+    )
+}
+
+/// `template` is the ChallengeTemplate PDA when instantiating one (see `pda::challenge_template`)
+/// `answer_commitment` is `Challenge::commit_answer(expected_hash, salt)`; keep the salt
+/// for `reveal_challenge_answer`
+#[allow(clippy::too_many_arguments)]
+pub fn create_challenge(
+    challenger: Pubkey,
+    agent: Pubkey,
+    price_feed: Option<Pubkey>,
+    question: String,
+    answer_commitment: String,
+    nonce: u64,
+    challenge_type: ChallengeType,
+    judged: bool,
+    template: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::CreateChallenge {
+            challenger,
+            agent,
+            config: pda::config().0,
+            challenge: pda::challenge(&agent, &challenger, nonce).0,
+            price_feed,
+            system_program: system_program::ID,
+            template,
+        },
+        instruction::CreateChallenge {
+            question,
+            answer_commitment,
+            nonce,
+            challenge_type,
+            judged,
+        },
+    )
+}
+
+pub fn create_challenge_template(
+    creator: Pubkey,
+    spec_hash: [u8; 32],
+    params: ChallengeTemplateParams,
+) -> Instruction {
+    build(
+        accounts::CreateChallengeTemplate {
+            creator,
+            registry: pda::registry().0,
+            config: pda::config().0,
+            template: pda::challenge_template(&spec_hash).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateChallengeTemplate { spec_hash, params },
+    )
+}
+
+pub fn set_challenge_template_active(authority: Pubkey, spec_hash: [u8; 32], active: bool) -> Instruction {
+    build(
+        accounts::SetChallengeTemplateActive {
+            authority,
+            registry: pda::registry().0,
+            template: pda::challenge_template(&spec_hash).0,
+        },
+        instruction::SetChallengeTemplateActive { active },
+    )
+}
+
+pub fn submit_response(
+    owner: Pubkey,
+    agent_id: u64,
+    challenger: Pubkey,
+    response_hash: String,
+    nonce: u64,
+) -> Instruction {
+    let agent = pda::agent(&owner, agent_id).0;
+    build(
+        accounts::SubmitResponse {
+            owner,
+            registry: pda::registry().0,
+            agent,
+            challenge: pda::challenge(&agent, &challenger, nonce).0,
+        },
+        instruction::SubmitResponse {
+            response_hash,
+            nonce,
+        },
+    )
+}
+
+pub fn reveal_challenge_answer(
+    challenger: Pubkey,
+    agent: Pubkey,
+    owner: Pubkey,
+    nonce: u64,
+    expected_hash: String,
+    salt: [u8; 32],
+    record_history: bool,
+) -> Instruction {
+    build(
+        accounts::RevealChallengeAnswer {
+            challenger,
+            config: pda::config().0,
+            agent,
+            challenge: pda::challenge(&agent, &challenger, nonce).0,
+            owner,
+            history: record_history.then(|| pda::reputation_history(&agent).0),
+            challenge_stream: pda::challenge_stream(&agent).0,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+            hooks: pda::reputation_hooks(&agent).0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        instruction::RevealChallengeAnswer {
+            nonce,
+            expected_hash,
+            salt,
+        },
+    )
+}
+
+pub fn expire_challenge(
+    caller: Pubkey,
+    agent: Pubkey,
+    challenger: Pubkey,
+    nonce: u64,
+    record_history: bool,
+) -> Instruction {
+    build(
+        accounts::ExpireChallenge {
+            caller,
+            registry: pda::registry().0,
+            config: pda::config().0,
+            agent,
+            challenge: pda::challenge(&agent, &challenger, nonce).0,
+            challenger,
+            unstake_request: pda::unstake_request(&agent).0,
+            history: record_history.then(|| pda::reputation_history(&agent).0),
+            challenge_stream: pda::challenge_stream(&agent).0,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+            hooks: pda::reputation_hooks(&agent).0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        instruction::ExpireChallenge { nonce },
+    )
+}
+
+pub fn close_challenge(challenger: Pubkey, agent: Pubkey, nonce: u64) -> Instruction {
+    build(
+        accounts::CloseChallenge {
+            challenger,
+            agent,
+            challenge: pda::challenge(&agent, &challenger, nonce).0,
+        },
+        instruction::CloseChallenge { nonce },
+    )
+}
+
+pub fn void_challenge(caller: Pubkey, agent: Pubkey, challenger: Pubkey, nonce: u64) -> Instruction {
+    build(
+        accounts::VoidChallenge {
+            caller,
+            challenge: pda::challenge(&agent, &challenger, nonce).0,
+            agent,
+            challenger,
+        },
+        instruction::VoidChallenge { nonce },
+    )
+}
+
+/// `recipient` is the agent owner for a passed challenge, otherwise the challenger
+pub fn claim_challenge_escrow(
+    caller: Pubkey,
+    agent: Pubkey,
+    challenger: Pubkey,
+    nonce: u64,
+    recipient: Pubkey,
+) -> Instruction {
+    build(
+        accounts::ClaimChallengeEscrow {
+            caller,
+            challenge: pda::challenge(&agent, &challenger, nonce).0,
+            agent,
+            recipient,
+        },
+        instruction::ClaimChallengeEscrow { nonce },
+    )
+}
+
+/// `submitter` is the challenger or the agent owner
+pub fn submit_challenge_evidence(
+    submitter: Pubkey,
+    agent: Pubkey,
+    challenger: Pubkey,
+    nonce: u64,
+    kind: EvidenceKind,
+    evidence_hash: [u8; 32],
+) -> Instruction {
+    let challenge = pda::challenge(&agent, &challenger, nonce).0;
+    build(
+        accounts::SubmitChallengeEvidence {
+            submitter,
+            agent,
+            challenge,
+            evidence: pda::challenge_evidence(&challenge).0,
+            system_program: system_program::ID,
+        },
+        instruction::SubmitChallengeEvidence {
+            nonce,
+            kind,
+            evidence_hash,
+        },
+    )
+}
+
+pub fn register_judge(admin: Pubkey, judge_authority: Pubkey) -> Instruction {
+    build(
+        accounts::RegisterJudge {
+            admin,
+            registry: pda::registry().0,
+            config: pda::config().0,
+            judge: pda::judge(&judge_authority).0,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterJudge { judge_authority },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn cast_judge_vote(
+    authority: Pubkey,
+    agent: Pubkey,
+    agent_owner: Pubkey,
+    challenger: Pubkey,
+    nonce: u64,
+    passed: bool,
+    record_history: bool,
+    template: Option<Pubkey>,
+) -> Instruction {
+    let challenge = pda::challenge(&agent, &challenger, nonce).0;
+    build(
+        accounts::CastJudgeVote {
+            authority,
+            judge: pda::judge(&authority).0,
+            config: pda::config().0,
+            agent,
+            challenge,
+            vote: pda::judge_vote(&challenge, &authority).0,
+            owner: agent_owner,
+            challenger,
+            system_program: system_program::ID,
+            history: record_history.then(|| pda::reputation_history(&agent).0),
+            evidence: pda::challenge_evidence(&challenge).0,
+            template,
+            challenge_stream: pda::challenge_stream(&agent).0,
+            watchers: pda::agent_watchers(&agent).0,
+            hooks: pda::reputation_hooks(&agent).0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        instruction::CastJudgeVote { nonce, passed },
+    )
+}
+
+/// `audit_index` must be the audit summary's current `total_entries` (0 for the first entry)
+pub fn log_audit(
+    actor: Pubkey,
+    agent: Pubkey,
+    audit_index: u64,
+    action_type: ActionType,
+    context_risk: u8,
+    details_hash: String,
+) -> Instruction {
+    build(
+        accounts::LogAudit {
+            actor,
+            agent,
+            config: pda::config().0,
+            treasury: pda::treasury().0,
+            audit_summary: pda::audit_summary(&agent).0,
+            audit_entry: pda::audit_entry(&agent, audit_index).0,
+            system_program: system_program::ID,
+        },
+        instruction::LogAudit {
+            action_type,
+            context_risk,
+            details_hash,
+        },
+    )
+}
+
+/// `batch_index` must be the Merkle summary's current `total_batches` (0 for the first batch)
+/// `submitter` is the owner, or a delegate with `delegated` set to pass its AuditDelegate
+pub fn store_merkle_audit(
+    submitter: Pubkey,
+    agent: Pubkey,
+    delegated: bool,
+    batch_index: u64,
+    merkle_root: [u8; 32],
+    entries_count: u32,
+) -> Instruction {
+    build(
+        accounts::StoreMerkleAudit {
+            submitter,
+            agent,
+            audit_delegate: delegated.then(|| pda::audit_delegate(&agent, &submitter).0),
+            config: pda::config().0,
+            treasury: pda::treasury().0,
+            audit_summary: pda::merkle_summary(&agent).0,
+            audit_root: pda::merkle_root(&agent, batch_index).0,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        instruction::StoreMerkleAudit {
+            merkle_root,
+            entries_count,
+        },
+    )
+}
+
+/// `submitter` and `delegated` as in `store_merkle_audit`
+pub fn append_audit_leaf(submitter: Pubkey, agent: Pubkey, delegated: bool, leaf: [u8; 32]) -> Instruction {
+    build(
+        accounts::AppendAuditLeaf {
+            submitter,
+            agent,
+            audit_delegate: delegated.then(|| pda::audit_delegate(&agent, &submitter).0),
+            config: pda::config().0,
+            treasury: pda::treasury().0,
+            audit_tree: pda::audit_tree(&agent).0,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        instruction::AppendAuditLeaf { leaf },
+    )
+}
+
+/// Simulate to read the result (`merkle::audit_leaves` / `batch_proof` build the proof)
+pub fn verify_audit_entry(agent: Pubkey, batch_index: u64, entry: AuditEntryProof) -> Instruction {
+    build(
+        accounts::VerifyAuditEntry {
+            agent,
+            audit_root: pda::merkle_root(&agent, batch_index).0,
+        },
+        instruction::VerifyAuditEntry { entry },
+    )
+}
+
+pub fn set_audit_delegate(
+    owner: Pubkey,
+    agent: Pubkey,
+    delegate: Pubkey,
+    scopes: u8,
+    expires_at: i64,
+) -> Instruction {
+    build(
+        accounts::SetAuditDelegate {
+            owner,
+            agent,
+            audit_delegate: pda::audit_delegate(&agent, &delegate).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetAuditDelegate {
+            delegate,
+            scopes,
+            expires_at,
+        },
+    )
+}
+
+pub fn revoke_audit_delegate(owner: Pubkey, agent: Pubkey, delegate: Pubkey) -> Instruction {
+    build(
+        accounts::RevokeAuditDelegate {
+            owner,
+            agent,
+            audit_delegate: pda::audit_delegate(&agent, &delegate).0,
+        },
+        instruction::RevokeAuditDelegate {},
+    )
+}
+
+/// `epoch` must be the current Solana epoch
+pub fn open_epoch_rewards(payer: Pubkey, epoch: u64) -> Instruction {
+    build(
+        accounts::OpenEpochRewards {
+            payer,
+            config: pda::config().0,
+            epoch_rewards: pda::epoch_rewards(epoch).0,
+            treasury: pda::treasury().0,
+            system_program: system_program::ID,
+        },
+        instruction::OpenEpochRewards {},
+    )
+}
+
+/// `epoch` must be the current Solana epoch, with its rewards opened
+pub fn snapshot_agent_epoch(payer: Pubkey, agent: Pubkey, epoch: u64) -> Instruction {
+    build(
+        accounts::SnapshotAgentEpoch {
+            payer,
+            agent,
+            snapshot: pda::epoch_snapshot(&agent, epoch).0,
+            epoch_rewards: pda::epoch_rewards(epoch).0,
+            system_program: system_program::ID,
+        },
+        instruction::SnapshotAgentEpoch {},
+    )
+}
+
+pub fn claim_epoch_rewards(owner: Pubkey, agent: Pubkey, epoch: u64) -> Instruction {
+    build(
+        accounts::ClaimEpochRewards {
+            owner,
+            agent,
+            snapshot: pda::epoch_snapshot(&agent, epoch).0,
+            epoch_rewards: pda::epoch_rewards(epoch).0,
+            claim: pda::epoch_reward_claim(&agent, epoch).0,
+            config: pda::config().0,
+            treasury: pda::treasury().0,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimEpochRewards { epoch },
+    )
+}
+
+pub fn release_epoch_rewards(epoch: u64) -> Instruction {
+    build(
+        accounts::ReleaseEpochRewards {
+            epoch_rewards: pda::epoch_rewards(epoch).0,
+            config: pda::config().0,
+        },
+        instruction::ReleaseEpochRewards { epoch },
+    )
+}
+
+/// Simulate and read the `EffectiveReputationResponse` return data
+pub fn get_effective_reputation(agent: Pubkey) -> Instruction {
+    build(
+        accounts::GetAgentView {
+            config: pda::config().0,
+            agent,
+        },
+        instruction::GetEffectiveReputation {},
+    )
+}
+
+/// Simulate and read the `WinRateResponse` return data
+pub fn get_win_rate(agent: Pubkey) -> Instruction {
+    build(
+        accounts::GetAgentView {
+            config: pda::config().0,
+            agent,
+        },
+        instruction::GetWinRate {},
+    )
+}
+
+/// Simulate and read the `NextInQueueResponse` return data
+pub fn next_in_queue() -> Instruction {
+    build(
+        accounts::GetNextInQueue {
+            queue: pda::verification_queue().0,
+        },
+        instruction::NextInQueue {},
+    )
+}
+
+/// Set `has_external_scores` once an oracle has imported a score for the agent
+pub fn get_composite_reputation(agent: Pubkey, has_external_scores: bool) -> Instruction {
+    build(
+        accounts::GetCompositeReputation {
+            config: pda::config().0,
+            agent,
+            external_scores: has_external_scores.then(|| pda::external_scores(&agent).0),
+        },
+        instruction::GetCompositeReputation {},
+    )
+}
+
+/// Simulate and read the `Vec<AgentSummary>` return data (at most `MAX_SUMMARY_AGENTS`)
+pub fn get_agents_summary(agents: &[Pubkey]) -> Instruction {
+    let mut ix = build(
+        accounts::GetAgentsSummary {
+            config: pda::config().0,
+        },
+        instruction::GetAgentsSummary {},
+    );
+    ix.accounts
+        .extend(agents.iter().map(|key| AccountMeta::new_readonly(*key, false)));
+    ix
+}
+
+/// Simulate and read the `PolicySimulation` return data for `agent` under `params`
+pub fn simulate_policy(agent: Pubkey, params: UpdateConfigParams) -> Instruction {
+    build(
+        accounts::GetAgentView {
+            config: pda::config().0,
+            agent,
+        },
+        instruction::SimulatePolicy { params },
+    )
+}
+
+pub fn migrate_agent(payer: Pubkey, agent: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateAgent {
+            payer,
+            agent,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+            unstake_request: pda::unstake_request(&agent).0,
+        },
+        instruction::MigrateAgent {},
+    )
+}
+
+pub fn create_sponsor_pool(
+    sponsor: Pubkey,
+    deposit: u64,
+    whitelist: RegistrantWhitelist,
+    registrations_per_registrant: u16,
+) -> Instruction {
+    let pool = pda::sponsor_pool(&sponsor).0;
+    build(
+        accounts::CreateSponsorPool {
+            sponsor,
+            pool,
+            vault: pda::sponsor_vault(&pool).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateSponsorPool {
+            deposit,
+            whitelist,
+            registrations_per_registrant,
+        },
+    )
+}
+
+pub fn fund_sponsor_pool(
+    sponsor: Pubkey,
+    amount: u64,
+    whitelist: Option<RegistrantWhitelist>,
+    registrations_per_registrant: Option<u16>,
+) -> Instruction {
+    let pool = pda::sponsor_pool(&sponsor).0;
+    build(
+        accounts::FundSponsorPool {
+            sponsor,
+            pool,
+            vault: pda::sponsor_vault(&pool).0,
+            system_program: system_program::ID,
+        },
+        instruction::FundSponsorPool {
+            amount,
+            whitelist,
+            registrations_per_registrant,
+        },
+    )
+}
+
+pub fn add_sponsored_registrant(sponsor: Pubkey, registrant: Pubkey, registrations: u16) -> Instruction {
+    let pool = pda::sponsor_pool(&sponsor).0;
+    build(
+        accounts::AddSponsoredRegistrant {
+            sponsor,
+            pool,
+            allowance: pda::sponsor_allowance(&pool, &registrant).0,
+            system_program: system_program::ID,
+        },
+        instruction::AddSponsoredRegistrant {
+            registrant,
+            registrations,
+        },
+    )
+}
+
+pub fn withdraw_sponsor_pool(sponsor: Pubkey, amount: u64) -> Instruction {
+    let pool = pda::sponsor_pool(&sponsor).0;
+    build(
+        accounts::WithdrawSponsorPool {
+            sponsor,
+            pool,
+            vault: pda::sponsor_vault(&pool).0,
+            system_program: system_program::ID,
+        },
+        instruction::WithdrawSponsorPool { amount },
+    )
+}
+
+/// `agent_id` must be the registry's current `total_agents`; pass an empty proof
+/// when the registrant already has an allowance account
+#[allow(clippy::too_many_arguments)]
+pub fn register_agent_sponsored(
+    owner: Pubkey,
+    sponsor: Pubkey,
+    agent_id: u64,
+    nft_mint: Pubkey,
+    price_feed: Option<Pubkey>,
+    allowlisted: bool,
+    unique_model: bool,
+    rate_limited: bool,
+    name: String,
+    model_hash: String,
+    capabilities: String,
+    leaf_index: u64,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    let pool = pda::sponsor_pool(&sponsor).0;
+    build(
+        accounts::RegisterAgentSponsored {
+            owner,
+            registry: pda::registry().0,
+            config: pda::config().0,
+            treasury: pda::treasury().0,
+            price_feed,
+            allowlist_entry: allowlisted.then(|| pda::allowlist_entry(&owner).0),
+            model_record: unique_model.then(|| pda::model_record(&model_hash).0),
+            pool,
+            vault: pda::sponsor_vault(&pool).0,
+            allowance: pda::sponsor_allowance(&pool, &owner).0,
+            agent: pda::agent(&owner, agent_id).0,
+            owner_counter: rate_limited.then(|| pda::owner_counter(&owner).0),
+            nft_mint,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterAgentSponsored {
+            name,
+            model_hash,
+            capabilities,
+            leaf_index,
+            proof,
+        },
+    )
+}
+
+pub fn create_agent_multisig(owner: Pubkey, agent_id: u64, owners: Vec<Pubkey>, threshold: u8) -> Instruction {
+    let agent = pda::agent(&owner, agent_id).0;
+    build(
+        accounts::CreateAgentMultisig {
+            owner,
+            agent,
+            multisig: pda::agent_multisig(&agent).0,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::CreateAgentMultisig { owners, threshold },
+    )
+}
+
+/// `cosigners` are the other owners signing this transaction; `indexed_owner` is the
+/// agent's owner when a `Close` or `Archive` action should drop it from their index
+pub fn execute_multisig_action(
+    payer: Pubkey,
+    agent: Pubkey,
+    cosigners: &[Pubkey],
+    recipient: Option<Pubkey>,
+    indexed_owner: Option<Pubkey>,
+    action: MultisigAction,
+) -> Instruction {
+    let archive = matches!(action, MultisigAction::Archive { .. });
+    let mut ix = build(
+        accounts::ExecuteMultisigAction {
+            payer,
+            agent,
+            multisig: pda::agent_multisig(&agent).0,
+            config: pda::config().0,
+            recipient,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+            unstake_request: pda::unstake_request(&agent).0,
+            owner_index: indexed_owner.map(|owner| pda::owner_index(&owner).0),
+            archive: archive.then(|| pda::agent_archive(&agent).0),
+        },
+        instruction::ExecuteMultisigAction { action },
+    );
+    ix.accounts
+        .extend(cosigners.iter().map(|key| AccountMeta::new_readonly(*key, true)));
+    ix
+}
+
+/// `index` must be the multisig's current `proposal_count`
+pub fn propose_multisig_action(
+    proposer: Pubkey,
+    agent: Pubkey,
+    index: u64,
+    action: MultisigAction,
+) -> Instruction {
+    let multisig = pda::agent_multisig(&agent).0;
+    build(
+        accounts::ProposeMultisigAction {
+            proposer,
+            multisig,
+            proposal: pda::multisig_proposal(&multisig, index).0,
+            system_program: system_program::ID,
+        },
+        instruction::ProposeMultisigAction { action },
+    )
+}
+
+pub fn approve_multisig_proposal(owner: Pubkey, agent: Pubkey, index: u64) -> Instruction {
+    let multisig = pda::agent_multisig(&agent).0;
+    build(
+        accounts::ApproveMultisigProposal {
+            owner,
+            multisig,
+            proposal: pda::multisig_proposal(&multisig, index).0,
+        },
+        instruction::ApproveMultisigProposal {},
+    )
+}
+
+/// `indexed_owner` as in `execute_multisig_action`; set `archive` when the proposal is
+/// an `Archive` action
+pub fn execute_multisig_proposal(
+    executor: Pubkey,
+    agent: Pubkey,
+    index: u64,
+    recipient: Option<Pubkey>,
+    indexed_owner: Option<Pubkey>,
+    archive: bool,
+) -> Instruction {
+    let multisig = pda::agent_multisig(&agent).0;
+    build(
+        accounts::ExecuteMultisigProposal {
+            executor,
+            agent,
+            multisig,
+            proposal: pda::multisig_proposal(&multisig, index).0,
+            config: pda::config().0,
+            recipient,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+            unstake_request: pda::unstake_request(&agent).0,
+            owner_index: indexed_owner.map(|owner| pda::owner_index(&owner).0),
+            archive: archive.then(|| pda::agent_archive(&agent).0),
+        },
+        instruction::ExecuteMultisigProposal {},
+    )
+}
+
+pub fn set_governance(
+    admin: Pubkey,
+    active: bool,
+    weighting: VoteWeighting,
+    quorum_weight: u64,
+    majority_bps: u16,
+    voting_period_secs: i64,
+) -> Instruction {
+    build(
+        accounts::SetGovernance {
+            admin,
+            registry: pda::registry().0,
+            admin_log: pda::admin_log().0,
+            governance: pda::governance().0,
+            system_program: system_program::ID,
+        },
+        instruction::SetGovernance {
+            active,
+            weighting,
+            quorum_weight,
+            majority_bps,
+            voting_period_secs,
+        },
+    )
+}
+
+/// `proposal_id` is the governance account's current `proposal_count`
+pub fn create_governance_proposal(
+    proposer: Pubkey,
+    agent: Pubkey,
+    proposal_id: u64,
+    params: UpdateConfigParams,
+    description_hash: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::CreateGovernanceProposal {
+            proposer,
+            agent,
+            governance: pda::governance().0,
+            proposal: pda::governance_proposal(proposal_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateGovernanceProposal {
+            params,
+            description_hash,
+        },
+    )
+}
+
+pub fn cast_governance_vote(
+    voter: Pubkey,
+    agent: Pubkey,
+    agent_id: u64,
+    proposal_id: u64,
+    approve: bool,
+) -> Instruction {
+    let proposal = pda::governance_proposal(proposal_id).0;
+    build(
+        accounts::CastGovernanceVote {
+            voter,
+            agent,
+            governance: pda::governance().0,
+            config: pda::config().0,
+            proposal,
+            vote: pda::governance_vote(&proposal, agent_id).0,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::CastGovernanceVote { approve },
+    )
+}
+
+pub fn execute_governance_proposal(executor: Pubkey, proposal_id: u64) -> Instruction {
+    build(
+        accounts::ExecuteGovernanceProposal {
+            executor,
+            proposal: pda::governance_proposal(proposal_id).0,
+            admin_log: pda::admin_log().0,
+            config: pda::config().0,
+        },
+        instruction::ExecuteGovernanceProposal {},
+    )
+}
+
+pub fn define_capability(admin: Pubkey, id: u8, label_hash: [u8; 32], description_uri: String) -> Instruction {
+    build(
+        accounts::DefineCapability {
+            admin,
+            registry: pda::registry().0,
+            config: pda::config().0,
+            capability: pda::capability(id).0,
+            system_program: system_program::ID,
+        },
+        instruction::DefineCapability {
+            id,
+            label_hash,
+            description_uri,
+        },
+    )
+}
+
+pub fn retire_capability(admin: Pubkey, id: u8) -> Instruction {
+    build(
+        accounts::RetireCapability {
+            admin,
+            registry: pda::registry().0,
+            config: pda::config().0,
+            capability: pda::capability(id).0,
+        },
+        instruction::RetireCapability {},
+    )
+}
+
+pub fn set_capability_flags(owner: Pubkey, agent_id: u64, flags: u64) -> Instruction {
+    let agent = pda::agent(&owner, agent_id).0;
+    build(
+        accounts::SetCapabilityFlags {
+            owner,
+            config: pda::config().0,
+            agent,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::SetCapabilityFlags { flags },
+    )
+}
+
+/// `authority` is the agent owner or the admin
+pub fn freeze_fields(authority: Pubkey, agent: Pubkey, fields: u8) -> Instruction {
+    build(
+        accounts::FreezeFields {
+            authority,
+            registry: pda::registry().0,
+            agent,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::FreezeFields { fields },
+    )
+}
+
+/// `agent`'s Merkle audit batch `batch_index` becomes an eval batch vouched for by `evaluator`
+pub fn tag_eval_batch(evaluator: Pubkey, agent: Pubkey, batch_index: u64) -> Instruction {
+    let audit_root = pda::merkle_root(&agent, batch_index).0;
+    build(
+        accounts::TagEvalBatch {
+            evaluator,
+            reputation_authority: pda::reputation_authority(&evaluator).0,
+            audit_root,
+            eval_batch: pda::eval_batch(&audit_root).0,
+            system_program: system_program::ID,
+        },
+        instruction::TagEvalBatch {},
+    )
+}
+
+/// `proof` is the batch path for `EvalBatch::leaf_hash(agent, capability_id)` at `leaf_index`
+pub fn prove_capability(
+    agent: Pubkey,
+    batch_index: u64,
+    capability_id: u8,
+    leaf_index: u64,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    let audit_root = pda::merkle_root(&agent, batch_index).0;
+    build(
+        accounts::ProveCapability {
+            agent,
+            audit_root,
+            eval_batch: pda::eval_batch(&audit_root).0,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::ProveCapability {
+            capability_id,
+            leaf_index,
+            proof,
+        },
+    )
+}
+
+pub fn set_zk_verifier(admin: Pubkey, circuit_id: u32, vk: ZkVerifyingKey, active: bool) -> Instruction {
+    build(
+        accounts::SetZkVerifier {
+            admin,
+            registry: pda::registry().0,
+            admin_log: pda::admin_log().0,
+            verifier: pda::zk_verifier(circuit_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetZkVerifier {
+            circuit_id,
+            vk,
+            active,
+        },
+    )
+}
+
+/// Public inputs are the agent's model commitment and `output_commitment`
+pub fn verify_inference(
+    payer: Pubkey,
+    agent: Pubkey,
+    circuit_id: u32,
+    proof: Groth16Proof,
+    output_commitment: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::VerifyInference {
+            payer,
+            agent,
+            verifier: pda::zk_verifier(circuit_id).0,
+            inference: pda::zk_inference(circuit_id, &output_commitment).0,
+            system_program: system_program::ID,
+            watchers: pda::agent_watchers(&agent).0,
+        },
+        instruction::VerifyInference {
+            proof,
+            output_commitment,
+        },
+    )
+}
+
+pub fn create_service_listing(
+    owner: Pubkey,
+    agent_id: u64,
+    listing_id: u64,
+    capability_flags: u64,
+    pricing: PricingModel,
+    price_lamports: u64,
+    endpoint_hash: [u8; 32],
+) -> Instruction {
+    let agent = pda::agent(&owner, agent_id).0;
+    build(
+        accounts::CreateServiceListing {
+            owner,
+            agent,
+            listing: pda::service_listing(&agent, listing_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateServiceListing {
+            listing_id,
+            capability_flags,
+            pricing,
+            price_lamports,
+            endpoint_hash,
+        },
+    )
+}
+
+pub fn update_service_listing(
+    owner: Pubkey,
+    agent_id: u64,
+    listing_id: u64,
+    params: UpdateServiceListingParams,
+) -> Instruction {
+    let agent = pda::agent(&owner, agent_id).0;
+    build(
+        accounts::UpdateServiceListing {
+            owner,
+            agent,
+            listing: pda::service_listing(&agent, listing_id).0,
+        },
+        instruction::UpdateServiceListing { params },
+    )
+}
+
+pub fn close_service_listing(owner: Pubkey, agent_id: u64, listing_id: u64) -> Instruction {
+    let agent = pda::agent(&owner, agent_id).0;
+    build(
+        accounts::CloseServiceListing {
+            owner,
+            agent,
+            listing: pda::service_listing(&agent, listing_id).0,
+        },
+        instruction::CloseServiceListing {},
+    )
+}
+
+/// `agent_owner` receives the payment for `calls` at the listing's per-call price
+pub fn purchase_allowance(
+    consumer: Pubkey,
+    agent: Pubkey,
+    agent_owner: Pubkey,
+    listing: Pubkey,
+    calls: u64,
+    duration_secs: i64,
+    max_cost: u64,
+) -> Instruction {
+    build(
+        accounts::PurchaseAllowance {
+            consumer,
+            agent,
+            listing,
+            allowance: pda::allowance(&listing, &consumer).0,
+            owner: agent_owner,
+            system_program: system_program::ID,
+        },
+        instruction::PurchaseAllowance {
+            calls,
+            duration_secs,
+            max_cost,
+        },
+    )
+}
+
+/// `meter` is the listing's metering key (or the agent owner when none is set)
+pub fn meter_usage(meter: Pubkey, agent: Pubkey, listing: Pubkey, consumer: Pubkey, calls: u64) -> Instruction {
+    build(
+        accounts::MeterUsage {
+            meter,
+            agent,
+            listing,
+            allowance: pda::allowance(&listing, &consumer).0,
+        },
+        instruction::MeterUsage { calls },
+    )
+}
+
+pub fn close_allowance(consumer: Pubkey, listing: Pubkey) -> Instruction {
+    build(
+        accounts::CloseAllowance {
+            consumer,
+            allowance: pda::allowance(&listing, &consumer).0,
+        },
+        instruction::CloseAllowance {},
+    )
+}
+
+pub fn open_channel(consumer: Pubkey, agent: Pubkey, channel_id: u64, deposit: u64) -> Instruction {
+    build(
+        accounts::OpenChannel {
+            consumer,
+            agent,
+            channel: pda::payment_channel(&agent, &consumer, channel_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::OpenChannel { channel_id, deposit },
+    )
+}
+
+pub fn fund_channel(consumer: Pubkey, agent: Pubkey, channel_id: u64, amount: u64) -> Instruction {
+    build(
+        accounts::FundChannel {
+            consumer,
+            channel: pda::payment_channel(&agent, &consumer, channel_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::FundChannel { amount },
+    )
+}
+
+/// Pair with [`ed25519_verify`] of the consumer's signature over
+/// `channel_voucher_message(channel, opened_at, cumulative)`, placed immediately before
+pub fn redeem_voucher(owner: Pubkey, channel: Pubkey, cumulative: u64) -> Instruction {
+    build(
+        accounts::RedeemVoucher {
+            owner,
+            channel,
+            instructions_sysvar: solana_sdk_ids::sysvar::instructions::ID,
+        },
+        instruction::RedeemVoucher { cumulative },
+    )
+}
+
+pub fn request_channel_close(consumer: Pubkey, channel: Pubkey) -> Instruction {
+    build(
+        accounts::RequestChannelClose { consumer, channel },
+        instruction::RequestChannelClose {},
+    )
+}
+
+/// `caller` is the agent owner, or the consumer once its close request has matured
+pub fn close_channel(caller: Pubkey, consumer: Pubkey, channel: Pubkey) -> Instruction {
+    build(
+        accounts::CloseChannel {
+            caller,
+            consumer,
+            channel,
+        },
+        instruction::CloseChannel {},
+    )
+}
+
+/// Jobs are addressed by (listing, consumer, job_id); `agent` is the listing's agent
+pub struct JobRef {
+    pub agent: Pubkey,
+    pub agent_owner: Pubkey,
+    pub listing: Pubkey,
+    pub consumer: Pubkey,
+    pub job_id: u64,
+}
+
+impl JobRef {
+    fn address(&self) -> Pubkey {
+        pda::job(&self.listing, &self.consumer, self.job_id).0
+    }
+}
+
+pub fn create_job(
+    job: &JobRef,
+    request_hash: [u8; 32],
+    accept_window: i64,
+    delivery_window: i64,
+) -> Instruction {
+    build(
+        accounts::CreateJob {
+            consumer: job.consumer,
+            agent: job.agent,
+            listing: job.listing,
+            job: job.address(),
+            system_program: system_program::ID,
+        },
+        instruction::CreateJob {
+            job_id: job.job_id,
+            request_hash,
+            accept_window,
+            delivery_window,
+        },
+    )
+}
+
+pub fn accept_job(job: &JobRef) -> Instruction {
+    build(
+        accounts::AcceptJob {
+            owner: job.agent_owner,
+            agent: job.agent,
+            job: job.address(),
+        },
+        instruction::AcceptJob {},
+    )
+}
+
+pub fn deliver_job(job: &JobRef, deliverable_hash: [u8; 32]) -> Instruction {
+    build(
+        accounts::DeliverJob {
+            owner: job.agent_owner,
+            agent: job.agent,
+            job: job.address(),
+        },
+        instruction::DeliverJob { deliverable_hash },
+    )
+}
+
+/// `caller` is the consumer, or the owner once the review window has lapsed
+pub fn settle_job(caller: Pubkey, job: &JobRef, record_history: bool) -> Instruction {
+    build(
+        accounts::SettleJob {
+            caller,
+            config: pda::config().0,
+            agent: job.agent,
+            job: job.address(),
+            owner: job.agent_owner,
+            consumer: job.consumer,
+            history: record_history.then(|| pda::reputation_history(&job.agent).0),
+            hooks: pda::reputation_hooks(&job.agent).0,
+            watchers: pda::agent_watchers(&job.agent).0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        instruction::SettleJob {},
+    )
+}
+
+pub fn dispute_job(job: &JobRef) -> Instruction {
+    build(
+        accounts::DisputeJob {
+            consumer: job.consumer,
+            job: job.address(),
+        },
+        instruction::DisputeJob {},
+    )
+}
+
+pub fn resolve_job_dispute(
+    admin: Pubkey,
+    job: &JobRef,
+    in_favor_of_agent: bool,
+    record_history: bool,
+) -> Instruction {
+    build(
+        accounts::ResolveJobDispute {
+            admin,
+            registry: pda::registry().0,
+            config: pda::config().0,
+            agent: job.agent,
+            job: job.address(),
+            owner: job.agent_owner,
+            consumer: job.consumer,
+            history: record_history.then(|| pda::reputation_history(&job.agent).0),
+            hooks: pda::reputation_hooks(&job.agent).0,
+            watchers: pda::agent_watchers(&job.agent).0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        instruction::ResolveJobDispute { in_favor_of_agent },
+    )
+}
+
+pub fn cancel_job(job: &JobRef, record_history: bool) -> Instruction {
+    build(
+        accounts::CancelJob {
+            caller: job.consumer,
+            config: pda::config().0,
+            agent: job.agent,
+            job: job.address(),
+            owner: job.agent_owner,
+            consumer: job.consumer,
+            history: record_history.then(|| pda::reputation_history(&job.agent).0),
+            hooks: pda::reputation_hooks(&job.agent).0,
+            watchers: pda::agent_watchers(&job.agent).0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        instruction::CancelJob {},
+    )
+}
+
+pub fn void_job(caller: Pubkey, job: &JobRef) -> Instruction {
+    build(
+        accounts::VoidJob {
+            caller,
+            job: job.address(),
+            agent: job.agent,
+            consumer: job.consumer,
+        },
+        instruction::VoidJob {},
+    )
+}
+
+pub fn submit_review(job: &JobRef, rating: u8, comment_hash: [u8; 32]) -> Instruction {
+    let job_address = job.address();
+    build(
+        accounts::SubmitReview {
+            consumer: job.consumer,
+            job: job_address,
+            review: pda::review(&job_address).0,
+            system_program: system_program::ID,
+        },
+        instruction::SubmitReview {
+            rating,
+            comment_hash,
+        },
+    )
+}
+
+pub fn respond_to_review(job: &JobRef, response_hash: [u8; 32]) -> Instruction {
+    build(
+        accounts::RespondToReview {
+            owner: job.agent_owner,
+            agent: job.agent,
+            review: pda::review(&job.address()).0,
+        },
+        instruction::RespondToReview { response_hash },
+    )
+}
+
+/// Seed `count` demo agents starting at the registry's next id `first_agent_id`
+/// (requires the program and this crate built with the `devnet` feature)
+#[cfg(feature = "devnet")]
+pub fn seed_demo_data(admin: Pubkey, first_agent_id: u64, count: u64) -> Instruction {
+    let mut ix = build(
+        accounts::SeedDemoData {
+            admin,
+            registry: pda::registry().0,
+            config: pda::config().0,
+            system_program: system_program::ID,
+        },
+        instruction::SeedDemoData {},
+    );
+    for agent_id in first_agent_id..first_agent_id + count {
+        let agent = pda::agent(&admin, agent_id).0;
+        ix.accounts.push(AccountMeta::new(agent, false));
+        ix.accounts.push(AccountMeta::new(pda::audit_summary(&agent).0, false));
+    }
+    ix
+}
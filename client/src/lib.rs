@@ -0,0 +1,19 @@
+//! Off-chain client for the Agent Proof-of-Intelligence registry program.
+//!
+//! - [`pda`]: PDA derivation for every registry account
+//! - [`instructions`]: typed instruction builders
+//! - [`accounts`]: account fetch + deserialize helpers (RPC-agnostic)
+//! - [`errors`]: decoding of the structured error context in transaction logs
+//! - [`merkle`]: Merkle tree construction matching the on-chain hashing
+//! - `test_utils` (feature `test-utils`): account fixtures and scenario builders for tests
+
+pub mod accounts;
+pub mod errors;
+pub mod instructions;
+pub mod merkle;
+pub mod pda;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
+pub use agent_registry::state;
+pub use agent_registry::ID as PROGRAM_ID;
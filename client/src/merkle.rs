@@ -0,0 +1,183 @@
+//! Merkle tree construction matching the on-chain hashing
+//!
+//! - Batch trees (`store_merkle_audit`, registry snapshots): SHA256(0xff || left || right),
+//!   odd levels duplicate the last node, a single leaf is its own root
+//!   (same as `agent/poi/merkle_audit.py`); proofs are `batch_depth(leaf count)` long
+//! - Incremental tree (`append_audit_leaf`): fixed depth, empty slots are zero leaves
+//!   (also the per-agent challenge stream, whose leaves are `Challenge::result_leaf`)
+//! - Audit summary running root: SHA256(running || batch root), folded from zero
+//! - Leaf encodings and domain separators: [`agent_registry::merkle`], re-exported here;
+//!   audit entries are hashed with [`AuditLeaf`]; both crates' tests check them against
+//!   the fixed vectors in `agent_registry::merkle::vectors`
+
+use agent_registry::state::{hash_pair, AgentAccount, RegistrySnapshot, SponsorPool, AUDIT_TREE_DEPTH};
+use anchor_lang::prelude::Pubkey;
+
+pub use agent_registry::merkle::{
+    batch_depth, verify_batch_proof, verify_proof, AuditLeaf, AUDIT_ENTRY_LEAF_PREFIX,
+    CHALLENGE_RESULT_LEAF_PREFIX, EVAL_LEAF_PREFIX, NODE_PREFIX, STANDING_LEAF_PREFIX,
+};
+
+/// Root of a batch tree (zero hash for an empty batch)
+pub fn batch_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level[0]
+}
+
+/// Sibling path for `index` in a batch tree (bottom-up)
+pub fn batch_proof(leaves: &[[u8; 32]], mut index: usize) -> Option<Vec<[u8; 32]>> {
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        let sibling = if index & 1 == 0 {
+            *level.get(index + 1).unwrap_or(&level[index])
+        } else {
+            level[index - 1]
+        };
+        proof.push(sibling);
+        level = next_level(&level);
+        index /= 2;
+    }
+    Some(proof)
+}
+
+/// `MerkleAuditSummary::running_root` after folding in `roots` in batch order
+pub fn running_root(roots: &[[u8; 32]]) -> [u8; 32] {
+    roots.iter().fold([0u8; 32], |running, root| hash_pair(&running, root))
+}
+
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+        .collect()
+}
+
+/// Zero hashes per level of the incremental tree (index 0 = empty leaf)
+pub fn zero_hashes() -> [[u8; 32]; AUDIT_TREE_DEPTH + 1] {
+    let mut zeros = [[0u8; 32]; AUDIT_TREE_DEPTH + 1];
+    for level in 1..=AUDIT_TREE_DEPTH {
+        zeros[level] = hash_pair(&zeros[level - 1], &zeros[level - 1]);
+    }
+    zeros
+}
+
+/// Root of the on-chain incremental tree after appending `leaves` in order
+pub fn audit_tree_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    let zeros = zero_hashes();
+    let mut level = leaves.to_vec();
+    for zero in zeros.iter().take(AUDIT_TREE_DEPTH) {
+        if level.is_empty() {
+            return zeros[AUDIT_TREE_DEPTH];
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(zero)))
+            .collect();
+    }
+    level[0]
+}
+
+/// Sibling path (length `AUDIT_TREE_DEPTH`) for `index` in the incremental tree
+pub fn audit_tree_proof(leaves: &[[u8; 32]], mut index: usize) -> Option<Vec<[u8; 32]>> {
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let zeros = zero_hashes();
+    let mut proof = Vec::with_capacity(AUDIT_TREE_DEPTH);
+    let mut level = leaves.to_vec();
+    for zero in zeros.iter().take(AUDIT_TREE_DEPTH) {
+        let sibling = if index & 1 == 0 {
+            *level.get(index + 1).unwrap_or(zero)
+        } else {
+            level[index - 1]
+        };
+        proof.push(sibling);
+        level = level
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(zero)))
+            .collect();
+        index /= 2;
+    }
+    Some(proof)
+}
+
+/// Registry snapshot leaves, in the order given (sort by agent ID for a canonical tree)
+pub fn registry_snapshot_leaves(agents: &[(Pubkey, AgentAccount)]) -> Vec<[u8; 32]> {
+    agents
+        .iter()
+        .map(|(address, agent)| {
+            RegistrySnapshot::leaf_hash(address, agent.reputation_score, agent.verified)
+        })
+        .collect()
+}
+
+/// Sponsor whitelist leaves, in the order given (build with `batch_root` / `batch_proof`)
+pub fn sponsor_whitelist_leaves(registrants: &[Pubkey]) -> Vec<[u8; 32]> {
+    registrants.iter().map(SponsorPool::registrant_leaf).collect()
+}
+
+/// Audit entry leaves, in batch order (build with `batch_root` / `batch_proof`)
+pub fn audit_leaves(entries: &[AuditLeaf]) -> Vec<[u8; 32]> {
+    entries.iter().map(AuditLeaf::hash).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agent_registry::merkle::vectors::*;
+
+    fn batch_entries() -> Vec<AuditLeaf> {
+        (0..3).map(batch_entry).collect()
+    }
+
+    #[test]
+    fn leaf_and_node_hashes_match_program() {
+        assert_eq!(AUDIT_LEAF.encode(), AUDIT_LEAF_ENCODED);
+        assert_eq!(audit_leaves(&[AUDIT_LEAF]), vec![AUDIT_LEAF_HASH]);
+        assert_eq!(hash_pair(&[0x01; 32], &[0x02; 32]), PAIR_HASH);
+    }
+
+    #[test]
+    fn batch_tree_matches_vectors() {
+        let leaves = audit_leaves(&batch_entries());
+        assert_eq!(leaves, BATCH_LEAVES);
+        assert_eq!(batch_root(&leaves), BATCH_ROOT);
+        assert_eq!(batch_proof(&leaves, 2).unwrap(), BATCH_PROOF_2);
+        assert_eq!(batch_proof(&leaves, 3), None);
+    }
+
+    #[test]
+    fn batch_proofs_verify_on_chain() {
+        for &(leaf_count, depth) in BATCH_DEPTHS.iter().filter(|(count, _)| *count <= 9) {
+            let entries: Vec<AuditLeaf> = (0..leaf_count as u8).map(batch_entry).collect();
+            let leaves = audit_leaves(&entries);
+            let root = batch_root(&leaves);
+            for (index, leaf) in leaves.iter().enumerate() {
+                let proof = batch_proof(&leaves, index).unwrap();
+                assert_eq!(proof.len(), depth, "{} leaves", leaf_count);
+                assert!(verify_batch_proof(&root, *leaf, index as u64, leaf_count, &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn single_leaf_is_its_own_root() {
+        assert_eq!(batch_root(&[AUDIT_LEAF_HASH]), AUDIT_LEAF_HASH);
+        assert_eq!(batch_proof(&[AUDIT_LEAF_HASH], 0).unwrap(), Vec::<[u8; 32]>::new());
+        assert!(verify_batch_proof(&AUDIT_LEAF_HASH, AUDIT_LEAF_HASH, 0, 1, &[]));
+        assert_eq!(batch_root(&[]), [0; 32]);
+    }
+}
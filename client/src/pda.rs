@@ -0,0 +1,399 @@
+//! PDA derivation helpers (seeds mirror the program's account constraints)
+
+use agent_registry::state::{
+    AdminActionLog, AgentAccount, AgentArchive, AgentAuditSummary, AgentCounters, AgentDid, AgentTransfer, AgentWatchers, Allowance, AllowlistEntry, Attestation, Attester, AgentMultisig, BadgeClaim, BadgeKind, BadgeMint, Benchmark, BenchmarkEvaluator, BenchmarkScore, CapabilityDefinition, CredentialAttestation, CredentialIssuer, MultisigProposal, PaymentChannel, AuditEntry, AuditDelegate, AuditMerkleTree, AuditSelection, Challenge, ChallengeEvidence, ChallengeTemplate,
+    EpochRewardClaim, EpochRewards, EpochSnapshot, EvalBatch, ExternalScores, Governance, GovernanceProposal, GovernanceVote, ReputationOracle, Job, Judge, JudgeVote, MerkleAuditRoot, MerkleAuditSummary, ModelIndex, ModelRecord, Moderator, OwnerCounter, OwnerIndex, TagIndex, RegistryMigration, RegistryNamespace, ReferrerStats, RegistryConfig, ReputationAuthority, ReputationHistory, ReputationHooks,
+    RegistrySnapshot, RegistryState, Review, ServiceListing, SponsorAllowance, SponsorPool,
+    UnstakeRequest, VerificationQueue, VerificationRequest, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, ZkInference, ZkVerifier,
+};
+use agent_registry::constants::HOOK_AUTHORITY_SEED;
+use agent_registry::ID;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+pub fn registry() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RegistryState::SEED_PREFIX], &ID)
+}
+
+pub fn config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RegistryConfig::SEED_PREFIX], &ID)
+}
+
+/// Upgradeable-loader program data account of the registry program
+pub fn program_data() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID)
+}
+
+pub fn admin_log() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AdminActionLog::SEED_PREFIX], &ID)
+}
+
+pub fn treasury() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RegistryState::TREASURY_SEED], &ID)
+}
+
+pub fn agent(owner: &Pubkey, agent_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[AgentAccount::SEED_PREFIX, owner.as_ref(), &agent_id.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn challenge(agent: &Pubkey, challenger: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Challenge::SEED_PREFIX,
+            agent.as_ref(),
+            challenger.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn challenge_template(spec_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ChallengeTemplate::SEED_PREFIX, spec_hash.as_ref()], &ID)
+}
+
+pub fn challenge_evidence(challenge: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ChallengeEvidence::SEED_PREFIX, challenge.as_ref()], &ID)
+}
+
+pub fn judge(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Judge::SEED_PREFIX, authority.as_ref()], &ID)
+}
+
+pub fn judge_vote(challenge: &Pubkey, judge_authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[JudgeVote::SEED_PREFIX, challenge.as_ref(), judge_authority.as_ref()],
+        &ID,
+    )
+}
+
+pub fn audit_summary(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AgentAuditSummary::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+pub fn audit_entry(agent: &Pubkey, audit_index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[AuditEntry::SEED_PREFIX, agent.as_ref(), &audit_index.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn merkle_summary(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MerkleAuditSummary::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+pub fn merkle_root(agent: &Pubkey, batch_index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MerkleAuditRoot::SEED_PREFIX, agent.as_ref(), &batch_index.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn eval_batch(audit_root: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EvalBatch::SEED_PREFIX, audit_root.as_ref()], &ID)
+}
+
+pub fn audit_tree(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AuditMerkleTree::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+/// The agent's challenge-result stream (an audit tree appended to on challenge resolution)
+pub fn challenge_stream(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[AuditMerkleTree::SEED_PREFIX, agent.as_ref(), AuditMerkleTree::CHALLENGE_STREAM],
+        &ID,
+    )
+}
+
+pub fn audit_delegate(agent: &Pubkey, delegate: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AuditDelegate::SEED_PREFIX, agent.as_ref(), delegate.as_ref()], &ID)
+}
+
+pub fn audit_selection(round: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AuditSelection::SEED_PREFIX, &round.to_le_bytes()], &ID)
+}
+
+pub fn epoch_snapshot(agent: &Pubkey, epoch: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[EpochSnapshot::SEED_PREFIX, agent.as_ref(), &epoch.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn epoch_rewards(epoch: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EpochRewards::SEED_PREFIX, &epoch.to_le_bytes()], &ID)
+}
+
+pub fn epoch_reward_claim(agent: &Pubkey, epoch: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[EpochRewardClaim::SEED_PREFIX, agent.as_ref(), &epoch.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn registry_snapshot(index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RegistrySnapshot::SEED_PREFIX, &index.to_le_bytes()], &ID)
+}
+
+pub fn sponsor_pool(sponsor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SponsorPool::SEED_PREFIX, sponsor.as_ref()], &ID)
+}
+
+pub fn sponsor_vault(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SponsorPool::VAULT_SEED, pool.as_ref()], &ID)
+}
+
+pub fn sponsor_allowance(pool: &Pubkey, registrant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SponsorAllowance::SEED_PREFIX, pool.as_ref(), registrant.as_ref()],
+        &ID,
+    )
+}
+
+pub fn unstake_request(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[UnstakeRequest::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+pub fn referrer(referrer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ReferrerStats::SEED_PREFIX, referrer.as_ref()], &ID)
+}
+
+pub fn agent_multisig(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AgentMultisig::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+pub fn multisig_proposal(multisig: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MultisigProposal::SEED_PREFIX, multisig.as_ref(), &index.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn governance() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Governance::SEED_PREFIX], &ID)
+}
+
+pub fn governance_proposal(proposal_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GovernanceProposal::SEED_PREFIX, &proposal_id.to_le_bytes()], &ID)
+}
+
+pub fn governance_vote(proposal: &Pubkey, agent_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[GovernanceVote::SEED_PREFIX, proposal.as_ref(), &agent_id.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn capability(id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CapabilityDefinition::SEED_PREFIX, &[id]], &ID)
+}
+
+pub fn service_listing(agent: &Pubkey, listing_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ServiceListing::SEED_PREFIX, agent.as_ref(), &listing_id.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn allowance(listing: &Pubkey, consumer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Allowance::SEED_PREFIX, listing.as_ref(), consumer.as_ref()], &ID)
+}
+
+pub fn payment_channel(agent: &Pubkey, consumer: &Pubkey, channel_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PaymentChannel::SEED_PREFIX,
+            agent.as_ref(),
+            consumer.as_ref(),
+            &channel_id.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn job(listing: &Pubkey, consumer: &Pubkey, job_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Job::SEED_PREFIX, listing.as_ref(), consumer.as_ref(), &job_id.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn review(job: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Review::SEED_PREFIX, job.as_ref()], &ID)
+}
+
+pub fn verification_request(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VerificationRequest::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+pub fn verification_queue() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VerificationQueue::SEED_PREFIX], &ID)
+}
+
+pub fn attester(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Attester::SEED_PREFIX, authority.as_ref()], &ID)
+}
+
+pub fn attestation(agent: &Pubkey, attester: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Attestation::SEED_PREFIX, agent.as_ref(), attester.as_ref()],
+        &ID,
+    )
+}
+
+pub fn namespace(name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[RegistryNamespace::SEED_PREFIX, RegistryNamespace::hash(name).as_ref()],
+        &ID,
+    )
+}
+
+/// `from` is the deprecated RegistryMigration, RegistryNamespace, or the program ID for the deployment
+pub fn registry_migration(agent: &Pubkey, from: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[RegistryMigration::SEED_PREFIX, agent.as_ref(), from.as_ref()],
+        &ID,
+    )
+}
+
+pub fn moderator(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Moderator::SEED_PREFIX, authority.as_ref()], &ID)
+}
+
+pub fn reputation_authority(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ReputationAuthority::SEED_PREFIX, authority.as_ref()], &ID)
+}
+
+pub fn allowlist_entry(registrant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AllowlistEntry::SEED_PREFIX, registrant.as_ref()], &ID)
+}
+
+pub fn credential_issuer(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CredentialIssuer::SEED_PREFIX, authority.as_ref()], &ID)
+}
+
+pub fn credential(subject: &Pubkey, issuer: &Pubkey, credential_type: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            CredentialAttestation::SEED_PREFIX,
+            subject.as_ref(),
+            issuer.as_ref(),
+            &credential_type.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn benchmark_evaluator(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BenchmarkEvaluator::SEED_PREFIX, authority.as_ref()], &ID)
+}
+
+pub fn benchmark(benchmark_id: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Benchmark::SEED_PREFIX, &benchmark_id.to_le_bytes()], &ID)
+}
+
+pub fn benchmark_score(agent: &Pubkey, benchmark_id: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BenchmarkScore::SEED_PREFIX, agent.as_ref(), &benchmark_id.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn agent_did(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AgentDid::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+pub fn reputation_oracle(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ReputationOracle::SEED_PREFIX, authority.as_ref()], &ID)
+}
+
+pub fn external_scores(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ExternalScores::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+pub fn reputation_history(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ReputationHistory::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+pub fn agent_archive(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AgentArchive::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+pub fn agent_transfer(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AgentTransfer::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+pub fn agent_counters(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AgentCounters::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+pub fn reputation_hooks(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ReputationHooks::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+/// Derived under the hook program, not the registry
+pub fn hook_authority(hook_program: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[HOOK_AUTHORITY_SEED], hook_program)
+}
+
+pub fn agent_watchers(agent: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AgentWatchers::SEED_PREFIX, agent.as_ref()], &ID)
+}
+
+pub fn model_record(model_hash: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ModelRecord::SEED_PREFIX, &ModelRecord::digest(model_hash)], &ID)
+}
+
+pub fn model_index(model_hash: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ModelIndex::SEED_PREFIX, &ModelRecord::digest(model_hash)], &ID)
+}
+
+pub fn owner_index(owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[OwnerIndex::SEED_PREFIX, owner.as_ref()], &ID)
+}
+
+pub fn owner_counter(owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[OwnerCounter::SEED_PREFIX, owner.as_ref()], &ID)
+}
+
+pub fn tag_index(tag: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TagIndex::SEED_PREFIX, tag.as_bytes()], &ID)
+}
+
+pub fn badge_mint(kind: BadgeKind) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BadgeMint::SEED_PREFIX, &[kind.seed()]], &ID)
+}
+
+pub fn badge_claim(agent_id: u64, kind: BadgeKind) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BadgeClaim::SEED_PREFIX, &agent_id.to_le_bytes(), &[kind.seed()]],
+        &ID,
+    )
+}
+
+/// `wallet`'s associated Token-2022 account for a badge kind
+pub fn badge_token_account(wallet: &Pubkey, kind: BadgeKind) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), TOKEN_2022_PROGRAM_ID.as_ref(), badge_mint(kind).0.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+}
+
+pub fn zk_verifier(circuit_id: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ZkVerifier::SEED_PREFIX, &circuit_id.to_le_bytes()], &ID)
+}
+
+pub fn zk_inference(circuit_id: u32, output_commitment: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ZkInference::SEED_PREFIX, &circuit_id.to_le_bytes(), output_commitment],
+        &ID,
+    )
+}
+
+/// Signer PDA Anchor uses for `emit_cpi!` self-invocations
+pub fn event_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"__event_authority"], &ID)
+}
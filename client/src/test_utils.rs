@@ -0,0 +1,365 @@
+//! Fixtures for integration tests (LiteSVM, solana-program-test, banks)
+//!
+//! Builders produce ready-to-load [`FixtureAccount`]s (address, owner, lamports,
+//! serialized data) so CPI tests can start from a registered, verified or audited
+//! agent without replaying the instructions that create one. Load them with the
+//! harness's `set_account` / `add_account`, then exercise your program against them.
+//!
+//! Enabled by the `test-utils` feature.
+
+use agent_registry::state::{
+    ActionType, AgentAccount, AgentAuditSummary, AuditEntry, AuditMerkleTree, Challenge,
+    ChallengeStatus, ChallengeType, MerkleAuditRoot, MerkleAuditSummary, RegistryState, RiskLevel,
+};
+use agent_registry::ID;
+use anchor_lang::prelude::{Pubkey, Rent};
+use anchor_lang::{AccountSerialize, Space};
+
+use crate::{merkle, pda};
+
+/// Timestamp fixtures are stamped with unless overridden (2023-11-14)
+pub const FIXTURE_TIMESTAMP: i64 = 1_700_000_000;
+
+/// A registry-owned account, ready to be loaded into a test validator
+#[derive(Clone, Debug)]
+pub struct FixtureAccount {
+    pub address: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+}
+
+impl FixtureAccount {
+    /// Serialize `value` (with discriminator) into a rent-exempt account of `space` bytes
+    pub fn new<T: AccountSerialize>(address: Pubkey, value: &T, space: usize) -> Self {
+        let mut data = Vec::with_capacity(space);
+        value
+            .try_serialize(&mut data)
+            .expect("fixture account serializes");
+        assert!(data.len() <= space, "fixture account exceeds its space");
+        data.resize(space, 0);
+
+        Self {
+            address,
+            owner: ID,
+            lamports: Rent::default().minimum_balance(space),
+            data,
+        }
+    }
+}
+
+/// Registry singleton with `admin` and `total_agents`
+pub fn registry(admin: Pubkey, total_agents: u64) -> FixtureAccount {
+    let (address, bump) = pda::registry();
+    let state = RegistryState {
+        admin,
+        total_agents,
+        collection: Pubkey::default(),
+        collection_initialized: false,
+        bump,
+    };
+    FixtureAccount::new(address, &state, 8 + RegistryState::INIT_SPACE)
+}
+
+/// Deterministic distinct leaves (SHA256 of the little-endian index)
+pub fn leaves(count: usize) -> Vec<[u8; 32]> {
+    (0..count as u64)
+        .map(|i| solana_sha256_hasher::hash(&i.to_le_bytes()).to_bytes())
+        .collect()
+}
+
+/// Batch root and the proof for every leaf, as `store_merkle_audit` callers need
+pub fn batch_with_proofs(leaves: &[[u8; 32]]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+    let proofs = (0..leaves.len())
+        .map(|i| merkle::batch_proof(leaves, i).expect("index in range"))
+        .collect();
+    (merkle::batch_root(leaves), proofs)
+}
+
+/// Audit entry to seed an agent's history with
+#[derive(Clone, Copy, Debug)]
+pub struct AuditRecord {
+    pub action_type: ActionType,
+    pub context_risk: u8,
+}
+
+impl AuditRecord {
+    pub fn new(action_type: ActionType, context_risk: u8) -> Self {
+        Self { action_type, context_risk }
+    }
+}
+
+/// Scenario builder for one agent and the accounts around it
+///
+/// ```ignore
+/// let accounts = AgentFixture::new(owner, 0)
+///     .verified()
+///     .with_challenges(challenger, ChallengeType::Accuracy, 8, 2)
+///     .with_audits(&[AuditRecord::new(ActionType::AgentUpdated, 0); 5])
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct AgentFixture {
+    agent: AgentAccount,
+    address: Pubkey,
+    now: i64,
+    challenges: Vec<FixtureAccount>,
+    audits: Vec<AuditRecord>,
+    merkle_batches: Vec<Vec<[u8; 32]>>,
+    tree_leaves: Option<Vec<[u8; 32]>>,
+}
+
+impl AgentFixture {
+    /// Freshly registered agent, as `register_agent` leaves it under the default config
+    pub fn new(owner: Pubkey, agent_id: u64) -> Self {
+        let (address, bump) = pda::agent(&owner, agent_id);
+        let agent = AgentAccount {
+            agent_id,
+            owner,
+            name: format!("fixture-agent-{agent_id}"),
+            model_hash: format!("sha256:{}", "0".repeat(64)),
+            capabilities: "analysis".to_string(),
+            reputation_score: AgentAccount::INITIAL_REPUTATION,
+            tier: AgentAccount::tier_for(AgentAccount::INITIAL_REPUTATION),
+            created_at: FIXTURE_TIMESTAMP,
+            updated_at: FIXTURE_TIMESTAMP,
+            bump,
+            version: AgentAccount::CURRENT_VERSION,
+            ..AgentAccount::default()
+        };
+
+        Self {
+            agent,
+            address,
+            now: FIXTURE_TIMESTAMP,
+            challenges: Vec::new(),
+            audits: Vec::new(),
+            merkle_batches: Vec::new(),
+            tree_leaves: None,
+        }
+    }
+
+    /// Agent PDA address
+    pub fn address(&self) -> Pubkey {
+        self.address
+    }
+
+    /// Timestamp for everything built after this call
+    pub fn at(mut self, now: i64) -> Self {
+        self.now = now;
+        self
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.agent.name = name.to_string();
+        self
+    }
+
+    pub fn model_hash(mut self, model_hash: &str) -> Self {
+        self.agent.model_hash = model_hash.to_string();
+        self
+    }
+
+    pub fn capabilities(mut self, capabilities: &str) -> Self {
+        self.agent.capabilities = capabilities.to_string();
+        self
+    }
+
+    pub fn reputation(mut self, reputation_score: u32) -> Self {
+        self.agent.set_reputation(reputation_score);
+        self
+    }
+
+    /// Stake held in the agent PDA (added to its lamports by `build`)
+    pub fn stake(mut self, lamports: u64) -> Self {
+        self.agent.staked_lamports = lamports;
+        self
+    }
+
+    /// Admin-verified agent
+    pub fn verified(mut self) -> Self {
+        self.agent.verified = true;
+        self.agent.verification_level = AgentAccount::VERIFICATION_ADMIN;
+        self
+    }
+
+    /// Direct access for fields without a dedicated setter
+    pub fn with_account(mut self, edit: impl FnOnce(&mut AgentAccount)) -> Self {
+        edit(&mut self.agent);
+        self
+    }
+
+    /// Resolved challenges from `challenger` (passes first, then failures), with the
+    /// agent's counters, streaks and win rate updated to match; reputation is untouched
+    pub fn with_challenges(
+        mut self,
+        challenger: Pubkey,
+        challenge_type: ChallengeType,
+        passed: u32,
+        failed: u32,
+    ) -> Self {
+        let outcomes = std::iter::repeat_n(true, passed as usize)
+            .chain(std::iter::repeat_n(false, failed as usize));
+
+        for passed in outcomes {
+            let nonce = self.challenges.len() as u64;
+            let (address, bump) = pda::challenge(&self.address, &challenger, nonce);
+            let challenge = Challenge {
+                agent: self.address,
+                challenger,
+                question: format!("fixture question {nonce}"),
+                answer_commitment: "0".repeat(64),
+                challenge_type,
+                judged: false,
+                response_hash: "0".repeat(64),
+                votes_pass: 0,
+                votes_fail: 0,
+                status: if passed { ChallengeStatus::Passed } else { ChallengeStatus::Failed },
+                created_at: self.now,
+                expires_at: self.now + Challenge::DEFAULT_DURATION,
+                responded_at: self.now,
+                escrow: 0,
+                nonce,
+                bump,
+                version: Challenge::CURRENT_VERSION,
+                template: Pubkey::default(),
+                pass_weight_bps: 0,
+                fail_weight_bps: 0,
+            };
+            self.challenges
+                .push(FixtureAccount::new(address, &challenge, 8 + Challenge::INIT_SPACE));
+
+            let idx = challenge_type.index();
+            if passed {
+                self.agent.challenges_passed += 1;
+                self.agent.passed_by_type[idx] += 1;
+            } else {
+                self.agent.challenges_failed += 1;
+                self.agent.failed_by_type[idx] += 1;
+            }
+            self.agent.update_stats(passed);
+        }
+        self
+    }
+
+    /// `log_audit` history: one AuditEntry per record plus the AgentAuditSummary
+    pub fn with_audits(mut self, records: &[AuditRecord]) -> Self {
+        self.audits.extend_from_slice(records);
+        self
+    }
+
+    /// One `store_merkle_audit` batch per call, committing the root of `leaves`
+    pub fn with_merkle_batch(mut self, leaves: &[[u8; 32]]) -> Self {
+        assert!(!leaves.is_empty(), "empty Merkle batch");
+        self.merkle_batches.push(leaves.to_vec());
+        self
+    }
+
+    /// Incremental audit tree (`append_audit_leaf`) holding `leaves` in order
+    pub fn with_audit_tree(mut self, leaves: &[[u8; 32]]) -> Self {
+        self.tree_leaves = Some(leaves.to_vec());
+        self
+    }
+
+    /// The agent account followed by every account the scenario added
+    pub fn build(self) -> Vec<FixtureAccount> {
+        let agent_key = self.address;
+        let space = AgentAccount::space_for(self.agent.name.len(), self.agent.capabilities.len());
+        let mut agent = FixtureAccount::new(agent_key, &self.agent, space);
+        agent.lamports += self.agent.staked_lamports;
+
+        let mut accounts = vec![agent];
+        accounts.extend(self.challenges);
+
+        if !self.audits.is_empty() {
+            let (summary_key, summary_bump) = pda::audit_summary(&agent_key);
+            let mut summary = AgentAuditSummary {
+                agent: agent_key,
+                total_entries: 0,
+                security_alerts: 0,
+                avg_risk_score: 0,
+                max_risk_score: 0,
+                last_audit_at: 0,
+                safe_streak: 0,
+                bump: summary_bump,
+            };
+
+            for record in &self.audits {
+                let risk_score = AuditEntry::calculate_risk_score(&record.action_type, record.context_risk);
+                let is_alert = matches!(record.action_type, ActionType::SecurityAlert) || risk_score >= 75;
+                let (address, bump) = pda::audit_entry(&agent_key, summary.total_entries);
+                let entry = AuditEntry {
+                    agent: agent_key,
+                    actor: self.agent.owner,
+                    action_type: record.action_type,
+                    risk_score,
+                    risk_level: RiskLevel::from_score(risk_score),
+                    timestamp: self.now,
+                    details_hash: "0".repeat(64),
+                    audit_index: summary.total_entries,
+                    bump,
+                };
+                accounts.push(FixtureAccount::new(address, &entry, 8 + AuditEntry::INIT_SPACE));
+                summary.record_entry(risk_score, is_alert, self.now);
+            }
+            accounts.push(FixtureAccount::new(
+                summary_key,
+                &summary,
+                8 + AgentAuditSummary::INIT_SPACE,
+            ));
+        }
+
+        if !self.merkle_batches.is_empty() {
+            let (summary_key, summary_bump) = pda::merkle_summary(&agent_key);
+            let mut summary = MerkleAuditSummary {
+                agent: agent_key,
+                total_batches: 0,
+                total_entries: 0,
+                last_batch_at: self.now,
+                running_root: [0u8; 32],
+                bump: summary_bump,
+            };
+
+            for leaves in &self.merkle_batches {
+                let (address, bump) = pda::merkle_root(&agent_key, summary.total_batches);
+                let root = MerkleAuditRoot {
+                    agent: agent_key,
+                    merkle_root: merkle::batch_root(leaves),
+                    entries_count: leaves.len() as u32,
+                    timestamp: self.now,
+                    batch_index: summary.total_batches,
+                    bump,
+                };
+                accounts.push(FixtureAccount::new(address, &root, 8 + MerkleAuditRoot::INIT_SPACE));
+                summary.total_batches += 1;
+                summary.total_entries += leaves.len() as u64;
+                summary.fold_root(&root.merkle_root);
+            }
+            accounts.push(FixtureAccount::new(
+                summary_key,
+                &summary,
+                8 + MerkleAuditSummary::INIT_SPACE,
+            ));
+        }
+
+        if let Some(leaves) = self.tree_leaves {
+            let (address, bump) = pda::audit_tree(&agent_key);
+            let mut tree = AuditMerkleTree {
+                agent: agent_key,
+                root: [0u8; 32],
+                next_index: 0,
+                filled_subtrees: Default::default(),
+                last_append_at: self.now,
+                bump,
+            };
+            tree.init(agent_key, bump);
+            for leaf in leaves {
+                tree.append(leaf).expect("audit tree capacity");
+            }
+            tree.last_append_at = self.now;
+            accounts.push(FixtureAccount::new(address, &tree, 8 + AuditMerkleTree::INIT_SPACE));
+        }
+
+        accounts
+    }
+}
@@ -0,0 +1,312 @@
+use anchor_lang::prelude::*;
+
+// Values SDKs need to derive PDAs and validate inputs. Marked `#[constant]` so they
+// land in the IDL; the state structs re-export them as associated constants
+
+// PDA seeds
+
+#[constant]
+pub const ADMIN_LOG_SEED: &[u8] = b"admin_log";
+
+#[constant]
+pub const AGENT_ARCHIVE_SEED: &[u8] = b"agent_archive";
+
+#[constant]
+pub const AGENT_COUNTERS_SEED: &[u8] = b"agent_counters";
+
+#[constant]
+pub const AGENT_SEED: &[u8] = b"agent";
+
+#[constant]
+pub const AGENT_DID_SEED: &[u8] = b"agent_did";
+
+#[constant]
+pub const AGENT_MULTISIG_SEED: &[u8] = b"agent_multisig";
+
+#[constant]
+pub const AGENT_TRANSFER_SEED: &[u8] = b"agent_transfer";
+
+#[constant]
+pub const ALLOWANCE_SEED: &[u8] = b"allowance";
+
+#[constant]
+pub const ALLOWLIST_SEED: &[u8] = b"allowlist";
+
+#[constant]
+pub const ATTESTATION_SEED: &[u8] = b"attestation";
+
+#[constant]
+pub const ATTESTER_SEED: &[u8] = b"attester";
+
+#[constant]
+pub const AUDIT_DELEGATE_SEED: &[u8] = b"audit_delegate";
+
+#[constant]
+pub const AUDIT_SEED: &[u8] = b"audit";
+
+#[constant]
+pub const AUDIT_SELECTION_SEED: &[u8] = b"audit_selection";
+
+#[constant]
+pub const AUDIT_SUMMARY_SEED: &[u8] = b"audit_summary";
+
+#[constant]
+pub const AUDIT_TREE_SEED: &[u8] = b"audit_tree";
+
+#[constant]
+pub const BADGE_CLAIM_SEED: &[u8] = b"badge_claim";
+
+#[constant]
+pub const BADGE_MINT_SEED: &[u8] = b"badge_mint";
+
+#[constant]
+pub const BENCHMARK_EVALUATOR_SEED: &[u8] = b"benchmark_evaluator";
+
+#[constant]
+pub const BENCHMARK_SCORE_SEED: &[u8] = b"benchmark_score";
+
+#[constant]
+pub const BENCHMARK_SEED: &[u8] = b"benchmark";
+
+#[constant]
+pub const CAPABILITY_SEED: &[u8] = b"capability";
+
+#[constant]
+pub const CHALLENGE_AUTHORITY_SEED: &[u8] = b"challenge_authority";
+
+#[constant]
+pub const CHALLENGE_EVIDENCE_SEED: &[u8] = b"challenge_evidence";
+
+#[constant]
+pub const CHALLENGE_SEED: &[u8] = b"challenge";
+
+#[constant]
+pub const CHALLENGE_STREAM_SEED: &[u8] = b"challenges";
+
+#[constant]
+pub const CHALLENGE_TEMPLATE_SEED: &[u8] = b"challenge_template";
+
+#[constant]
+pub const CONFIG_SEED: &[u8] = b"config";
+
+#[constant]
+pub const CREDENTIAL_SEED: &[u8] = b"credential";
+
+#[constant]
+pub const CREDENTIAL_ISSUER_SEED: &[u8] = b"credential_issuer";
+
+#[constant]
+pub const EPOCH_REWARD_CLAIM_SEED: &[u8] = b"epoch_reward_claim";
+
+#[constant]
+pub const EPOCH_REWARDS_SEED: &[u8] = b"epoch_rewards";
+
+#[constant]
+pub const EPOCH_SNAPSHOT_SEED: &[u8] = b"epoch_snapshot";
+
+#[constant]
+pub const EVAL_BATCH_SEED: &[u8] = b"eval_batch";
+
+#[constant]
+pub const EXTERNAL_SCORES_SEED: &[u8] = b"external_scores";
+
+#[constant]
+pub const GOVERNANCE_PROPOSAL_SEED: &[u8] = b"governance_proposal";
+
+#[constant]
+pub const GOVERNANCE_SEED: &[u8] = b"governance";
+
+#[constant]
+pub const GOVERNANCE_VOTE_SEED: &[u8] = b"governance_vote";
+
+#[constant]
+pub const HOOK_AUTHORITY_SEED: &[u8] = b"hook_authority";
+
+#[constant]
+pub const JOB_SEED: &[u8] = b"job";
+
+#[constant]
+pub const JUDGE_SEED: &[u8] = b"judge";
+
+#[constant]
+pub const JUDGE_VOTE_SEED: &[u8] = b"judge_vote";
+
+#[constant]
+pub const MERKLE_AUDIT_SEED: &[u8] = b"merkle_audit";
+
+#[constant]
+pub const MERKLE_SUMMARY_SEED: &[u8] = b"merkle_summary";
+
+#[constant]
+pub const MODEL_INDEX_SEED: &[u8] = b"model_index";
+
+#[constant]
+pub const MODEL_RECORD_SEED: &[u8] = b"model_record";
+
+#[constant]
+pub const MODERATOR_SEED: &[u8] = b"moderator";
+
+#[constant]
+pub const MULTISIG_PROPOSAL_SEED: &[u8] = b"multisig_proposal";
+
+#[constant]
+pub const NAMESPACE_SEED: &[u8] = b"namespace";
+
+#[constant]
+pub const OWNER_COUNTER_SEED: &[u8] = b"owner_counter";
+
+#[constant]
+pub const OWNER_INDEX_SEED: &[u8] = b"owner_index";
+
+#[constant]
+pub const PAYMENT_CHANNEL_SEED: &[u8] = b"payment_channel";
+
+#[constant]
+pub const REFERRER_SEED: &[u8] = b"referrer";
+
+#[constant]
+pub const REGISTRY_MIGRATION_SEED: &[u8] = b"registry_migration";
+
+#[constant]
+pub const REGISTRY_SEED: &[u8] = b"registry";
+
+#[constant]
+pub const REGISTRY_SNAPSHOT_SEED: &[u8] = b"registry_snapshot";
+
+#[constant]
+pub const REPUTATION_AUTHORITY_SEED: &[u8] = b"reputation_authority";
+
+#[constant]
+pub const REPUTATION_HISTORY_SEED: &[u8] = b"reputation_history";
+
+#[constant]
+pub const REPUTATION_HOOKS_SEED: &[u8] = b"reputation_hooks";
+
+#[constant]
+pub const REPUTATION_ORACLE_SEED: &[u8] = b"reputation_oracle";
+
+#[constant]
+pub const REVIEW_SEED: &[u8] = b"review";
+
+#[constant]
+pub const SERVICE_LISTING_SEED: &[u8] = b"service_listing";
+
+#[constant]
+pub const SPONSOR_ALLOWANCE_SEED: &[u8] = b"sponsor_allowance";
+
+#[constant]
+pub const SPONSOR_POOL_SEED: &[u8] = b"sponsor_pool";
+
+#[constant]
+pub const SPONSOR_VAULT_SEED: &[u8] = b"sponsor_vault";
+
+#[constant]
+pub const TAG_INDEX_SEED: &[u8] = b"tag_index";
+
+#[constant]
+pub const TREASURY_SEED: &[u8] = b"treasury";
+
+#[constant]
+pub const UNSTAKE_REQUEST_SEED: &[u8] = b"unstake_request";
+
+#[constant]
+pub const VERIFICATION_QUEUE_SEED: &[u8] = b"verification_queue";
+
+#[constant]
+pub const VERIFICATION_REQUEST_SEED: &[u8] = b"verification_request";
+
+#[constant]
+pub const WATCHERS_SEED: &[u8] = b"watchers";
+
+#[constant]
+pub const ZK_INFERENCE_SEED: &[u8] = b"zk_inference";
+
+#[constant]
+pub const ZK_VERIFIER_SEED: &[u8] = b"zk_verifier";
+
+// Metadata limits
+
+/// Longest agent name accepted at registration
+#[constant]
+pub const BASE_NAME_LEN: u32 = 64;
+
+/// Longest capabilities string accepted at registration
+#[constant]
+pub const BASE_CAPABILITIES_LEN: u32 = 256;
+
+/// Longest agent name `update_agent` will grow the account to fit
+#[constant]
+pub const MAX_NAME_LEN: u32 = 256;
+
+/// Longest capabilities string `update_agent` will grow the account to fit
+#[constant]
+pub const MAX_CAPABILITIES_LEN: u32 = 2048;
+
+/// Most discovery tags per agent
+#[constant]
+pub const MAX_AGENT_TAGS: u32 = 4;
+
+/// Longest discovery tag
+#[constant]
+pub const MAX_TAG_LEN: u32 = 16;
+
+// AgentAccount memcmp offsets (with discriminator; the index header precedes every
+// string and is stable across layout versions)
+
+/// Offset of `owner` (Pubkey)
+#[constant]
+pub const AGENT_OWNER_OFFSET: u32 = 16;
+
+/// Offset of `win_rate_bps` (u16, basis points)
+#[constant]
+pub const AGENT_WIN_RATE_OFFSET: u32 = 48;
+
+/// Offset of `current_streak` (u32)
+#[constant]
+pub const AGENT_CURRENT_STREAK_OFFSET: u32 = 50;
+
+/// Offset of `best_streak` (u32)
+#[constant]
+pub const AGENT_BEST_STREAK_OFFSET: u32 = 54;
+
+/// Offset of `status` (u8 AgentStatus variant index)
+#[constant]
+pub const AGENT_STATUS_OFFSET: u32 = 58;
+
+/// Offset of `verification_level` (u8)
+#[constant]
+pub const AGENT_VERIFICATION_LEVEL_OFFSET: u32 = 59;
+
+/// Offset of `tier` (u8 reputation tier)
+#[constant]
+pub const AGENT_TIER_OFFSET: u32 = 60;
+
+/// Offset of `capability_flags` (u64 bitmask)
+#[constant]
+pub const AGENT_CAPABILITY_FLAGS_OFFSET: u32 = 61;
+
+/// Offset of `namespace` ([u8; 32] namespace name hash)
+#[constant]
+pub const AGENT_NAMESPACE_OFFSET: u32 = 69;
+
+/// Offset of `seq` (u64 change sequence number)
+#[constant]
+pub const AGENT_SEQ_OFFSET: u32 = 101;
+
+// Reputation limits
+
+/// Score new agents start with under the default 0-10000 range
+#[constant]
+pub const INITIAL_REPUTATION: u32 = 5000;
+
+/// Highest reputation ceiling a deployment can configure (100.00%)
+#[constant]
+pub const MAX_REPUTATION: u32 = 10000;
+
+/// Lowest reputation floor a deployment can configure
+#[constant]
+pub const MIN_REPUTATION: u32 = 0;
+
+/// Largest absolute delta `update_reputation` accepts in one call
+#[constant]
+pub const MAX_REPUTATION_DELTA: i32 = 1000;
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+use crate::events::ErrorContext;
+
+/// Input a limit error refers to (`ErrorContext::field`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ErrorField {
+    /// Agent name (bytes)
+    Name,
+    /// Agent capabilities string (bytes)
+    Capabilities,
+    /// Challenge question (bytes)
+    Question,
+    /// DID or capability description URI (bytes)
+    Uri,
+    /// Moderation or verification reason (bytes)
+    Reason,
+    /// Registered reputation hooks (count)
+    Hooks,
+    /// Agents passed to a summary view (count)
+    SummaryAgents,
+}
+
+/// Fail with `error` unless `actual <= limit`
+/// The failure is preceded by an ErrorContext event naming the field, the offending
+/// value and the limit, so clients can say which limit was hit and by how much
+pub fn require_within(field: ErrorField, actual: usize, limit: usize, error: RegistryError) -> Result<()> {
+    if actual <= limit {
+        return Ok(());
+    }
+
+    // `emit!` rather than `emit_cpi!`: program logs survive the failed transaction,
+    // and the event needs no extra accounts on every instruction that checks a limit
+    emit!(ErrorContext {
+        error_code: error.into(),
+        field,
+        actual: actual as u64,
+        limit: limit as u64,
+    });
+    Err(error.into())
+}
@@ -2,13 +2,13 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum RegistryError {
-    #[msg("Name is too long (max 64 characters)")]
+    #[msg("Name is too long (max 64 bytes at registration, 256 via update)")]
     NameTooLong,
 
-    #[msg("Model hash is invalid (must be sha256:...)")]
+    #[msg("Model hash is invalid (must be scheme:hex, e.g. sha256:<64 lowercase hex digits>)")]
     InvalidModelHash,
 
-    #[msg("Capabilities string is too long (max 256 characters)")]
+    #[msg("Capabilities string is too long (max 256 bytes at registration, 2048 via update)")]
     CapabilitiesTooLong,
 
     #[msg("Agent is already verified")]
@@ -35,7 +35,7 @@ pub enum RegistryError {
     #[msg("Question is too long (max 256 characters)")]
     QuestionTooLong,
 
-    #[msg("Expected hash must be 64 characters (SHA256 hex)")]
+    #[msg("Expected hash or answer commitment must be 64 characters (SHA256 hex)")]
     InvalidExpectedHash,
 
     #[msg("Response hash must be 64 characters (SHA256 hex)")]
@@ -65,4 +65,595 @@ pub enum RegistryError {
 
     #[msg("Challenge is still pending (must be resolved before closing)")]
     ChallengeStillPending,
+
+    #[msg("Audit Merkle tree is full")]
+    AuditTreeFull,
+
+    #[msg("Reputation weight exceeds maximum (50000 bps)")]
+    InvalidWeight,
+
+    #[msg("Challenge duration out of range (1 minute - 30 days)")]
+    InvalidChallengeDuration,
+
+    #[msg("Judge quorum must be at least 1")]
+    InvalidQuorum,
+
+    #[msg("Judge is not active")]
+    JudgeInactive,
+
+    #[msg("Challenge is not awaiting a committee verdict")]
+    ChallengeNotAwaitingVerdict,
+
+    #[msg("Randomness account is not a valid Switchboard randomness account")]
+    InvalidRandomnessAccount,
+
+    #[msg("Randomness must be committed in the previous slot")]
+    RandomnessStale,
+
+    #[msg("Randomness has already been revealed")]
+    RandomnessAlreadyRevealed,
+
+    #[msg("Randomness has not been revealed")]
+    RandomnessNotRevealed,
+
+    #[msg("Audit selection round already settled")]
+    SelectionAlreadySettled,
+
+    #[msg("Price feed account required for USD-denominated amounts")]
+    PriceFeedRequired,
+
+    #[msg("Invalid Pyth price feed account")]
+    InvalidPriceFeed,
+
+    #[msg("Pyth price is too old")]
+    PriceStale,
+
+    #[msg("Pyth price confidence interval is too wide")]
+    PriceConfidenceTooWide,
+
+    #[msg("Invalid price oracle parameters")]
+    InvalidPriceParams,
+
+    #[msg("Insufficient treasury balance")]
+    InsufficientTreasury,
+
+    #[msg("Stake amount must be greater than 0")]
+    InvalidStakeAmount,
+
+    #[msg("Insufficient staked balance")]
+    InsufficientStake,
+
+    #[msg("Snapshot covers more agents than registered or a future slot")]
+    InvalidSnapshot,
+
+    #[msg("Agent does not meet the required reputation or verification")]
+    AgentConstraintNotMet,
+
+    #[msg("Decay rate must be at most 10000 bps and grace period non-negative")]
+    InvalidDecayParams,
+
+    #[msg("Account is already at the current layout version")]
+    AlreadyMigrated,
+
+    #[msg("Signed action nonce does not match the agent's next nonce")]
+    InvalidNonce,
+
+    #[msg("Missing or mismatched ed25519 signature instruction")]
+    InvalidSignature,
+
+    #[msg("Registrant is not whitelisted by this sponsor pool")]
+    SponsorNotWhitelisted,
+
+    #[msg("Sponsored registration allowance exhausted")]
+    SponsorAllowanceExhausted,
+
+    #[msg("Sponsor pool has insufficient funds")]
+    InsufficientSponsorFunds,
+
+    #[msg("Referral share must be at most 10000 bps")]
+    InvalidReferralShare,
+
+    #[msg("An agent owner cannot refer their own registration")]
+    SelfReferral,
+
+    #[msg("No referral rewards to claim")]
+    NothingToClaim,
+
+    #[msg("Agent is multisig-controlled; use the multisig instructions")]
+    MultisigRequired,
+
+    #[msg("Multisig needs 1-10 unique owners and a threshold between 1 and the owner count")]
+    InvalidMultisigConfig,
+
+    #[msg("Signer is not an owner of this multisig")]
+    NotMultisigOwner,
+
+    #[msg("Not enough multisig owner approvals")]
+    ThresholdNotMet,
+
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Proposal predates the current multisig owner set")]
+    ProposalStale,
+
+    #[msg("Recipient does not match the proposed action")]
+    InvalidRecipient,
+
+    #[msg("Capability ID must be between 0 and 63")]
+    InvalidCapabilityId,
+
+    #[msg("Capability flags reference an undefined or retired capability")]
+    UndefinedCapability,
+
+    #[msg("URI is too long (max 200 characters)")]
+    UriTooLong,
+
+    #[msg("Service must declare capabilities the agent has")]
+    InvalidServiceCapabilities,
+
+    #[msg("Invalid pricing (billing period must be at least one hour)")]
+    InvalidPricing,
+
+    #[msg("Service listing is not active")]
+    ListingInactive,
+
+    #[msg("Job window must be between 1 second and 30 days")]
+    InvalidJobWindow,
+
+    #[msg("Job is not in the required status for this action")]
+    InvalidJobStatus,
+
+    #[msg("Job deadline has passed")]
+    JobDeadlinePassed,
+
+    #[msg("Job deadline has not passed yet")]
+    JobDeadlineNotReached,
+
+    #[msg("Rating must be between 1 and 5")]
+    InvalidRating,
+
+    #[msg("Review already has an owner response")]
+    AlreadyResponded,
+
+    #[msg("A verification request is already pending or approved")]
+    VerificationPending,
+
+    #[msg("Verification request is not pending")]
+    VerificationNotPending,
+
+    #[msg("Reason is too long (max 200 characters)")]
+    ReasonTooLong,
+
+    #[msg("Attester is not active")]
+    AttesterInactive,
+
+    #[msg("Attestation is expired, too long-lived or for a different model")]
+    InvalidAttestation,
+
+    #[msg("Reputation value exceeds the maximum score")]
+    InvalidReputationThreshold,
+
+    #[msg("Agent is retired, suspended or banned")]
+    AgentNotActive,
+
+    #[msg("Agent is retired or banned")]
+    AgentRetired,
+
+    #[msg("Invalid agent status transition")]
+    InvalidStatusTransition,
+
+    #[msg("Retired agent cannot be closed yet")]
+    CloseDelayNotElapsed,
+
+    #[msg("Signer is neither the admin nor an active moderator")]
+    NotModerator,
+
+    #[msg("Suspension must last between 1 second and 1 year")]
+    InvalidSuspensionDuration,
+
+    #[msg("Registry is allowlist-only and the owner is not on the allowlist")]
+    NotAllowlisted,
+
+    #[msg("Credential is expired, revoked or not newer than the recorded one")]
+    InvalidCredential,
+
+    #[msg("Credential issuer is not active")]
+    IssuerInactive,
+
+    #[msg("Reputation oracle is not active")]
+    OracleInactive,
+
+    #[msg("Agent already has scores from the maximum number of external sources")]
+    ExternalSourcesFull,
+
+    #[msg("Registry requires unique models: pass the model record account")]
+    ModelRecordRequired,
+
+    #[msg("Model hash is already registered by an unrelated agent")]
+    DuplicateModel,
+
+    #[msg("Agent is already in this index")]
+    AgentAlreadyIndexed,
+
+    #[msg("Model index already tracks the maximum number of agents")]
+    ModelIndexFull,
+
+    #[msg("Agent is not in this index")]
+    AgentNotIndexed,
+
+    #[msg("Agent account still exists")]
+    AgentStillRegistered,
+
+    #[msg("Agent already has the maximum number of reputation hooks")]
+    TooManyHooks,
+
+    #[msg("Reputation hook is already registered")]
+    HookAlreadyRegistered,
+
+    #[msg("Reputation hook is not registered")]
+    HookNotRegistered,
+
+    #[msg("Registered hook program was not passed as a remaining account")]
+    HookProgramMissing,
+
+    #[msg("Hook program cannot be the registry itself")]
+    InvalidHookProgram,
+
+    #[msg("Fee discount exceeds 100%")]
+    InvalidFeeDiscount,
+
+    #[msg("Reputation floor must be below the ceiling and the ceiling at most 10000")]
+    InvalidReputationBounds,
+
+    #[msg("Registry is token-gated: pass a token account of the gate mint")]
+    GateTokenAccountRequired,
+
+    #[msg("Not an initialized SPL token account")]
+    InvalidTokenAccount,
+
+    #[msg("Token account is not the registrant's account of the gate mint")]
+    GateTokenAccountMismatch,
+
+    #[msg("Gate token balance is below the registry minimum")]
+    InsufficientGateBalance,
+
+    #[msg("Badge mint already exists for this kind")]
+    BadgeMintExists,
+
+    #[msg("Badge mint has not been created for this kind")]
+    BadgeMintNotCreated,
+
+    #[msg("Agent has not reached this badge's milestone")]
+    MilestoneNotReached,
+
+    #[msg("Epoch has not ended yet")]
+    EpochNotEnded,
+
+    #[msg("Snapshot is not eligible for the epoch's rewards")]
+    NotEligibleForRewards,
+
+    #[msg("Stake lockup and unstake cooldown must be between 0 and 1 year")]
+    InvalidStakeLockup,
+
+    #[msg("Stake is still locked")]
+    StakeLocked,
+
+    #[msg("An unstake cooldown is configured: use request_unstake")]
+    UnstakeCooldownRequired,
+
+    #[msg("Unstake cooldown has not elapsed")]
+    UnstakeCooldownActive,
+
+    #[msg("Reputation delta exceeds the co-sign threshold: the designated co-signer must sign")]
+    CosignerRequired,
+
+    #[msg("Signer is not the designated reputation co-signer")]
+    InvalidCosigner,
+
+    #[msg("Only the program upgrade authority can bootstrap the registry")]
+    NotUpgradeAuthority,
+
+    #[msg("Initial role accounts must match the roles set in the bootstrap params")]
+    InitialRoleMismatch,
+
+    #[msg("Demo seeding expects up to 8 fresh [agent, audit summary] PDA pairs for the next agent ids")]
+    InvalidDemoAccounts,
+
+    #[msg("Namespace names are 1-32 lowercase alphanumerics or '-'")]
+    InvalidNamespaceName,
+
+    #[msg("Agent already belongs to a namespace")]
+    AlreadyInNamespace,
+
+    #[msg("Agent does not belong to this namespace")]
+    NotInNamespace,
+
+    #[msg("Joining this namespace requires the namespace admin's signature")]
+    NamespaceApprovalRequired,
+
+    #[msg("Agent does not meet the namespace's reputation or verification requirements")]
+    NamespaceRequirementsNotMet,
+
+    #[msg("Registry or namespace is deprecated")]
+    RegistryDeprecated,
+
+    #[msg("Registry or namespace is not deprecated")]
+    RegistryNotDeprecated,
+
+    #[msg("Successor does not match the deprecated registry's successor")]
+    InvalidSuccessor,
+
+    #[msg("Agent changed since its counters were synced; call sync_agent_counters")]
+    AgentCountersStale,
+
+    #[msg("Verification queue is full")]
+    VerificationQueueFull,
+
+    #[msg("Verification request is not at the head of the queue")]
+    NotQueueHead,
+
+    #[msg("Queued verification requests must be processed through the queue")]
+    VerificationQueueRequired,
+
+    #[msg("Agent has no request in the verification queue")]
+    NotQueued,
+
+    #[msg("Queued agent can still be verified")]
+    QueuedAgentActive,
+
+    #[msg("Challenge cannot be voided (verdict window open, or agent can still be expired)")]
+    ChallengeNotVoidable,
+
+    #[msg("Challenge holds no escrow")]
+    NoChallengeEscrow,
+
+    #[msg("Escrow recipient does not match the challenge outcome")]
+    InvalidEscrowRecipient,
+
+    #[msg("Challenge escrow must be claimed before closing")]
+    EscrowNotReleased,
+
+    #[msg("Submitter is neither the challenger nor the agent owner")]
+    NotChallengeParty,
+
+    #[msg("Evidence limit reached for this party")]
+    TooMuchEvidence,
+
+    #[msg("Challenge is already resolved")]
+    ChallengeAlreadyResolved,
+
+    #[msg("Signer is not an active reputation authority")]
+    NotReputationAuthority,
+
+    #[msg("Reputation authority's per-epoch delta budget exceeded")]
+    ReputationBudgetExceeded,
+
+    #[msg("Capability is not among the agent's declared capability flags")]
+    CapabilityNotClaimed,
+
+    #[msg("Capability is already proven")]
+    CapabilityAlreadyProven,
+
+    #[msg("Merkle proof does not match the eval batch")]
+    InvalidCapabilityProof,
+
+    #[msg("ZK verifier is not accepting proofs")]
+    ZkVerifierInactive,
+
+    #[msg("Groth16 proof does not verify")]
+    InvalidZkProof,
+
+    #[msg("Field is frozen and can no longer be updated")]
+    FieldFrozen,
+
+    #[msg("Unknown or empty frozen field mask")]
+    InvalidFrozenFields,
+
+    #[msg("Allowance needs at least one call and a duration of up to a year")]
+    InvalidAllowance,
+
+    #[msg("Allowance has expired")]
+    AllowanceExpired,
+
+    #[msg("Not enough calls left in the allowance")]
+    AllowanceExhausted,
+
+    #[msg("Allowance still has unexpired calls")]
+    AllowanceActive,
+
+    #[msg("Channel amount must be greater than zero")]
+    InvalidChannelAmount,
+
+    #[msg("Payment channel is closing")]
+    ChannelClosing,
+
+    #[msg("Voucher must exceed the redeemed amount and stay within the deposit")]
+    InvalidVoucher,
+
+    #[msg("Channel close is not requested or still in its challenge period")]
+    ChannelChallengePeriod,
+
+    #[msg("Governance needs a non-zero quorum, a majority above 50% and a 1-30 day voting period")]
+    InvalidGovernanceConfig,
+
+    #[msg("Governance proposals are disabled")]
+    GovernanceInactive,
+
+    #[msg("Only verified, solely-owned agents in good standing can propose or vote")]
+    NotEligibleVoter,
+
+    #[msg("Voting on this proposal has closed")]
+    VotingClosed,
+
+    #[msg("Voting on this proposal is still open")]
+    VotingOpen,
+
+    #[msg("Proposal did not reach quorum and majority")]
+    ProposalNotPassed,
+
+    #[msg("Too many agents for one summary (max 26)")]
+    TooManySummaryAgents,
+
+    #[msg("Owner index is full (max 64 agents)")]
+    OwnerIndexFull,
+
+    #[msg("Tags must be unique, at most 4, and 1-16 characters of [a-z0-9-]")]
+    InvalidTags,
+
+    #[msg("Tag index is full (max 32 agents)")]
+    TagIndexFull,
+
+    #[msg("Agent does not carry this tag")]
+    TagNotSet,
+
+    #[msg("Agent still carries this tag")]
+    AgentStillTagged,
+
+    #[msg("Remaining account is not a writable tag index")]
+    InvalidTagIndex,
+
+    #[msg("Garbage collection window must be 0 (disabled) or at least the agent close delay")]
+    InvalidGcWindow,
+
+    #[msg("Agent is verified, tested, staked, banned or active within the garbage collection window")]
+    AgentNotCollectable,
+
+    #[msg("Benchmark evaluator is not active")]
+    EvaluatorInactive,
+
+    #[msg("Benchmark is not accepting results")]
+    BenchmarkInactive,
+
+    #[msg("Score exceeds the maximum, or the evaluation is not newer than the recorded one")]
+    InvalidBenchmarkScore,
+
+    #[msg("Agent account predates the current layout; run migrate_agent first")]
+    AgentNeedsMigration,
+
+    #[msg("Owner reached the registration limit for the current window")]
+    RegistrationRateLimited,
+
+    #[msg("Owner counter account is required while registrations are rate limited")]
+    OwnerCounterRequired,
+
+    #[msg("Audit delegate scopes must be non-empty known bits and expiry in the future")]
+    InvalidAuditDelegate,
+
+    #[msg("Audit delegation has expired or does not cover this instruction")]
+    AuditDelegateNotPermitted,
+
+    #[msg("Template weights exceed the cap or its judge set is duplicated, too large or below quorum")]
+    InvalidChallengeTemplate,
+
+    #[msg("Challenge template is inactive")]
+    ChallengeTemplateInactive,
+
+    #[msg("Challenge type or judging mode differs from the template")]
+    ChallengeTemplateMismatch,
+
+    #[msg("Judge is not in the challenge template's judge set")]
+    JudgeNotInTemplate,
+
+    #[msg("Verification request is not approved")]
+    VerificationNotApproved,
+
+    #[msg("No verification bond is held")]
+    NoVerificationBond,
+
+    #[msg("Verification bond is still in its clean period")]
+    VerificationBondLocked,
+
+    #[msg("Deactivation must end in the future and within 1 year")]
+    InvalidDeactivationWindow,
+
+    #[msg("Agent deactivation is still in effect")]
+    DeactivationActive,
+
+    #[msg("Transfer haircut cannot exceed 10000 basis points")]
+    InvalidTransferHaircut,
+
+    #[msg("New owner must differ from the current owner")]
+    InvalidNewOwner,
+
+    #[msg("Watch mask must select at least one defined change flag")]
+    InvalidWatchMask,
+
+    #[msg("Agent already has the maximum number of watchers")]
+    WatchersFull,
+
+    #[msg("Wallet is not watching this agent")]
+    NotWatching,
+
+    #[msg("Name must not be blank or contain control or invisible characters")]
+    InvalidName,
+
+    #[msg("Capabilities must not contain control or invisible characters")]
+    InvalidCapabilities,
+
+    #[msg("Agent's audit history is quarantined")]
+    AuditQuarantined,
+
+    #[msg("Challenge is not awaiting the challenger's reveal")]
+    ChallengeNotAwaitingReveal,
+
+    #[msg("Revealed answer does not match the challenge's commitment")]
+    AnswerCommitmentMismatch,
+
+    #[msg("Stake slash share must be at most 10000 basis points")]
+    InvalidSlashShare,
+
+    #[msg("Judge quorum exceeds the number of active judges")]
+    QuorumExceedsJudges,
+
+    #[msg("Audit selection round has expired")]
+    SelectionExpired,
+
+    #[msg("Audit selection round was revealed or is still within its settle window")]
+    SelectionNotExpired,
+
+    #[msg("Proof does not place the agent at the drawn snapshot leaf")]
+    InvalidSnapshotProof,
+
+    #[msg("Job escrow is below the minimum")]
+    JobEscrowTooLow,
+
+    #[msg("Agent owner cannot hire its own agent")]
+    SelfDealing,
+
+    #[msg("Hook has already received the latest reputation change")]
+    HookUpToDate,
+
+    #[msg("Epoch snapshots can only be taken at the start of the epoch")]
+    SnapshotWindowClosed,
+
+    #[msg("Agent has a pending unstake; withdraw it first")]
+    UnstakePending,
+
+    #[msg("Pass the successor's account exactly when the agent's own account exists")]
+    SuccessorAccountMismatch,
+
+    #[msg("Agent account would fall below rent exemption once its stake is set aside")]
+    AgentRentNotCovered,
+
+    #[msg("Agent has no pending unstake")]
+    NoUnstakePending,
+
+    #[msg("Archive account must be passed exactly for an Archive action")]
+    ArchiveAccountMismatch,
+
+    #[msg("Epoch reward claim window has closed")]
+    ClaimWindowClosed,
+
+    #[msg("Epoch reward claim window is still open")]
+    ClaimWindowOpen,
+
+    #[msg("Job can only be voided once its agent has been closed")]
+    JobNotVoidable,
+
+    #[msg("Registry config layout does not match this program version")]
+    ConfigVersionMismatch,
+
+    #[msg("Allowance would cost more than the maximum the consumer agreed to")]
+    AllowanceCostExceeded,
 }
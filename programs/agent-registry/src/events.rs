@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+// Critical events are emitted with `emit_cpi!` so indexers can decode them from
+// inner instructions instead of relying on (truncatable) program logs
+
+/// An agent's reputation score moved
+#[event]
+pub struct ReputationChanged {
+    pub agent: Pubkey,
+    pub agent_id: u64,
+    pub old_score: u32,
+    pub new_score: u32,
+    /// What caused the change (`ReputationSource as u8`)
+    pub source: u8,
+    pub timestamp: i64,
+}
+
+/// A batch Merkle audit root was stored for an agent
+#[event]
+pub struct MerkleAuditStored {
+    pub agent: Pubkey,
+    pub batch_index: u64,
+    pub merkle_root: [u8; 32],
+    pub entries_count: u32,
+    /// Summary `running_root` after folding in this batch
+    pub running_root: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// A leaf was appended to an agent's incremental audit tree
+#[event]
+pub struct AuditLeafAppended {
+    pub agent: Pubkey,
+    pub leaf_index: u64,
+    pub leaf: [u8; 32],
+    /// Tree root after the append
+    pub root: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// A resolved challenge was anchored into the agent's challenge stream
+#[event]
+pub struct ChallengeAnchored {
+    pub agent: Pubkey,
+    pub challenge: Pubkey,
+    pub leaf_index: u64,
+    pub leaf: [u8; 32],
+    /// Challenge stream root after the append
+    pub root: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// An agent's audit history was quarantined for fraud (audit accounts kept as evidence)
+#[event]
+pub struct AuditHistoryQuarantined {
+    pub agent: Pubkey,
+    pub agent_id: u64,
+    /// Admin or moderator that quarantined it
+    pub authority: Pubkey,
+    pub reason_code: u16,
+    /// Individual entries logged (0 if the summary wasn't passed)
+    pub audit_entries: u64,
+    /// Entries across stored Merkle batches, and the running root over those batches
+    pub batched_entries: u64,
+    pub batch_root: [u8; 32],
+    /// Leaves in the audit tree, and its root
+    pub tree_leaves: u64,
+    pub tree_root: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// The admin changed the configured reputation range
+#[event]
+pub struct ReputationBoundsUpdated {
+    pub old_floor: u32,
+    pub old_ceiling: u32,
+    pub floor: u32,
+    pub ceiling: u32,
+    pub timestamp: i64,
+}
+
+/// Machine-readable context for the RegistryError the instruction fails with
+/// (logged just before the failure by `error_context::require_within`)
+#[event]
+pub struct ErrorContext {
+    /// Anchor error code of the failure (`RegistryError` + 6000)
+    pub error_code: u32,
+    pub field: crate::error_context::ErrorField,
+    /// Offending length or count
+    pub actual: u64,
+    /// Largest accepted value
+    pub limit: u64,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Job, JobStatus};
+use crate::errors::RegistryError;
+
+/// Accept an open job, starting the delivery clock (agent owner only)
+#[derive(Accounts)]
+pub struct AcceptJob<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = agent.accepts_work(Clock::get()?.unix_timestamp) @ RegistryError::AgentNotActive
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            Job::SEED_PREFIX,
+            job.listing.as_ref(),
+            job.consumer.as_ref(),
+            job.job_id.to_le_bytes().as_ref()
+        ],
+        bump = job.bump,
+        constraint = job.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub job: Account<'info, Job>,
+}
+
+pub fn handler(ctx: Context<AcceptJob>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let job = &mut ctx.accounts.job;
+
+    require!(job.status == JobStatus::Open, RegistryError::InvalidJobStatus);
+    require!(now <= job.accept_deadline, RegistryError::JobDeadlinePassed);
+
+    job.status = JobStatus::Accepted;
+    job.delivery_deadline = now + job.delivery_window;
+
+    msg!(
+        "Job accepted: agent={}, job={}, deliver_by={}",
+        ctx.accounts.agent.agent_id,
+        job.job_id,
+        job.delivery_deadline
+    );
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{AllowlistEntry, RegistryState};
+use crate::errors::RegistryError;
+
+/// Approve a wallet for allowlist-only registration (admin only)
+#[derive(Accounts)]
+#[instruction(registrant: Pubkey)]
+pub struct AddAllowlistEntry<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AllowlistEntry::INIT_SPACE,
+        seeds = [AllowlistEntry::SEED_PREFIX, registrant.as_ref()],
+        bump
+    )]
+    pub entry: Account<'info, AllowlistEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddAllowlistEntry>, registrant: Pubkey) -> Result<()> {
+    let entry = &mut ctx.accounts.entry;
+    entry.registrant = registrant;
+    entry.added_by = ctx.accounts.admin.key();
+    entry.added_at = Clock::get()?.unix_timestamp;
+    entry.bump = ctx.bumps.entry;
+
+    msg!("Allowlist entry added: {}", registrant);
+
+    Ok(())
+}
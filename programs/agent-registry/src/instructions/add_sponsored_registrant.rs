@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{SponsorAllowance, SponsorPool};
+use crate::errors::RegistryError;
+
+/// Whitelist a registrant for pool-funded registrations (sponsor only)
+/// Calling again for the same registrant adds to their remaining allowance
+#[derive(Accounts)]
+#[instruction(registrant: Pubkey)]
+pub struct AddSponsoredRegistrant<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        seeds = [SponsorPool::SEED_PREFIX, sponsor.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.sponsor == sponsor.key() @ RegistryError::Unauthorized
+    )]
+    pub pool: Account<'info, SponsorPool>,
+
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = 8 + SponsorAllowance::INIT_SPACE,
+        seeds = [SponsorAllowance::SEED_PREFIX, pool.key().as_ref(), registrant.as_ref()],
+        bump
+    )]
+    pub allowance: Account<'info, SponsorAllowance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddSponsoredRegistrant>, registrant: Pubkey, registrations: u16) -> Result<()> {
+    let allowance = &mut ctx.accounts.allowance;
+    allowance.pool = ctx.accounts.pool.key();
+    allowance.registrant = registrant;
+    allowance.remaining = allowance.remaining.saturating_add(registrations);
+    allowance.bump = ctx.bumps.allowance;
+
+    msg!(
+        "Sponsored registrant added: pool={}, registrant={}, remaining={}",
+        allowance.pool,
+        registrant,
+        allowance.remaining
+    );
+
+    Ok(())
+}
@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentStatus, ExternalScores, RegistryConfig};
+use crate::error_context::{require_within, ErrorField};
+use crate::errors::RegistryError;
+use super::update_config::{apply_config_params, UpdateConfigParams};
+
+/// Read-only view over an agent and the scoring config
+/// Derived values are computed on-chain so every client shares one formula
+#[derive(Accounts)]
+pub struct GetAgentView<'info> {
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// The agent to query
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+}
+
+/// Read-only view of the composite (challenge + external) reputation
+#[derive(Accounts)]
+pub struct GetCompositeReputation<'info> {
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// The agent to query
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// The agent's imported scores, if any were imported
+    #[account(
+        seeds = [ExternalScores::SEED_PREFIX, agent.key().as_ref()],
+        bump = external_scores.bump
+    )]
+    pub external_scores: Option<Account<'info, ExternalScores>>,
+}
+
+/// Read-only batch view; the agents to summarize are passed as remaining accounts
+#[derive(Accounts)]
+pub struct GetAgentsSummary<'info> {
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+}
+
+/// Returns the agent's reputation after inactivity decay
+pub fn get_effective_reputation(ctx: Context<GetAgentView>) -> Result<EffectiveReputationResponse> {
+    let agent = &ctx.accounts.agent;
+    let now = Clock::get()?.unix_timestamp;
+
+    Ok(EffectiveReputationResponse {
+        agent_id: agent.agent_id,
+        reputation_score: agent.reputation_score,
+        effective_reputation: ctx.accounts.config.effective_reputation(agent, now),
+        last_active_at: agent.updated_at,
+    })
+}
+
+/// Returns raw and type-weighted challenge win rates
+pub fn get_win_rate(ctx: Context<GetAgentView>) -> Result<WinRateResponse> {
+    let agent = &ctx.accounts.agent;
+
+    Ok(WinRateResponse {
+        agent_id: agent.agent_id,
+        challenges_passed: agent.challenges_passed,
+        challenges_failed: agent.challenges_failed,
+        win_rate_bps: agent.win_rate_bps,
+        weighted_win_rate_bps: ctx.accounts.config.weighted_win_rate_bps(agent),
+    })
+}
+
+/// Returns decayed challenge reputation blended with averaged external scores
+pub fn get_composite_reputation(
+    ctx: Context<GetCompositeReputation>,
+) -> Result<CompositeReputationResponse> {
+    let agent = &ctx.accounts.agent;
+    let external = ctx.accounts.external_scores.as_deref();
+    let now = Clock::get()?.unix_timestamp;
+
+    Ok(CompositeReputationResponse {
+        agent_id: agent.agent_id,
+        effective_reputation: ctx.accounts.config.effective_reputation(agent, now),
+        external_average: external.and_then(|e| e.average()),
+        composite_reputation: ctx.accounts.config.composite_reputation(agent, external, now),
+    })
+}
+
+/// Returns one packed summary per agent in `remaining_accounts`, in order
+/// Accounts that aren't current-layout AgentAccounts (closed, foreign or not yet
+/// migrated) are skipped; each summary carries its pubkey so callers can match them up
+pub fn get_agents_summary(ctx: Context<GetAgentsSummary>) -> Result<Vec<AgentSummary>> {
+    require_within(
+        ErrorField::SummaryAgents,
+        ctx.remaining_accounts.len(),
+        MAX_SUMMARY_AGENTS,
+        RegistryError::TooManySummaryAgents,
+    )?;
+
+    let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    let summaries = ctx
+        .remaining_accounts
+        .iter()
+        .filter(|info| info.owner == &crate::ID)
+        .filter_map(|info| {
+            let data = info.try_borrow_data().ok()?;
+            let agent = AgentAccount::try_deserialize(&mut &data[..]).ok()?;
+            Some(AgentSummary {
+                agent: info.key(),
+                effective_reputation: config.effective_reputation(&agent, now),
+                tier: agent.tier,
+                status: agent.status,
+            })
+        })
+        .collect();
+
+    Ok(summaries)
+}
+
+/// Returns the agent's standing under the current config and under the config
+/// `params` would produce (same semantics and validation as `update_config`)
+/// Nothing is written, so governance can size up a change against real agents
+pub fn simulate_policy(ctx: Context<GetAgentView>, params: UpdateConfigParams) -> Result<PolicySimulation> {
+    let agent = &ctx.accounts.agent;
+    let current = &ctx.accounts.config;
+    let mut proposed = (**current).clone();
+    apply_config_params(&mut proposed, params)?;
+    let now = Clock::get()?.unix_timestamp;
+
+    Ok(PolicySimulation {
+        agent_id: agent.agent_id,
+        current: PolicyOutcome::evaluate(current, agent, now),
+        proposed: PolicyOutcome::evaluate(&proposed, agent, now),
+    })
+}
+
+/// Most agents `get_agents_summary` takes: 26 summaries of 38 bytes plus the vec
+/// length fill the 1024-byte return data limit
+pub const MAX_SUMMARY_AGENTS: usize = 26;
+
+/// Packed per-agent entry returned by `get_agents_summary` (38 bytes)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AgentSummary {
+    pub agent: Pubkey,
+    pub effective_reputation: u32,
+    pub tier: u8,
+    pub status: AgentStatus,
+}
+
+/// Response struct for effective reputation queries
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EffectiveReputationResponse {
+    pub agent_id: u64,
+    pub reputation_score: u32,
+    pub effective_reputation: u32,
+    pub last_active_at: i64,
+}
+
+/// Response struct for win rate queries (rates in basis points)
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WinRateResponse {
+    pub agent_id: u64,
+    pub challenges_passed: u32,
+    pub challenges_failed: u32,
+    pub win_rate_bps: u16,
+    pub weighted_win_rate_bps: u16,
+}
+
+/// Response struct for composite reputation queries
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CompositeReputationResponse {
+    pub agent_id: u64,
+    pub effective_reputation: u32,
+    pub external_average: Option<u32>,
+    pub composite_reputation: u32,
+}
+
+/// An agent's config-dependent standing (see `simulate_policy`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PolicyOutcome {
+    pub effective_reputation: u32,
+    /// Tier of the effective reputation
+    pub tier: u8,
+    pub weighted_win_rate_bps: u16,
+    pub fee_discount_bps: u16,
+    /// Audit fee after the agent's discount
+    pub audit_fee_lamports: u64,
+}
+
+impl PolicyOutcome {
+    pub fn evaluate(config: &RegistryConfig, agent: &AgentAccount, now: i64) -> Self {
+        let effective_reputation = config.effective_reputation(agent, now);
+        Self {
+            effective_reputation,
+            tier: AgentAccount::tier_for(effective_reputation),
+            weighted_win_rate_bps: config.weighted_win_rate_bps(agent),
+            fee_discount_bps: config.fee_discount_bps(agent, now),
+            audit_fee_lamports: config.discounted_fee(config.audit_fee_lamports, agent, now),
+        }
+    }
+}
+
+/// Response struct for policy simulations
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PolicySimulation {
+    pub agent_id: u64,
+    pub current: PolicyOutcome,
+    pub proposed: PolicyOutcome,
+}
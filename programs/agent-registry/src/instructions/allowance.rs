@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{AgentAccount, Allowance, PricingModel, ServiceListing};
+use crate::errors::RegistryError;
+
+/// Buy (or top up) a prepaid call package on a per-call listing (consumer)
+/// `price_lamports * calls` goes straight to the agent owner; `max_cost` guards against
+/// the owner raising the price while the transaction is in flight
+#[derive(Accounts)]
+pub struct PurchaseAllowance<'info> {
+    #[account(mut)]
+    pub consumer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.accepts_work(Clock::get()?.unix_timestamp) @ RegistryError::AgentNotActive
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        seeds = [
+            ServiceListing::SEED_PREFIX,
+            agent.key().as_ref(),
+            listing.listing_id.to_le_bytes().as_ref()
+        ],
+        bump = listing.bump,
+        constraint = listing.active @ RegistryError::ListingInactive
+    )]
+    pub listing: Account<'info, ServiceListing>,
+
+    #[account(
+        init_if_needed,
+        payer = consumer,
+        space = 8 + Allowance::INIT_SPACE,
+        seeds = [Allowance::SEED_PREFIX, listing.key().as_ref(), consumer.key().as_ref()],
+        bump
+    )]
+    pub allowance: Account<'info, Allowance>,
+
+    /// CHECK: agent owner wallet, receives the payment
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub owner: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Draw calls from a consumer's allowance (the listing's metering key)
+#[derive(Accounts)]
+pub struct MeterUsage<'info> {
+    pub meter: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        seeds = [
+            ServiceListing::SEED_PREFIX,
+            agent.key().as_ref(),
+            listing.listing_id.to_le_bytes().as_ref()
+        ],
+        bump = listing.bump,
+        constraint = listing.is_meter(&meter.key(), &agent.owner) @ RegistryError::Unauthorized
+    )]
+    pub listing: Account<'info, ServiceListing>,
+
+    #[account(
+        mut,
+        seeds = [Allowance::SEED_PREFIX, listing.key().as_ref(), allowance.consumer.as_ref()],
+        bump = allowance.bump
+    )]
+    pub allowance: Account<'info, Allowance>,
+}
+
+/// Reclaim the rent of a used-up or expired allowance (consumer)
+#[derive(Accounts)]
+pub struct CloseAllowance<'info> {
+    #[account(mut)]
+    pub consumer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = consumer,
+        seeds = [Allowance::SEED_PREFIX, allowance.listing.as_ref(), consumer.key().as_ref()],
+        bump = allowance.bump,
+        constraint = allowance.consumer == consumer.key() @ RegistryError::Unauthorized
+    )]
+    pub allowance: Account<'info, Allowance>,
+}
+
+pub fn purchase_allowance(
+    ctx: Context<PurchaseAllowance>,
+    calls: u64,
+    duration_secs: i64,
+    max_cost: u64,
+) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    require!(listing.pricing == PricingModel::PerCall, RegistryError::InvalidPricing);
+    require!(
+        calls > 0 && duration_secs > 0 && duration_secs <= Allowance::MAX_DURATION_SECS,
+        RegistryError::InvalidAllowance
+    );
+
+    let cost = listing
+        .price_lamports
+        .checked_mul(calls)
+        .ok_or(RegistryError::InvalidAllowance)?;
+    require!(cost <= max_cost, RegistryError::AllowanceCostExceeded);
+    if cost > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.consumer.to_account_info(),
+                    to: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            cost,
+        )?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let allowance = &mut ctx.accounts.allowance;
+    if allowance.consumer == Pubkey::default() {
+        allowance.consumer = ctx.accounts.consumer.key();
+        allowance.agent = ctx.accounts.agent.key();
+        allowance.listing = listing.key();
+        allowance.bump = ctx.bumps.allowance;
+    }
+    allowance.top_up(calls, now + duration_secs, now);
+
+    msg!(
+        "Allowance purchased: agent={}, listing={}, calls={}, paid={}, remaining={}",
+        ctx.accounts.agent.agent_id,
+        listing.listing_id,
+        calls,
+        cost,
+        allowance.remaining_calls
+    );
+
+    Ok(())
+}
+
+pub fn meter_usage(ctx: Context<MeterUsage>, calls: u64) -> Result<()> {
+    require!(calls > 0, RegistryError::InvalidAllowance);
+
+    let allowance = &mut ctx.accounts.allowance;
+    allowance.consume(calls, Clock::get()?.unix_timestamp)?;
+
+    msg!(
+        "Usage metered: agent={}, listing={}, calls={}, remaining={}",
+        ctx.accounts.agent.agent_id,
+        ctx.accounts.listing.listing_id,
+        calls,
+        allowance.remaining_calls
+    );
+
+    Ok(())
+}
+
+pub fn close_allowance(ctx: Context<CloseAllowance>) -> Result<()> {
+    let allowance = &ctx.accounts.allowance;
+    require!(
+        allowance.remaining_calls == 0 || allowance.is_expired(Clock::get()?.unix_timestamp),
+        RegistryError::AllowanceActive
+    );
+
+    msg!(
+        "Allowance closed: listing={}, used={}",
+        allowance.listing,
+        allowance.used_calls
+    );
+
+    Ok(())
+}
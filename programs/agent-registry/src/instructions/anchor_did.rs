@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentDid};
+use crate::error_context::{require_within, ErrorField};
+use crate::errors::RegistryError;
+
+/// Anchor a DID document to an agent and name its controller (owner only, once)
+#[derive(Accounts)]
+pub struct AnchorDid<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + AgentDid::INIT_SPACE,
+        seeds = [AgentDid::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub did: Account<'info, AgentDid>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<AnchorDid>,
+    did_uri: String,
+    did_doc_hash: [u8; 32],
+    controller: Pubkey,
+) -> Result<()> {
+    require_within(ErrorField::Uri, did_uri.len(), AgentDid::MAX_URI_LEN, RegistryError::UriTooLong)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let did = &mut ctx.accounts.did;
+    did.agent = ctx.accounts.agent.key();
+    did.controller = controller;
+    did.did_uri = did_uri;
+    did.did_doc_hash = did_doc_hash;
+    did.revision = 0;
+    did.created_at = now;
+    did.updated_at = now;
+    did.bump = ctx.bumps.did;
+
+    msg!(
+        "DID anchored: agent={}, did={}, controller={}",
+        ctx.accounts.agent.agent_id,
+        did.did_uri,
+        controller
+    );
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AuditDelegate, AuditMerkleTree, RegistryConfig, RegistryState};
+use crate::errors::RegistryError;
+use crate::events::AuditLeafAppended;
+
+use super::log_audit::charge_audit_fee;
+
+/// Accounts for appending a single leaf to the agent's incremental audit tree
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(leaf: [u8; 32])]
+pub struct AppendAuditLeaf<'info> {
+    /// The agent owner, or an audit delegate when `audit_delegate` is passed
+    /// Pays the audit fee and any rent
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+
+    /// The agent being audited
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.accepts_work(Clock::get()?.unix_timestamp) @ RegistryError::AgentNotActive,
+        constraint = !agent.is_audit_quarantined() @ RegistryError::AuditQuarantined
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// The submitter's delegation from the agent owner (required unless the owner submits)
+    #[account(
+        mut,
+        seeds = [AuditDelegate::SEED_PREFIX, agent.key().as_ref(), submitter.key().as_ref()],
+        bump = audit_delegate.bump
+    )]
+    pub audit_delegate: Option<Account<'info, AuditDelegate>>,
+
+    /// Registry config (audit fee and discount schedule)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Treasury PDA receiving the audit fee
+    #[account(
+        mut,
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// The incremental Merkle tree for this agent (created on first append)
+    #[account(
+        init_if_needed,
+        payer = submitter,
+        space = 8 + AuditMerkleTree::INIT_SPACE,
+        seeds = [AuditMerkleTree::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_tree: Account<'info, AuditMerkleTree>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AppendAuditLeaf>, leaf: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    if ctx.accounts.agent.owner != ctx.accounts.submitter.key() {
+        ctx.accounts
+            .audit_delegate
+            .as_mut()
+            .ok_or(RegistryError::Unauthorized)?
+            .record_submission(AuditDelegate::SCOPE_AUDIT_LEAF, clock.unix_timestamp)?;
+    }
+    let audit_fee = charge_audit_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.agent,
+        &ctx.accounts.submitter.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        clock.unix_timestamp,
+    )?;
+    let agent_key = ctx.accounts.agent.key();
+
+    // Initializes the tree on first append
+    let tree = &mut ctx.accounts.audit_tree;
+    let leaf_index = tree.record(agent_key, ctx.bumps.audit_tree, leaf, clock.unix_timestamp)?;
+    let root = tree.root;
+
+    emit_cpi!(AuditLeafAppended {
+        agent: agent_key,
+        leaf_index,
+        leaf,
+        root,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Audit leaf appended: agent={}, index={}, fee={}, root={:?}",
+        agent_key,
+        leaf_index,
+        audit_fee,
+        &root[..8] // Log first 8 bytes for brevity
+    );
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentMultisig, MultisigProposal};
+use crate::errors::RegistryError;
+
+/// Approve a pending multisig proposal (owners only)
+#[derive(Accounts)]
+pub struct ApproveMultisigProposal<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [AgentMultisig::SEED_PREFIX, multisig.agent.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AgentMultisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            MultisigProposal::SEED_PREFIX,
+            multisig.key().as_ref(),
+            proposal.index.to_le_bytes().as_ref()
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+}
+
+pub fn handler(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(!proposal.executed, RegistryError::ProposalAlreadyExecuted);
+    require!(
+        proposal.owner_set_seq == multisig.owner_set_seq,
+        RegistryError::ProposalStale
+    );
+
+    let idx = multisig
+        .owner_index(&ctx.accounts.owner.key())
+        .ok_or(RegistryError::NotMultisigOwner)?;
+    proposal.approvals |= 1 << idx;
+
+    msg!(
+        "Multisig proposal approved: index={}, approvals={}/{}",
+        proposal.index,
+        proposal.approvals.count_ones(),
+        multisig.threshold
+    );
+
+    Ok(())
+}
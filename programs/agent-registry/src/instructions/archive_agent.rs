@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentArchive, AgentStatus, OwnerIndex, UnstakeRequest};
+use crate::errors::RegistryError;
+
+use super::close_agent::check_close;
+
+/// Replace a retired agent with a small AgentArchive once the close delay has passed
+/// (owner only; multisig agents use the `Archive` action). The agent is closed as in
+/// `close_agent`: rent and any remaining stake go to the owner (stake unlocked, no unstake
+/// pending), who funds the much smaller archive out of it
+#[derive(Accounts)]
+pub struct ArchiveAgent<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status == AgentStatus::Retired @ RegistryError::InvalidStatusTransition
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: the agent's UnstakeRequest PDA; it must not exist, since a pending
+    /// withdrawal is still slashable and would be stranded by the close
+    #[account(
+        seeds = [UnstakeRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub unstake_request: UncheckedAccount<'info>,
+
+    /// Owner's agent index; the closed agent's ID is dropped when passed
+    #[account(
+        mut,
+        seeds = [OwnerIndex::SEED_PREFIX, owner.key().as_ref()],
+        bump = owner_index.bump
+    )]
+    pub owner_index: Option<Account<'info, OwnerIndex>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + AgentArchive::INIT_SPACE,
+        seeds = [AgentArchive::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub archive: Account<'info, AgentArchive>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ArchiveAgent>) -> Result<()> {
+    let agent = &ctx.accounts.agent;
+    let now = Clock::get()?.unix_timestamp;
+    check_close(agent, &ctx.accounts.unstake_request, now)?;
+
+    if let Some(index) = ctx.accounts.owner_index.as_mut() {
+        index.remove(agent.agent_id);
+    }
+
+    record_archive(&mut ctx.accounts.archive, agent, now, ctx.bumps.archive)?;
+
+    msg!(
+        "Agent archived: id={}, reputation={}, stake returned={}",
+        agent.agent_id,
+        agent.reputation_score,
+        agent.staked_lamports
+    );
+
+    Ok(())
+}
+
+/// Fill `archive` from the agent about to be closed, shared with the multisig `Archive` action
+pub(crate) fn record_archive(
+    archive: &mut AgentArchive,
+    agent: &Account<AgentAccount>,
+    now: i64,
+    bump: u8,
+) -> Result<()> {
+    let mut serialized = Vec::new();
+    agent.try_serialize(&mut serialized)?;
+
+    archive.agent = agent.key();
+    archive.agent_id = agent.agent_id;
+    archive.owner = agent.owner;
+    archive.state_hash = solana_sha256_hasher::hash(&serialized).to_bytes();
+    archive.reputation_score = agent.reputation_score;
+    archive.challenges_passed = agent.challenges_passed;
+    archive.challenges_failed = agent.challenges_failed;
+    archive.win_rate_bps = agent.win_rate_bps;
+    archive.best_streak = agent.best_streak;
+    archive.tier = agent.tier;
+    archive.verification_level = agent.verification_level;
+    archive.proven_capabilities = agent.proven_capabilities;
+    archive.zk_verified_inferences = agent.zk_verified_inferences;
+    archive.created_at = agent.created_at;
+    archive.retired_at = agent.status_changed_at;
+    archive.final_seq = agent.seq;
+    archive.archived_at = now;
+    archive.bump = bump;
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Attestation, Attester};
+use crate::errors::RegistryError;
+
+/// Issue or renew an attestation for an agent's current model (active attesters only)
+#[derive(Accounts)]
+pub struct AttestAgent<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Attester::SEED_PREFIX, authority.key().as_ref()],
+        bump = attester.bump,
+        constraint = attester.active @ RegistryError::AttesterInactive
+    )]
+    pub attester: Account<'info, Attester>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Attestation::INIT_SPACE,
+        seeds = [Attestation::SEED_PREFIX, agent.key().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AttestAgent>, report_hash: [u8; 32], expires_at: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        expires_at > now && expires_at - now <= Attestation::MAX_VALIDITY_SECS,
+        RegistryError::InvalidAttestation
+    );
+
+    let attester = &mut ctx.accounts.attester;
+    attester.attestations_issued = attester.attestations_issued.saturating_add(1);
+
+    let attestation = &mut ctx.accounts.attestation;
+    attestation.agent = ctx.accounts.agent.key();
+    attestation.attester = attester.authority;
+    attestation.kind = attester.kind;
+    attestation.report_hash = report_hash;
+    attestation.model_hash = ctx.accounts.agent.model_hash.clone();
+    attestation.issued_at = now;
+    attestation.expires_at = expires_at;
+    attestation.bump = ctx.bumps.attestation;
+
+    msg!(
+        "Agent attested: id={}, attester={}, kind={:?}, expires_at={}",
+        ctx.accounts.agent.agent_id,
+        attestation.attester,
+        attestation.kind,
+        expires_at
+    );
+
+    Ok(())
+}
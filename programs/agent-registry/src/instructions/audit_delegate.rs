@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AuditDelegate};
+use crate::errors::RegistryError;
+
+/// Authorize (or re-scope) a service to submit audits for an agent (owner only)
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct SetAuditDelegate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + AuditDelegate::INIT_SPACE,
+        seeds = [AuditDelegate::SEED_PREFIX, agent.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub audit_delegate: Account<'info, AuditDelegate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Revoke a delegation, reclaiming its rent (owner only)
+#[derive(Accounts)]
+pub struct RevokeAuditDelegate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            AuditDelegate::SEED_PREFIX,
+            agent.key().as_ref(),
+            audit_delegate.delegate.as_ref()
+        ],
+        bump = audit_delegate.bump
+    )]
+    pub audit_delegate: Account<'info, AuditDelegate>,
+}
+
+pub fn set_audit_delegate(
+    ctx: Context<SetAuditDelegate>,
+    delegate: Pubkey,
+    scopes: u8,
+    expires_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        scopes != 0
+            && scopes & !AuditDelegate::ALL_SCOPES == 0
+            && (expires_at == 0 || expires_at > now)
+            && delegate != ctx.accounts.owner.key(),
+        RegistryError::InvalidAuditDelegate
+    );
+
+    let audit_delegate = &mut ctx.accounts.audit_delegate;
+    if audit_delegate.delegate == Pubkey::default() {
+        audit_delegate.agent = ctx.accounts.agent.key();
+        audit_delegate.delegate = delegate;
+        audit_delegate.bump = ctx.bumps.audit_delegate;
+    }
+    audit_delegate.scopes = scopes;
+    audit_delegate.expires_at = expires_at;
+    audit_delegate.authorized_at = now;
+
+    msg!(
+        "Audit delegate set: agent={}, delegate={}, scopes={:#04b}, expires_at={}",
+        ctx.accounts.agent.agent_id,
+        delegate,
+        scopes,
+        expires_at
+    );
+
+    Ok(())
+}
+
+pub fn revoke_audit_delegate(ctx: Context<RevokeAuditDelegate>) -> Result<()> {
+    let audit_delegate = &ctx.accounts.audit_delegate;
+
+    msg!(
+        "Audit delegate revoked: agent={}, delegate={}, submissions={}",
+        ctx.accounts.agent.agent_id,
+        audit_delegate.delegate,
+        audit_delegate.submissions
+    );
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentWatchers, Attestation, Attester, RegistryConfig};
+use crate::errors::RegistryError;
+
+/// Grant base verification to an agent that meets the stake, reputation and
+/// attestation thresholds (permissionless)
+#[derive(Accounts)]
+pub struct AutoVerify<'info> {
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.verification_level < AgentAccount::VERIFICATION_BASE
+            @ RegistryError::AlreadyVerified,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        seeds = [Attester::SEED_PREFIX, attester.authority.as_ref()],
+        bump = attester.bump,
+        constraint = attester.active @ RegistryError::AttesterInactive
+    )]
+    pub attester: Account<'info, Attester>,
+
+    #[account(
+        seeds = [Attestation::SEED_PREFIX, agent.key().as_ref(), attester.authority.as_ref()],
+        bump = attestation.bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<AutoVerify>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    let agent = &mut ctx.accounts.agent;
+
+    require!(
+        ctx.accounts.attestation.is_valid_for(agent, now),
+        RegistryError::InvalidAttestation
+    );
+    require!(
+        agent.staked_lamports >= config.auto_verify_min_stake_lamports
+            && agent.reputation_score >= config.auto_verify_min_reputation,
+        RegistryError::AgentConstraintNotMet
+    );
+
+    agent.verified = true;
+    agent.verification_level = AgentAccount::VERIFICATION_BASE;
+    agent.touch(now);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_VERIFICATION)?;
+
+    msg!(
+        "Agent auto-verified: id={}, stake={}, reputation={}, attester={}",
+        agent.agent_id,
+        agent.staked_lamports,
+        agent.reputation_score,
+        ctx.accounts.attester.authority
+    );
+
+    Ok(())
+}
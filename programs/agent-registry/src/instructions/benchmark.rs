@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    benchmark_message, verify_ed25519_signature, AgentAccount, Benchmark, BenchmarkEvaluator,
+    BenchmarkScore, ModelRecord, RegistryState,
+};
+use crate::errors::RegistryError;
+
+/// Approve or deactivate a benchmark evaluator (admin only)
+#[derive(Accounts)]
+#[instruction(evaluator_authority: Pubkey)]
+pub struct SetBenchmarkEvaluator<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + BenchmarkEvaluator::INIT_SPACE,
+        seeds = [BenchmarkEvaluator::SEED_PREFIX, evaluator_authority.as_ref()],
+        bump
+    )]
+    pub evaluator: Account<'info, BenchmarkEvaluator>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Define, redefine or retire a benchmark suite (admin only)
+#[derive(Accounts)]
+#[instruction(benchmark_id: u32)]
+pub struct DefineBenchmark<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Benchmark::INIT_SPACE,
+        seeds = [Benchmark::SEED_PREFIX, benchmark_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub benchmark: Account<'info, Benchmark>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Record (or refresh) an evaluator-signed benchmark result for an agent
+/// The transaction must carry an ed25519 program instruction right before this one,
+/// signed by the evaluator over
+/// `benchmark_message(agent, benchmark_id, score, model_digest, evaluated_at)`
+#[derive(Accounts)]
+pub struct RecordBenchmarkScore<'info> {
+    /// Any relayer; pays fees and rent
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BenchmarkEvaluator::SEED_PREFIX, evaluator.authority.as_ref()],
+        bump = evaluator.bump,
+        constraint = evaluator.active @ RegistryError::EvaluatorInactive
+    )]
+    pub evaluator: Account<'info, BenchmarkEvaluator>,
+
+    #[account(
+        mut,
+        seeds = [Benchmark::SEED_PREFIX, benchmark.benchmark_id.to_le_bytes().as_ref()],
+        bump = benchmark.bump,
+        constraint = benchmark.active @ RegistryError::BenchmarkInactive
+    )]
+    pub benchmark: Account<'info, Benchmark>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.accepts_work(Clock::get()?.unix_timestamp) @ RegistryError::AgentNotActive
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + BenchmarkScore::INIT_SPACE,
+        seeds = [
+            BenchmarkScore::SEED_PREFIX,
+            agent.key().as_ref(),
+            benchmark.benchmark_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub score: Account<'info, BenchmarkScore>,
+
+    /// CHECK: address is pinned to the instructions sysvar
+    #[account(address = solana_sdk_ids::sysvar::instructions::ID @ RegistryError::InvalidSignature)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_benchmark_evaluator(
+    ctx: Context<SetBenchmarkEvaluator>,
+    evaluator_authority: Pubkey,
+    active: bool,
+) -> Result<()> {
+    let evaluator = &mut ctx.accounts.evaluator;
+
+    if evaluator.authority == Pubkey::default() {
+        evaluator.authority = evaluator_authority;
+        evaluator.registered_at = Clock::get()?.unix_timestamp;
+        evaluator.bump = ctx.bumps.evaluator;
+    }
+    evaluator.active = active;
+
+    msg!("Benchmark evaluator set: {} active={}", evaluator_authority, active);
+
+    Ok(())
+}
+
+pub fn define_benchmark(
+    ctx: Context<DefineBenchmark>,
+    benchmark_id: u32,
+    suite_hash: [u8; 32],
+    max_score: u32,
+    active: bool,
+) -> Result<()> {
+    require!(max_score > 0, RegistryError::InvalidBenchmarkScore);
+
+    let benchmark = &mut ctx.accounts.benchmark;
+    if benchmark.created_at == 0 {
+        benchmark.created_at = Clock::get()?.unix_timestamp;
+        benchmark.benchmark_id = benchmark_id;
+        benchmark.bump = ctx.bumps.benchmark;
+    }
+    // A changed suite or scale makes existing rankings incomparable
+    if benchmark.suite_hash != suite_hash || benchmark.max_score != max_score {
+        benchmark.leaderboard.clear();
+    }
+    benchmark.suite_hash = suite_hash;
+    benchmark.max_score = max_score;
+    benchmark.active = active;
+
+    msg!(
+        "Benchmark defined: id={}, max_score={}, active={}",
+        benchmark_id,
+        max_score,
+        active
+    );
+
+    Ok(())
+}
+
+pub fn record_benchmark_score(
+    ctx: Context<RecordBenchmarkScore>,
+    score: u32,
+    evaluated_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let record = &mut ctx.accounts.score;
+
+    // Signed evaluation times only move forward, so an old result can't be replayed
+    require!(
+        score <= ctx.accounts.benchmark.max_score
+            && evaluated_at <= now
+            && evaluated_at > record.evaluated_at,
+        RegistryError::InvalidBenchmarkScore
+    );
+
+    let agent = &ctx.accounts.agent;
+    let benchmark_id = ctx.accounts.benchmark.benchmark_id;
+    let model_digest = ModelRecord::digest(&agent.model_hash);
+    let evaluator = &mut ctx.accounts.evaluator;
+    let message = benchmark_message(&agent.key(), benchmark_id, score, &model_digest, evaluated_at);
+    let signature = verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &evaluator.authority,
+        &message,
+    )?;
+
+    evaluator.scores_recorded = evaluator.scores_recorded.saturating_add(1);
+
+    record.agent = agent.key();
+    record.benchmark_id = benchmark_id;
+    record.evaluator = evaluator.authority;
+    record.score = score;
+    record.model_digest = model_digest;
+    record.signature = signature;
+    record.evaluated_at = evaluated_at;
+    record.recorded_at = now;
+    record.bump = ctx.bumps.score;
+
+    let benchmark = &mut ctx.accounts.benchmark;
+    benchmark.submissions = benchmark.submissions.saturating_add(1);
+    benchmark.rank(agent.key(), score);
+
+    msg!(
+        "Benchmark score recorded: agent={}, benchmark={}, score={}/{}, evaluator={}",
+        agent.agent_id,
+        benchmark_id,
+        score,
+        benchmark.max_score,
+        record.evaluator
+    );
+
+    Ok(())
+}
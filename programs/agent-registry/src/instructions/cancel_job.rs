@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, AgentWatchers, Job, JobStatus, RegistryConfig, ReputationHistory,
+    ReputationHooks, ReputationSource,
+};
+use crate::errors::RegistryError;
+use crate::events::ReputationChanged;
+
+/// Withdraw from a job and recover the escrow (consumer only)
+/// Open jobs cancel without penalty; accepted jobs past their delivery
+/// deadline are refunded and the agent is penalized
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelJob<'info> {
+    #[account(address = job.consumer @ RegistryError::Unauthorized)]
+    pub caller: Signer<'info>,
+
+    /// Registry config (reputation bounds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            Job::SEED_PREFIX,
+            job.listing.as_ref(),
+            job.consumer.as_ref(),
+            job.job_id.to_le_bytes().as_ref()
+        ],
+        bump = job.bump,
+        constraint = job.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub job: Account<'info, Job>,
+
+    /// CHECK: agent owner wallet, receives the escrow on settlement
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: consumer wallet, receives the escrow on refund
+    #[account(mut, address = job.consumer @ RegistryError::Unauthorized)]
+    pub consumer: UncheckedAccount<'info>,
+
+    /// Optional reputation history; the change is appended when passed
+    #[account(
+        mut,
+        constraint = history.load()?.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub history: Option<AccountLoader<'info, ReputationHistory>>,
+
+    /// CHECK: the agent's ReputationHooks PDA, which may not exist; once it does the
+    /// change is recorded there and delivered to hook programs passed as remaining accounts
+    #[account(
+        mut,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub hooks: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CancelJob<'info>>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    match ctx.accounts.job.status {
+        JobStatus::Open => {
+            let job = &mut ctx.accounts.job;
+            job.status = JobStatus::Cancelled;
+            job.closed_at = now;
+            let refunded = Job::release_escrow(job, &ctx.accounts.consumer.to_account_info())?;
+
+            msg!("Job cancelled: job={}, refunded={}", job.job_id, refunded);
+        }
+        JobStatus::Accepted => {
+            require!(
+                now > ctx.accounts.job.delivery_deadline,
+                RegistryError::JobDeadlineNotReached
+            );
+            let old_reputation = ctx.accounts.agent.reputation_score;
+            let refunded = Job::finalize(
+                &mut ctx.accounts.job,
+                &mut ctx.accounts.agent,
+                &ctx.accounts.config,
+                false,
+                &ctx.accounts.owner.to_account_info(),
+                &ctx.accounts.consumer.to_account_info(),
+                now,
+            )?;
+            ctx.accounts.agent.credit_reputation(ReputationSource::JobSettlement, old_reputation);
+            ReputationHistory::record(
+                ctx.accounts.history.as_ref(),
+                old_reputation,
+                ctx.accounts.agent.reputation_score,
+                ReputationSource::JobSettlement,
+                now,
+            )?;
+            emit_cpi!(ReputationChanged {
+                agent: ctx.accounts.agent.key(),
+                agent_id: ctx.accounts.agent.agent_id,
+                old_score: old_reputation,
+                new_score: ctx.accounts.agent.reputation_score,
+                source: ReputationSource::JobSettlement as u8,
+                timestamp: now,
+            });
+            ReputationHooks::notify_agent(
+                &ctx.accounts.hooks,
+                &ctx.accounts.agent,
+                ctx.remaining_accounts,
+                old_reputation,
+                now,
+            )?;
+            AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_REPUTATION)?;
+
+            msg!(
+                "Job refunded after missed deadline: job={}, refunded={}, reputation={}",
+                ctx.accounts.job.job_id,
+                refunded,
+                ctx.accounts.agent.reputation_score
+            );
+        }
+        _ => return err!(RegistryError::InvalidJobStatus),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,275 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, AgentWatchers, AuditMerkleTree, Challenge, ChallengeEvidence, ChallengeStatus,
+    ChallengeTemplate, Judge, JudgeVote, RegistryConfig, ReputationHistory, ReputationHooks,
+    ReputationSource,
+};
+use crate::errors::RegistryError;
+use crate::events::{ChallengeAnchored, ReputationChanged};
+
+/// A committee judge votes on a judged challenge's result
+/// Once either verdict reaches `config.judge_quorum` votes the challenge is
+/// resolved in the same transaction (reputation + escrow + challenge stream), no admin involved
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nonce: u64, passed: bool)]
+pub struct CastJudgeVote<'info> {
+    /// The voting judge (pays for the vote record)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The judge's committee record
+    #[account(
+        mut,
+        seeds = [Judge::SEED_PREFIX, authority.key().as_ref()],
+        bump = judge.bump,
+        constraint = judge.active @ RegistryError::JudgeInactive
+    )]
+    pub judge: Account<'info, Judge>,
+
+    /// Registry config (quorum and reputation weights)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// The agent that was challenged
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// The challenge being judged
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.status == ChallengeStatus::AwaitingVerdict @ RegistryError::ChallengeNotAwaitingVerdict
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// The vote record (one per judge per challenge)
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + JudgeVote::INIT_SPACE,
+        seeds = [JudgeVote::SEED_PREFIX, challenge.key().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub vote: Account<'info, JudgeVote>,
+
+    /// CHECK: The agent owner - receives the escrow on a pass verdict
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: The challenger - receives the escrow back on a fail verdict
+    #[account(mut, address = challenge.challenger @ RegistryError::ChallengeMismatch)]
+    pub challenger: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Optional reputation history; the change is appended when passed
+    #[account(
+        mut,
+        constraint = history.load()?.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub history: Option<AccountLoader<'info, ReputationHistory>>,
+
+    /// CHECK: the challenge's ChallengeEvidence PDA, which may not exist yet; the vote
+    /// records the evidence digest it was cast on
+    #[account(
+        seeds = [ChallengeEvidence::SEED_PREFIX, challenge.key().as_ref()],
+        bump
+    )]
+    pub evidence: UncheckedAccount<'info>,
+
+    /// The challenge's template (required when it was created from one, for the judge set)
+    #[account(address = challenge.template @ RegistryError::ChallengeMismatch)]
+    pub template: Option<Account<'info, ChallengeTemplate>>,
+
+    /// The agent's challenge-result audit stream (created on first resolution)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AuditMerkleTree::INIT_SPACE,
+        seeds = [AuditMerkleTree::SEED_PREFIX, agent.key().as_ref(), AuditMerkleTree::CHALLENGE_STREAM],
+        bump
+    )]
+    pub challenge_stream: Account<'info, AuditMerkleTree>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's ReputationHooks PDA, which may not exist; once it does the
+    /// change is recorded there and delivered to hook programs passed as remaining accounts
+    #[account(
+        mut,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub hooks: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CastJudgeVote<'info>>,
+    _nonce: u64,
+    passed: bool,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let challenge_key = ctx.accounts.challenge.key();
+    require!(
+        !ctx.accounts.challenge.verdict_overdue(clock.unix_timestamp),
+        RegistryError::ChallengeExpired
+    );
+
+    if ctx.accounts.challenge.has_template() {
+        let template = ctx.accounts.template.as_ref().ok_or(RegistryError::ChallengeMismatch)?;
+        require!(
+            template.allows_judge(&ctx.accounts.authority.key()),
+            RegistryError::JudgeNotInTemplate
+        );
+    }
+
+    // Record the vote
+    let vote = &mut ctx.accounts.vote;
+    vote.challenge = challenge_key;
+    vote.judge = ctx.accounts.authority.key();
+    vote.passed = passed;
+    vote.voted_at = clock.unix_timestamp;
+    vote.bump = ctx.bumps.vote;
+    vote.evidence_digest = evidence_digest(&ctx.accounts.evidence)?;
+
+    // Update judge accountability counters
+    let judge = &mut ctx.accounts.judge;
+    judge.votes_cast = judge.votes_cast.saturating_add(1);
+    if passed {
+        judge.votes_pass = judge.votes_pass.saturating_add(1);
+    } else {
+        judge.votes_fail = judge.votes_fail.saturating_add(1);
+    }
+    judge.last_vote_at = clock.unix_timestamp;
+
+    // Tally
+    let challenge = &mut ctx.accounts.challenge;
+    if passed {
+        challenge.votes_pass = challenge.votes_pass.saturating_add(1);
+    } else {
+        challenge.votes_fail = challenge.votes_fail.saturating_add(1);
+    }
+
+    msg!(
+        "Judge vote: challenge={}, judge={}, passed={}, tally={}/{}",
+        challenge_key,
+        vote.judge,
+        passed,
+        challenge.votes_pass,
+        challenge.votes_fail
+    );
+
+    // Resolve automatically once a verdict reaches quorum
+    let quorum = ctx.accounts.config.judge_quorum;
+    let verdict = if challenge.votes_pass >= quorum {
+        Some(true)
+    } else if challenge.votes_fail >= quorum {
+        Some(false)
+    } else {
+        None
+    };
+
+    if let Some(verdict) = verdict {
+        let owner_info = ctx.accounts.owner.to_account_info();
+        let challenger_info = ctx.accounts.challenger.to_account_info();
+        let agent = &mut ctx.accounts.agent;
+        let old_reputation = agent.reputation_score;
+        let escrow = Challenge::settle(
+            challenge,
+            agent,
+            &ctx.accounts.config,
+            verdict,
+            &owner_info,
+            &challenger_info,
+            clock.unix_timestamp,
+        )?;
+        agent.credit_reputation(ReputationSource::ChallengeProgram, old_reputation);
+        ReputationHistory::record(
+            ctx.accounts.history.as_ref(),
+            old_reputation,
+            agent.reputation_score,
+            ReputationSource::ChallengeProgram,
+            clock.unix_timestamp,
+        )?;
+        emit_cpi!(ReputationChanged {
+            agent: agent.key(),
+            agent_id: agent.agent_id,
+            old_score: old_reputation,
+            new_score: agent.reputation_score,
+            source: ReputationSource::ChallengeProgram as u8,
+            timestamp: clock.unix_timestamp,
+        });
+        ReputationHooks::notify_agent(
+            &ctx.accounts.hooks,
+            agent,
+            ctx.remaining_accounts,
+            old_reputation,
+            clock.unix_timestamp,
+        )?;
+        AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_REPUTATION)?;
+
+        // Link the result into the agent's audit history
+        let leaf = challenge.result_leaf(
+            &challenge_key,
+            old_reputation,
+            agent.reputation_score,
+            clock.unix_timestamp,
+        );
+        let stream = &mut ctx.accounts.challenge_stream;
+        let leaf_index =
+            stream.record(agent.key(), ctx.bumps.challenge_stream, leaf, clock.unix_timestamp)?;
+        emit_cpi!(ChallengeAnchored {
+            agent: agent.key(),
+            challenge: challenge_key,
+            leaf_index,
+            leaf,
+            root: stream.root,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Committee verdict reached: agent {} {} (reputation: {}, escrow moved: {})",
+            agent.agent_id,
+            if verdict { "PASSED" } else { "FAILED" },
+            agent.reputation_score,
+            escrow
+        );
+    }
+
+    Ok(())
+}
+
+/// Digest of the anchored evidence, zero when none was submitted
+fn evidence_digest(evidence: &UncheckedAccount) -> Result<[u8; 32]> {
+    if evidence.data_is_empty() {
+        return Ok([0u8; 32]);
+    }
+    require_keys_eq!(*evidence.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    Ok(ChallengeEvidence::try_deserialize(&mut &evidence.try_borrow_data()?[..])?.digest)
+}
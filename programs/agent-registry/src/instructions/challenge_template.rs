@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::{ChallengeTemplate, ChallengeType, RegistryConfig, RegistryState};
+use crate::errors::RegistryError;
+
+/// Publish a standardized challenge spec (anyone; templates from the admin are curated)
+#[derive(Accounts)]
+#[instruction(spec_hash: [u8; 32])]
+pub struct CreateChallengeTemplate<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Registry config (weight cap and judge quorum)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + ChallengeTemplate::INIT_SPACE,
+        seeds = [ChallengeTemplate::SEED_PREFIX, spec_hash.as_ref()],
+        bump
+    )]
+    pub template: Account<'info, ChallengeTemplate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open or retire a template for new challenges (its creator or the admin)
+#[derive(Accounts)]
+pub struct SetChallengeTemplateActive<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [ChallengeTemplate::SEED_PREFIX, template.spec_hash.as_ref()],
+        bump = template.bump,
+        constraint = authority.key() == template.creator
+            || authority.key() == registry.admin @ RegistryError::Unauthorized
+    )]
+    pub template: Account<'info, ChallengeTemplate>,
+}
+
+/// A template's challenge settings
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChallengeTemplateParams {
+    pub challenge_type: ChallengeType,
+    pub judged: bool,
+    pub default_stake: u64,
+    pub pass_weight_bps: u16,
+    pub fail_weight_bps: u16,
+    pub judges: Vec<Pubkey>,
+}
+
+pub fn create_challenge_template(
+    ctx: Context<CreateChallengeTemplate>,
+    spec_hash: [u8; 32],
+    params: ChallengeTemplateParams,
+) -> Result<()> {
+    require!(
+        ChallengeTemplate::is_valid(
+            params.judged,
+            params.pass_weight_bps,
+            params.fail_weight_bps,
+            &params.judges,
+            &ctx.accounts.config
+        ),
+        RegistryError::InvalidChallengeTemplate
+    );
+
+    let creator = ctx.accounts.creator.key();
+    let template = &mut ctx.accounts.template;
+    template.spec_hash = spec_hash;
+    template.creator = creator;
+    template.curated = creator == ctx.accounts.registry.admin;
+    template.challenge_type = params.challenge_type;
+    template.judged = params.judged;
+    template.default_stake = params.default_stake;
+    template.pass_weight_bps = params.pass_weight_bps;
+    template.fail_weight_bps = params.fail_weight_bps;
+    template.judges = params.judges;
+    template.active = true;
+    template.challenges_created = 0;
+    template.created_at = Clock::get()?.unix_timestamp;
+    template.bump = ctx.bumps.template;
+
+    msg!(
+        "Challenge template created: template={}, type={:?}, judged={}, curated={}, stake={}, weights={}/{}bps, judges={}",
+        template.key(),
+        template.challenge_type,
+        template.judged,
+        template.curated,
+        template.default_stake,
+        template.pass_weight_bps,
+        template.fail_weight_bps,
+        template.judges.len()
+    );
+
+    Ok(())
+}
+
+pub fn set_challenge_template_active(ctx: Context<SetChallengeTemplateActive>, active: bool) -> Result<()> {
+    let template = &mut ctx.accounts.template;
+    template.active = active;
+
+    msg!("Challenge template {}: active={}", template.key(), active);
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use crate::state::{
+    AgentAccount, AgentAuditSummary, AuditMerkleTree, BadgeClaim, BadgeKind, BadgeMint, MerkleAuditSummary,
+    ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
+};
+use crate::errors::RegistryError;
+
+/// Mint a soulbound badge to the agent owner once a milestone is reached
+/// Milestones are checked against on-chain counters; each is claimable once per agent
+#[derive(Accounts)]
+#[instruction(kind: BadgeKind)]
+pub struct ClaimBadge<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Individual audit entry counter (counted toward `MillionAudits` when passed)
+    #[account(
+        seeds = [AgentAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump = audit_summary.bump
+    )]
+    pub audit_summary: Option<Account<'info, AgentAuditSummary>>,
+
+    /// Batched audit entry counter (counted toward `MillionAudits` when passed)
+    #[account(
+        seeds = [MerkleAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump = merkle_summary.bump
+    )]
+    pub merkle_summary: Option<Account<'info, MerkleAuditSummary>>,
+
+    /// Audit tree leaf counter (counted toward `MillionAudits` when passed)
+    #[account(
+        seeds = [AuditMerkleTree::SEED_PREFIX, agent.key().as_ref()],
+        bump = audit_tree.bump
+    )]
+    pub audit_tree: Option<Account<'info, AuditMerkleTree>>,
+
+    /// Keyed by agent ID, which `transfer_agent` keeps, so a transferred agent's
+    /// successor can't claim the same badge again
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + BadgeClaim::INIT_SPACE,
+        seeds = [
+            BadgeClaim::SEED_PREFIX,
+            agent.agent_id.to_le_bytes().as_ref(),
+            &[kind.seed()]
+        ],
+        bump
+    )]
+    pub badge_claim: Account<'info, BadgeClaim>,
+
+    /// CHECK: badge mint PDA (initialized by `create_badge_mint`, validated by Token-2022)
+    #[account(
+        mut,
+        seeds = [BadgeMint::SEED_PREFIX, &[kind.seed()]],
+        bump,
+        owner = TOKEN_2022_PROGRAM_ID @ RegistryError::BadgeMintNotCreated
+    )]
+    pub badge_mint: UncheckedAccount<'info>,
+
+    /// CHECK: owner's associated token account for the badge mint (created if missing;
+    /// the associated token program rejects any other address)
+    #[account(mut)]
+    pub owner_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Token-2022 program
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: UncheckedAccount<'info>,
+
+    /// CHECK: associated token account program
+    #[account(address = ASSOCIATED_TOKEN_PROGRAM_ID)]
+    pub associated_token_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimBadge>, kind: BadgeKind) -> Result<()> {
+    let agent = &ctx.accounts.agent;
+    let reached = match kind {
+        BadgeKind::FirstVerification => agent.verified,
+        BadgeKind::CenturyPasses => agent.challenges_passed >= BadgeKind::CENTURY_PASSES,
+        // Quarantined audit history counts for nothing
+        BadgeKind::MillionAudits if agent.is_audit_quarantined() => false,
+        BadgeKind::MillionAudits => {
+            let entries = ctx.accounts.audit_summary.as_ref().map_or(0, |s| s.total_entries);
+            let batched = ctx.accounts.merkle_summary.as_ref().map_or(0, |s| s.total_entries);
+            let leaves = ctx.accounts.audit_tree.as_ref().map_or(0, |t| t.next_index);
+            entries.saturating_add(batched).saturating_add(leaves) >= BadgeKind::MILLION_AUDITS
+        }
+    };
+    require!(reached, RegistryError::MilestoneNotReached);
+
+    let owner = ctx.accounts.owner.to_account_info();
+    let mint = ctx.accounts.badge_mint.to_account_info();
+    let token_account = ctx.accounts.owner_token_account.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    invoke(
+        &BadgeMint::create_token_account_ix(owner.key(), token_account.key(), owner.key(), mint.key()),
+        &[
+            owner.clone(),
+            token_account.clone(),
+            owner.clone(),
+            mint.clone(),
+            ctx.accounts.system_program.to_account_info(),
+            token_program.clone(),
+            ctx.accounts.associated_token_program.to_account_info(),
+        ],
+    )?;
+
+    let bump = [ctx.bumps.badge_mint];
+    let kind_seed = [kind.seed()];
+    invoke_signed(
+        &BadgeMint::mint_one_ix(mint.key(), token_account.key()),
+        &[mint.clone(), token_account.clone(), token_program],
+        &[&[BadgeMint::SEED_PREFIX, &kind_seed, &bump]],
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let claim = &mut ctx.accounts.badge_claim;
+    claim.agent = agent.key();
+    claim.owner = agent.owner;
+    claim.kind = kind;
+    claim.claimed_at = now;
+    claim.bump = ctx.bumps.badge_claim;
+
+    msg!(
+        "Badge claimed: agent={}, kind={:?}, owner={}",
+        claim.agent,
+        kind,
+        claim.owner
+    );
+
+    Ok(())
+}
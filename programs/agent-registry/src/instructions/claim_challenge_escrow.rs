@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Challenge};
+use crate::errors::RegistryError;
+
+/// Pay out escrow still held by a resolved challenge (permissionless)
+/// Passed and Unrevealed: to the agent owner; Failed, Expired and Void: back to the challenger.
+/// Resolution normally pays out in the same instruction, so this recovers escrow
+/// left behind by older program versions, interrupted flows or a challenger that never
+/// revealed its answer (`void_challenge`). If a passed agent
+/// has since been closed its owner can no longer be resolved and the challenger is refunded
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ClaimChallengeEscrow<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            challenge.agent.as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.is_resolved() @ RegistryError::ChallengeStillPending,
+        constraint = challenge.escrow > 0 @ RegistryError::NoChallengeEscrow
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// CHECK: the challenged agent; read for its owner when the challenge passed
+    #[account(address = challenge.agent @ RegistryError::ChallengeMismatch)]
+    pub agent: UncheckedAccount<'info>,
+
+    /// CHECK: escrow recipient for the challenge's outcome (checked in handler)
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimChallengeEscrow>, _nonce: u64) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let agent_info = &ctx.accounts.agent;
+
+    let expected = if challenge.escrow_to_owner() && !agent_info.data_is_empty() {
+        require_keys_eq!(*agent_info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        AgentAccount::try_deserialize(&mut &agent_info.try_borrow_data()?[..])?.owner
+    } else {
+        challenge.challenger
+    };
+    require_keys_eq!(ctx.accounts.recipient.key(), expected, RegistryError::InvalidEscrowRecipient);
+
+    let recipient_info = ctx.accounts.recipient.to_account_info();
+    let released = Challenge::release_escrow(challenge, &recipient_info)?;
+
+    msg!(
+        "Challenge escrow claimed: agent={}, status={:?}, amount={}, recipient={}",
+        challenge.agent,
+        challenge.status,
+        released,
+        expected
+    );
+
+    Ok(())
+}
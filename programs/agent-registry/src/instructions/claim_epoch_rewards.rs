@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{
+    AgentAccount, EpochRewardClaim, EpochRewards, EpochSnapshot, RegistryConfig, RegistryState,
+};
+use crate::errors::RegistryError;
+
+/// Pay an agent's share of a finished epoch's emission out of the treasury (owner only)
+/// The share is proportional to the agent's snapshot reputation among eligible snapshots,
+/// and can be claimed until `EpochRewards::CLAIM_WINDOW_EPOCHS` epochs have passed
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct ClaimEpochRewards<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        seeds = [EpochSnapshot::SEED_PREFIX, agent.key().as_ref(), epoch.to_le_bytes().as_ref()],
+        bump = snapshot.bump
+    )]
+    pub snapshot: Account<'info, EpochSnapshot>,
+
+    #[account(
+        mut,
+        seeds = [EpochRewards::SEED_PREFIX, epoch.to_le_bytes().as_ref()],
+        bump = epoch_rewards.bump
+    )]
+    pub epoch_rewards: Account<'info, EpochRewards>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + EpochRewardClaim::INIT_SPACE,
+        seeds = [EpochRewardClaim::SEED_PREFIX, agent.key().as_ref(), epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, EpochRewardClaim>,
+
+    /// Registry config (the claim settles part of its treasury liabilities)
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Treasury PDA the emissions are paid from
+    #[account(
+        mut,
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimEpochRewards>, epoch: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(clock.epoch > epoch, RegistryError::EpochNotEnded);
+
+    let rewards = &ctx.accounts.epoch_rewards;
+    require!(rewards.claimable(clock.epoch), RegistryError::ClaimWindowClosed);
+    let snapshot = &ctx.accounts.snapshot;
+    require!(rewards.is_eligible(snapshot), RegistryError::NotEligibleForRewards);
+
+    let amount = rewards.reward_for(snapshot);
+    require!(amount > 0, RegistryError::NothingToClaim);
+    require!(
+        ctx.accounts.treasury.lamports() >= amount,
+        RegistryError::InsufficientTreasury
+    );
+
+    let bump = [ctx.bumps.treasury];
+    let signer_seeds: &[&[&[u8]]] = &[&[RegistryState::TREASURY_SEED, &bump]];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.treasury.to_account_info(),
+                to: ctx.accounts.owner.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    let rewards = &mut ctx.accounts.epoch_rewards;
+    rewards.claimed_lamports = rewards.claimed_lamports.saturating_add(amount);
+    let config = &mut ctx.accounts.config;
+    config.treasury_liabilities = config.treasury_liabilities.saturating_sub(amount);
+
+    let claim = &mut ctx.accounts.claim;
+    claim.agent = ctx.accounts.agent.key();
+    claim.epoch = epoch;
+    claim.amount = amount;
+    claim.claimed_at = clock.unix_timestamp;
+    claim.bump = ctx.bumps.claim;
+
+    msg!(
+        "Epoch rewards claimed: agent={}, epoch={}, amount={}, share_of={}",
+        ctx.accounts.agent.agent_id,
+        epoch,
+        amount,
+        rewards.total_reputation
+    );
+
+    Ok(())
+}
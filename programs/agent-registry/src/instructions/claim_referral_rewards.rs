@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{ReferrerStats, RegistryConfig, RegistryState};
+use crate::errors::RegistryError;
+
+/// Pay accrued referral rewards out of the treasury (referrer only)
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ReferrerStats::SEED_PREFIX, referrer.key().as_ref()],
+        bump = referral.bump
+    )]
+    pub referral: Account<'info, ReferrerStats>,
+
+    /// Registry config (the claim settles part of its treasury liabilities)
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Treasury PDA the registration fees were paid into
+    #[account(
+        mut,
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+    let amount = ctx.accounts.referral.rewards_claimable;
+    require!(amount > 0, RegistryError::NothingToClaim);
+    require!(
+        ctx.accounts.treasury.lamports() >= amount,
+        RegistryError::InsufficientTreasury
+    );
+
+    let bump = [ctx.bumps.treasury];
+    let signer_seeds: &[&[&[u8]]] = &[&[RegistryState::TREASURY_SEED, &bump]];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.treasury.to_account_info(),
+                to: ctx.accounts.referrer.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    ctx.accounts.referral.rewards_claimable = 0;
+    let config = &mut ctx.accounts.config;
+    config.treasury_liabilities = config.treasury_liabilities.saturating_sub(amount);
+
+    msg!(
+        "Referral rewards claimed: referrer={}, amount={}",
+        ctx.accounts.referrer.key(),
+        amount
+    );
+
+    Ok(())
+}
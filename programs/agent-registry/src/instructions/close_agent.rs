@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentStatus, OwnerIndex, UnstakeRequest};
+use crate::errors::RegistryError;
+
+/// Close a retired agent once the close delay has passed (owner only; multisig agents use
+/// the `Close` action)
+/// Rent and any remaining stake are returned to the owner, once the stake lock has
+/// lapsed and no unstake is pending
+#[derive(Accounts)]
+pub struct CloseAgent<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status == AgentStatus::Retired @ RegistryError::InvalidStatusTransition
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: the agent's UnstakeRequest PDA; it must not exist, since a pending
+    /// withdrawal is still slashable and would be stranded by the close
+    #[account(
+        seeds = [UnstakeRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub unstake_request: UncheckedAccount<'info>,
+
+    /// Owner's agent index; the closed agent's ID is dropped when passed
+    #[account(
+        mut,
+        seeds = [OwnerIndex::SEED_PREFIX, owner.key().as_ref()],
+        bump = owner_index.bump
+    )]
+    pub owner_index: Option<Account<'info, OwnerIndex>>,
+}
+
+pub fn handler(ctx: Context<CloseAgent>) -> Result<()> {
+    let agent = &ctx.accounts.agent;
+    check_close(agent, &ctx.accounts.unstake_request, Clock::get()?.unix_timestamp)?;
+
+    if let Some(index) = ctx.accounts.owner_index.as_mut() {
+        index.remove(agent.agent_id);
+    }
+
+    msg!(
+        "Agent closed: id={}, stake returned={}",
+        agent.agent_id,
+        agent.staked_lamports
+    );
+
+    Ok(())
+}
+
+/// Whether a Retired agent may be closed or archived at `now`, shared with `archive_agent`
+/// and the multisig `Close` and `Archive` actions
+pub(crate) fn check_close(
+    agent: &AgentAccount,
+    unstake_request: &AccountInfo,
+    now: i64,
+) -> Result<()> {
+    require!(agent.status == AgentStatus::Retired, RegistryError::InvalidStatusTransition);
+    require!(
+        now >= agent.status_changed_at.saturating_add(AgentAccount::CLOSE_DELAY_SECS),
+        RegistryError::CloseDelayNotElapsed
+    );
+    require!(now >= agent.stake_locked_until, RegistryError::StakeLocked);
+    require!(unstake_request.data_is_empty(), RegistryError::UnstakePending);
+    Ok(())
+}
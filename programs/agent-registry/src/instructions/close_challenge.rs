@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, Challenge, ChallengeStatus};
+use crate::state::Challenge;
 use crate::errors::RegistryError;
 
 /// Close a resolved challenge account and reclaim rent
 /// Only the original challenger can close, and only after the challenge is resolved
+/// and its escrow paid out (see `claim_challenge_escrow`)
 /// This is a critical mainnet optimization: reclaims ~0.012 SOL per challenge
 #[derive(Accounts)]
 #[instruction(nonce: u64)]
@@ -12,16 +13,9 @@ pub struct CloseChallenge<'info> {
     #[account(mut)]
     pub challenger: Signer<'info>,
 
-    /// The agent that was challenged (for PDA derivation)
-    #[account(
-        seeds = [
-            AgentAccount::SEED_PREFIX,
-            agent.owner.as_ref(),
-            agent.agent_id.to_le_bytes().as_ref()
-        ],
-        bump = agent.bump
-    )]
-    pub agent: Account<'info, AgentAccount>,
+    /// CHECK: The agent that was challenged (for PDA derivation; may since have been closed)
+    #[account(address = challenge.agent @ RegistryError::ChallengeMismatch)]
+    pub agent: UncheckedAccount<'info>,
 
     /// The challenge account to close (rent returned to challenger)
     #[account(
@@ -35,7 +29,8 @@ pub struct CloseChallenge<'info> {
         ],
         bump = challenge.bump,
         constraint = challenge.challenger == challenger.key() @ RegistryError::Unauthorized,
-        constraint = challenge.status != ChallengeStatus::Pending @ RegistryError::ChallengeStillPending,
+        constraint = challenge.is_resolved() @ RegistryError::ChallengeStillPending,
+        constraint = challenge.escrow == 0 @ RegistryError::EscrowNotReleased,
     )]
     pub challenge: Account<'info, Challenge>,
 }
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, ServiceListing};
+use crate::errors::RegistryError;
+
+/// Remove a service listing and reclaim its rent (owner only)
+#[derive(Accounts)]
+pub struct CloseServiceListing<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            ServiceListing::SEED_PREFIX,
+            agent.key().as_ref(),
+            listing.listing_id.to_le_bytes().as_ref()
+        ],
+        bump = listing.bump
+    )]
+    pub listing: Account<'info, ServiceListing>,
+}
+
+pub fn handler(ctx: Context<CloseServiceListing>) -> Result<()> {
+    msg!(
+        "Service listing closed: agent={}, listing={}",
+        ctx.accounts.agent.agent_id,
+        ctx.accounts.listing.listing_id
+    );
+    Ok(())
+}
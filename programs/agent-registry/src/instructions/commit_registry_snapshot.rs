@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::{RegistryConfig, RegistrySnapshot, RegistryState};
+use crate::errors::RegistryError;
+
+/// Commit a registry-wide Merkle root over all agents' standings (admin only)
+#[derive(Accounts)]
+pub struct CommitRegistrySnapshot<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RegistrySnapshot::INIT_SPACE,
+        seeds = [RegistrySnapshot::SEED_PREFIX, config.registry_snapshots.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub snapshot: Account<'info, RegistrySnapshot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CommitRegistrySnapshot>,
+    merkle_root: [u8; 32],
+    agent_count: u64,
+    slot: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // A snapshot can't cover agents that don't exist yet or a future slot
+    require!(
+        agent_count <= ctx.accounts.registry.total_agents,
+        RegistryError::InvalidSnapshot
+    );
+    require!(slot <= clock.slot, RegistryError::InvalidSnapshot);
+
+    let config = &mut ctx.accounts.config;
+    let snapshot = &mut ctx.accounts.snapshot;
+    snapshot.index = config.registry_snapshots;
+    snapshot.merkle_root = merkle_root;
+    snapshot.agent_count = agent_count;
+    snapshot.slot = slot;
+    snapshot.committed_at = clock.unix_timestamp;
+    snapshot.committed_by = ctx.accounts.admin.key();
+    snapshot.bump = ctx.bumps.snapshot;
+
+    config.registry_snapshots = config.registry_snapshots.saturating_add(1);
+
+    msg!(
+        "Registry snapshot committed: index={}, agents={}, slot={}, root={:?}",
+        snapshot.index,
+        agent_count,
+        slot,
+        &merkle_root[..8] // Log first 8 bytes for brevity
+    );
+
+    Ok(())
+}
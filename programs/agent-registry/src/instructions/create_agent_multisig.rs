@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentMultisig, AgentWatchers};
+use crate::errors::RegistryError;
+
+/// Hand an agent over to an m-of-n owner set (current owner only, one-time)
+/// Afterwards owner-key mutations are rejected with MultisigRequired
+#[derive(Accounts)]
+pub struct CreateAgentMultisig<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + AgentMultisig::INIT_SPACE,
+        seeds = [AgentMultisig::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, AgentMultisig>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CreateAgentMultisig>, owners: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    require!(
+        AgentMultisig::is_valid_config(&owners, threshold),
+        RegistryError::InvalidMultisigConfig
+    );
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.agent = ctx.accounts.agent.key();
+    multisig.owners = owners;
+    multisig.threshold = threshold;
+    multisig.owner_set_seq = 0;
+    multisig.proposal_count = 0;
+    multisig.bump = ctx.bumps.multisig;
+
+    let agent = &mut ctx.accounts.agent;
+    agent.multisig = multisig.key();
+    agent.touch(Clock::get()?.unix_timestamp);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_METADATA)?;
+
+    msg!(
+        "Agent multisig created: id={}, owners={}, threshold={}",
+        agent.agent_id,
+        multisig.owners.len(),
+        threshold
+    );
+
+    Ok(())
+}
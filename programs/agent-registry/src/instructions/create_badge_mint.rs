@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::system_program;
+use crate::state::{BadgeKind, BadgeMint, RegistryState, TOKEN_2022_PROGRAM_ID};
+use crate::errors::RegistryError;
+
+/// Create the non-transferable Token-2022 mint for a badge kind (admin only, one-time)
+#[derive(Accounts)]
+#[instruction(kind: BadgeKind)]
+pub struct CreateBadgeMint<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// CHECK: badge mint PDA, created and initialized here
+    #[account(
+        mut,
+        seeds = [BadgeMint::SEED_PREFIX, &[kind.seed()]],
+        bump
+    )]
+    pub badge_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token-2022 program
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateBadgeMint>, kind: BadgeKind) -> Result<()> {
+    let mint = ctx.accounts.badge_mint.to_account_info();
+    require!(mint.data_is_empty(), RegistryError::BadgeMintExists);
+
+    let bump = [ctx.bumps.badge_mint];
+    let kind_seed = [kind.seed()];
+    let signer_seeds: &[&[&[u8]]] = &[&[BadgeMint::SEED_PREFIX, &kind_seed, &bump]];
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::CreateAccount {
+                from: ctx.accounts.admin.to_account_info(),
+                to: mint.clone(),
+            },
+            signer_seeds,
+        ),
+        Rent::get()?.minimum_balance(BadgeMint::SPACE),
+        BadgeMint::SPACE as u64,
+        &TOKEN_2022_PROGRAM_ID,
+    )?;
+
+    // The extension must be initialized before the mint itself
+    let token_program = ctx.accounts.token_program.to_account_info();
+    invoke(
+        &BadgeMint::initialize_non_transferable_ix(mint.key()),
+        &[mint.clone(), token_program.clone()],
+    )?;
+    invoke(
+        &BadgeMint::initialize_mint_ix(mint.key()),
+        &[mint.clone(), token_program],
+    )?;
+
+    msg!("Badge mint created: kind={:?}, mint={}", kind, mint.key());
+
+    Ok(())
+}
@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, Challenge, ChallengeStatus};
+use anchor_lang::system_program;
+use crate::state::{AgentAccount, Challenge, ChallengeStatus, ChallengeTemplate, ChallengeType, RegistryConfig};
+use crate::error_context::{require_within, ErrorField};
 use crate::errors::RegistryError;
 
 #[derive(Accounts)]
-#[instruction(question: String, expected_hash: String, nonce: u64)]
+#[instruction(question: String, answer_commitment: String, nonce: u64, challenge_type: ChallengeType, judged: bool)]
 pub struct CreateChallenge<'info> {
     #[account(mut)]
     pub challenger: Signer<'info>,
@@ -15,10 +17,18 @@ pub struct CreateChallenge<'info> {
             agent.owner.as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
-        bump = agent.bump
+        bump = agent.bump,
+        constraint = agent.accepts_work(Clock::get()?.unix_timestamp) @ RegistryError::AgentNotActive
     )]
     pub agent: Account<'info, AgentAccount>,
 
+    /// Registry config (challenge duration and escrow)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
     /// The challenge account (PDA derived from agent + challenger + nonce)
     #[account(
         init,
@@ -34,40 +44,104 @@ pub struct CreateChallenge<'info> {
     )]
     pub challenge: Account<'info, Challenge>,
 
+    /// CHECK: Pyth SOL/USD PriceUpdateV2 account (required when escrow is USD-denominated)
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
+
+    /// Template the challenge instantiates; its type and judging mode must match the
+    /// arguments, and its stake, weights and judge set apply
+    #[account(
+        mut,
+        seeds = [ChallengeTemplate::SEED_PREFIX, template.spec_hash.as_ref()],
+        bump = template.bump,
+        constraint = template.active @ RegistryError::ChallengeTemplateInactive
+    )]
+    pub template: Option<Account<'info, ChallengeTemplate>>,
 }
 
 pub fn handler(
     ctx: Context<CreateChallenge>,
     question: String,
-    expected_hash: String,
+    answer_commitment: String,
     nonce: u64,
+    challenge_type: ChallengeType,
+    judged: bool,
 ) -> Result<()> {
     // Validate inputs
-    require!(question.len() <= 256, RegistryError::QuestionTooLong);
+    require_within(ErrorField::Question, question.len(), 256, RegistryError::QuestionTooLong)?;
     require!(
-        expected_hash.len() == 64,
+        answer_commitment.len() == 64,
         RegistryError::InvalidExpectedHash
     );
 
-    let challenge = &mut ctx.accounts.challenge;
+    let config = &ctx.accounts.config;
+    require!(!judged || config.quorum_reachable(), RegistryError::QuorumExceedsJudges);
     let clock = Clock::get()?;
+    let price_feed = ctx.accounts.price_feed.as_ref().map(|feed| feed.to_account_info());
+    let mut escrow = config.challenge_escrow(price_feed.as_ref(), clock.unix_timestamp)?;
+    if let Some(template) = ctx.accounts.template.as_mut() {
+        require!(
+            template.challenge_type == challenge_type && template.judged == judged,
+            RegistryError::ChallengeTemplateMismatch
+        );
+        escrow = escrow.max(template.default_stake);
+        template.challenges_created = template.challenges_created.saturating_add(1);
+    }
+
+    // Lock the challenger's escrow in the challenge PDA until resolution
+    if escrow > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.challenger.to_account_info(),
+                    to: ctx.accounts.challenge.to_account_info(),
+                },
+            ),
+            escrow,
+        )?;
+    }
+
+    let challenge = &mut ctx.accounts.challenge;
 
     challenge.agent = ctx.accounts.agent.key();
     challenge.challenger = ctx.accounts.challenger.key();
     challenge.question = question.clone();
-    challenge.expected_hash = expected_hash;
+    challenge.answer_commitment = answer_commitment;
+    challenge.challenge_type = challenge_type;
+    challenge.judged = judged;
+    challenge.response_hash = String::new();
+    challenge.votes_pass = 0;
+    challenge.votes_fail = 0;
     challenge.status = ChallengeStatus::Pending;
     challenge.created_at = clock.unix_timestamp;
-    challenge.expires_at = clock.unix_timestamp + Challenge::DEFAULT_DURATION;
+    challenge.expires_at = clock.unix_timestamp + config.challenge_duration;
     challenge.responded_at = 0;
+    challenge.escrow = escrow;
     challenge.nonce = nonce;
     challenge.bump = ctx.bumps.challenge;
+    challenge.version = Challenge::CURRENT_VERSION;
+    match ctx.accounts.template.as_ref() {
+        Some(template) => {
+            challenge.template = template.key();
+            challenge.pass_weight_bps = template.pass_weight_bps;
+            challenge.fail_weight_bps = template.fail_weight_bps;
+        }
+        None => {
+            challenge.template = Pubkey::default();
+            challenge.pass_weight_bps = 0;
+            challenge.fail_weight_bps = 0;
+        }
+    }
 
     msg!(
-        "Challenge created for agent {} by {}: {}",
+        "Challenge created for agent {} by {} ({:?}, escrow={}, expires_at={}): {}",
         ctx.accounts.agent.key(),
         ctx.accounts.challenger.key(),
+        challenge_type,
+        escrow,
+        challenge.expires_at,
         question
     );
 
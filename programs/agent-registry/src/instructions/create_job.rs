@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{AgentAccount, Job, JobStatus, ServiceListing};
+use crate::errors::RegistryError;
+
+/// Purchase a job from a service listing, escrowing the listed price (consumer pays)
+/// The price must cover Job::MIN_ESCROW_LAMPORTS and the owner can't hire its own agent
+#[derive(Accounts)]
+#[instruction(job_id: u64)]
+pub struct CreateJob<'info> {
+    #[account(mut)]
+    pub consumer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.accepts_work(Clock::get()?.unix_timestamp) @ RegistryError::AgentNotActive,
+        constraint = agent.owner != consumer.key() @ RegistryError::SelfDealing
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        seeds = [
+            ServiceListing::SEED_PREFIX,
+            agent.key().as_ref(),
+            listing.listing_id.to_le_bytes().as_ref()
+        ],
+        bump = listing.bump,
+        constraint = listing.active @ RegistryError::ListingInactive,
+        constraint = listing.price_lamports >= Job::MIN_ESCROW_LAMPORTS @ RegistryError::JobEscrowTooLow
+    )]
+    pub listing: Account<'info, ServiceListing>,
+
+    #[account(
+        init,
+        payer = consumer,
+        space = 8 + Job::INIT_SPACE,
+        seeds = [
+            Job::SEED_PREFIX,
+            listing.key().as_ref(),
+            consumer.key().as_ref(),
+            job_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub job: Account<'info, Job>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateJob>,
+    job_id: u64,
+    request_hash: [u8; 32],
+    accept_window: i64,
+    delivery_window: i64,
+) -> Result<()> {
+    require!(
+        (1..=Job::MAX_WINDOW).contains(&accept_window)
+            && (1..=Job::MAX_WINDOW).contains(&delivery_window),
+        RegistryError::InvalidJobWindow
+    );
+
+    let price = ctx.accounts.listing.price_lamports;
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.consumer.to_account_info(),
+                to: ctx.accounts.job.to_account_info(),
+            },
+        ),
+        price,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let job = &mut ctx.accounts.job;
+    job.listing = ctx.accounts.listing.key();
+    job.agent = ctx.accounts.agent.key();
+    job.consumer = ctx.accounts.consumer.key();
+    job.job_id = job_id;
+    job.status = JobStatus::Open;
+    job.escrow = price;
+    job.request_hash = request_hash;
+    job.deliverable_hash = [0u8; 32];
+    job.accept_deadline = now + accept_window;
+    job.delivery_window = delivery_window;
+    job.delivery_deadline = 0;
+    job.created_at = now;
+    job.delivered_at = 0;
+    job.closed_at = 0;
+    job.bump = ctx.bumps.job;
+
+    msg!(
+        "Job created: agent={}, listing={}, job={}, escrow={}",
+        ctx.accounts.agent.agent_id,
+        ctx.accounts.listing.listing_id,
+        job_id,
+        price
+    );
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{RegistryNamespace, RegistryState};
+use crate::errors::RegistryError;
+
+/// Create a registry namespace with its own admin (registry admin only)
+/// The namespace starts open: no fee, reputation floor, verification or approval
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateNamespace<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RegistryNamespace::INIT_SPACE,
+        seeds = [RegistryNamespace::SEED_PREFIX, RegistryNamespace::hash(&name).as_ref()],
+        bump
+    )]
+    pub namespace: Account<'info, RegistryNamespace>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateNamespace>, name: String, namespace_admin: Pubkey) -> Result<()> {
+    require!(
+        RegistryNamespace::is_valid_name(&name),
+        RegistryError::InvalidNamespaceName
+    );
+
+    let namespace = &mut ctx.accounts.namespace;
+    namespace.name_hash = RegistryNamespace::hash(&name);
+    namespace.name = name;
+    namespace.admin = namespace_admin;
+    namespace.join_fee_lamports = 0;
+    namespace.min_reputation = 0;
+    namespace.verified_only = false;
+    namespace.approval_required = false;
+    namespace.member_count = 0;
+    namespace.total_joined = 0;
+    namespace.created_at = Clock::get()?.unix_timestamp;
+    namespace.deprecated_at = 0;
+    namespace.successor = Pubkey::default();
+    namespace.bump = ctx.bumps.namespace;
+
+    msg!("Namespace created: {} admin={}", namespace.name, namespace.admin);
+
+    Ok(())
+}
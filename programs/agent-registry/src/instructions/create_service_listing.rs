@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, PricingModel, ServiceListing};
+use crate::errors::RegistryError;
+
+/// Publish a priced service for an agent (owner only)
+#[derive(Accounts)]
+#[instruction(listing_id: u64)]
+pub struct CreateServiceListing<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.accepts_work(Clock::get()?.unix_timestamp) @ RegistryError::AgentNotActive
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + ServiceListing::INIT_SPACE,
+        seeds = [
+            ServiceListing::SEED_PREFIX,
+            agent.key().as_ref(),
+            listing_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub listing: Account<'info, ServiceListing>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateServiceListing>,
+    listing_id: u64,
+    capability_flags: u64,
+    pricing: PricingModel,
+    price_lamports: u64,
+    endpoint_hash: [u8; 32],
+) -> Result<()> {
+    let agent = &ctx.accounts.agent;
+    require!(
+        capability_flags != 0 && capability_flags & !agent.capability_flags == 0,
+        RegistryError::InvalidServiceCapabilities
+    );
+    require!(ServiceListing::is_valid_pricing(&pricing), RegistryError::InvalidPricing);
+
+    let now = Clock::get()?.unix_timestamp;
+    let listing = &mut ctx.accounts.listing;
+    listing.agent = agent.key();
+    listing.listing_id = listing_id;
+    listing.capability_flags = capability_flags;
+    listing.pricing = pricing;
+    listing.price_lamports = price_lamports;
+    listing.endpoint_hash = endpoint_hash;
+    listing.meter = Pubkey::default();
+    listing.active = true;
+    listing.created_at = now;
+    listing.updated_at = now;
+    listing.bump = ctx.bumps.listing;
+
+    msg!(
+        "Service listed: agent={}, listing={}, pricing={:?}, price={}",
+        agent.agent_id,
+        listing_id,
+        pricing,
+        price_lamports
+    );
+
+    Ok(())
+}
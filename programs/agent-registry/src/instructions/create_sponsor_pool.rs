@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{RegistrantWhitelist, SponsorPool};
+
+/// Create a sponsorship pool and make an initial deposit (sponsor pays)
+#[derive(Accounts)]
+pub struct CreateSponsorPool<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        init,
+        payer = sponsor,
+        space = 8 + SponsorPool::INIT_SPACE,
+        seeds = [SponsorPool::SEED_PREFIX, sponsor.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, SponsorPool>,
+
+    /// Vault PDA holding the pool's lamports
+    #[account(
+        mut,
+        seeds = [SponsorPool::VAULT_SEED, pool.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateSponsorPool>,
+    deposit: u64,
+    whitelist: RegistrantWhitelist,
+    registrations_per_registrant: u16,
+) -> Result<()> {
+    if deposit > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.sponsor.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            deposit,
+        )?;
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    pool.sponsor = ctx.accounts.sponsor.key();
+    pool.set_whitelist(whitelist);
+    pool.registrations_per_registrant = registrations_per_registrant;
+    pool.registrations = 0;
+    pool.lamports_spent = 0;
+    pool.created_at = Clock::get()?.unix_timestamp;
+    pool.vault_bump = ctx.bumps.vault;
+    pool.bump = ctx.bumps.pool;
+
+    msg!(
+        "Sponsor pool created: sponsor={}, deposit={}, per_registrant={}",
+        pool.sponsor,
+        deposit,
+        registrations_per_registrant
+    );
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentStatus, AgentWatchers};
+use crate::errors::RegistryError;
+
+/// Take an agent offline for maintenance, optionally until a scheduled time (owner only)
+/// Existing jobs and challenges can still resolve; new work is rejected and
+/// reputation decay is paused
+#[derive(Accounts)]
+pub struct DeactivateAgent<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status == AgentStatus::Active @ RegistryError::InvalidStatusTransition
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+/// Bring a deactivated agent back online (owner at any time; anyone once the
+/// scheduled window has passed)
+#[derive(Accounts)]
+pub struct ReactivateAgent<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.status == AgentStatus::Deactivated @ RegistryError::InvalidStatusTransition
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn deactivate_agent(ctx: Context<DeactivateAgent>, until: Option<i64>) -> Result<()> {
+    deactivate(&mut ctx.accounts.agent, until, &ctx.accounts.watchers)
+}
+
+/// Deactivate an Active agent, shared with the multisig `Deactivate` action
+pub(crate) fn deactivate(
+    agent: &mut AgentAccount,
+    until: Option<i64>,
+    watchers: &AccountInfo,
+) -> Result<()> {
+    require!(agent.status == AgentStatus::Active, RegistryError::InvalidStatusTransition);
+    let now = Clock::get()?.unix_timestamp;
+    let until = match until {
+        Some(until) => {
+            require!(
+                until > now && until - now <= AgentAccount::MAX_DEACTIVATION_SECS,
+                RegistryError::InvalidDeactivationWindow
+            );
+            until
+        }
+        None => 0,
+    };
+
+    agent.status = AgentStatus::Deactivated;
+    agent.suspended_until = until;
+    agent.status_changed_at = now;
+    agent.touch(now);
+    AgentWatchers::notify(watchers, AgentWatchers::FLAG_STATUS)?;
+
+    msg!("Agent deactivated: id={}, until={}", agent.agent_id, until);
+
+    Ok(())
+}
+
+pub fn reactivate_agent(ctx: Context<ReactivateAgent>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let agent = &mut ctx.accounts.agent;
+    let by_owner = ctx.accounts.caller.key() == agent.owner && !agent.has_multisig();
+    if !by_owner {
+        // Indefinite deactivations only end at the owner's (or multisig's) call
+        require!(
+            agent.suspended_until != 0 && !agent.is_deactivated(now),
+            RegistryError::DeactivationActive
+        );
+    }
+    reactivate(agent, now, &ctx.accounts.watchers)
+}
+
+/// Bring a Deactivated agent back online, shared with the multisig `Reactivate` action
+pub(crate) fn reactivate(agent: &mut AgentAccount, now: i64, watchers: &AccountInfo) -> Result<()> {
+    require!(
+        agent.status == AgentStatus::Deactivated,
+        RegistryError::InvalidStatusTransition
+    );
+
+    // Decay resumes from the end of a lapsed window, otherwise from now
+    let resumed_at = match agent.suspended_until {
+        0 => now,
+        until => until.min(now),
+    };
+    let resumed_at = resumed_at.max(agent.updated_at);
+    agent.status = AgentStatus::Active;
+    agent.suspended_until = 0;
+    agent.status_changed_at = now;
+    agent.touch(resumed_at);
+    AgentWatchers::notify(watchers, AgentWatchers::FLAG_STATUS)?;
+
+    msg!("Agent reactivated: id={}", agent.agent_id);
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::{CapabilityDefinition, RegistryConfig, RegistryState};
+use crate::error_context::{require_within, ErrorField};
+use crate::errors::RegistryError;
+
+/// Define (or redefine and reactivate) a capability in the taxonomy (admin only)
+#[derive(Accounts)]
+#[instruction(id: u8)]
+pub struct DefineCapability<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + CapabilityDefinition::INIT_SPACE,
+        seeds = [CapabilityDefinition::SEED_PREFIX, &[id]],
+        bump
+    )]
+    pub capability: Account<'info, CapabilityDefinition>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<DefineCapability>,
+    id: u8,
+    label_hash: [u8; 32],
+    description_uri: String,
+) -> Result<()> {
+    require!(id <= CapabilityDefinition::MAX_ID, RegistryError::InvalidCapabilityId);
+    require_within(ErrorField::Uri, description_uri.len(), 200, RegistryError::UriTooLong)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let capability = &mut ctx.accounts.capability;
+
+    if capability.created_at == 0 {
+        capability.created_at = now;
+    }
+    capability.id = id;
+    capability.label_hash = label_hash;
+    capability.description_uri = description_uri;
+    capability.active = true;
+    capability.updated_at = now;
+    capability.bump = ctx.bumps.capability;
+
+    let config = &mut ctx.accounts.config;
+    config.defined_capabilities |= CapabilityDefinition::flag(id);
+
+    msg!(
+        "Capability defined: id={}, uri={}",
+        id,
+        capability.description_uri
+    );
+
+    Ok(())
+}
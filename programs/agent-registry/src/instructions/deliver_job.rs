@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Job, JobStatus};
+use crate::errors::RegistryError;
+
+/// Commit the deliverable for an accepted job (agent owner only)
+/// Opens the consumer's review window
+#[derive(Accounts)]
+pub struct DeliverJob<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            Job::SEED_PREFIX,
+            job.listing.as_ref(),
+            job.consumer.as_ref(),
+            job.job_id.to_le_bytes().as_ref()
+        ],
+        bump = job.bump,
+        constraint = job.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub job: Account<'info, Job>,
+}
+
+pub fn handler(ctx: Context<DeliverJob>, deliverable_hash: [u8; 32]) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let job = &mut ctx.accounts.job;
+
+    require!(job.status == JobStatus::Accepted, RegistryError::InvalidJobStatus);
+    require!(now <= job.delivery_deadline, RegistryError::JobDeadlinePassed);
+
+    job.status = JobStatus::Delivered;
+    job.deliverable_hash = deliverable_hash;
+    job.delivered_at = now;
+
+    msg!(
+        "Job delivered: agent={}, job={}",
+        ctx.accounts.agent.agent_id,
+        job.job_id
+    );
+
+    Ok(())
+}
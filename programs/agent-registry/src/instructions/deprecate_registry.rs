@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminActionLog, RegistryConfig, RegistryNamespace, RegistryState};
+use crate::errors::RegistryError;
+
+/// Sunset the deployment (registry admin) or a namespace (its admin), naming a successor
+/// Deprecated registries take no new agents; existing ones keep working and can
+/// carry their record over with `migrate_agent_to_registry`. Irreversible.
+#[derive(Accounts)]
+pub struct DeprecateRegistry<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Namespace to deprecate; omit to deprecate the whole deployment
+    #[account(
+        mut,
+        seeds = [RegistryNamespace::SEED_PREFIX, namespace.name_hash.as_ref()],
+        bump = namespace.bump
+    )]
+    pub namespace: Option<Account<'info, RegistryNamespace>>,
+}
+
+/// `successor` is the successor RegistryNamespace's address when deprecating a
+/// namespace, or the successor program ID when deprecating the deployment
+pub fn handler(ctx: Context<DeprecateRegistry>, successor: Pubkey) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let target = if let Some(namespace) = ctx.accounts.namespace.as_mut() {
+        require_keys_eq!(namespace.admin, authority, RegistryError::Unauthorized);
+        require!(!namespace.is_deprecated(), RegistryError::RegistryDeprecated);
+        require!(successor != namespace.key(), RegistryError::InvalidSuccessor);
+
+        namespace.deprecated_at = now;
+        namespace.successor = successor;
+        msg!("Namespace deprecated: {} successor={}", namespace.name, successor);
+        namespace.key()
+    } else {
+        require_keys_eq!(ctx.accounts.registry.admin, authority, RegistryError::Unauthorized);
+        let config = &mut ctx.accounts.config;
+        require!(!config.is_deprecated(), RegistryError::RegistryDeprecated);
+        require!(successor != crate::ID, RegistryError::InvalidSuccessor);
+
+        config.deprecated_at = now;
+        config.successor_program = successor;
+        msg!("Registry deprecated: successor program={}", successor);
+        config.key()
+    };
+
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_DEPRECATE,
+        authority,
+        target,
+        0,
+        now,
+    )
+}
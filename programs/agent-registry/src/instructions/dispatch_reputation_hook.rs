@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, ReputationHooks};
+use crate::errors::RegistryError;
+
+/// Deliver an agent's latest reputation change to one of its hooks (permissionless)
+/// Catches up hooks the updating transaction didn't reach; a failing hook only fails
+/// this transaction, never the reputation update
+#[derive(Accounts)]
+pub struct DispatchReputationHook<'info> {
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump = hooks.bump
+    )]
+    pub hooks: Account<'info, ReputationHooks>,
+
+    /// CHECK: a registered callback program, checked against `hooks`
+    #[account(executable)]
+    pub hook_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<DispatchReputationHook>) -> Result<()> {
+    let hooks = &mut ctx.accounts.hooks;
+    let index = hooks
+        .position(&ctx.accounts.hook_program.key())
+        .ok_or(RegistryError::HookNotRegistered)?;
+    require!(hooks.hooks[index].delivered_seq < hooks.seq, RegistryError::HookUpToDate);
+
+    hooks.deliver(
+        index,
+        &ctx.accounts.agent.to_account_info(),
+        &ctx.accounts.hook_program.to_account_info(),
+    )?;
+
+    msg!(
+        "Reputation hook dispatched: agent={}, program={}, seq={}",
+        ctx.accounts.agent.agent_id,
+        ctx.accounts.hook_program.key(),
+        hooks.seq
+    );
+
+    Ok(())
+}
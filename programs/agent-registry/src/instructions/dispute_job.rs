@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{Job, JobStatus};
+use crate::errors::RegistryError;
+
+/// Dispute a delivery during the review window (consumer only)
+/// Freezes the escrow until the admin resolves the dispute
+#[derive(Accounts)]
+pub struct DisputeJob<'info> {
+    pub consumer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Job::SEED_PREFIX,
+            job.listing.as_ref(),
+            consumer.key().as_ref(),
+            job.job_id.to_le_bytes().as_ref()
+        ],
+        bump = job.bump,
+        constraint = job.consumer == consumer.key() @ RegistryError::Unauthorized
+    )]
+    pub job: Account<'info, Job>,
+}
+
+pub fn handler(ctx: Context<DisputeJob>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let job = &mut ctx.accounts.job;
+
+    require!(job.status == JobStatus::Delivered, RegistryError::InvalidJobStatus);
+    require!(
+        now <= job.delivered_at + Job::REVIEW_WINDOW,
+        RegistryError::JobDeadlinePassed
+    );
+
+    job.status = JobStatus::Disputed;
+
+    msg!("Job disputed: job={}, consumer={}", job.job_id, job.consumer);
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminActionLog, AgentAccount, Moderator, RegistryState, ReputationHooks};
+use crate::errors::RegistryError;
+
+/// Drop a hook squatting on an agent's hook slots (admin or active moderator)
+/// Owners can't veto hooks, so this is the way out when spam registrations fill all
+/// `MAX_HOOKS` slots; the hook program may register again
+#[derive(Accounts)]
+#[instruction(hook_program: Pubkey)]
+pub struct EvictReputationHook<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    /// Required unless the authority is the admin
+    #[account(
+        mut,
+        seeds = [Moderator::SEED_PREFIX, authority.key().as_ref()],
+        bump = moderator.bump
+    )]
+    pub moderator: Option<Account<'info, Moderator>>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump = hooks.bump
+    )]
+    pub hooks: Account<'info, ReputationHooks>,
+}
+
+pub fn handler(ctx: Context<EvictReputationHook>, hook_program: Pubkey) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    if authority != ctx.accounts.registry.admin {
+        let moderator = ctx
+            .accounts
+            .moderator
+            .as_mut()
+            .ok_or(RegistryError::NotModerator)?;
+        require!(moderator.active, RegistryError::NotModerator);
+        moderator.actions_taken = moderator.actions_taken.saturating_add(1);
+    }
+
+    let hooks = &mut ctx.accounts.hooks;
+    let position = hooks
+        .position(&hook_program)
+        .ok_or(RegistryError::HookNotRegistered)?;
+    hooks.hooks.remove(position);
+
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_EVICT_HOOK,
+        authority,
+        hook_program,
+        ctx.accounts.agent.agent_id,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    msg!(
+        "Reputation hook evicted: agent={}, program={}, hooks={}",
+        ctx.accounts.agent.agent_id,
+        hook_program,
+        hooks.hooks.len()
+    );
+
+    Ok(())
+}
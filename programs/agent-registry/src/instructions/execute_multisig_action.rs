@@ -0,0 +1,338 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, AgentArchive, AgentMultisig, AgentWatchers, RegistryConfig, MultisigAction,
+    OwnerIndex, UnstakeRequest,
+};
+use crate::errors::RegistryError;
+
+use super::archive_agent::record_archive;
+use super::close_agent::check_close;
+use super::deactivation::{deactivate, reactivate};
+use super::retire_agent::retire;
+use super::transfer_agent::close_pda;
+use super::unstake_agent::check_unstake;
+use super::update_agent::apply_update;
+
+/// Execute a multisig action co-signed in this transaction
+/// Owner signers are passed as remaining accounts (the payer counts if it is an owner)
+#[derive(Accounts)]
+pub struct ExecuteMultisigAction<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.multisig == multisig.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [AgentMultisig::SEED_PREFIX, agent.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AgentMultisig>,
+
+    /// Registry config (capability taxonomy, unstake cooldown)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: must match the recipient of an `Unstake` or `WithdrawUnstake` action
+    #[account(mut)]
+    pub recipient: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's UnstakeRequest PDA, which may not exist; lamports pending there
+    /// don't count towards the agent account's rent. Created by `RequestUnstake` and
+    /// closed by `WithdrawUnstake`
+    #[account(
+        mut,
+        seeds = [UnstakeRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub unstake_request: UncheckedAccount<'info>,
+
+    /// Owner's agent index; a closed or archived agent's ID is dropped when passed
+    #[account(
+        mut,
+        seeds = [OwnerIndex::SEED_PREFIX, agent.owner.as_ref()],
+        bump = owner_index.bump
+    )]
+    pub owner_index: Option<Account<'info, OwnerIndex>>,
+
+    /// Written by an `Archive` action, and passed only for one
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AgentArchive::INIT_SPACE,
+        seeds = [AgentArchive::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub archive: Option<Account<'info, AgentArchive>>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteMultisigAction<'info>>,
+    action: MultisigAction,
+) -> Result<()> {
+    let payer = ctx.accounts.payer.to_account_info();
+    let approvals = ctx.accounts.multisig.signer_mask(ctx.remaining_accounts)
+        | ctx.accounts.multisig.signer_mask(std::slice::from_ref(&payer));
+    require!(
+        ctx.accounts.multisig.meets_threshold(approvals),
+        RegistryError::ThresholdNotMet
+    );
+
+    msg!(
+        "Multisig action co-signed: agent={}, approvals={}",
+        ctx.accounts.agent.agent_id,
+        approvals.count_ones()
+    );
+
+    apply_action(
+        &mut ctx.accounts.agent,
+        &mut ctx.accounts.multisig,
+        &ctx.accounts.config,
+        action,
+        payer,
+        ctx.accounts.recipient.as_ref().map(|r| r.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.unstake_request,
+        &ctx.accounts.watchers,
+        ctx.accounts.owner_index.as_mut(),
+        ctx.accounts.archive.as_mut().zip(ctx.bumps.archive),
+    )
+}
+
+/// Apply an authorized multisig action (shared with `execute_multisig_proposal`)
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_action<'info>(
+    agent: &mut Account<'info, AgentAccount>,
+    multisig: &mut Account<'info, AgentMultisig>,
+    config: &RegistryConfig,
+    action: MultisigAction,
+    payer: AccountInfo<'info>,
+    recipient: Option<AccountInfo<'info>>,
+    system_program: AccountInfo<'info>,
+    unstake_request: &AccountInfo<'info>,
+    watchers: &AccountInfo<'info>,
+    owner_index: Option<&mut Account<'info, OwnerIndex>>,
+    archive: Option<(&mut Account<'info, AgentArchive>, u8)>,
+) -> Result<()> {
+    if action.needs_mutable_agent() {
+        require!(agent.status.is_mutable(), RegistryError::AgentRetired);
+    }
+    require!(
+        archive.is_some() == matches!(action, MultisigAction::Archive { .. }),
+        RegistryError::ArchiveAccountMismatch
+    );
+
+    match action {
+        MultisigAction::UpdateMetadata { name, capabilities } => {
+            let pending = UnstakeRequest::pending(unstake_request)?;
+            apply_update(agent, payer, system_program, pending, name, capabilities)?;
+            AgentWatchers::notify(watchers, AgentWatchers::FLAG_METADATA)
+        }
+        MultisigAction::SetOwners { owners, threshold } => {
+            require!(
+                AgentMultisig::is_valid_config(&owners, threshold),
+                RegistryError::InvalidMultisigConfig
+            );
+            multisig.owners = owners;
+            multisig.threshold = threshold;
+            multisig.owner_set_seq = multisig.owner_set_seq.saturating_add(1);
+            agent.touch(Clock::get()?.unix_timestamp);
+            AgentWatchers::notify(watchers, AgentWatchers::FLAG_METADATA)?;
+
+            msg!(
+                "Multisig owners replaced: agent={}, owners={}, threshold={}",
+                agent.agent_id,
+                multisig.owners.len(),
+                threshold
+            );
+            Ok(())
+        }
+        MultisigAction::Unstake { amount, recipient: expected } => {
+            let recipient = recipient.ok_or(RegistryError::InvalidRecipient)?;
+            require_keys_eq!(recipient.key(), expected, RegistryError::InvalidRecipient);
+            let now = Clock::get()?.unix_timestamp;
+            check_unstake(agent, amount, now)?;
+            require!(config.unstake_cooldown_secs == 0, RegistryError::UnstakeCooldownRequired);
+
+            agent.staked_lamports -= amount;
+            agent.touch(now);
+
+            // Agent PDA is program-owned, so lamports can be moved directly
+            let agent_info = agent.to_account_info();
+            **agent_info.try_borrow_mut_lamports()? -= amount;
+            **recipient.try_borrow_mut_lamports()? += amount;
+            agent.require_rent_exempt(&agent_info, UnstakeRequest::pending(unstake_request)?)?;
+            AgentWatchers::notify(watchers, AgentWatchers::FLAG_STAKE)?;
+
+            msg!(
+                "Multisig unstake: agent={}, amount={}, recipient={}",
+                agent.agent_id,
+                amount,
+                expected
+            );
+            Ok(())
+        }
+        MultisigAction::SetCapabilityFlags { flags } => {
+            require!(config.capabilities_defined(flags), RegistryError::UndefinedCapability);
+            agent.set_capability_flags(flags)?;
+            agent.touch(Clock::get()?.unix_timestamp);
+            AgentWatchers::notify(watchers, AgentWatchers::FLAG_METADATA)?;
+
+            msg!("Multisig capability flags set: agent={}, flags={:#x}", agent.agent_id, flags);
+            Ok(())
+        }
+        MultisigAction::FreezeFields { fields } => {
+            agent.freeze(fields)?;
+            agent.touch(Clock::get()?.unix_timestamp);
+            AgentWatchers::notify(watchers, AgentWatchers::FLAG_METADATA)?;
+
+            msg!("Multisig fields frozen: agent={}, fields={:#x}", agent.agent_id, fields);
+            Ok(())
+        }
+        MultisigAction::RequestUnstake { amount } => {
+            let now = Clock::get()?.unix_timestamp;
+            check_unstake(agent, amount, now)?;
+            let agent_key = agent.key();
+            let bump =
+                UnstakeRequest::create(unstake_request, &agent_key, &payer, &system_program)?;
+            let request = UnstakeRequest {
+                agent: agent_key,
+                amount,
+                requested_at: now,
+                available_at: now.saturating_add(config.unstake_cooldown_secs),
+                bump,
+            };
+            request.try_serialize(&mut &mut unstake_request.try_borrow_mut_data()?[..])?;
+
+            agent.staked_lamports -= amount;
+            agent.touch(now);
+            AgentWatchers::notify(watchers, AgentWatchers::FLAG_STAKE)?;
+
+            msg!(
+                "Multisig unstake requested: agent={}, amount={}, available_at={}",
+                agent.agent_id,
+                amount,
+                request.available_at
+            );
+            Ok(())
+        }
+        MultisigAction::WithdrawUnstake { recipient: expected } => {
+            let recipient = recipient.ok_or(RegistryError::InvalidRecipient)?;
+            require_keys_eq!(recipient.key(), expected, RegistryError::InvalidRecipient);
+            require!(!unstake_request.data_is_empty(), RegistryError::NoUnstakePending);
+            require_keys_eq!(
+                *unstake_request.owner,
+                crate::ID,
+                ErrorCode::AccountOwnedByWrongProgram
+            );
+            let request =
+                UnstakeRequest::try_deserialize(&mut &unstake_request.try_borrow_data()?[..])?;
+            let now = Clock::get()?.unix_timestamp;
+            require!(now >= request.available_at, RegistryError::UnstakeCooldownActive);
+
+            agent.touch(now);
+
+            // Agent PDA is program-owned, so lamports can be moved directly; the
+            // request's rent goes to the recipient along with the payout
+            let agent_info = agent.to_account_info();
+            **agent_info.try_borrow_mut_lamports()? -= request.amount;
+            **recipient.try_borrow_mut_lamports()? += request.amount;
+            close_pda(unstake_request, &recipient)?;
+            agent.require_rent_exempt(&agent_info, 0)?;
+            AgentWatchers::notify(watchers, AgentWatchers::FLAG_STAKE)?;
+
+            msg!(
+                "Multisig unstake withdrawn: agent={}, amount={}, recipient={}",
+                agent.agent_id,
+                request.amount,
+                expected
+            );
+            Ok(())
+        }
+        MultisigAction::Retire => {
+            retire(agent, watchers)?;
+            msg!("Multisig agent retired: id={}, name={}", agent.agent_id, agent.name);
+            Ok(())
+        }
+        MultisigAction::Deactivate { until } => {
+            deactivate(agent, until, watchers)?;
+            msg!(
+                "Multisig agent deactivated: id={}, until={}",
+                agent.agent_id,
+                agent.suspended_until
+            );
+            Ok(())
+        }
+        MultisigAction::Reactivate => {
+            reactivate(agent, Clock::get()?.unix_timestamp, watchers)?;
+            msg!("Multisig agent reactivated: id={}", agent.agent_id);
+            Ok(())
+        }
+        MultisigAction::Close { recipient: expected } => {
+            let recipient = recipient.ok_or(RegistryError::InvalidRecipient)?;
+            require_keys_eq!(recipient.key(), expected, RegistryError::InvalidRecipient);
+            check_close(agent, unstake_request, Clock::get()?.unix_timestamp)?;
+
+            msg!(
+                "Multisig agent closed: id={}, stake returned={}",
+                agent.agent_id,
+                agent.staked_lamports
+            );
+            close_agent_to(agent, multisig, owner_index, recipient)
+        }
+        MultisigAction::Archive { recipient: expected } => {
+            let recipient = recipient.ok_or(RegistryError::InvalidRecipient)?;
+            require_keys_eq!(recipient.key(), expected, RegistryError::InvalidRecipient);
+            let now = Clock::get()?.unix_timestamp;
+            check_close(agent, unstake_request, now)?;
+            let (archive, bump) = archive.ok_or(RegistryError::ArchiveAccountMismatch)?;
+            record_archive(archive, agent, now, bump)?;
+
+            msg!(
+                "Multisig agent archived: id={}, reputation={}, stake returned={}",
+                agent.agent_id,
+                agent.reputation_score,
+                agent.staked_lamports
+            );
+            close_agent_to(agent, multisig, owner_index, recipient)
+        }
+    }
+}
+
+/// Close a multisig agent and its multisig, sending rent and remaining stake to `recipient`
+fn close_agent_to<'info>(
+    agent: &Account<'info, AgentAccount>,
+    multisig: &Account<'info, AgentMultisig>,
+    owner_index: Option<&mut Account<'info, OwnerIndex>>,
+    recipient: AccountInfo<'info>,
+) -> Result<()> {
+    if let Some(index) = owner_index {
+        index.remove(agent.agent_id);
+    }
+    multisig.close(recipient.clone())?;
+    agent.close(recipient)
+}
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, AgentArchive, AgentMultisig, AgentWatchers, RegistryConfig, MultisigProposal,
+    OwnerIndex, UnstakeRequest,
+};
+use crate::errors::RegistryError;
+
+use super::execute_multisig_action::apply_action;
+
+/// Execute a proposal that reached the threshold (anyone may submit)
+#[derive(Accounts)]
+pub struct ExecuteMultisigProposal<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.multisig == multisig.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [AgentMultisig::SEED_PREFIX, agent.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AgentMultisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            MultisigProposal::SEED_PREFIX,
+            multisig.key().as_ref(),
+            proposal.index.to_le_bytes().as_ref()
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    /// Registry config (capability taxonomy)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: must match the recipient of an `Unstake` or `WithdrawUnstake` action
+    #[account(mut)]
+    pub recipient: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's UnstakeRequest PDA, which may not exist; lamports pending there
+    /// don't count towards the agent account's rent. Created by `RequestUnstake` and
+    /// closed by `WithdrawUnstake`
+    #[account(
+        mut,
+        seeds = [UnstakeRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub unstake_request: UncheckedAccount<'info>,
+
+    /// Owner's agent index; a closed or archived agent's ID is dropped when passed
+    #[account(
+        mut,
+        seeds = [OwnerIndex::SEED_PREFIX, agent.owner.as_ref()],
+        bump = owner_index.bump
+    )]
+    pub owner_index: Option<Account<'info, OwnerIndex>>,
+
+    /// Written by an `Archive` action, and passed only for one
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + AgentArchive::INIT_SPACE,
+        seeds = [AgentArchive::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub archive: Option<Account<'info, AgentArchive>>,
+}
+
+pub fn handler(ctx: Context<ExecuteMultisigProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(!proposal.executed, RegistryError::ProposalAlreadyExecuted);
+    require!(
+        proposal.owner_set_seq == ctx.accounts.multisig.owner_set_seq,
+        RegistryError::ProposalStale
+    );
+    require!(
+        ctx.accounts.multisig.meets_threshold(proposal.approvals),
+        RegistryError::ThresholdNotMet
+    );
+
+    proposal.executed = true;
+
+    msg!(
+        "Multisig proposal executed: agent={}, index={}",
+        ctx.accounts.agent.agent_id,
+        proposal.index
+    );
+
+    apply_action(
+        &mut ctx.accounts.agent,
+        &mut ctx.accounts.multisig,
+        &ctx.accounts.config,
+        proposal.action.clone(),
+        ctx.accounts.executor.to_account_info(),
+        ctx.accounts.recipient.as_ref().map(|r| r.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.unstake_request,
+        &ctx.accounts.watchers,
+        ctx.accounts.owner_index.as_mut(),
+        ctx.accounts.archive.as_mut().zip(ctx.bumps.archive),
+    )
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{AuditSelection, RegistryState, SwitchboardRandomness};
+use crate::errors::RegistryError;
+
+/// Expire a round whose randomness was not revealed within `AuditSelection::SETTLE_WINDOW`
+/// (permissionless). The requester's bond is forfeited to the treasury, so starting rounds
+/// and withholding the reveal costs the requester
+#[derive(Accounts)]
+#[instruction(round: u64)]
+pub struct ExpireAuditSelection<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AuditSelection::SEED_PREFIX, round.to_le_bytes().as_ref()],
+        bump = selection.bump,
+        constraint = !selection.settled @ RegistryError::SelectionAlreadySettled,
+        constraint = !selection.expired @ RegistryError::SelectionExpired
+    )]
+    pub selection: Account<'info, AuditSelection>,
+
+    /// CHECK: Must be the randomness account committed at request time
+    #[account(address = selection.randomness_account @ RegistryError::InvalidRandomnessAccount)]
+    pub randomness_account: UncheckedAccount<'info>,
+
+    /// Treasury PDA receiving the forfeited bond
+    #[account(
+        mut,
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ExpireAuditSelection>, _round: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let selection = &mut ctx.accounts.selection;
+    require!(selection.lapsed(now), RegistryError::SelectionNotExpired);
+
+    // A reveal that landed but was never recorded still settles the round
+    let revealed = SwitchboardRandomness::parse(&ctx.accounts.randomness_account)
+        .is_some_and(|randomness| randomness.revealed_for(selection.commit_slot));
+    require!(!revealed, RegistryError::SelectionNotExpired);
+
+    selection.expired = true;
+    let bond = selection.bond;
+    selection.bond = 0;
+    **selection.to_account_info().try_borrow_mut_lamports()? -= bond;
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += bond;
+
+    msg!(
+        "Audit selection expired unrevealed: round={}, bond forfeited: {}",
+        selection.round,
+        bond
+    );
+
+    Ok(())
+}
@@ -1,14 +1,25 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, Challenge, ChallengeStatus, RegistryState};
+use crate::state::{
+    AgentAccount, AgentWatchers, AuditMerkleTree, Challenge, ChallengeStatus, ExpiryPolicy,
+    RegistryConfig, RegistryState, ReputationHistory, ReputationHooks, ReputationSource,
+    UnstakeRequest,
+};
 use crate::errors::RegistryError;
+use crate::events::{ChallengeAnchored, ReputationChanged};
 
 /// Expire a challenge that has passed its deadline
 ///
 /// This instruction can be called by ANYONE to expire a stale challenge.
 /// This is important for:
 /// 1. Network hygiene - cleaning up stale state
-/// 2. Accountability - agents that don't respond get penalized
+/// 2. Accountability - agents that don't respond get penalized and lose
+///    `RegistryConfig::expiry_slash_bps` of their stake to the challenger (or the
+///    challenge is voided, per `RegistryConfig::expiry_policy`)
 /// 3. Permissionless - anyone can trigger this, incentivizing cleanup
+/// 4. Escrow - the challenger's escrow is refunded instead of staying stuck
+///
+/// Either outcome is anchored into the agent's challenge stream
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(nonce: u64)]
 pub struct ExpireChallenge<'info> {
@@ -23,6 +34,13 @@ pub struct ExpireChallenge<'info> {
     )]
     pub registry: Account<'info, RegistryState>,
 
+    /// Registry config (per-type reputation weights)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
     /// The agent that was challenged
     #[account(
         mut,
@@ -49,9 +67,62 @@ pub struct ExpireChallenge<'info> {
         constraint = challenge.status == ChallengeStatus::Pending @ RegistryError::ChallengeNotPending
     )]
     pub challenge: Account<'info, Challenge>,
+
+    /// CHECK: The challenger - receives the escrow refund and any slashed stake
+    #[account(mut, address = challenge.challenger @ RegistryError::ChallengeMismatch)]
+    pub challenger: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's UnstakeRequest PDA, which may not exist; a pending
+    /// withdrawal is slashed along with the stake
+    #[account(
+        mut,
+        seeds = [UnstakeRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub unstake_request: UncheckedAccount<'info>,
+
+    /// Optional reputation history; the change is appended when passed
+    #[account(
+        mut,
+        constraint = history.load()?.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub history: Option<AccountLoader<'info, ReputationHistory>>,
+
+    /// The agent's challenge-result audit stream (created on first resolution)
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = 8 + AuditMerkleTree::INIT_SPACE,
+        seeds = [AuditMerkleTree::SEED_PREFIX, agent.key().as_ref(), AuditMerkleTree::CHALLENGE_STREAM],
+        bump
+    )]
+    pub challenge_stream: Account<'info, AuditMerkleTree>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's ReputationHooks PDA, which may not exist; once it does the
+    /// change is recorded there and delivered to hook programs passed as remaining accounts
+    #[account(
+        mut,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub hooks: UncheckedAccount<'info>,
 }
 
-pub fn handler(ctx: Context<ExpireChallenge>, _nonce: u64) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExpireChallenge<'info>>,
+    _nonce: u64,
+) -> Result<()> {
     let challenge = &mut ctx.accounts.challenge;
     let agent = &mut ctx.accounts.agent;
     let clock = Clock::get()?;
@@ -62,20 +133,118 @@ pub fn handler(ctx: Context<ExpireChallenge>, _nonce: u64) -> Result<()> {
         RegistryError::ChallengeNotExpired
     );
 
-    // Mark as expired
-    challenge.status = ChallengeStatus::Expired;
     challenge.responded_at = clock.unix_timestamp;
+    let old_reputation = agent.reputation_score;
+    let mut slashed = 0;
+
+    match ctx.accounts.config.expiry_policy {
+        ExpiryPolicy::Fail => {
+            // Mark as expired and apply penalty for not responding (same as failing)
+            challenge.status = ChallengeStatus::Expired;
+            let delta = challenge.reputation_delta(&ctx.accounts.config, false);
+            agent.record_challenge(
+                challenge.challenge_type,
+                false,
+                delta,
+                &ctx.accounts.config,
+                clock.unix_timestamp,
+            );
+            agent.touch(clock.unix_timestamp);
+            agent.credit_reputation(ReputationSource::ChallengeProgram, old_reputation);
+            ReputationHistory::record(
+                ctx.accounts.history.as_ref(),
+                old_reputation,
+                agent.reputation_score,
+                ReputationSource::ChallengeProgram,
+                clock.unix_timestamp,
+            )?;
+            emit_cpi!(ReputationChanged {
+                agent: agent.key(),
+                agent_id: agent.agent_id,
+                old_score: old_reputation,
+                new_score: agent.reputation_score,
+                source: ReputationSource::ChallengeProgram as u8,
+                timestamp: clock.unix_timestamp,
+            });
+            AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_REPUTATION)?;
+
+            let challenger_info = ctx.accounts.challenger.to_account_info();
+            slashed = slash_expired_stake(
+                agent,
+                &ctx.accounts.unstake_request,
+                ctx.accounts.config.expiry_slash_bps,
+                &challenger_info,
+            )?;
+            if slashed > 0 {
+                AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_STAKE)?;
+            }
+            // Hooks see the slashed stake too
+            ReputationHooks::notify_agent(
+                &ctx.accounts.hooks,
+                agent,
+                ctx.remaining_accounts,
+                old_reputation,
+                clock.unix_timestamp,
+            )?;
+        }
+        ExpiryPolicy::Void => {
+            // No verdict - agent reputation is untouched
+            challenge.status = ChallengeStatus::Void;
+        }
+    }
+
+    // Link the result into the agent's audit history
+    let leaf = challenge.result_leaf(
+        &challenge.key(),
+        old_reputation,
+        agent.reputation_score,
+        clock.unix_timestamp,
+    );
+    let stream = &mut ctx.accounts.challenge_stream;
+    let leaf_index =
+        stream.record(agent.key(), ctx.bumps.challenge_stream, leaf, clock.unix_timestamp)?;
+    emit_cpi!(ChallengeAnchored {
+        agent: agent.key(),
+        challenge: challenge.key(),
+        leaf_index,
+        leaf,
+        root: stream.root,
+        timestamp: clock.unix_timestamp,
+    });
 
-    // Apply penalty for not responding (same as failing)
-    agent.challenges_failed = agent.challenges_failed.saturating_add(1);
-    agent.adjust_reputation(Challenge::FAIL_REPUTATION_DELTA);
-    agent.updated_at = clock.unix_timestamp;
+    // Either way the challenger gets the escrow back
+    let challenger_info = ctx.accounts.challenger.to_account_info();
+    let refunded = Challenge::release_escrow(challenge, &challenger_info)?;
 
     msg!(
-        "Challenge EXPIRED! Agent {} did not respond. Reputation: {}",
+        "Challenge EXPIRED ({:?})! Agent {} did not respond. Reputation: {}, escrow refunded: {}, stake slashed: {}",
+        ctx.accounts.config.expiry_policy,
         agent.agent_id,
-        agent.reputation_score
+        agent.reputation_score,
+        refunded,
+        slashed
     );
 
     Ok(())
 }
+
+/// Slash the agent's stake, and its pending unstake when the request exists
+fn slash_expired_stake<'info>(
+    agent: &mut Account<'info, AgentAccount>,
+    unstake_request: &UncheckedAccount<'info>,
+    bps: u16,
+    challenger: &AccountInfo<'info>,
+) -> Result<u64> {
+    if bps == 0 {
+        return Ok(0);
+    }
+    if unstake_request.data_is_empty() {
+        return AgentAccount::slash_stake(agent, None, bps, challenger);
+    }
+
+    require_keys_eq!(*unstake_request.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    let mut request = UnstakeRequest::try_deserialize(&mut &unstake_request.try_borrow_data()?[..])?;
+    let slashed = AgentAccount::slash_stake(agent, Some(&mut request), bps, challenger)?;
+    request.try_serialize(&mut &mut unstake_request.try_borrow_mut_data()?[..])?;
+    Ok(slashed)
+}
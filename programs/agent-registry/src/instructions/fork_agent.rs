@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{
+    AgentAccount, AgentWatchers, AllowlistEntry, ModelRecord, OwnerCounter, OwnerIndex,
+    RegistryConfig, RegistryState,
+};
+use crate::errors::RegistryError;
+
+use super::register_agent::{
+    check_allowlist, check_token_gate, claim_model, count_registration, index_owner, init_agent,
+    validate_metadata, RegisterAgentResponse,
+};
+
+/// Register a derivative of an existing agent, recording its lineage
+/// Anyone may fork; the fork starts with fresh reputation like any new agent
+#[derive(Accounts)]
+#[instruction(name: String, model_hash: String)]
+pub struct ForkAgent<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.collection_initialized @ RegistryError::CollectionNotInitialized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Registry config (registration fee)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Treasury PDA receiving the registration fee
+    #[account(
+        mut,
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// CHECK: Pyth SOL/USD PriceUpdateV2 account (required when the fee is USD-denominated)
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    /// Owner's allowlist approval (required while the registry is allowlist-only)
+    #[account(
+        seeds = [AllowlistEntry::SEED_PREFIX, owner.key().as_ref()],
+        bump = allowlist_entry.bump
+    )]
+    pub allowlist_entry: Option<Account<'info, AllowlistEntry>>,
+
+    /// CHECK: owner's token account of the gate mint (required while the registry is token-gated)
+    /// Mint, holder and balance are checked in the handler
+    pub gate_token_account: Option<UncheckedAccount<'info>>,
+
+    /// The agent being forked
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            parent.owner.as_ref(),
+            parent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = parent.bump,
+        constraint = parent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub parent: Account<'info, AgentAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + AgentAccount::INIT_SPACE,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            registry.total_agents.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Owner's agent index; the new agent's ID is appended when passed
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + OwnerIndex::INIT_SPACE,
+        seeds = [OwnerIndex::SEED_PREFIX, owner.key().as_ref()],
+        bump
+    )]
+    pub owner_index: Option<Account<'info, OwnerIndex>>,
+
+    /// Owner's registration counter (required while registrations are rate limited)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + OwnerCounter::INIT_SPACE,
+        seeds = [OwnerCounter::SEED_PREFIX, owner.key().as_ref()],
+        bump
+    )]
+    pub owner_counter: Option<Account<'info, OwnerCounter>>,
+
+    /// Claim on the model hash (required while the registry enforces unique models)
+    /// A fork that keeps its parent's model links to the existing record
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + ModelRecord::INIT_SPACE,
+        seeds = [ModelRecord::SEED_PREFIX, ModelRecord::digest(&model_hash).as_ref()],
+        bump
+    )]
+    pub model_record: Option<Account<'info, ModelRecord>>,
+
+    /// CHECK: NFT mint account - same hackathon limitation as `register_agent`
+    pub nft_mint: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the parent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, parent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ForkAgent>,
+    name: String,
+    model_hash: String,
+    capabilities: String,
+) -> Result<RegisterAgentResponse> {
+    let (name, capabilities) = validate_metadata(&name, &model_hash, &capabilities)?;
+    check_allowlist(&ctx.accounts.config, ctx.accounts.allowlist_entry.as_ref())?;
+    check_token_gate(
+        &ctx.accounts.config,
+        ctx.accounts.gate_token_account.as_ref(),
+        ctx.accounts.owner.key(),
+    )?;
+
+    let clock = Clock::get()?;
+    count_registration(
+        &ctx.accounts.config,
+        ctx.accounts.owner_counter.as_deref_mut(),
+        ctx.bumps.owner_counter,
+        ctx.accounts.owner.key(),
+        clock.unix_timestamp,
+    )?;
+
+    match ctx.accounts.model_record.as_deref_mut() {
+        Some(record) if record.agent != Pubkey::default() => {
+            require!(
+                ctx.accounts.parent.model_hash == model_hash,
+                RegistryError::DuplicateModel
+            );
+            record.linked_forks = record.linked_forks.saturating_add(1);
+        }
+        record => claim_model(
+            &ctx.accounts.config,
+            record,
+            ctx.bumps.model_record,
+            ctx.accounts.agent.key(),
+            &model_hash,
+            clock.unix_timestamp,
+        )?,
+    }
+
+    // Forks pay the same registration fee as fresh agents
+    let price_feed = ctx.accounts.price_feed.as_ref().map(|feed| feed.to_account_info());
+    let fee = ctx.accounts.config.registration_fee(price_feed.as_ref(), clock.unix_timestamp)?;
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    let parent = &mut ctx.accounts.parent;
+    let agent = &mut ctx.accounts.agent;
+
+    init_agent(
+        agent,
+        &mut ctx.accounts.registry,
+        &ctx.accounts.config,
+        ctx.accounts.owner.key(),
+        ctx.accounts.nft_mint.key(),
+        name.clone(),
+        model_hash,
+        capabilities,
+        ctx.bumps.agent,
+        clock.unix_timestamp,
+    )?;
+    index_owner(
+        ctx.accounts.owner_index.as_deref_mut(),
+        ctx.bumps.owner_index,
+        agent.owner,
+        agent.agent_id,
+    )?;
+    agent.parent_agent = parent.key();
+    agent.parent_model_hash = parent.model_hash.clone();
+
+    parent.fork_count = parent.fork_count.saturating_add(1);
+    parent.bump_seq();
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_METADATA)?;
+
+    msg!(
+        "Agent forked: id={}, name={}, parent={}, parent_forks={}, fee={}",
+        agent.agent_id,
+        name,
+        parent.agent_id,
+        parent.fork_count,
+        fee
+    );
+
+    Ok(RegisterAgentResponse {
+        agent: agent.key(),
+        agent_id: agent.agent_id,
+    })
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentWatchers, RegistryState};
+use crate::errors::RegistryError;
+
+/// Permanently lock agent fields against updates (owner or admin)
+/// Freezing only ever adds bits; there is no unfreeze
+#[derive(Accounts)]
+pub struct FreezeFields<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<FreezeFields>, fields: u8) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let agent = &mut ctx.accounts.agent;
+    if authority != ctx.accounts.registry.admin {
+        require_keys_eq!(authority, agent.owner, RegistryError::Unauthorized);
+        require!(!agent.has_multisig(), RegistryError::MultisigRequired);
+    }
+
+    agent.freeze(fields)?;
+    agent.touch(Clock::get()?.unix_timestamp);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_METADATA)?;
+
+    msg!(
+        "Agent fields frozen: id={}, fields={:#x}, frozen={:#x}",
+        agent.agent_id,
+        fields,
+        agent.frozen_fields
+    );
+
+    Ok(())
+}
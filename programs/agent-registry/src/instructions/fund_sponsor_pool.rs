@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{RegistrantWhitelist, SponsorPool};
+use crate::errors::RegistryError;
+
+/// Top up a sponsorship pool and optionally replace its whitelist (sponsor only)
+#[derive(Accounts)]
+pub struct FundSponsorPool<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SponsorPool::SEED_PREFIX, sponsor.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.sponsor == sponsor.key() @ RegistryError::Unauthorized
+    )]
+    pub pool: Account<'info, SponsorPool>,
+
+    #[account(
+        mut,
+        seeds = [SponsorPool::VAULT_SEED, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<FundSponsorPool>,
+    amount: u64,
+    whitelist: Option<RegistrantWhitelist>,
+    registrations_per_registrant: Option<u16>,
+) -> Result<()> {
+    if amount > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.sponsor.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    if let Some(whitelist) = whitelist {
+        pool.set_whitelist(whitelist);
+    }
+    if let Some(quota) = registrations_per_registrant {
+        pool.registrations_per_registrant = quota;
+    }
+
+    msg!(
+        "Sponsor pool funded: sponsor={}, amount={}, balance={}",
+        pool.sponsor,
+        amount,
+        ctx.accounts.vault.lamports()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, OwnerIndex, RegistryConfig, RegistryState};
+use crate::errors::RegistryError;
+
+/// Close a stale agent that was never verified or challenged (permissionless)
+/// The reclaimed rent is split between the caller and the treasury; jobs still in flight
+/// are refunded to their consumers by `void_job`
+#[derive(Accounts)]
+pub struct GarbageCollectAgent<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Registry config (garbage collection window)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Treasury PDA receiving the remaining rent
+    #[account(
+        mut,
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = treasury,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.is_collectable(config.gc_idle_secs, Clock::get()?.unix_timestamp)
+            @ RegistryError::AgentNotCollectable
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Owner's agent index; the collected agent's ID is dropped when passed
+    #[account(
+        mut,
+        seeds = [OwnerIndex::SEED_PREFIX, agent.owner.as_ref()],
+        bump = owner_index.bump
+    )]
+    pub owner_index: Option<Account<'info, OwnerIndex>>,
+}
+
+pub fn handler(ctx: Context<GarbageCollectAgent>) -> Result<()> {
+    let info = ctx.accounts.agent.to_account_info();
+    let rent = info.lamports();
+    // Anything above rent (e.g. a pending unstake) belongs to the owner, not the collector
+    require!(
+        rent <= Rent::get()?.minimum_balance(info.data_len()),
+        RegistryError::AgentNotCollectable
+    );
+
+    let agent = &ctx.accounts.agent;
+    if let Some(index) = ctx.accounts.owner_index.as_mut() {
+        index.remove(agent.agent_id);
+    }
+
+    // Program-owned account, so lamports move directly; `close` sends the rest to the treasury
+    let reward = ((rent as u128) * (RegistryConfig::GC_CALLER_SHARE_BPS as u128)
+        / (RegistryConfig::BPS_DENOMINATOR as u128)) as u64;
+    **info.try_borrow_mut_lamports()? -= reward;
+    **ctx.accounts.caller.to_account_info().try_borrow_mut_lamports()? += reward;
+
+    msg!(
+        "Agent garbage collected: id={}, owner={}, caller reward={}, treasury={}",
+        agent.agent_id,
+        agent.owner,
+        reward,
+        rent - reward
+    );
+
+    Ok(())
+}
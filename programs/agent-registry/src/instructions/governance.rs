@@ -0,0 +1,339 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AdminActionLog, AgentAccount, AgentWatchers, Governance, GovernanceProposal, GovernanceVote,
+    RegistryConfig, RegistryState, VoteWeighting,
+};
+use crate::errors::RegistryError;
+use super::update_config::{apply_config_params, UpdateConfigParams};
+
+/// Create or retune the governance thresholds (admin only)
+#[derive(Accounts)]
+pub struct SetGovernance<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Governance::INIT_SPACE,
+        seeds = [Governance::SEED_PREFIX],
+        bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Put a config change to an agent vote (owner of a verified agent)
+#[derive(Accounts)]
+#[instruction(params: UpdateConfigParams)]
+pub struct CreateGovernanceProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [Governance::SEED_PREFIX],
+        bump = governance.bump,
+        constraint = governance.active @ RegistryError::GovernanceInactive
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = GovernanceProposal::space(&params),
+        seeds = [
+            GovernanceProposal::SEED_PREFIX,
+            governance.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Vote on an open proposal with one verified agent (its owner)
+/// Under stake weighting the agent's stake is locked until voting ends, so the same
+/// lamports can't be unstaked and restaked behind another agent's vote
+#[derive(Accounts)]
+pub struct CastGovernanceVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        seeds = [Governance::SEED_PREFIX],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Registry config (reputation decay)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            GovernanceProposal::SEED_PREFIX,
+            proposal.proposal_id.to_le_bytes().as_ref()
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    /// Keyed by agent ID, which `transfer_agent` keeps, so a transferred agent's
+    /// successor can't vote again on the same proposal
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + GovernanceVote::INIT_SPACE,
+        seeds = [
+            GovernanceVote::SEED_PREFIX,
+            proposal.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub vote: Account<'info, GovernanceVote>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+/// Apply a proposal that passed once voting has closed (anyone may submit)
+#[derive(Accounts)]
+pub struct ExecuteGovernanceProposal<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GovernanceProposal::SEED_PREFIX,
+            proposal.proposal_id.to_le_bytes().as_ref()
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    /// Admin action log (the config change is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+}
+
+pub fn set_governance(
+    ctx: Context<SetGovernance>,
+    active: bool,
+    weighting: VoteWeighting,
+    quorum_weight: u64,
+    majority_bps: u16,
+    voting_period_secs: i64,
+) -> Result<()> {
+    require!(
+        Governance::is_valid_config(quorum_weight, majority_bps, voting_period_secs),
+        RegistryError::InvalidGovernanceConfig
+    );
+
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_SET_GOVERNANCE,
+        ctx.accounts.admin.key(),
+        ctx.accounts.governance.key(),
+        if active { quorum_weight } else { 0 },
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let governance = &mut ctx.accounts.governance;
+    governance.active = active;
+    governance.weighting = weighting;
+    governance.quorum_weight = quorum_weight;
+    governance.majority_bps = majority_bps;
+    governance.voting_period_secs = voting_period_secs;
+    governance.bump = ctx.bumps.governance;
+
+    msg!(
+        "Governance set: active={}, weighting={:?}, quorum={}, majority={}bps, period={}s",
+        active,
+        weighting,
+        quorum_weight,
+        majority_bps,
+        voting_period_secs
+    );
+
+    Ok(())
+}
+
+pub fn create_governance_proposal(
+    ctx: Context<CreateGovernanceProposal>,
+    params: UpdateConfigParams,
+    description_hash: [u8; 32],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        Governance::is_eligible(&ctx.accounts.agent, &ctx.accounts.proposer.key(), now),
+        RegistryError::NotEligibleVoter
+    );
+
+    let governance = &mut ctx.accounts.governance;
+    let proposal_id = governance.proposal_count;
+    governance.proposal_count += 1;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.proposal_id = proposal_id;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.proposer_agent = ctx.accounts.agent.key();
+    proposal.description_hash = description_hash;
+    proposal.params = params;
+    proposal.quorum_weight = governance.quorum_weight;
+    proposal.majority_bps = governance.majority_bps;
+    proposal.votes_for = 0;
+    proposal.votes_against = 0;
+    proposal.voters = 0;
+    proposal.executed = false;
+    proposal.created_at = now;
+    proposal.voting_ends_at = now + governance.voting_period_secs;
+    proposal.bump = ctx.bumps.proposal;
+
+    msg!(
+        "Governance proposal created: id={}, agent={}, voting ends at {}",
+        proposal_id,
+        ctx.accounts.agent.agent_id,
+        proposal.voting_ends_at
+    );
+
+    Ok(())
+}
+
+pub fn cast_governance_vote(ctx: Context<CastGovernanceVote>, approve: bool) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let agent = &mut ctx.accounts.agent;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(proposal.is_open(now), RegistryError::VotingClosed);
+    // Agents registered after the proposal can't be spun up to sway it
+    require!(
+        Governance::is_eligible(agent, &ctx.accounts.voter.key(), now)
+            && agent.created_at < proposal.created_at,
+        RegistryError::NotEligibleVoter
+    );
+
+    let governance = &ctx.accounts.governance;
+    let weight = governance.vote_weight(agent, &ctx.accounts.config, now);
+    if governance.weighs_stake() && agent.stake_locked_until < proposal.voting_ends_at {
+        agent.stake_locked_until = proposal.voting_ends_at;
+        agent.bump_seq();
+        AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_STAKE)?;
+    }
+    if approve {
+        proposal.votes_for = proposal.votes_for.saturating_add(weight);
+    } else {
+        proposal.votes_against = proposal.votes_against.saturating_add(weight);
+    }
+    proposal.voters += 1;
+
+    let vote = &mut ctx.accounts.vote;
+    vote.proposal = proposal.key();
+    vote.agent = agent.key();
+    vote.agent_id = agent.agent_id;
+    vote.approve = approve;
+    vote.weight = weight;
+    vote.voted_at = now;
+    vote.bump = ctx.bumps.vote;
+
+    msg!(
+        "Governance vote: proposal={}, agent={}, approve={}, weight={}, tally={}/{}",
+        proposal.proposal_id,
+        agent.agent_id,
+        approve,
+        weight,
+        proposal.votes_for,
+        proposal.votes_against
+    );
+
+    Ok(())
+}
+
+pub fn execute_governance_proposal(ctx: Context<ExecuteGovernanceProposal>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.executed, RegistryError::ProposalAlreadyExecuted);
+    require!(!proposal.is_open(now), RegistryError::VotingOpen);
+    require!(proposal.passed(), RegistryError::ProposalNotPassed);
+
+    proposal.executed = true;
+
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_CONFIG_CHANGE,
+        proposal.key(),
+        ctx.accounts.config.key(),
+        proposal.proposal_id,
+        now,
+    )?;
+
+    msg!(
+        "Governance proposal executed: id={}, tally={}/{}",
+        proposal.proposal_id,
+        proposal.votes_for,
+        proposal.votes_against
+    );
+
+    apply_config_params(&mut ctx.accounts.config, proposal.params.clone())
+}
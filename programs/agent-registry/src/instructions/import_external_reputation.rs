@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, ExternalScore, ExternalScores, ReputationOracle};
+use crate::errors::RegistryError;
+
+/// Record an off-chain score for an agent under the oracle's source (active oracles only)
+#[derive(Accounts)]
+pub struct ImportExternalReputation<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ReputationOracle::SEED_PREFIX, authority.key().as_ref()],
+        bump = oracle.bump,
+        constraint = oracle.active @ RegistryError::OracleInactive
+    )]
+    pub oracle: Account<'info, ReputationOracle>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ExternalScores::INIT_SPACE,
+        seeds = [ExternalScores::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub external_scores: Account<'info, ExternalScores>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ImportExternalReputation>, score: u32) -> Result<()> {
+    require!(
+        score <= AgentAccount::MAX_REPUTATION,
+        RegistryError::InvalidReputationThreshold
+    );
+
+    let oracle = &mut ctx.accounts.oracle;
+    let external = &mut ctx.accounts.external_scores;
+    external.agent = ctx.accounts.agent.key();
+    external.bump = ctx.bumps.external_scores;
+
+    require!(
+        external.upsert(ExternalScore {
+            source_id: oracle.source_id,
+            score,
+            oracle: oracle.authority,
+            updated_at: Clock::get()?.unix_timestamp,
+        }),
+        RegistryError::ExternalSourcesFull
+    );
+    oracle.imports = oracle.imports.saturating_add(1);
+
+    msg!(
+        "External reputation imported: agent={}, source={}, score={}",
+        ctx.accounts.agent.agent_id,
+        oracle.source_id,
+        score
+    );
+
+    Ok(())
+}
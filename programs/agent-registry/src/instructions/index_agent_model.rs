@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, ModelIndex, ModelRecord};
+use crate::errors::RegistryError;
+
+/// Add an agent to the index for its model hash (permissionless, payer funds rent)
+#[derive(Accounts)]
+pub struct IndexAgentModel<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ModelIndex::INIT_SPACE,
+        seeds = [ModelIndex::SEED_PREFIX, ModelRecord::digest(&agent.model_hash).as_ref()],
+        bump
+    )]
+    pub model_index: Account<'info, ModelIndex>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Drop a closed agent from its model index (permissionless)
+#[derive(Accounts)]
+pub struct PruneModelIndex<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ModelIndex::SEED_PREFIX, model_index.model_digest.as_ref()],
+        bump = model_index.bump
+    )]
+    pub model_index: Account<'info, ModelIndex>,
+
+    /// CHECK: the indexed agent; must no longer hold any data (closed via `close_agent`)
+    #[account(constraint = agent.data_is_empty() @ RegistryError::AgentStillRegistered)]
+    pub agent: UncheckedAccount<'info>,
+}
+
+pub fn index_agent_model(ctx: Context<IndexAgentModel>) -> Result<()> {
+    let agent_key = ctx.accounts.agent.key();
+    let index = &mut ctx.accounts.model_index;
+    if index.agents.is_empty() {
+        index.model_digest = ModelRecord::digest(&ctx.accounts.agent.model_hash);
+        index.bump = ctx.bumps.model_index;
+    }
+
+    require!(!index.agents.contains(&agent_key), RegistryError::AgentAlreadyIndexed);
+    require!(index.agents.len() < ModelIndex::MAX_AGENTS, RegistryError::ModelIndexFull);
+    index.agents.push(agent_key);
+
+    msg!(
+        "Agent indexed by model: agent={}, model={}, agents={}",
+        ctx.accounts.agent.agent_id,
+        ctx.accounts.agent.model_hash,
+        index.agents.len()
+    );
+
+    Ok(())
+}
+
+pub fn prune_model_index(ctx: Context<PruneModelIndex>) -> Result<()> {
+    let agent_key = ctx.accounts.agent.key();
+    let index = &mut ctx.accounts.model_index;
+
+    let position = index
+        .agents
+        .iter()
+        .position(|agent| *agent == agent_key)
+        .ok_or(RegistryError::AgentNotIndexed)?;
+    index.agents.remove(position);
+
+    msg!(
+        "Agent pruned from model index: agent={}, remaining={}",
+        agent_key,
+        index.agents.len()
+    );
+
+    Ok(())
+}
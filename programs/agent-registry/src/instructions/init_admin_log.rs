@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminActionLog, RegistryState};
+use crate::errors::RegistryError;
+
+/// Create the registry's admin action log (admin only, one-time)
+/// Privileged instructions require it, so this must run before any of them
+#[derive(Accounts)]
+pub struct InitAdminLog<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminActionLog::SPACE,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitAdminLog>) -> Result<()> {
+    let mut log = ctx.accounts.admin_log.load_init()?;
+    log.bump = ctx.bumps.admin_log;
+
+    msg!("Admin action log created: capacity={}", AdminActionLog::CAPACITY);
+
+    Ok(())
+}
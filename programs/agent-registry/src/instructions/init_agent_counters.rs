@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentCounters};
+
+/// Create an agent's zero-copy hot counters for `update_reputation_fast`
+/// (permissionless, payer funds rent)
+#[derive(Accounts)]
+pub struct InitAgentCounters<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = AgentCounters::SPACE,
+        seeds = [AgentCounters::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub counters: AccountLoader<'info, AgentCounters>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitAgentCounters>) -> Result<()> {
+    let mut counters = ctx.accounts.counters.load_init()?;
+    counters.agent = ctx.accounts.agent.key();
+    counters.copy_from(&ctx.accounts.agent);
+    counters.bump = ctx.bumps.counters;
+
+    msg!(
+        "Agent counters created: agent={}, seq={}",
+        ctx.accounts.agent.agent_id,
+        counters.synced_seq
+    );
+
+    Ok(())
+}
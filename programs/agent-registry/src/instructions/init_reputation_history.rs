@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, ReputationHistory};
+
+/// Create an agent's reputation history ring buffer (permissionless, payer funds rent)
+#[derive(Accounts)]
+pub struct InitReputationHistory<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ReputationHistory::SPACE,
+        seeds = [ReputationHistory::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub history: AccountLoader<'info, ReputationHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitReputationHistory>) -> Result<()> {
+    let mut history = ctx.accounts.history.load_init()?;
+    history.agent = ctx.accounts.agent.key();
+    history.bump = ctx.bumps.history;
+
+    msg!(
+        "Reputation history created: agent={}, capacity={}",
+        ctx.accounts.agent.agent_id,
+        ReputationHistory::CAPACITY
+    );
+
+    Ok(())
+}
@@ -1,7 +1,17 @@
 use anchor_lang::prelude::*;
-use crate::state::RegistryState;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use crate::state::{
+    AdminActionLog, AttestationKind, Attester, Moderator, RegistryConfig, RegistryState,
+};
+use crate::errors::RegistryError;
+use super::initialize_config::set_config_defaults;
+use super::update_config::{apply_config_params, UpdateConfigParams};
 
+/// Bootstrap the registry in one call: registry state, config, admin log and initial roles
+/// Only the program's upgrade authority may run it, so a fresh deployment cannot be
+/// front-run; `init` on every singleton rejects any second call
 #[derive(Accounts)]
+#[instruction(params: InitializeParams)]
 pub struct Initialize<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -15,13 +25,85 @@ pub struct Initialize<'info> {
     )]
     pub registry: Account<'info, RegistryState>,
 
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RegistryConfig::INIT_SPACE,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminActionLog::SPACE,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    /// This program's upgradeable-loader data account, naming the upgrade authority
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ RegistryError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Initial moderator (pauser) role - required iff `params.moderator` is set
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Moderator::INIT_SPACE,
+        seeds = [Moderator::SEED_PREFIX, params.moderator.as_ref()],
+        bump
+    )]
+    pub moderator: Option<Account<'info, Moderator>>,
+
+    /// Initial attester (verifier) role - required iff `params.attester` is set
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Attester::INIT_SPACE,
+        seeds = [Attester::SEED_PREFIX, params.attester.as_ref()],
+        bump
+    )]
+    pub attester: Option<Account<'info, Attester>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<Initialize>) -> Result<()> {
-    let registry = &mut ctx.accounts.registry;
+/// Bootstrap parameters - config overrides on top of the defaults plus optional roles
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitializeParams {
+    /// Fees, limits and mode flags; `None` keeps the default
+    pub config: UpdateConfigParams,
+    /// Initial moderator authority (default pubkey = none)
+    pub moderator: Pubkey,
+    /// Initial attester authority (default pubkey = none)
+    pub attester: Pubkey,
+    /// Kind of the initial attester, ignored when none is set
+    pub attester_kind: AttestationKind,
+}
+
+pub fn handler(ctx: Context<Initialize>, params: InitializeParams) -> Result<()> {
+    require!(
+        ctx.accounts.moderator.is_some() == (params.moderator != Pubkey::default()),
+        RegistryError::InitialRoleMismatch
+    );
+    require!(
+        ctx.accounts.attester.is_some() == (params.attester != Pubkey::default()),
+        RegistryError::InitialRoleMismatch
+    );
 
-    registry.admin = ctx.accounts.admin.key();
+    let admin = ctx.accounts.admin.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let registry = &mut ctx.accounts.registry;
+    registry.admin = admin;
     registry.total_agents = 0;
     registry.collection = Pubkey::default();
     registry.collection_initialized = false;
@@ -29,5 +111,53 @@ pub fn handler(ctx: Context<Initialize>) -> Result<()> {
 
     msg!("Registry initialized with admin: {}", registry.admin);
 
+    ctx.accounts.admin_log.load_init()?.bump = ctx.bumps.admin_log;
+
+    let config = &mut ctx.accounts.config;
+    set_config_defaults(config, ctx.bumps.config);
+    apply_config_params(config, params.config)?;
+
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_CONFIG_CHANGE,
+        admin,
+        ctx.accounts.config.key(),
+        0,
+        now,
+    )?;
+
+    if let Some(moderator) = ctx.accounts.moderator.as_mut() {
+        moderator.authority = params.moderator;
+        moderator.active = true;
+        moderator.actions_taken = 0;
+        moderator.registered_at = now;
+        moderator.bump = ctx.bumps.moderator.unwrap_or_default();
+
+        AdminActionLog::record(
+            &ctx.accounts.admin_log,
+            AdminActionLog::ACTION_SET_MODERATOR,
+            admin,
+            params.moderator,
+            1,
+            now,
+        )?;
+        msg!("Moderator set: {} active=true", params.moderator);
+    }
+
+    if let Some(attester) = ctx.accounts.attester.as_mut() {
+        attester.authority = params.attester;
+        attester.kind = params.attester_kind;
+        attester.active = true;
+        attester.attestations_issued = 0;
+        attester.registered_at = now;
+        attester.bump = ctx.bumps.attester.unwrap_or_default();
+
+        msg!(
+            "Attester set: {} kind={:?} active=true",
+            params.attester,
+            params.attester_kind
+        );
+    }
+
     Ok(())
 }
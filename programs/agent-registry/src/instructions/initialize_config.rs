@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, Challenge, ExpiryPolicy, RegistryConfig, RegistryState, ReputationTransferPolicy,
+};
+use crate::constants::MAX_REPUTATION_DELTA;
+use crate::errors::RegistryError;
+
+/// Create the registry config with default parameters (admin only, one-time)
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RegistryConfig::INIT_SPACE,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeConfig>) -> Result<()> {
+    set_config_defaults(&mut ctx.accounts.config, ctx.bumps.config);
+
+    msg!("Registry config initialized");
+
+    Ok(())
+}
+
+/// Fill a freshly created config with the default parameters
+pub(crate) fn set_config_defaults(config: &mut RegistryConfig, bump: u8) {
+    config.pass_weights_bps = RegistryConfig::DEFAULT_PASS_WEIGHTS_BPS;
+    config.fail_weights_bps = RegistryConfig::DEFAULT_FAIL_WEIGHTS_BPS;
+    config.challenge_duration = Challenge::DEFAULT_DURATION;
+    config.challenge_escrow_lamports = 0;
+    config.challenge_escrow_usd = 0;
+    config.registration_fee_lamports = 0;
+    config.registration_fee_usd = 0;
+    config.allowlist_only = false;
+    config.unique_models = false;
+    config.gate_mint = Pubkey::default();
+    config.gate_min_balance = 0;
+    config.referral_share_bps = RegistryConfig::DEFAULT_REFERRAL_SHARE_BPS;
+    config.verification_fee_lamports = 0;
+    config.audit_fee_lamports = 0;
+    config.verified_fee_discount_bps = 0;
+    config.reputation_fee_discount_min = AgentAccount::MAX_REPUTATION;
+    config.reputation_fee_discount_bps = 0;
+    config.auto_verify_min_stake_lamports = RegistryConfig::DEFAULT_AUTO_VERIFY_MIN_STAKE_LAMPORTS;
+    config.auto_verify_min_reputation = RegistryConfig::DEFAULT_AUTO_VERIFY_MIN_REPUTATION;
+    config.stake_lockup_secs = 0;
+    config.unstake_cooldown_secs = RegistryConfig::DEFAULT_UNSTAKE_COOLDOWN_SECS;
+    config.price_feed_id = RegistryConfig::DEFAULT_PRICE_FEED_ID;
+    config.max_price_age_secs = RegistryConfig::DEFAULT_MAX_PRICE_AGE_SECS;
+    config.max_price_conf_bps = RegistryConfig::DEFAULT_MAX_PRICE_CONF_BPS;
+    config.expiry_policy = ExpiryPolicy::Fail;
+    config.expiry_slash_bps = 0;
+    config.judge_quorum = RegistryConfig::DEFAULT_JUDGE_QUORUM;
+    config.reputation_decay_bps_per_day = RegistryConfig::DEFAULT_REPUTATION_DECAY_BPS_PER_DAY;
+    config.decay_grace_secs = RegistryConfig::DEFAULT_DECAY_GRACE_SECS;
+    config.external_reputation_weight_bps = RegistryConfig::DEFAULT_EXTERNAL_REPUTATION_WEIGHT_BPS;
+    config.reputation_floor = AgentAccount::MIN_REPUTATION;
+    config.reputation_ceiling = AgentAccount::MAX_REPUTATION;
+    config.reputation_cosigner = Pubkey::default();
+    config.cosign_delta_threshold = MAX_REPUTATION_DELTA as u32;
+    config.emission_lamports_per_epoch = 0;
+    config.emission_start_epoch = 0;
+    config.emission_halving_epochs = 0;
+    config.emission_min_reputation = RegistryConfig::DEFAULT_AUTO_VERIFY_MIN_REPUTATION;
+    config.emission_min_stake_lamports = RegistryConfig::DEFAULT_EMISSION_MIN_STAKE_LAMPORTS;
+    config.deprecated_at = 0;
+    config.successor_program = Pubkey::default();
+    config.challenge_program = Pubkey::default();
+    config.gc_idle_secs = 0;
+    config.max_registrations_per_day = 0;
+    config.verification_bond_lamports = 0;
+    config.verification_bond_clean_secs = 0;
+    config.reputation_transfer_policy = ReputationTransferPolicy::CarryOver;
+    config.transfer_haircut_bps = 0;
+    config.active_judges = 0;
+    config.defined_capabilities = 0;
+    config.audit_rounds = 0;
+    config.registry_snapshots = 0;
+    config.treasury_liabilities = 0;
+    config.bump = bump;
+    config.version = RegistryConfig::CURRENT_VERSION;
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{AgentAccount, AgentWatchers, RegistryNamespace};
+use crate::errors::RegistryError;
+
+/// Tag an agent with a namespace (owner only; one namespace per agent)
+/// Enforces the namespace's reputation, verification and approval policy and pays its fee
+#[derive(Accounts)]
+pub struct JoinNamespace<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [RegistryNamespace::SEED_PREFIX, namespace.name_hash.as_ref()],
+        bump = namespace.bump
+    )]
+    pub namespace: Account<'info, RegistryNamespace>,
+
+    /// CHECK: namespace admin - receives the join fee; must sign when approval is required
+    #[account(mut, address = namespace.admin @ RegistryError::Unauthorized)]
+    pub namespace_admin: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<JoinNamespace>) -> Result<()> {
+    let namespace = &ctx.accounts.namespace;
+    let agent = &ctx.accounts.agent;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(!agent.in_namespace(), RegistryError::AlreadyInNamespace);
+    require!(!namespace.is_deprecated(), RegistryError::RegistryDeprecated);
+    require!(
+        !namespace.approval_required || ctx.accounts.namespace_admin.is_signer,
+        RegistryError::NamespaceApprovalRequired
+    );
+    require!(
+        agent.reputation_score >= namespace.min_reputation
+            && (agent.verified || !namespace.verified_only)
+            && !agent.is_restricted(now),
+        RegistryError::NamespaceRequirementsNotMet
+    );
+
+    let fee = namespace.join_fee_lamports;
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.namespace_admin.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    let namespace = &mut ctx.accounts.namespace;
+    namespace.member_count = namespace.member_count.saturating_add(1);
+    namespace.total_joined = namespace.total_joined.saturating_add(1);
+
+    let agent = &mut ctx.accounts.agent;
+    agent.namespace = namespace.name_hash;
+    agent.touch(now);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_METADATA)?;
+
+    msg!(
+        "Agent joined namespace: id={}, namespace={}, fee={}",
+        agent.agent_id,
+        namespace.name,
+        fee
+    );
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentWatchers, RegistryNamespace};
+use crate::errors::RegistryError;
+
+/// Remove an agent's namespace tag (owner only; the join fee is not refunded)
+#[derive(Accounts)]
+pub struct LeaveNamespace<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.namespace == namespace.name_hash @ RegistryError::NotInNamespace
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [RegistryNamespace::SEED_PREFIX, namespace.name_hash.as_ref()],
+        bump = namespace.bump
+    )]
+    pub namespace: Account<'info, RegistryNamespace>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<LeaveNamespace>) -> Result<()> {
+    let namespace = &mut ctx.accounts.namespace;
+    namespace.member_count = namespace.member_count.saturating_sub(1);
+
+    let agent = &mut ctx.accounts.agent;
+    agent.namespace = [0u8; 32];
+    agent.touch(Clock::get()?.unix_timestamp);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_METADATA)?;
+
+    msg!("Agent left namespace: id={}, namespace={}", agent.agent_id, namespace.name);
+
+    Ok(())
+}
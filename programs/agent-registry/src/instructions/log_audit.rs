@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, AuditEntry, AgentAuditSummary, ActionType, RiskLevel};
+use anchor_lang::system_program;
+use crate::state::{
+    AgentAccount, AuditEntry, AgentAuditSummary, ActionType, RegistryConfig, RegistryState, RiskLevel,
+};
 use crate::errors::RegistryError;
 
 /// Accounts for logging an audit entry
@@ -18,10 +21,27 @@ pub struct LogAudit<'info> {
             agent.owner.as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
-        bump = agent.bump
+        bump = agent.bump,
+        constraint = agent.accepts_work(Clock::get()?.unix_timestamp) @ RegistryError::AgentNotActive,
+        constraint = !agent.is_audit_quarantined() @ RegistryError::AuditQuarantined
     )]
     pub agent: Account<'info, AgentAccount>,
 
+    /// Registry config (audit fee and discount schedule)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Treasury PDA receiving the audit fee
+    #[account(
+        mut,
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
     /// The audit summary for this agent (created if first audit)
     #[account(
         init_if_needed,
@@ -65,6 +85,14 @@ pub fn handler(
     require!(context_risk <= 100, RegistryError::InvalidRiskScore);
 
     let clock = Clock::get()?;
+    let audit_fee = charge_audit_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.agent,
+        &ctx.accounts.actor.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        clock.unix_timestamp,
+    )?;
     let agent_key = ctx.accounts.agent.key();
 
     // Calculate risk score based on action type and context
@@ -95,16 +123,43 @@ pub fn handler(
     summary.record_entry(risk_score, is_alert, clock.unix_timestamp);
 
     msg!(
-        "Audit logged: agent={}, action={:?}, risk={}, index={}",
+        "Audit logged: agent={}, action={:?}, risk={}, index={}, fee={}",
         agent_key,
         action_type,
         risk_score,
-        entry.audit_index
+        entry.audit_index,
+        audit_fee
     );
 
     Ok(())
 }
 
+/// Charge the per-commit audit fee (after the agent's tier discount) to the treasury
+/// Shared by every audit path so the schedule applies uniformly
+pub(crate) fn charge_audit_fee<'info>(
+    config: &RegistryConfig,
+    agent: &AgentAccount,
+    payer: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    now: i64,
+) -> Result<u64> {
+    let fee = config.discounted_fee(config.audit_fee_lamports, agent, now);
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: treasury.clone(),
+                },
+            ),
+            fee,
+        )?;
+    }
+    Ok(fee)
+}
+
 /// Accounts for querying agent audit status (read-only helper)
 #[derive(Accounts)]
 pub struct GetAuditStatus<'info> {
@@ -130,6 +185,7 @@ pub struct GetAuditStatus<'info> {
 /// Returns audit status for an agent
 pub fn get_audit_status(ctx: Context<GetAuditStatus>) -> Result<AuditStatusResponse> {
     let summary = &ctx.accounts.audit_summary;
+    let quarantined = ctx.accounts.agent.is_audit_quarantined();
 
     Ok(AuditStatusResponse {
         total_entries: summary.total_entries,
@@ -137,8 +193,9 @@ pub fn get_audit_status(ctx: Context<GetAuditStatus>) -> Result<AuditStatusRespo
         avg_risk_score: summary.avg_risk_score,
         max_risk_score: summary.max_risk_score,
         safe_streak: summary.safe_streak,
-        is_trusted: summary.is_trusted(),
+        is_trusted: summary.is_trusted() && !quarantined,
         last_audit_at: summary.last_audit_at,
+        quarantined,
     })
 }
 
@@ -152,4 +209,6 @@ pub struct AuditStatusResponse {
     pub safe_streak: u32,
     pub is_trusted: bool,
     pub last_audit_at: i64,
+    /// History quarantined for fraud; the stats are kept as evidence but not trusted
+    pub quarantined: bool,
 }
@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{AgentAccount, AgentWatchers, UnstakeRequest};
+use crate::errors::RegistryError;
+
+/// Upgrade an agent account to the current layout (permissionless, payer funds rent)
+/// The account is taken unchecked because old layouts may not deserialize until resized
+/// Accounts from the original release (`AgentAccountV0`) are recognised by their size
+#[derive(Accounts)]
+pub struct MigrateAgent<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: owner, discriminator and PDA are verified in the handler
+    #[account(mut, owner = crate::ID @ RegistryError::AgentNotFound)]
+    pub agent: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's UnstakeRequest PDA, which may not exist; lamports pending there
+    /// don't count towards the agent account's rent
+    #[account(
+        seeds = [UnstakeRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub unstake_request: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<MigrateAgent>) -> Result<()> {
+    let info = ctx.accounts.agent.to_account_info();
+
+    let mut agent = {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && &data[..8] == AgentAccount::DISCRIMINATOR,
+            RegistryError::AgentNotFound
+        );
+
+        decode(&data[8..])?
+    };
+
+    let expected = Pubkey::create_program_address(
+        &[
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref(),
+            &[agent.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| RegistryError::AgentNotFound)?;
+    require_keys_eq!(expected, info.key(), RegistryError::AgentNotFound);
+
+    require!(
+        agent.version < AgentAccount::CURRENT_VERSION,
+        RegistryError::AlreadyMigrated
+    );
+
+    let from_version = agent.version;
+    // Per-version upgrades go here; fields the old layout lacks start out zeroed
+    // v0 -> v1: index header, lineage, stake, lifecycle and the other appended fields;
+    // previously verified agents were admin-verified
+    if from_version < 1 && agent.verified {
+        agent.verification_level = AgentAccount::VERIFICATION_ADMIN;
+    }
+    agent.version = AgentAccount::CURRENT_VERSION;
+    agent.bump_seq();
+
+    // Grow to fit the current layout, topping up rent from the payer (stake doesn't count)
+    let new_len = info
+        .data_len()
+        .max(AgentAccount::space_for(agent.name.len(), agent.capabilities.len()));
+    if info.data_len() < new_len {
+        let shortfall = agent.rent_shortfall(
+            info.lamports(),
+            UnstakeRequest::pending(&ctx.accounts.unstake_request)?,
+            Rent::get()?.minimum_balance(new_len),
+        );
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        info.resize(new_len)?;
+    }
+
+    let mut data = info.try_borrow_mut_data()?;
+    agent.try_serialize(&mut &mut data[..])?;
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_METADATA)?;
+
+    msg!(
+        "Agent migrated: id={}, version {} -> {}",
+        agent.agent_id,
+        from_version,
+        agent.version
+    );
+
+    Ok(())
+}
+
+/// Decode a past layout into the current one
+/// The original release's accounts were all allocated at exactly `AgentAccountV0::LEN`,
+/// which no current account can be (they are at least `8 + AgentAccount::INIT_SPACE`)
+fn decode(body: &[u8]) -> Result<AgentAccount> {
+    if 8 + body.len() == AgentAccountV0::LEN {
+        return Ok(AgentAccountV0::deserialize(&mut &body[..])?.into());
+    }
+
+    // Pad so fields appended after the account was created read as zero
+    let mut body = body.to_vec();
+    body.resize(body.len().max(AgentAccount::INIT_SPACE), 0);
+    Ok(AgentAccount::deserialize(&mut &body[..])?)
+}
+
+/// AgentAccount layout of the original release (version 0, no version byte)
+#[derive(AnchorDeserialize, InitSpace)]
+struct AgentAccountV0 {
+    agent_id: u64,
+    owner: Pubkey,
+    #[max_len(64)]
+    name: String,
+    #[max_len(72)]
+    model_hash: String,
+    #[max_len(256)]
+    capabilities: String,
+    reputation_score: u32,
+    challenges_passed: u32,
+    challenges_failed: u32,
+    verified: bool,
+    created_at: i64,
+    updated_at: i64,
+    nft_mint: Pubkey,
+    bump: u8,
+}
+
+impl AgentAccountV0 {
+    /// Allocated size of every version 0 account (with discriminator)
+    const LEN: usize = 8 + Self::INIT_SPACE;
+}
+
+impl From<AgentAccountV0> for AgentAccount {
+    fn from(legacy: AgentAccountV0) -> Self {
+        Self {
+            agent_id: legacy.agent_id,
+            owner: legacy.owner,
+            win_rate_bps: AgentAccount::rate_bps(
+                legacy.challenges_passed as u64,
+                legacy.challenges_failed as u64,
+            ),
+            tier: AgentAccount::tier_for(legacy.reputation_score),
+            name: legacy.name,
+            model_hash: legacy.model_hash,
+            capabilities: legacy.capabilities,
+            reputation_score: legacy.reputation_score,
+            challenges_passed: legacy.challenges_passed,
+            challenges_failed: legacy.challenges_failed,
+            verified: legacy.verified,
+            created_at: legacy.created_at,
+            updated_at: legacy.updated_at,
+            nft_mint: legacy.nft_mint,
+            bump: legacy.bump,
+            version: 0,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, AgentWatchers, RegistryConfig, RegistryMigration, RegistryNamespace,
+};
+use crate::errors::RegistryError;
+
+/// Carry an agent out of a deprecated namespace or deployment (owner only)
+/// Leaves a RegistryMigration provenance record with the agent's ID, reputation and
+/// verification. From a namespace the agent is re-tagged with the successor (its join
+/// policy and fee are waived); from the deployment the record is what the successor
+/// program reads, and the agent stays usable here until it is retired.
+#[derive(Accounts)]
+pub struct MigrateAgentToRegistry<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Deprecated namespace the agent leaves; omit to migrate out of the deployment
+    #[account(
+        mut,
+        seeds = [RegistryNamespace::SEED_PREFIX, namespace.name_hash.as_ref()],
+        bump = namespace.bump
+    )]
+    pub namespace: Option<Account<'info, RegistryNamespace>>,
+
+    /// The deprecated namespace's successor (required with `namespace`)
+    #[account(
+        mut,
+        seeds = [RegistryNamespace::SEED_PREFIX, successor_namespace.name_hash.as_ref()],
+        bump = successor_namespace.bump
+    )]
+    pub successor_namespace: Option<Account<'info, RegistryNamespace>>,
+
+    /// One record per agent and source registry
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + RegistryMigration::INIT_SPACE,
+        seeds = [
+            RegistryMigration::SEED_PREFIX,
+            agent.key().as_ref(),
+            namespace.as_ref().map_or(crate::ID, |namespace| namespace.key()).as_ref()
+        ],
+        bump
+    )]
+    pub migration: Account<'info, RegistryMigration>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<MigrateAgentToRegistry>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let (from, to) = match ctx.accounts.namespace.as_mut() {
+        Some(namespace) => {
+            require!(namespace.is_deprecated(), RegistryError::RegistryNotDeprecated);
+            require!(
+                ctx.accounts.agent.namespace == namespace.name_hash,
+                RegistryError::NotInNamespace
+            );
+            let successor = ctx
+                .accounts
+                .successor_namespace
+                .as_mut()
+                .ok_or(RegistryError::InvalidSuccessor)?;
+            require_keys_eq!(successor.key(), namespace.successor, RegistryError::InvalidSuccessor);
+            require!(!successor.is_deprecated(), RegistryError::RegistryDeprecated);
+
+            namespace.member_count = namespace.member_count.saturating_sub(1);
+            successor.member_count = successor.member_count.saturating_add(1);
+            successor.total_joined = successor.total_joined.saturating_add(1);
+
+            let agent = &mut ctx.accounts.agent;
+            agent.namespace = successor.name_hash;
+            agent.touch(now);
+            AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_METADATA)?;
+            (namespace.key(), successor.key())
+        }
+        None => {
+            let config = &ctx.accounts.config;
+            require!(config.is_deprecated(), RegistryError::RegistryNotDeprecated);
+            require!(
+                config.successor_program != Pubkey::default(),
+                RegistryError::InvalidSuccessor
+            );
+            (crate::ID, config.successor_program)
+        }
+    };
+
+    let agent = &ctx.accounts.agent;
+    let migration = &mut ctx.accounts.migration;
+    migration.agent = agent.key();
+    migration.owner = agent.owner;
+    migration.agent_id = agent.agent_id;
+    migration.from = from;
+    migration.to = to;
+    migration.reputation_score = agent.reputation_score;
+    migration.verified = agent.verified;
+    migration.verification_level = agent.verification_level;
+    migration.challenges_passed = agent.challenges_passed;
+    migration.challenges_failed = agent.challenges_failed;
+    migration.migrated_at = now;
+    migration.bump = ctx.bumps.migration;
+
+    msg!(
+        "Agent migrated to successor registry: id={}, from={}, to={}, reputation={}",
+        agent.agent_id,
+        from,
+        to,
+        agent.reputation_score
+    );
+
+    Ok(())
+}
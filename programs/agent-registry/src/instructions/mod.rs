@@ -10,7 +10,128 @@ pub mod expire_challenge;
 pub mod close_challenge;
 pub mod log_audit;
 pub mod store_merkle_audit;
+pub mod append_audit_leaf;
+pub mod initialize_config;
+pub mod update_config;
+pub mod register_judge;
+pub mod remove_judge;
+pub mod cast_judge_vote;
+pub mod request_audit_selection;
+pub mod select_challenge_target;
+pub mod withdraw_treasury;
+pub mod stake_agent;
+pub mod unstake_agent;
+pub mod snapshot_agent_epoch;
+pub mod commit_registry_snapshot;
+pub mod agent_views;
+pub mod migrate_agent;
+pub mod update_agent_with_signature;
+pub mod create_sponsor_pool;
+pub mod fund_sponsor_pool;
+pub mod add_sponsored_registrant;
+pub mod withdraw_sponsor_pool;
+pub mod register_agent_sponsored;
+pub mod register_referrer;
+pub mod claim_referral_rewards;
+pub mod fork_agent;
+pub mod create_agent_multisig;
+pub mod execute_multisig_action;
+pub mod propose_multisig_action;
+pub mod approve_multisig_proposal;
+pub mod execute_multisig_proposal;
+pub mod define_capability;
+pub mod retire_capability;
+pub mod set_capability_flags;
+pub mod create_service_listing;
+pub mod update_service_listing;
+pub mod close_service_listing;
+pub mod create_job;
+pub mod accept_job;
+pub mod deliver_job;
+pub mod settle_job;
+pub mod dispute_job;
+pub mod resolve_job_dispute;
+pub mod cancel_job;
+pub mod void_job;
+pub mod submit_review;
+pub mod respond_to_review;
+pub mod request_verification;
+pub mod process_verification;
+pub mod set_attester;
+pub mod attest_agent;
+pub mod auto_verify;
+pub mod retire_agent;
+pub mod close_agent;
+pub mod set_moderator;
+pub mod moderate_agent;
+pub mod add_allowlist_entry;
+pub mod remove_allowlist_entry;
+pub mod set_credential_issuer;
+pub mod record_credential;
+pub mod revoke_credential;
+pub mod anchor_did;
+pub mod update_did;
+pub mod set_reputation_oracle;
+pub mod import_external_reputation;
+pub mod init_reputation_history;
+pub mod index_agent_model;
+pub mod register_reputation_hook;
+pub mod remove_reputation_hook;
+pub mod evict_reputation_hook;
+pub mod set_reputation_bounds;
+pub mod create_badge_mint;
+pub mod claim_badge;
+pub mod claim_epoch_rewards;
+pub mod request_unstake;
+pub mod withdraw_unstake;
+pub mod init_admin_log;
+#[cfg(feature = "devnet")]
+pub mod seed_demo_data;
+pub mod create_namespace;
+pub mod update_namespace;
+pub mod join_namespace;
+pub mod leave_namespace;
+pub mod deprecate_registry;
+pub mod migrate_agent_to_registry;
+pub mod init_agent_counters;
+pub mod sync_agent_counters;
+pub mod update_reputation_fast;
+pub mod verification_queue;
+pub mod void_challenge;
+pub mod claim_challenge_escrow;
+pub mod submit_challenge_evidence;
+pub mod update_reputation_by_program;
+pub mod set_reputation_authority;
+pub mod tag_eval_batch;
+pub mod prove_capability;
+pub mod set_zk_verifier;
+pub mod verify_inference;
+pub mod archive_agent;
+pub mod freeze_fields;
+pub mod allowance;
+pub mod payment_channel;
+pub mod governance;
+pub mod owner_index;
+pub mod tags;
+pub mod garbage_collect_agent;
+pub mod benchmark;
+pub mod audit_delegate;
+pub mod challenge_template;
+pub mod set_encryption_pubkey;
+pub mod deactivation;
+pub mod transfer_agent;
+pub mod watch_agent;
+pub mod quarantine_audit_history;
+pub mod verify_audit_entry;
+pub mod reveal_challenge_answer;
+pub mod expire_audit_selection;
+pub mod dispatch_reputation_hook;
+pub mod open_epoch_rewards;
+pub mod release_epoch_rewards;
 
+// Every instruction module exposes its own `handler`; the glob re-exports exist
+// for the Accounts structs, and lib.rs always calls handlers by full path.
+#[allow(ambiguous_glob_reexports)]
 pub use initialize::*;
 pub use create_collection::*;
 pub use register_agent::*;
@@ -23,3 +144,121 @@ pub use expire_challenge::*;
 pub use close_challenge::*;
 pub use log_audit::*;
 pub use store_merkle_audit::*;
+pub use append_audit_leaf::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use register_judge::*;
+pub use remove_judge::*;
+pub use cast_judge_vote::*;
+pub use request_audit_selection::*;
+pub use select_challenge_target::*;
+pub use withdraw_treasury::*;
+pub use stake_agent::*;
+pub use unstake_agent::*;
+pub use snapshot_agent_epoch::*;
+pub use commit_registry_snapshot::*;
+pub use agent_views::*;
+pub use migrate_agent::*;
+pub use update_agent_with_signature::*;
+pub use create_sponsor_pool::*;
+pub use fund_sponsor_pool::*;
+pub use add_sponsored_registrant::*;
+pub use withdraw_sponsor_pool::*;
+pub use register_agent_sponsored::*;
+pub use register_referrer::*;
+pub use claim_referral_rewards::*;
+pub use fork_agent::*;
+pub use create_agent_multisig::*;
+pub use execute_multisig_action::*;
+pub use propose_multisig_action::*;
+pub use approve_multisig_proposal::*;
+pub use execute_multisig_proposal::*;
+pub use define_capability::*;
+pub use retire_capability::*;
+pub use set_capability_flags::*;
+pub use create_service_listing::*;
+pub use update_service_listing::*;
+pub use close_service_listing::*;
+pub use create_job::*;
+pub use accept_job::*;
+pub use deliver_job::*;
+pub use settle_job::*;
+pub use dispute_job::*;
+pub use resolve_job_dispute::*;
+pub use cancel_job::*;
+pub use void_job::*;
+pub use submit_review::*;
+pub use respond_to_review::*;
+pub use request_verification::*;
+pub use process_verification::*;
+pub use set_attester::*;
+pub use attest_agent::*;
+pub use auto_verify::*;
+pub use retire_agent::*;
+pub use close_agent::*;
+pub use set_moderator::*;
+pub use moderate_agent::*;
+pub use add_allowlist_entry::*;
+pub use remove_allowlist_entry::*;
+pub use set_credential_issuer::*;
+pub use record_credential::*;
+pub use revoke_credential::*;
+pub use anchor_did::*;
+pub use update_did::*;
+pub use set_reputation_oracle::*;
+pub use import_external_reputation::*;
+pub use init_reputation_history::*;
+pub use index_agent_model::*;
+pub use register_reputation_hook::*;
+pub use remove_reputation_hook::*;
+pub use evict_reputation_hook::*;
+pub use set_reputation_bounds::*;
+pub use create_badge_mint::*;
+pub use claim_badge::*;
+pub use claim_epoch_rewards::*;
+pub use request_unstake::*;
+pub use withdraw_unstake::*;
+pub use init_admin_log::*;
+#[cfg(feature = "devnet")]
+pub use seed_demo_data::*;
+pub use create_namespace::*;
+pub use update_namespace::*;
+pub use join_namespace::*;
+pub use leave_namespace::*;
+pub use deprecate_registry::*;
+pub use migrate_agent_to_registry::*;
+pub use init_agent_counters::*;
+pub use sync_agent_counters::*;
+pub use update_reputation_fast::*;
+pub use verification_queue::*;
+pub use void_challenge::*;
+pub use claim_challenge_escrow::*;
+pub use submit_challenge_evidence::*;
+pub use update_reputation_by_program::*;
+pub use set_reputation_authority::*;
+pub use tag_eval_batch::*;
+pub use prove_capability::*;
+pub use set_zk_verifier::*;
+pub use verify_inference::*;
+pub use archive_agent::*;
+pub use freeze_fields::*;
+pub use allowance::*;
+pub use payment_channel::*;
+pub use governance::*;
+pub use owner_index::*;
+pub use tags::*;
+pub use garbage_collect_agent::*;
+pub use benchmark::*;
+pub use audit_delegate::*;
+pub use challenge_template::*;
+pub use set_encryption_pubkey::*;
+pub use deactivation::*;
+pub use transfer_agent::*;
+pub use watch_agent::*;
+pub use quarantine_audit_history::*;
+pub use verify_audit_entry::*;
+pub use reveal_challenge_answer::*;
+pub use expire_audit_selection::*;
+pub use dispatch_reputation_hook::*;
+pub use open_epoch_rewards::*;
+pub use release_epoch_rewards::*;
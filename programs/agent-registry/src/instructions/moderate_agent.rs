@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AdminActionLog, AgentAccount, AgentStatus, AgentWatchers, Moderator, RegistryState,
+};
+use crate::errors::RegistryError;
+
+/// Suspend, ban or reinstate an agent (admin or active moderator)
+#[derive(Accounts)]
+pub struct ModerateAgent<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    /// Required unless the authority is the admin
+    #[account(
+        mut,
+        seeds = [Moderator::SEED_PREFIX, authority.key().as_ref()],
+        bump = moderator.bump
+    )]
+    pub moderator: Option<Account<'info, Moderator>>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn suspend_agent(
+    mut ctx: Context<ModerateAgent>,
+    reason_code: u16,
+    duration_secs: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let suspended_until = match duration_secs {
+        Some(duration) => {
+            require!(
+                duration > 0 && duration <= AgentAccount::MAX_SUSPENSION_SECS,
+                RegistryError::InvalidSuspensionDuration
+            );
+            now + duration
+        }
+        None => 0,
+    };
+
+    transition(&mut ctx, AgentStatus::Suspended, reason_code, suspended_until, now)?;
+
+    msg!(
+        "Agent suspended: id={}, reason={}, until={}",
+        ctx.accounts.agent.agent_id,
+        reason_code,
+        suspended_until
+    );
+
+    Ok(())
+}
+
+pub fn ban_agent(mut ctx: Context<ModerateAgent>, reason_code: u16) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    transition(&mut ctx, AgentStatus::Banned, reason_code, 0, now)?;
+
+    msg!(
+        "Agent banned: id={}, reason={}",
+        ctx.accounts.agent.agent_id,
+        reason_code
+    );
+
+    Ok(())
+}
+
+pub fn reinstate_agent(mut ctx: Context<ModerateAgent>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    transition(&mut ctx, AgentStatus::Active, 0, 0, now)?;
+
+    msg!("Agent reinstated: id={}", ctx.accounts.agent.agent_id);
+
+    Ok(())
+}
+
+fn transition(
+    ctx: &mut Context<ModerateAgent>,
+    status: AgentStatus,
+    reason_code: u16,
+    suspended_until: i64,
+    now: i64,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    if authority != ctx.accounts.registry.admin {
+        let moderator = ctx
+            .accounts
+            .moderator
+            .as_mut()
+            .ok_or(RegistryError::NotModerator)?;
+        require!(moderator.active, RegistryError::NotModerator);
+        moderator.actions_taken = moderator.actions_taken.saturating_add(1);
+    }
+
+    let agent = &mut ctx.accounts.agent;
+    require!(
+        agent.status.can_moderate_to(status),
+        RegistryError::InvalidStatusTransition
+    );
+
+    agent.status = status;
+    agent.status_reason = reason_code;
+    agent.suspended_until = suspended_until;
+    agent.status_changed_at = now;
+    agent.bump_seq();
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_STATUS)?;
+
+    let action = match status {
+        AgentStatus::Suspended => AdminActionLog::ACTION_SUSPEND,
+        AgentStatus::Banned => AdminActionLog::ACTION_BAN,
+        _ => AdminActionLog::ACTION_REINSTATE,
+    };
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        action,
+        authority,
+        agent.key(),
+        reason_code as u64,
+        now,
+    )?;
+
+    Ok(())
+}
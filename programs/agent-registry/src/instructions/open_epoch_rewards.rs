@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::{EpochRewards, RegistryConfig, RegistryState};
+
+/// Open the current epoch's rewards (permissionless crank, once per epoch)
+/// Fixes the emission and eligibility thresholds and reserves the emission in the
+/// treasury, capped by its free balance; snapshots are counted against it. Whatever is
+/// unclaimed after the claim window is freed by `release_epoch_rewards`
+#[derive(Accounts)]
+pub struct OpenEpochRewards<'info> {
+    /// Anyone can open an epoch (pays the rewards rent)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Registry config (emission schedule and the treasury liabilities it is reserved in)
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + EpochRewards::INIT_SPACE,
+        seeds = [EpochRewards::SEED_PREFIX, Clock::get()?.epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub epoch_rewards: Account<'info, EpochRewards>,
+
+    /// Treasury PDA the emission is paid from (its free balance caps the emission)
+    #[account(
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenEpochRewards>) -> Result<()> {
+    let epoch = Clock::get()?.epoch;
+    let config = &mut ctx.accounts.config;
+    let emission = config
+        .epoch_emission(epoch)
+        .min(config.treasury_free(&ctx.accounts.treasury)?);
+    config.treasury_liabilities = config.treasury_liabilities.saturating_add(emission);
+
+    let rewards = &mut ctx.accounts.epoch_rewards;
+    rewards.epoch = epoch;
+    rewards.emission_lamports = emission;
+    rewards.min_reputation = config.emission_min_reputation;
+    rewards.min_stake_lamports = config.emission_min_stake_lamports;
+    rewards.total_reputation = 0;
+    rewards.eligible_agents = 0;
+    rewards.claimed_lamports = 0;
+    rewards.bump = ctx.bumps.epoch_rewards;
+    rewards.released_lamports = 0;
+
+    msg!(
+        "Epoch rewards opened: epoch={}, emission={}, min_reputation={}, min_stake={}",
+        epoch,
+        emission,
+        rewards.min_reputation,
+        rewards.min_stake_lamports
+    );
+
+    Ok(())
+}
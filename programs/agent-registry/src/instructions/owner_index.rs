@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, OwnerIndex};
+use crate::errors::RegistryError;
+
+/// Add an existing agent to its owner's index (permissionless, payer funds rent)
+/// Backfills agents registered before the index existed or without passing it
+#[derive(Accounts)]
+pub struct IndexOwnerAgent<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + OwnerIndex::INIT_SPACE,
+        seeds = [OwnerIndex::SEED_PREFIX, agent.owner.as_ref()],
+        bump
+    )]
+    pub owner_index: Account<'info, OwnerIndex>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Drop a closed agent from its owner's index (permissionless)
+#[derive(Accounts)]
+#[instruction(agent_id: u64)]
+pub struct PruneOwnerIndex<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [OwnerIndex::SEED_PREFIX, owner_index.owner.as_ref()],
+        bump = owner_index.bump
+    )]
+    pub owner_index: Account<'info, OwnerIndex>,
+
+    /// CHECK: the indexed agent's PDA; must no longer hold any data (closed or archived)
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner_index.owner.as_ref(),
+            agent_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = agent.data_is_empty() @ RegistryError::AgentStillRegistered
+    )]
+    pub agent: UncheckedAccount<'info>,
+}
+
+pub fn index_owner_agent(ctx: Context<IndexOwnerAgent>) -> Result<()> {
+    let agent = &ctx.accounts.agent;
+    let index = &mut ctx.accounts.owner_index;
+    index.add(agent.owner, agent.agent_id, ctx.bumps.owner_index)?;
+
+    msg!(
+        "Agent indexed by owner: agent={}, owner={}, agents={}",
+        agent.agent_id,
+        agent.owner,
+        index.agent_ids.len()
+    );
+
+    Ok(())
+}
+
+pub fn prune_owner_index(ctx: Context<PruneOwnerIndex>, agent_id: u64) -> Result<()> {
+    let index = &mut ctx.accounts.owner_index;
+    require!(index.remove(agent_id), RegistryError::AgentNotIndexed);
+
+    msg!(
+        "Agent pruned from owner index: agent={}, remaining={}",
+        agent_id,
+        index.agent_ids.len()
+    );
+
+    Ok(())
+}
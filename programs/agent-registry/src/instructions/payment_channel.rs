@@ -0,0 +1,252 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{channel_voucher_message, verify_ed25519_signature, AgentAccount, PaymentChannel};
+use crate::errors::RegistryError;
+
+/// Open a payment channel to an agent with an initial deposit (consumer)
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct OpenChannel<'info> {
+    #[account(mut)]
+    pub consumer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.accepts_work(Clock::get()?.unix_timestamp) @ RegistryError::AgentNotActive
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init,
+        payer = consumer,
+        space = 8 + PaymentChannel::INIT_SPACE,
+        seeds = [
+            PaymentChannel::SEED_PREFIX,
+            agent.key().as_ref(),
+            consumer.key().as_ref(),
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub channel: Account<'info, PaymentChannel>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Add to an open channel's deposit (consumer)
+#[derive(Accounts)]
+pub struct FundChannel<'info> {
+    #[account(mut)]
+    pub consumer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PaymentChannel::SEED_PREFIX,
+            channel.agent.as_ref(),
+            consumer.key().as_ref(),
+            channel.channel_id.to_le_bytes().as_ref()
+        ],
+        bump = channel.bump,
+        constraint = !channel.is_closing() @ RegistryError::ChannelClosing
+    )]
+    pub channel: Account<'info, PaymentChannel>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Redeem a consumer-signed cumulative voucher (agent owner)
+/// The transaction must carry an ed25519 program instruction right before this one,
+/// signed by the consumer over `channel_voucher_message(channel, opened_at, cumulative)`
+#[derive(Accounts)]
+pub struct RedeemVoucher<'info> {
+    #[account(mut, address = channel.owner @ RegistryError::Unauthorized)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PaymentChannel::SEED_PREFIX,
+            channel.agent.as_ref(),
+            channel.consumer.as_ref(),
+            channel.channel_id.to_le_bytes().as_ref()
+        ],
+        bump = channel.bump
+    )]
+    pub channel: Account<'info, PaymentChannel>,
+
+    /// CHECK: address is pinned to the instructions sysvar
+    #[account(address = solana_sdk_ids::sysvar::instructions::ID @ RegistryError::InvalidSignature)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// Start the consumer's unilateral close; final after the challenge period (consumer)
+#[derive(Accounts)]
+pub struct RequestChannelClose<'info> {
+    pub consumer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PaymentChannel::SEED_PREFIX,
+            channel.agent.as_ref(),
+            consumer.key().as_ref(),
+            channel.channel_id.to_le_bytes().as_ref()
+        ],
+        bump = channel.bump,
+        constraint = !channel.is_closing() @ RegistryError::ChannelClosing
+    )]
+    pub channel: Account<'info, PaymentChannel>,
+}
+
+/// Close a channel, returning the unredeemed deposit and rent to the consumer
+/// (agent owner at any time; consumer once its close request has matured)
+#[derive(Accounts)]
+pub struct CloseChannel<'info> {
+    pub caller: Signer<'info>,
+
+    /// CHECK: consumer wallet, receives the remaining deposit and rent
+    #[account(mut, address = channel.consumer @ RegistryError::Unauthorized)]
+    pub consumer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = consumer,
+        seeds = [
+            PaymentChannel::SEED_PREFIX,
+            channel.agent.as_ref(),
+            channel.consumer.as_ref(),
+            channel.channel_id.to_le_bytes().as_ref()
+        ],
+        bump = channel.bump
+    )]
+    pub channel: Account<'info, PaymentChannel>,
+}
+
+pub fn open_channel(ctx: Context<OpenChannel>, channel_id: u64, deposit: u64) -> Result<()> {
+    require!(deposit > 0, RegistryError::InvalidChannelAmount);
+    deposit_into(&ctx.accounts.consumer, &ctx.accounts.channel, &ctx.accounts.system_program, deposit)?;
+
+    let channel = &mut ctx.accounts.channel;
+    channel.consumer = ctx.accounts.consumer.key();
+    channel.agent = ctx.accounts.agent.key();
+    channel.owner = ctx.accounts.agent.owner;
+    channel.channel_id = channel_id;
+    channel.deposit = deposit;
+    channel.redeemed = 0;
+    channel.closes_at = 0;
+    channel.opened_at = Clock::get()?.unix_timestamp;
+    channel.bump = ctx.bumps.channel;
+
+    msg!(
+        "Channel opened: agent={}, consumer={}, channel={}, deposit={}",
+        ctx.accounts.agent.agent_id,
+        channel.consumer,
+        channel_id,
+        deposit
+    );
+
+    Ok(())
+}
+
+pub fn fund_channel(ctx: Context<FundChannel>, amount: u64) -> Result<()> {
+    require!(amount > 0, RegistryError::InvalidChannelAmount);
+    deposit_into(&ctx.accounts.consumer, &ctx.accounts.channel, &ctx.accounts.system_program, amount)?;
+
+    let channel = &mut ctx.accounts.channel;
+    channel.deposit = channel.deposit.saturating_add(amount);
+
+    msg!("Channel funded: channel={}, deposit={}", channel.key(), channel.deposit);
+
+    Ok(())
+}
+
+pub fn redeem_voucher(ctx: Context<RedeemVoucher>, cumulative: u64) -> Result<()> {
+    let channel = &ctx.accounts.channel;
+    require!(
+        channel.accepts_vouchers(Clock::get()?.unix_timestamp),
+        RegistryError::ChannelClosing
+    );
+    require!(
+        cumulative > channel.redeemed && cumulative <= channel.deposit,
+        RegistryError::InvalidVoucher
+    );
+
+    let message = channel_voucher_message(&channel.key(), channel.opened_at, cumulative);
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &channel.consumer,
+        &message,
+    )?;
+
+    // Channel PDA is program-owned, so lamports can be moved directly
+    let payout = cumulative - channel.redeemed;
+    **ctx.accounts.channel.to_account_info().try_borrow_mut_lamports()? -= payout;
+    **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += payout;
+
+    let channel = &mut ctx.accounts.channel;
+    channel.redeemed = cumulative;
+
+    msg!(
+        "Voucher redeemed: channel={}, payout={}, redeemed={}/{}",
+        channel.key(),
+        payout,
+        channel.redeemed,
+        channel.deposit
+    );
+
+    Ok(())
+}
+
+pub fn request_channel_close(ctx: Context<RequestChannelClose>) -> Result<()> {
+    let channel = &mut ctx.accounts.channel;
+    channel.closes_at = Clock::get()?.unix_timestamp + PaymentChannel::CHALLENGE_PERIOD;
+
+    msg!("Channel closing: channel={}, final at {}", channel.key(), channel.closes_at);
+
+    Ok(())
+}
+
+pub fn close_channel(ctx: Context<CloseChannel>) -> Result<()> {
+    let caller = ctx.accounts.caller.key();
+    let channel = &ctx.accounts.channel;
+    if caller != channel.owner {
+        require_keys_eq!(caller, channel.consumer, RegistryError::Unauthorized);
+        require!(
+            channel.is_closing() && Clock::get()?.unix_timestamp >= channel.closes_at,
+            RegistryError::ChannelChallengePeriod
+        );
+    }
+
+    msg!(
+        "Channel closed: channel={}, redeemed={}, refunded={}",
+        channel.key(),
+        channel.redeemed,
+        channel.deposit - channel.redeemed
+    );
+
+    Ok(())
+}
+
+fn deposit_into<'info>(
+    consumer: &Signer<'info>,
+    channel: &Account<'info, PaymentChannel>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: consumer.to_account_info(),
+                to: channel.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
@@ -0,0 +1,325 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AdminActionLog, AgentAccount, AgentWatchers, RegistryConfig, RegistryState, VerificationQueue,
+    VerificationRequest, VerificationStatus,
+};
+use crate::error_context::{require_within, ErrorField};
+use crate::errors::RegistryError;
+
+/// Approve or reject a pending verification request (admin only)
+/// A rejection refunds the bond; an approval starts its clean period
+#[derive(Accounts)]
+pub struct ProcessVerification<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [VerificationRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump = request.bump,
+        constraint = request.status == VerificationStatus::Pending @ RegistryError::VerificationNotPending
+    )]
+    pub request: Account<'info, VerificationRequest>,
+
+    /// Registry config (bond clean period)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: requester wallet, receives a refunded bond
+    #[account(mut, address = request.requester @ RegistryError::Unauthorized)]
+    pub requester: UncheckedAccount<'info>,
+
+    /// Priority queue; required when the request was queued, which must then be its head
+    #[account(
+        mut,
+        seeds = [VerificationQueue::SEED_PREFIX],
+        bump = queue.load()?.bump
+    )]
+    pub queue: Option<AccountLoader<'info, VerificationQueue>>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+/// Return an approved request's bond once its clean period has passed (anyone may submit)
+#[derive(Accounts)]
+pub struct ReleaseVerificationBond<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VerificationRequest::SEED_PREFIX, request.agent.as_ref()],
+        bump = request.bump
+    )]
+    pub request: Account<'info, VerificationRequest>,
+
+    /// CHECK: requester wallet, receives the bond
+    #[account(mut, address = request.requester @ RegistryError::Unauthorized)]
+    pub requester: UncheckedAccount<'info>,
+}
+
+/// Withdraw an approved verification (admin only)
+/// For fraud, a bond still held is forfeited to the treasury; otherwise it is refunded
+#[derive(Accounts)]
+pub struct RevokeVerification<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [VerificationRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump = request.bump,
+        constraint = request.status == VerificationStatus::Approved @ RegistryError::VerificationNotApproved
+    )]
+    pub request: Account<'info, VerificationRequest>,
+
+    /// CHECK: requester wallet, receives a bond that isn't forfeited
+    #[account(mut, address = request.requester @ RegistryError::Unauthorized)]
+    pub requester: UncheckedAccount<'info>,
+
+    /// Treasury PDA receiving a forfeited bond
+    #[account(
+        mut,
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn approve_verification(ctx: Context<ProcessVerification>, reason: String) -> Result<()> {
+    let now = record_decision(
+        &mut ctx.accounts.request,
+        ctx.accounts.queue.as_ref(),
+        ctx.accounts.admin.key(),
+        VerificationStatus::Approved,
+        reason,
+    )?;
+
+    let agent = &mut ctx.accounts.agent;
+    require!(
+        agent.verification_level < AgentAccount::VERIFICATION_ADMIN,
+        RegistryError::AlreadyVerified
+    );
+    agent.verified = true;
+    agent.verification_level = AgentAccount::VERIFICATION_ADMIN;
+    agent.touch(now);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_VERIFICATION)?;
+
+    // The bond stays forfeitable for the clean period; without one it goes straight back
+    let clean_secs = ctx.accounts.config.verification_bond_clean_secs;
+    let request = &mut ctx.accounts.request;
+    request.bond_releases_at = now + clean_secs;
+    if clean_secs == 0 {
+        VerificationRequest::pay_out_bond(request, &ctx.accounts.requester.to_account_info())?;
+    }
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_VERIFY,
+        ctx.accounts.admin.key(),
+        agent.key(),
+        agent.verification_level as u64,
+        now,
+    )?;
+
+    msg!(
+        "Verification approved: id={}, name={}, bond held={} until {}",
+        agent.agent_id,
+        agent.name,
+        request.bond_lamports,
+        request.bond_releases_at
+    );
+
+    Ok(())
+}
+
+pub fn reject_verification(ctx: Context<ProcessVerification>, reason: String) -> Result<()> {
+    let now = record_decision(
+        &mut ctx.accounts.request,
+        ctx.accounts.queue.as_ref(),
+        ctx.accounts.admin.key(),
+        VerificationStatus::Rejected,
+        reason,
+    )?;
+    let refunded = VerificationRequest::pay_out_bond(
+        &mut ctx.accounts.request,
+        &ctx.accounts.requester.to_account_info(),
+    )?;
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_REJECT_VERIFICATION,
+        ctx.accounts.admin.key(),
+        ctx.accounts.agent.key(),
+        0,
+        now,
+    )?;
+
+    msg!(
+        "Verification rejected: id={}, bond refunded={}, reason={}",
+        ctx.accounts.agent.agent_id,
+        refunded,
+        ctx.accounts.request.reason
+    );
+
+    Ok(())
+}
+
+pub fn release_verification_bond(ctx: Context<ReleaseVerificationBond>) -> Result<()> {
+    let request = &mut ctx.accounts.request;
+    require!(request.bond_lamports > 0, RegistryError::NoVerificationBond);
+    require!(
+        request.bond_releasable(Clock::get()?.unix_timestamp),
+        RegistryError::VerificationBondLocked
+    );
+
+    let bond = VerificationRequest::pay_out_bond(request, &ctx.accounts.requester.to_account_info())?;
+
+    msg!("Verification bond released: agent={}, bond={}", request.agent, bond);
+
+    Ok(())
+}
+
+pub fn revoke_verification(ctx: Context<RevokeVerification>, fraud: bool, reason: String) -> Result<()> {
+    require_within(
+        ErrorField::Reason,
+        reason.len(),
+        VerificationRequest::MAX_REASON_LEN,
+        RegistryError::ReasonTooLong,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let request = &mut ctx.accounts.request;
+    let recipient = if fraud {
+        ctx.accounts.treasury.to_account_info()
+    } else {
+        ctx.accounts.requester.to_account_info()
+    };
+    let bond = VerificationRequest::pay_out_bond(request, &recipient)?;
+    request.status = VerificationStatus::Revoked;
+    request.processed_at = now;
+    request.processed_by = ctx.accounts.admin.key();
+    request.reason = reason;
+
+    let agent = &mut ctx.accounts.agent;
+    agent.verified = false;
+    agent.verification_level = AgentAccount::VERIFICATION_NONE;
+    agent.touch(now);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_VERIFICATION)?;
+
+    let forfeited = if fraud { bond } else { 0 };
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_REVOKE_VERIFICATION,
+        ctx.accounts.admin.key(),
+        agent.key(),
+        forfeited,
+        now,
+    )?;
+
+    msg!(
+        "Verification revoked: id={}, fraud={}, bond forfeited={}, refunded={}, reason={}",
+        agent.agent_id,
+        fraud,
+        forfeited,
+        bond - forfeited,
+        request.reason
+    );
+
+    Ok(())
+}
+
+fn record_decision(
+    request: &mut VerificationRequest,
+    queue: Option<&AccountLoader<VerificationQueue>>,
+    admin: Pubkey,
+    status: VerificationStatus,
+    reason: String,
+) -> Result<i64> {
+    require_within(
+        ErrorField::Reason,
+        reason.len(),
+        VerificationRequest::MAX_REASON_LEN,
+        RegistryError::ReasonTooLong,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    if request.queue_ticket != 0 {
+        let queue = queue.ok_or(RegistryError::VerificationQueueRequired)?;
+        queue.load_mut()?.pop_head(request.queue_ticket, now)?;
+        request.queue_ticket = 0;
+    }
+
+    request.status = status;
+    request.processed_at = now;
+    request.processed_by = admin;
+    request.reason = reason;
+    Ok(now)
+}
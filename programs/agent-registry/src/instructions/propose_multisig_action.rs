@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentMultisig, MultisigAction, MultisigProposal};
+use crate::errors::RegistryError;
+
+/// Propose a multisig action for later approval (owners only; counts as the first approval)
+#[derive(Accounts)]
+pub struct ProposeMultisigAction<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AgentMultisig::SEED_PREFIX, multisig.agent.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AgentMultisig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + MultisigProposal::INIT_SPACE,
+        seeds = [
+            MultisigProposal::SEED_PREFIX,
+            multisig.key().as_ref(),
+            multisig.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProposeMultisigAction>, action: MultisigAction) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let proposer_idx = multisig
+        .owner_index(&ctx.accounts.proposer.key())
+        .ok_or(RegistryError::NotMultisigOwner)?;
+
+    if let MultisigAction::SetOwners { owners, threshold } = &action {
+        require!(
+            AgentMultisig::is_valid_config(owners, *threshold),
+            RegistryError::InvalidMultisigConfig
+        );
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.multisig = multisig.key();
+    proposal.index = multisig.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.approvals = 1 << proposer_idx;
+    proposal.owner_set_seq = multisig.owner_set_seq;
+    proposal.executed = false;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.bump = ctx.bumps.proposal;
+
+    multisig.proposal_count = multisig.proposal_count.saturating_add(1);
+
+    msg!(
+        "Multisig proposal created: multisig={}, index={}, proposer={}",
+        proposal.multisig,
+        proposal.index,
+        proposal.proposer
+    );
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentWatchers, CapabilityDefinition, EvalBatch, MerkleAuditRoot};
+use crate::errors::RegistryError;
+
+/// Mark a claimed capability as proven by an eval result in a tagged batch (permissionless)
+/// The proof places `EvalBatch::leaf_hash(agent, capability_id)` in the batch's root
+#[derive(Accounts)]
+pub struct ProveCapability<'info> {
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        seeds = [
+            MerkleAuditRoot::SEED_PREFIX,
+            agent.key().as_ref(),
+            audit_root.batch_index.to_le_bytes().as_ref()
+        ],
+        bump = audit_root.bump
+    )]
+    pub audit_root: Account<'info, MerkleAuditRoot>,
+
+    /// The root's eval tag; untagged batches cannot prove anything
+    #[account(
+        seeds = [EvalBatch::SEED_PREFIX, audit_root.key().as_ref()],
+        bump = eval_batch.bump
+    )]
+    pub eval_batch: Account<'info, EvalBatch>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ProveCapability>,
+    capability_id: u8,
+    leaf_index: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(
+        capability_id <= CapabilityDefinition::MAX_ID,
+        RegistryError::UndefinedCapability
+    );
+    let flag = CapabilityDefinition::flag(capability_id);
+    let agent = &mut ctx.accounts.agent;
+    require!(agent.capability_flags & flag != 0, RegistryError::CapabilityNotClaimed);
+    require!(agent.proven_capabilities & flag == 0, RegistryError::CapabilityAlreadyProven);
+
+    let root = &ctx.accounts.audit_root;
+    require!(
+        EvalBatch::verify(
+            &root.merkle_root,
+            root.entries_count as u64,
+            &agent.key(),
+            capability_id,
+            leaf_index,
+            &proof
+        ),
+        RegistryError::InvalidCapabilityProof
+    );
+
+    agent.proven_capabilities |= flag;
+    agent.touch(Clock::get()?.unix_timestamp);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_VERIFICATION)?;
+
+    msg!(
+        "Capability proven: id={}, capability={}, batch={}",
+        agent.agent_id,
+        capability_id,
+        root.batch_index
+    );
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AdminActionLog, AgentAccount, AgentAuditSummary, AgentWatchers, AuditMerkleTree,
+    MerkleAuditSummary, Moderator, RegistryState,
+};
+use crate::errors::RegistryError;
+use crate::events::AuditHistoryQuarantined;
+
+/// Quarantine an agent's audit history after fraud is proven (admin or active moderator)
+/// The audit accounts are left in place as evidence; they stop accepting writes and no
+/// longer count toward the trust flag or audit milestones. Not reversible
+#[event_cpi]
+#[derive(Accounts)]
+pub struct QuarantineAuditHistory<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    /// Required unless the authority is the admin
+    #[account(
+        mut,
+        seeds = [Moderator::SEED_PREFIX, authority.key().as_ref()],
+        bump = moderator.bump
+    )]
+    pub moderator: Option<Account<'info, Moderator>>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_audit_quarantined() @ RegistryError::AuditQuarantined
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Individual audit entry counter (its totals are recorded in the event when passed)
+    #[account(
+        seeds = [AgentAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump = audit_summary.bump
+    )]
+    pub audit_summary: Option<Account<'info, AgentAuditSummary>>,
+
+    /// Batched audit entry counter (its totals and running root are recorded when passed)
+    #[account(
+        seeds = [MerkleAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump = merkle_summary.bump
+    )]
+    pub merkle_summary: Option<Account<'info, MerkleAuditSummary>>,
+
+    /// Audit tree (its leaf count and root are recorded when passed)
+    #[account(
+        seeds = [AuditMerkleTree::SEED_PREFIX, agent.key().as_ref()],
+        bump = audit_tree.bump
+    )]
+    pub audit_tree: Option<Account<'info, AuditMerkleTree>>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<QuarantineAuditHistory>, reason_code: u16) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.key();
+    if authority != ctx.accounts.registry.admin {
+        let moderator = ctx
+            .accounts
+            .moderator
+            .as_mut()
+            .ok_or(RegistryError::NotModerator)?;
+        require!(moderator.active, RegistryError::NotModerator);
+        moderator.actions_taken = moderator.actions_taken.saturating_add(1);
+    }
+
+    let agent = &mut ctx.accounts.agent;
+    agent.audit_quarantined_at = now;
+    agent.bump_seq();
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_STATUS)?;
+    let agent_key = agent.key();
+    let agent_id = agent.agent_id;
+
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_QUARANTINE_AUDIT,
+        authority,
+        agent_key,
+        reason_code as u64,
+        now,
+    )?;
+
+    // Snapshot what is being quarantined so the evidence can be checked against the accounts
+    let audit_entries = ctx.accounts.audit_summary.as_ref().map_or(0, |s| s.total_entries);
+    let (batched_entries, batch_root) = ctx
+        .accounts
+        .merkle_summary
+        .as_ref()
+        .map_or((0, [0; 32]), |s| (s.total_entries, s.running_root));
+    let (tree_leaves, tree_root) = ctx
+        .accounts
+        .audit_tree
+        .as_ref()
+        .map_or((0, [0; 32]), |t| (t.next_index, t.root));
+
+    emit_cpi!(AuditHistoryQuarantined {
+        agent: agent_key,
+        agent_id,
+        authority,
+        reason_code,
+        audit_entries,
+        batched_entries,
+        batch_root,
+        tree_leaves,
+        tree_root,
+        timestamp: now,
+    });
+
+    msg!(
+        "Audit history quarantined: id={}, reason={}, entries={}, batched={}, leaves={}",
+        agent_id,
+        reason_code,
+        audit_entries,
+        batched_entries,
+        tree_leaves
+    );
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::state::{credential_message, verify_ed25519_signature, CredentialAttestation, CredentialIssuer};
+use crate::errors::RegistryError;
+
+/// Record (or re-issue) an issuer-signed credential for an agent owner
+/// The transaction must carry an ed25519 program instruction right before this one,
+/// signed by the issuer over `credential_message(subject, type, hash, issued_at, expires_at)`
+#[derive(Accounts)]
+#[instruction(subject: Pubkey, credential_type: u16)]
+pub struct RecordCredential<'info> {
+    /// Any relayer; pays fees and rent
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CredentialIssuer::SEED_PREFIX, issuer.authority.as_ref()],
+        bump = issuer.bump,
+        constraint = issuer.active @ RegistryError::IssuerInactive
+    )]
+    pub issuer: Account<'info, CredentialIssuer>,
+
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + CredentialAttestation::INIT_SPACE,
+        seeds = [
+            CredentialAttestation::SEED_PREFIX,
+            subject.as_ref(),
+            issuer.authority.as_ref(),
+            credential_type.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub credential: Account<'info, CredentialAttestation>,
+
+    /// CHECK: address is pinned to the instructions sysvar
+    #[account(address = solana_sdk_ids::sysvar::instructions::ID @ RegistryError::InvalidSignature)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RecordCredential>,
+    subject: Pubkey,
+    credential_type: u16,
+    credential_hash: [u8; 32],
+    issued_at: i64,
+    expires_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let credential = &mut ctx.accounts.credential;
+
+    // Signed issue times only move forward, so an old signature can't undo a revocation
+    require!(
+        issued_at <= now && issued_at > credential.issued_at && expires_at > now,
+        RegistryError::InvalidCredential
+    );
+
+    let issuer = &mut ctx.accounts.issuer;
+    let message = credential_message(&subject, credential_type, &credential_hash, issued_at, expires_at);
+    let signature = verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &issuer.authority,
+        &message,
+    )?;
+
+    issuer.credentials_issued = issuer.credentials_issued.saturating_add(1);
+
+    credential.subject = subject;
+    credential.issuer = issuer.authority;
+    credential.credential_type = credential_type;
+    credential.credential_hash = credential_hash;
+    credential.signature = signature;
+    credential.issued_at = issued_at;
+    credential.expires_at = expires_at;
+    credential.revoked = false;
+    credential.bump = ctx.bumps.credential;
+
+    msg!(
+        "Credential recorded: subject={}, issuer={}, type={}, expires_at={}",
+        subject,
+        credential.issuer,
+        credential_type,
+        expires_at
+    );
+
+    Ok(())
+}
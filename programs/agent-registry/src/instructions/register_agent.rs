@@ -1,8 +1,14 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, RegistryState};
+use anchor_lang::system_program;
+use crate::state::{
+    AgentAccount, AgentStatus, AllowlistEntry, ModelRecord, OwnerCounter, OwnerIndex, ReferrerStats, RegistryConfig,
+    RegistryState, ReputationSource, TokenHolding,
+};
+use crate::validation;
 use crate::errors::RegistryError;
 
 #[derive(Accounts)]
+#[instruction(name: String, model_hash: String)]
 pub struct RegisterAgent<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -15,6 +21,55 @@ pub struct RegisterAgent<'info> {
     )]
     pub registry: Account<'info, RegistryState>,
 
+    /// Registry config (registration fee; referral rewards are added to its liabilities)
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Treasury PDA receiving the registration fee
+    #[account(
+        mut,
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// CHECK: Pyth SOL/USD PriceUpdateV2 account (required when the fee is USD-denominated)
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    /// Owner's allowlist approval (required while the registry is allowlist-only)
+    #[account(
+        seeds = [AllowlistEntry::SEED_PREFIX, owner.key().as_ref()],
+        bump = allowlist_entry.bump
+    )]
+    pub allowlist_entry: Option<Account<'info, AllowlistEntry>>,
+
+    /// CHECK: owner's token account of the gate mint (required while the registry is token-gated)
+    /// Mint, holder and balance are checked in the handler
+    pub gate_token_account: Option<UncheckedAccount<'info>>,
+
+    /// Claim on the model hash (required while the registry enforces unique models)
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + ModelRecord::INIT_SPACE,
+        seeds = [ModelRecord::SEED_PREFIX, ModelRecord::digest(&model_hash).as_ref()],
+        bump
+    )]
+    pub model_record: Option<Account<'info, ModelRecord>>,
+
+    /// Optional referrer stats; accrues a share of the registration fee
+    #[account(
+        mut,
+        seeds = [ReferrerStats::SEED_PREFIX, referral.referrer.as_ref()],
+        bump = referral.bump,
+        constraint = referral.referrer != owner.key() @ RegistryError::SelfReferral
+    )]
+    pub referral: Option<Account<'info, ReferrerStats>>,
+
     #[account(
         init,
         payer = owner,
@@ -28,6 +83,26 @@ pub struct RegisterAgent<'info> {
     )]
     pub agent: Account<'info, AgentAccount>,
 
+    /// Owner's agent index; the new agent's ID is appended when passed
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + OwnerIndex::INIT_SPACE,
+        seeds = [OwnerIndex::SEED_PREFIX, owner.key().as_ref()],
+        bump
+    )]
+    pub owner_index: Option<Account<'info, OwnerIndex>>,
+
+    /// Owner's registration counter (required while registrations are rate limited)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + OwnerCounter::INIT_SPACE,
+        seeds = [OwnerCounter::SEED_PREFIX, owner.key().as_ref()],
+        bump
+    )]
+    pub owner_counter: Option<Account<'info, OwnerCounter>>,
+
     /// CHECK: NFT mint account - SECURITY NOTICE
     ///
     /// HACKATHON LIMITATION: This account is unchecked for demo purposes.
@@ -50,44 +125,272 @@ pub fn handler(
     name: String,
     model_hash: String,
     capabilities: String,
+) -> Result<RegisterAgentResponse> {
+    let (name, capabilities) = validate_metadata(&name, &model_hash, &capabilities)?;
+    check_allowlist(&ctx.accounts.config, ctx.accounts.allowlist_entry.as_ref())?;
+    check_token_gate(
+        &ctx.accounts.config,
+        ctx.accounts.gate_token_account.as_ref(),
+        ctx.accounts.owner.key(),
+    )?;
+
+    let clock = Clock::get()?;
+    count_registration(
+        &ctx.accounts.config,
+        ctx.accounts.owner_counter.as_deref_mut(),
+        ctx.bumps.owner_counter,
+        ctx.accounts.owner.key(),
+        clock.unix_timestamp,
+    )?;
+    claim_model(
+        &ctx.accounts.config,
+        ctx.accounts.model_record.as_deref_mut(),
+        ctx.bumps.model_record,
+        ctx.accounts.agent.key(),
+        &model_hash,
+        clock.unix_timestamp,
+    )?;
+
+    // Charge the registration fee (USD-denominated fees are priced through Pyth)
+    let price_feed = ctx.accounts.price_feed.as_ref().map(|feed| feed.to_account_info());
+    let fee = ctx.accounts.config.registration_fee(price_feed.as_ref(), clock.unix_timestamp)?;
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    // Credit the referrer with a share of the fee (paid out of the treasury on claim)
+    if let Some(referral) = ctx.accounts.referral.as_mut() {
+        let config = &mut ctx.accounts.config;
+        let reward = config.referral_reward(fee);
+        config.treasury_liabilities = config.treasury_liabilities.saturating_add(reward);
+        referral.referrals = referral.referrals.saturating_add(1);
+        referral.fees_referred = referral.fees_referred.saturating_add(fee);
+        referral.rewards_accrued = referral.rewards_accrued.saturating_add(reward);
+        referral.rewards_claimable = referral.rewards_claimable.saturating_add(reward);
+        referral.last_referral_at = clock.unix_timestamp;
+
+        msg!(
+            "Referral recorded: referrer={}, reward={}, total_referrals={}",
+            referral.referrer,
+            reward,
+            referral.referrals
+        );
+    }
+
+    let registry = &mut ctx.accounts.registry;
+    let agent = &mut ctx.accounts.agent;
+
+    init_agent(
+        agent,
+        registry,
+        &ctx.accounts.config,
+        ctx.accounts.owner.key(),
+        ctx.accounts.nft_mint.key(),
+        name.clone(),
+        model_hash,
+        capabilities,
+        ctx.bumps.agent,
+        clock.unix_timestamp,
+    )?;
+    index_owner(
+        ctx.accounts.owner_index.as_deref_mut(),
+        ctx.bumps.owner_index,
+        agent.owner,
+        agent.agent_id,
+    )?;
+
+    msg!(
+        "Agent registered: id={}, name={}, nft={}, fee={}",
+        agent.agent_id,
+        name,
+        agent.nft_mint,
+        fee
+    );
+
+    Ok(RegisterAgentResponse {
+        agent: agent.key(),
+        agent_id: agent.agent_id,
+    })
+}
+
+/// Append a new agent to its owner's index, when one was passed
+/// Shared with `fork_agent`
+pub(crate) fn index_owner(
+    index: Option<&mut OwnerIndex>,
+    bump: Option<u8>,
+    owner: Pubkey,
+    agent_id: u64,
+) -> Result<()> {
+    if let (Some(index), Some(bump)) = (index, bump) {
+        index.add(owner, agent_id, bump)?;
+    }
+    Ok(())
+}
+
+/// Count a registration against the owner's rate limit, when one is set or a counter was passed
+/// Shared by every owner-initiated registration path
+pub(crate) fn count_registration(
+    config: &RegistryConfig,
+    counter: Option<&mut OwnerCounter>,
+    bump: Option<u8>,
+    owner: Pubkey,
+    now: i64,
+) -> Result<()> {
+    let (Some(counter), Some(bump)) = (counter, bump) else {
+        require!(
+            config.max_registrations_per_day == 0,
+            RegistryError::OwnerCounterRequired
+        );
+        return Ok(());
+    };
+
+    counter.record(owner, config.max_registrations_per_day, bump, now)
+}
+
+/// Enforce allowlist-only registration (the entry's seeds already bind it to the owner)
+pub(crate) fn check_allowlist(
+    config: &RegistryConfig,
+    entry: Option<&Account<AllowlistEntry>>,
 ) -> Result<()> {
-    // Validate inputs
-    require!(name.len() <= 64, RegistryError::NameTooLong);
     require!(
-        model_hash.starts_with("sha256:") && model_hash.len() >= 71,
-        RegistryError::InvalidModelHash
+        !config.allowlist_only || entry.is_some(),
+        RegistryError::NotAllowlisted
     );
-    require!(capabilities.len() <= 256, RegistryError::CapabilitiesTooLong);
+    Ok(())
+}
 
-    let registry = &mut ctx.accounts.registry;
-    let agent = &mut ctx.accounts.agent;
-    let clock = Clock::get()?;
+/// Enforce token-gated registration: the owner must hold at least the configured
+/// balance of the gate mint in the passed token account
+pub(crate) fn check_token_gate(
+    config: &RegistryConfig,
+    token_account: Option<&UncheckedAccount>,
+    owner: Pubkey,
+) -> Result<()> {
+    if !config.token_gated() {
+        return Ok(());
+    }
+
+    let token_account = token_account.ok_or(RegistryError::GateTokenAccountRequired)?;
+    let holding = TokenHolding::load(&token_account.to_account_info())?;
+    require!(
+        holding.mint == config.gate_mint && holding.owner == owner,
+        RegistryError::GateTokenAccountMismatch
+    );
+    require!(
+        holding.amount >= config.gate_min_balance,
+        RegistryError::InsufficientGateBalance
+    );
+    Ok(())
+}
+
+/// Claim a freshly created model record for a new agent
+/// The record's `init` is what rejects a second registration of the same model
+pub(crate) fn claim_model(
+    config: &RegistryConfig,
+    record: Option<&mut ModelRecord>,
+    bump: Option<u8>,
+    agent: Pubkey,
+    model_hash: &str,
+    now: i64,
+) -> Result<()> {
+    let Some(record) = record else {
+        require!(!config.unique_models, RegistryError::ModelRecordRequired);
+        return Ok(());
+    };
+
+    record.model_digest = ModelRecord::digest(model_hash);
+    record.agent = agent;
+    record.linked_forks = 0;
+    record.created_at = now;
+    record.bump = bump.ok_or(RegistryError::ModelRecordRequired)?;
+    Ok(())
+}
+
+/// Check registration metadata against the registration-time limits
+/// Returns the normalized name and capabilities to store
+pub(crate) fn validate_metadata(name: &str, model_hash: &str, capabilities: &str) -> Result<(String, String)> {
+    let name = validation::agent_name(name, AgentAccount::BASE_NAME_LEN)?;
+    validation::parse_model_hash(model_hash)?;
+    let capabilities = validation::agent_capabilities(capabilities, AgentAccount::BASE_CAPABILITIES_LEN)?;
+    Ok((name, capabilities))
+}
+
+/// Set a fresh agent's fields and assign it the next registry ID
+/// Shared by every registration path so new agents always start from the same state
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn init_agent(
+    agent: &mut AgentAccount,
+    registry: &mut RegistryState,
+    config: &RegistryConfig,
+    owner: Pubkey,
+    nft_mint: Pubkey,
+    name: String,
+    model_hash: String,
+    capabilities: String,
+    bump: u8,
+    now: i64,
+) -> Result<()> {
+    require!(!config.is_deprecated(), RegistryError::RegistryDeprecated);
 
-    // Set agent fields
     agent.agent_id = registry.total_agents;
-    agent.owner = ctx.accounts.owner.key();
-    agent.name = name.clone();
+    agent.owner = owner;
+    agent.name = name;
     agent.model_hash = model_hash;
     agent.capabilities = capabilities;
-    agent.reputation_score = AgentAccount::INITIAL_REPUTATION;
+    agent.set_reputation(config.initial_reputation());
     agent.challenges_passed = 0;
     agent.challenges_failed = 0;
+    agent.win_rate_bps = 0;
+    agent.current_streak = 0;
+    agent.best_streak = 0;
     agent.verified = false;
-    agent.created_at = clock.unix_timestamp;
-    agent.updated_at = clock.unix_timestamp;
-    agent.nft_mint = ctx.accounts.nft_mint.key();
-    agent.bump = ctx.bumps.agent;
+    agent.verification_level = AgentAccount::VERIFICATION_NONE;
+    agent.status = AgentStatus::Active;
+    agent.status_changed_at = 0;
+    agent.status_reason = 0;
+    agent.suspended_until = 0;
+    agent.stake_locked_until = 0;
+    agent.namespace = [0u8; 32];
+    agent.created_at = now;
+    agent.updated_at = now;
+    agent.seq = 0;
+    agent.nft_mint = nft_mint;
+    agent.staked_lamports = 0;
+    agent.bump = bump;
+    agent.version = AgentAccount::CURRENT_VERSION;
+    agent.nonce = 0;
+    agent.parent_agent = Pubkey::default();
+    agent.parent_model_hash = String::new();
+    agent.fork_count = 0;
+    agent.multisig = Pubkey::default();
+    agent.capability_flags = 0;
+    agent.proven_capabilities = 0;
+    agent.zk_verified_inferences = 0;
+    agent.frozen_fields = 0;
+    agent.tags = Vec::new();
+    agent.reputation_by_source = [0; ReputationSource::COUNT];
+    agent.encryption_pubkey = [0; 32];
+    agent.audit_quarantined_at = 0;
 
     // Increment total agents
     registry.total_agents = registry.total_agents.checked_add(1)
         .ok_or(RegistryError::RegistryFull)?;
 
-    msg!(
-        "Agent registered: id={}, name={}, nft={}",
-        agent.agent_id,
-        name,
-        agent.nft_mint
-    );
-
     Ok(())
 }
+
+/// Return data for `register_agent` (readable by CPI callers and simulators)
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RegisterAgentResponse {
+    pub agent: Pubkey,
+    pub agent_id: u64,
+}
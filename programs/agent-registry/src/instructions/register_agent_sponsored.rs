@@ -0,0 +1,278 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, AllowlistEntry, ModelRecord, OwnerCounter, RegistryConfig, RegistryState, SponsorAllowance,
+    SponsorPool,
+};
+use crate::errors::RegistryError;
+
+use super::register_agent::{
+    check_allowlist, claim_model, count_registration, init_agent, validate_metadata, RegisterAgentResponse,
+};
+
+/// Register an agent with rent and fee drawn from a sponsorship pool
+/// The registrant only signs; any relayer can pay the transaction fee
+#[derive(Accounts)]
+#[instruction(name: String, model_hash: String)]
+pub struct RegisterAgentSponsored<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.collection_initialized @ RegistryError::CollectionNotInitialized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Registry config (registration fee)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Treasury PDA receiving the registration fee
+    #[account(
+        mut,
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// CHECK: Pyth SOL/USD PriceUpdateV2 account (required when the fee is USD-denominated)
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    /// Owner's allowlist approval (required while the registry is allowlist-only)
+    #[account(
+        seeds = [AllowlistEntry::SEED_PREFIX, owner.key().as_ref()],
+        bump = allowlist_entry.bump
+    )]
+    pub allowlist_entry: Option<Account<'info, AllowlistEntry>>,
+
+    /// CHECK: model record PDA (required while the registry enforces unique models);
+    /// created in the handler from vault funds
+    #[account(
+        mut,
+        seeds = [ModelRecord::SEED_PREFIX, ModelRecord::digest(&model_hash).as_ref()],
+        bump
+    )]
+    pub model_record: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [SponsorPool::SEED_PREFIX, pool.sponsor.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, SponsorPool>,
+
+    #[account(
+        mut,
+        seeds = [SponsorPool::VAULT_SEED, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// CHECK: allowance PDA; created from a Merkle proof on first use, otherwise deserialized
+    #[account(
+        mut,
+        seeds = [SponsorAllowance::SEED_PREFIX, pool.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub allowance: UncheckedAccount<'info>,
+
+    /// CHECK: agent PDA; created in the handler from vault funds
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            registry.total_agents.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub agent: UncheckedAccount<'info>,
+
+    /// CHECK: owner counter PDA (required while registrations are rate limited);
+    /// created from vault funds on first use, otherwise deserialized
+    #[account(
+        mut,
+        seeds = [OwnerCounter::SEED_PREFIX, owner.key().as_ref()],
+        bump
+    )]
+    pub owner_counter: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: NFT mint account - same hackathon limitation as `register_agent`
+    pub nft_mint: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterAgentSponsored>,
+    name: String,
+    model_hash: String,
+    capabilities: String,
+    leaf_index: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<RegisterAgentResponse> {
+    let (name, capabilities) = validate_metadata(&name, &model_hash, &capabilities)?;
+    check_allowlist(&ctx.accounts.config, ctx.accounts.allowlist_entry.as_ref())?;
+
+    let clock = Clock::get()?;
+    let owner = ctx.accounts.owner.key();
+    let pool_key = ctx.accounts.pool.key();
+    let vault = ctx.accounts.vault.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let allowance_info = ctx.accounts.allowance.to_account_info();
+    let mut spent: u64 = 0;
+
+    // Load the allowance, or create it for a registrant proven against the whitelist root
+    let mut allowance = if allowance_info.data_is_empty() {
+        require!(
+            ctx.accounts.pool.is_whitelisted(&owner, leaf_index, &proof),
+            RegistryError::SponsorNotWhitelisted
+        );
+        let bump = [ctx.bumps.allowance];
+        spent += SponsorPool::create_funded_account(
+            &ctx.accounts.pool,
+            &vault,
+            &allowance_info,
+            &[SponsorAllowance::SEED_PREFIX, pool_key.as_ref(), owner.as_ref(), &bump],
+            8 + SponsorAllowance::INIT_SPACE,
+            &system_program,
+        )?;
+        SponsorAllowance {
+            pool: pool_key,
+            registrant: owner,
+            remaining: ctx.accounts.pool.registrations_per_registrant,
+            used: 0,
+            bump: ctx.bumps.allowance,
+        }
+    } else {
+        let data = allowance_info.try_borrow_data()?;
+        SponsorAllowance::try_deserialize(&mut &data[..])?
+    };
+
+    require!(allowance.remaining > 0, RegistryError::SponsorAllowanceExhausted);
+    allowance.remaining -= 1;
+    allowance.used = allowance.used.saturating_add(1);
+
+    // Count against the owner's rate limit so sponsored registrations can't bypass it
+    if let Some(counter_info) = ctx.accounts.owner_counter.as_ref() {
+        let counter_info = counter_info.to_account_info();
+        let counter_bump = ctx.bumps.owner_counter.ok_or(RegistryError::OwnerCounterRequired)?;
+        let mut counter = if counter_info.data_is_empty() {
+            spent += SponsorPool::create_funded_account(
+                &ctx.accounts.pool,
+                &vault,
+                &counter_info,
+                &[OwnerCounter::SEED_PREFIX, owner.as_ref(), &[counter_bump]],
+                8 + OwnerCounter::INIT_SPACE,
+                &system_program,
+            )?;
+            OwnerCounter::default()
+        } else {
+            let data = counter_info.try_borrow_data()?;
+            OwnerCounter::try_deserialize(&mut &data[..])?
+        };
+        count_registration(
+            &ctx.accounts.config,
+            Some(&mut counter),
+            Some(counter_bump),
+            owner,
+            clock.unix_timestamp,
+        )?;
+        counter.try_serialize(&mut &mut counter_info.try_borrow_mut_data()?[..])?;
+    } else {
+        count_registration(&ctx.accounts.config, None, None, owner, clock.unix_timestamp)?;
+    }
+
+    // Create the agent account from vault funds
+    let agent_info = ctx.accounts.agent.to_account_info();
+    let agent_id_bytes = ctx.accounts.registry.total_agents.to_le_bytes();
+    let agent_bump = [ctx.bumps.agent];
+    spent += SponsorPool::create_funded_account(
+        &ctx.accounts.pool,
+        &vault,
+        &agent_info,
+        &[AgentAccount::SEED_PREFIX, owner.as_ref(), agent_id_bytes.as_ref(), &agent_bump],
+        8 + AgentAccount::INIT_SPACE,
+        &system_program,
+    )?;
+
+    // The pool also covers the registration fee
+    let price_feed = ctx.accounts.price_feed.as_ref().map(|feed| feed.to_account_info());
+    let fee = ctx.accounts.config.registration_fee(price_feed.as_ref(), clock.unix_timestamp)?;
+    if fee > 0 {
+        require!(vault.lamports() >= fee, RegistryError::InsufficientSponsorFunds);
+        SponsorPool::pay_from_vault(
+            &ctx.accounts.pool,
+            &vault,
+            &ctx.accounts.treasury.to_account_info(),
+            fee,
+            &system_program,
+        )?;
+        spent += fee;
+    }
+
+    // Claim the model hash; creation fails if another agent already holds it
+    if let Some(record_info) = ctx.accounts.model_record.as_ref() {
+        let record_info = record_info.to_account_info();
+        let digest = ModelRecord::digest(&model_hash);
+        let record_bump = ctx.bumps.model_record.ok_or(RegistryError::ModelRecordRequired)?;
+        spent += SponsorPool::create_funded_account(
+            &ctx.accounts.pool,
+            &vault,
+            &record_info,
+            &[ModelRecord::SEED_PREFIX, digest.as_ref(), &[record_bump]],
+            8 + ModelRecord::INIT_SPACE,
+            &system_program,
+        )?;
+        let mut record = ModelRecord::default();
+        claim_model(
+            &ctx.accounts.config,
+            Some(&mut record),
+            Some(record_bump),
+            agent_info.key(),
+            &model_hash,
+            clock.unix_timestamp,
+        )?;
+        record.try_serialize(&mut &mut record_info.try_borrow_mut_data()?[..])?;
+    } else {
+        claim_model(&ctx.accounts.config, None, None, agent_info.key(), &model_hash, clock.unix_timestamp)?;
+    }
+
+    let mut agent = AgentAccount::default();
+    init_agent(
+        &mut agent,
+        &mut ctx.accounts.registry,
+        &ctx.accounts.config,
+        owner,
+        ctx.accounts.nft_mint.key(),
+        name.clone(),
+        model_hash,
+        capabilities,
+        ctx.bumps.agent,
+        clock.unix_timestamp,
+    )?;
+    agent.try_serialize(&mut &mut agent_info.try_borrow_mut_data()?[..])?;
+    allowance.try_serialize(&mut &mut allowance_info.try_borrow_mut_data()?[..])?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.registrations = pool.registrations.saturating_add(1);
+    pool.lamports_spent = pool.lamports_spent.saturating_add(spent);
+
+    msg!(
+        "Sponsored agent registered: id={}, name={}, sponsor={}, spent={}, remaining={}",
+        agent.agent_id,
+        name,
+        pool.sponsor,
+        spent,
+        allowance.remaining
+    );
+
+    Ok(RegisterAgentResponse {
+        agent: agent_info.key(),
+        agent_id: agent.agent_id,
+    })
+}
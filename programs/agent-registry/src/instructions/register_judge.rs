@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{Judge, RegistryConfig, RegistryState};
+use crate::errors::RegistryError;
+
+/// Add a judge to the challenge judging committee (admin only)
+/// Re-registering a removed judge reactivates the existing record
+#[derive(Accounts)]
+#[instruction(judge_authority: Pubkey)]
+pub struct RegisterJudge<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Judge::INIT_SPACE,
+        seeds = [Judge::SEED_PREFIX, judge_authority.as_ref()],
+        bump
+    )]
+    pub judge: Account<'info, Judge>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterJudge>, judge_authority: Pubkey) -> Result<()> {
+    let judge = &mut ctx.accounts.judge;
+    let config = &mut ctx.accounts.config;
+
+    // No-op counter-wise if the judge is already active
+    if !judge.active {
+        config.active_judges = config.active_judges.saturating_add(1);
+    }
+
+    if judge.authority == Pubkey::default() {
+        judge.authority = judge_authority;
+        judge.registered_at = Clock::get()?.unix_timestamp;
+        judge.bump = ctx.bumps.judge;
+    }
+    judge.active = true;
+
+    msg!(
+        "Judge registered: {} (active judges: {})",
+        judge_authority,
+        config.active_judges
+    );
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::ReferrerStats;
+
+/// Opt in as a referrer by creating a stats account (referrer pays rent)
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = referrer,
+        space = 8 + ReferrerStats::INIT_SPACE,
+        seeds = [ReferrerStats::SEED_PREFIX, referrer.key().as_ref()],
+        bump
+    )]
+    pub referral: Account<'info, ReferrerStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterReferrer>) -> Result<()> {
+    let referral = &mut ctx.accounts.referral;
+    referral.referrer = ctx.accounts.referrer.key();
+    referral.referrals = 0;
+    referral.fees_referred = 0;
+    referral.rewards_accrued = 0;
+    referral.rewards_claimable = 0;
+    referral.last_referral_at = 0;
+    referral.bump = ctx.bumps.referral;
+
+    msg!("Referrer registered: {}", referral.referrer);
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::constants::HOOK_AUTHORITY_SEED;
+use crate::state::{AgentAccount, ReputationHook, ReputationHooks};
+use crate::error_context::{require_within, ErrorField};
+use crate::errors::RegistryError;
+
+/// Subscribe a program to an agent's reputation changes
+/// Signed by the hook program itself via its `[HOOK_AUTHORITY_SEED]` PDA (see
+/// `interface::reputation_hooks`); the agent owner has no say, so it can't veto hooks.
+/// Slots squatted by spam programs are freed with `evict_reputation_hook`
+#[derive(Accounts)]
+pub struct RegisterReputationHook<'info> {
+    /// Pays for the hooks account on first registration
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: callback program; only its key is stored
+    #[account(
+        executable,
+        constraint = hook_program.key() != crate::ID @ RegistryError::InvalidHookProgram
+    )]
+    pub hook_program: UncheckedAccount<'info>,
+
+    /// The hook program's authority PDA
+    #[account(
+        seeds = [HOOK_AUTHORITY_SEED],
+        bump,
+        seeds::program = hook_program.key()
+    )]
+    pub hook_authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ReputationHooks::INIT_SPACE,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub hooks: Account<'info, ReputationHooks>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterReputationHook>) -> Result<()> {
+    let program_id = ctx.accounts.hook_program.key();
+    let hooks = &mut ctx.accounts.hooks;
+    hooks.agent = ctx.accounts.agent.key();
+    hooks.bump = ctx.bumps.hooks;
+
+    require!(hooks.position(&program_id).is_none(), RegistryError::HookAlreadyRegistered);
+    require_within(
+        ErrorField::Hooks,
+        hooks.hooks.len() + 1,
+        ReputationHooks::MAX_HOOKS,
+        RegistryError::TooManyHooks,
+    )?;
+    // Only changes from now on are owed to the new hook
+    let delivered_seq = hooks.seq;
+    hooks.hooks.push(ReputationHook {
+        program: program_id,
+        delivered_seq,
+        missed: 0,
+        over_budget: false,
+    });
+
+    msg!(
+        "Reputation hook registered: agent={}, program={}, hooks={}",
+        ctx.accounts.agent.agent_id,
+        program_id,
+        hooks.hooks.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::{EpochRewards, RegistryConfig};
+use crate::errors::RegistryError;
+
+/// Return an epoch's unclaimed emission to the treasury's free balance once its claim
+/// window has closed (permissionless crank)
+/// Covers shares nobody claimed, rounding dust and epochs with no eligible snapshots
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct ReleaseEpochRewards<'info> {
+    #[account(
+        mut,
+        seeds = [EpochRewards::SEED_PREFIX, epoch.to_le_bytes().as_ref()],
+        bump = epoch_rewards.bump
+    )]
+    pub epoch_rewards: Account<'info, EpochRewards>,
+
+    /// Registry config (the release settles the rest of the epoch's treasury liabilities)
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler(ctx: Context<ReleaseEpochRewards>, epoch: u64) -> Result<()> {
+    let rewards = &mut ctx.accounts.epoch_rewards;
+    require!(
+        !rewards.claimable(Clock::get()?.epoch),
+        RegistryError::ClaimWindowOpen
+    );
+
+    let released = rewards.unclaimed_lamports();
+    require!(released > 0, RegistryError::NothingToClaim);
+    rewards.released_lamports = rewards.released_lamports.saturating_add(released);
+    let config = &mut ctx.accounts.config;
+    config.treasury_liabilities = config.treasury_liabilities.saturating_sub(released);
+
+    msg!(
+        "Epoch rewards released: epoch={}, released={}, claimed={}",
+        epoch,
+        released,
+        rewards.claimed_lamports
+    );
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::{AllowlistEntry, RegistryState};
+use crate::errors::RegistryError;
+
+/// Revoke a wallet's registration approval (admin only, rent returned to admin)
+/// Agents the wallet already registered are unaffected
+#[derive(Accounts)]
+pub struct RemoveAllowlistEntry<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [AllowlistEntry::SEED_PREFIX, entry.registrant.as_ref()],
+        bump = entry.bump
+    )]
+    pub entry: Account<'info, AllowlistEntry>,
+}
+
+pub fn handler(ctx: Context<RemoveAllowlistEntry>) -> Result<()> {
+    msg!("Allowlist entry removed: {}", ctx.accounts.entry.registrant);
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{Judge, RegistryConfig, RegistryState};
+use crate::errors::RegistryError;
+
+/// Deactivate a judge (admin only)
+/// The judge record and its vote history are kept for accountability
+/// The remaining committee must still reach `judge_quorum` (lower it first), unless the
+/// last judge is removed
+#[derive(Accounts)]
+pub struct RemoveJudge<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [Judge::SEED_PREFIX, judge.authority.as_ref()],
+        bump = judge.bump,
+        constraint = judge.active @ RegistryError::JudgeInactive
+    )]
+    pub judge: Account<'info, Judge>,
+}
+
+pub fn handler(ctx: Context<RemoveJudge>) -> Result<()> {
+    let judge = &mut ctx.accounts.judge;
+    let config = &mut ctx.accounts.config;
+
+    let remaining = config.active_judges.saturating_sub(1);
+    require!(
+        RegistryConfig::quorum_fits(config.judge_quorum, remaining),
+        RegistryError::QuorumExceedsJudges
+    );
+
+    judge.active = false;
+    config.active_judges = remaining;
+
+    msg!(
+        "Judge removed: {} (active judges: {})",
+        judge.authority,
+        config.active_judges
+    );
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::constants::HOOK_AUTHORITY_SEED;
+use crate::state::{AgentAccount, ReputationHooks};
+use crate::errors::RegistryError;
+
+/// Unsubscribe a program from an agent's reputation changes
+/// Signed by the hook program's `[HOOK_AUTHORITY_SEED]` PDA, as for registration
+#[derive(Accounts)]
+#[instruction(hook_program: Pubkey)]
+pub struct RemoveReputationHook<'info> {
+    /// The hook program's authority PDA
+    #[account(
+        seeds = [HOOK_AUTHORITY_SEED],
+        bump,
+        seeds::program = hook_program
+    )]
+    pub hook_authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump = hooks.bump
+    )]
+    pub hooks: Account<'info, ReputationHooks>,
+}
+
+pub fn handler(ctx: Context<RemoveReputationHook>, hook_program: Pubkey) -> Result<()> {
+    let hooks = &mut ctx.accounts.hooks;
+    let position = hooks
+        .position(&hook_program)
+        .ok_or(RegistryError::HookNotRegistered)?;
+    hooks.hooks.remove(position);
+
+    msg!(
+        "Reputation hook removed: agent={}, program={}, hooks={}",
+        ctx.accounts.agent.agent_id,
+        hook_program,
+        hooks.hooks.len()
+    );
+
+    Ok(())
+}
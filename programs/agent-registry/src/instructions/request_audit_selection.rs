@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{AuditSelection, RegistryConfig, RegistrySnapshot, RegistryState, SwitchboardRandomness};
+use crate::errors::RegistryError;
+
+/// Start a random audit selection round by committing to a Switchboard randomness account
+/// and the latest registry snapshot, posting `AuditSelection::REQUEST_BOND_LAMPORTS`
+/// Permissionless: the commitment is made before the value exists, so no caller can bias it
+#[derive(Accounts)]
+pub struct RequestAuditSelection<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// The most recent registry snapshot; the target is drawn from its leaves
+    #[account(
+        seeds = [RegistrySnapshot::SEED_PREFIX, snapshot.index.to_le_bytes().as_ref()],
+        bump = snapshot.bump,
+        constraint = snapshot.index + 1 == config.registry_snapshots @ RegistryError::InvalidSnapshot
+    )]
+    pub snapshot: Account<'info, RegistrySnapshot>,
+
+    #[account(
+        init,
+        payer = requester,
+        space = 8 + AuditSelection::INIT_SPACE,
+        seeds = [AuditSelection::SEED_PREFIX, config.audit_rounds.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub selection: Account<'info, AuditSelection>,
+
+    /// CHECK: Switchboard On-Demand randomness account (owner + layout checked in handler)
+    pub randomness_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RequestAuditSelection>) -> Result<()> {
+    require!(ctx.accounts.snapshot.agent_count > 0, RegistryError::AgentNotFound);
+
+    let clock = Clock::get()?;
+    let randomness = SwitchboardRandomness::parse(&ctx.accounts.randomness_account)
+        .ok_or(RegistryError::InvalidRandomnessAccount)?;
+
+    // Must be freshly committed (seeded last slot) and not yet revealed
+    require!(
+        randomness.seed_slot == clock.slot.saturating_sub(1),
+        RegistryError::RandomnessStale
+    );
+    require!(randomness.reveal_slot == 0, RegistryError::RandomnessAlreadyRevealed);
+
+    // Bond held by the round until it is settled or expires
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.requester.to_account_info(),
+                to: ctx.accounts.selection.to_account_info(),
+            },
+        ),
+        AuditSelection::REQUEST_BOND_LAMPORTS,
+    )?;
+
+    let config = &mut ctx.accounts.config;
+    let selection = &mut ctx.accounts.selection;
+    selection.round = config.audit_rounds;
+    selection.randomness_account = ctx.accounts.randomness_account.key();
+    selection.commit_slot = randomness.seed_slot;
+    selection.requester = ctx.accounts.requester.key();
+    selection.requested_at = clock.unix_timestamp;
+    selection.snapshot = ctx.accounts.snapshot.index;
+    selection.bond = AuditSelection::REQUEST_BOND_LAMPORTS;
+    selection.revealed = false;
+    selection.settled = false;
+    selection.expired = false;
+    selection.population = ctx.accounts.snapshot.agent_count;
+    selection.redraws = 0;
+    selection.bump = ctx.bumps.selection;
+
+    config.audit_rounds = config.audit_rounds.saturating_add(1);
+
+    msg!(
+        "Audit selection requested: round={}, randomness={}, snapshot={}, bond={}",
+        selection.round,
+        selection.randomness_account,
+        selection.snapshot,
+        selection.bond
+    );
+
+    Ok(())
+}
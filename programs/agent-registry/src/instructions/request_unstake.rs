@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentWatchers, RegistryConfig, UnstakeRequest};
+use crate::errors::RegistryError;
+
+use super::unstake_agent::check_unstake;
+
+/// Start the unstake cooldown for part of an agent's stake (owner only, one pending at a time)
+/// The amount stops counting as stake immediately but stays in the agent PDA until withdrawn
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (unstake cooldown)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + UnstakeRequest::INIT_SPACE,
+        seeds = [UnstakeRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub unstake_request: Account<'info, UnstakeRequest>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let agent = &mut ctx.accounts.agent;
+    check_unstake(agent, amount, now)?;
+
+    agent.staked_lamports -= amount;
+    agent.touch(now);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_STAKE)?;
+
+    let request = &mut ctx.accounts.unstake_request;
+    request.agent = agent.key();
+    request.amount = amount;
+    request.requested_at = now;
+    request.available_at = now.saturating_add(ctx.accounts.config.unstake_cooldown_secs);
+    request.bump = ctx.bumps.unstake_request;
+
+    msg!(
+        "Unstake requested: id={}, amount={}, available_at={}, remaining={}",
+        agent.agent_id,
+        amount,
+        request.available_at,
+        agent.staked_lamports
+    );
+
+    Ok(())
+}
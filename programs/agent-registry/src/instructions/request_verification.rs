@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{
+    AgentAccount, RegistryConfig, RegistryState, VerificationQueue, VerificationRequest,
+    VerificationStatus,
+};
+use crate::errors::RegistryError;
+
+/// Pay the (tier-discounted) verification fee, escrow the configured bond in the request
+/// and queue the agent for admin verification (owner only)
+#[derive(Accounts)]
+pub struct RequestVerification<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = agent.verification_level < AgentAccount::VERIFICATION_ADMIN
+            @ RegistryError::AlreadyVerified,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + VerificationRequest::INIT_SPACE,
+        seeds = [VerificationRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub request: Account<'info, VerificationRequest>,
+
+    /// Treasury PDA receiving the verification fee
+    #[account(
+        mut,
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// Priority queue; when passed, the request is queued by stake plus fee
+    #[account(
+        mut,
+        seeds = [VerificationQueue::SEED_PREFIX],
+        bump = queue.load()?.bump
+    )]
+    pub queue: Option<AccountLoader<'info, VerificationQueue>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RequestVerification>) -> Result<()> {
+    let request = &mut ctx.accounts.request;
+
+    require!(request.can_request(), RegistryError::VerificationPending);
+
+    // Verified agents re-requesting an upgrade and high-reputation agents get the tier discount
+    let now = Clock::get()?.unix_timestamp;
+    let config = &ctx.accounts.config;
+    let fee = config.discounted_fee(config.verification_fee_lamports, &ctx.accounts.agent, now);
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    // Rejections and revocations settle the previous bond, so none is held here
+    let bond = config.verification_bond_lamports;
+    if bond > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: request.to_account_info(),
+                },
+            ),
+            bond,
+        )?;
+    }
+
+    request.agent = ctx.accounts.agent.key();
+    request.requester = ctx.accounts.owner.key();
+    request.status = VerificationStatus::Pending;
+    request.fee_paid = fee;
+    request.attempts = request.attempts.saturating_add(1);
+    request.requested_at = now;
+    request.processed_at = 0;
+    request.processed_by = Pubkey::default();
+    request.reason = String::new();
+    request.bump = ctx.bumps.request;
+    request.bond_lamports = bond;
+    request.bond_releases_at = 0;
+    request.queue_ticket = match ctx.accounts.queue.as_ref() {
+        Some(queue) => {
+            let priority = ctx.accounts.agent.staked_lamports.saturating_add(fee);
+            queue.load_mut()?.push(request.agent, priority, now)?
+        }
+        None => 0,
+    };
+
+    msg!(
+        "Verification requested: agent={}, fee={}, bond={}, attempt={}, ticket={}",
+        ctx.accounts.agent.agent_id,
+        fee,
+        bond,
+        request.attempts,
+        request.queue_ticket
+    );
+
+    Ok(())
+}
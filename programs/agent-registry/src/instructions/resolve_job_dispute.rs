@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, AgentWatchers, Job, JobStatus, RegistryConfig, RegistryState, ReputationHistory,
+    ReputationHooks, ReputationSource,
+};
+use crate::errors::RegistryError;
+use crate::events::ReputationChanged;
+
+/// Resolve a disputed job (admin only)
+/// In the agent's favour it settles; otherwise the consumer is refunded and the agent penalized
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResolveJobDispute<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Registry config (reputation bounds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            Job::SEED_PREFIX,
+            job.listing.as_ref(),
+            job.consumer.as_ref(),
+            job.job_id.to_le_bytes().as_ref()
+        ],
+        bump = job.bump,
+        constraint = job.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub job: Account<'info, Job>,
+
+    /// CHECK: agent owner wallet, receives the escrow on settlement
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: consumer wallet, receives the escrow on refund
+    #[account(mut, address = job.consumer @ RegistryError::Unauthorized)]
+    pub consumer: UncheckedAccount<'info>,
+
+    /// Optional reputation history; the change is appended when passed
+    #[account(
+        mut,
+        constraint = history.load()?.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub history: Option<AccountLoader<'info, ReputationHistory>>,
+
+    /// CHECK: the agent's ReputationHooks PDA, which may not exist; once it does the
+    /// change is recorded there and delivered to hook programs passed as remaining accounts
+    #[account(
+        mut,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub hooks: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ResolveJobDispute<'info>>,
+    in_favor_of_agent: bool,
+) -> Result<()> {
+    require!(
+        ctx.accounts.job.status == JobStatus::Disputed,
+        RegistryError::InvalidJobStatus
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let old_reputation = ctx.accounts.agent.reputation_score;
+    let moved = Job::finalize(
+        &mut ctx.accounts.job,
+        &mut ctx.accounts.agent,
+        &ctx.accounts.config,
+        in_favor_of_agent,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.consumer.to_account_info(),
+        now,
+    )?;
+    ctx.accounts.agent.credit_reputation(ReputationSource::DisputeResolution, old_reputation);
+    ReputationHistory::record(
+        ctx.accounts.history.as_ref(),
+        old_reputation,
+        ctx.accounts.agent.reputation_score,
+        ReputationSource::DisputeResolution,
+        now,
+    )?;
+    emit_cpi!(ReputationChanged {
+        agent: ctx.accounts.agent.key(),
+        agent_id: ctx.accounts.agent.agent_id,
+        old_score: old_reputation,
+        new_score: ctx.accounts.agent.reputation_score,
+        source: ReputationSource::DisputeResolution as u8,
+        timestamp: now,
+    });
+    ReputationHooks::notify_agent(
+        &ctx.accounts.hooks,
+        &ctx.accounts.agent,
+        ctx.remaining_accounts,
+        old_reputation,
+        now,
+    )?;
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_REPUTATION)?;
+
+    msg!(
+        "Job dispute resolved: job={}, in_favor_of_agent={}, escrow_moved={}",
+        ctx.accounts.job.job_id,
+        in_favor_of_agent,
+        moved
+    );
+
+    Ok(())
+}
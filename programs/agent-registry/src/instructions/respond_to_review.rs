@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Review};
+use crate::errors::RegistryError;
+
+/// Attach the owner's single response to a review of their agent (owner only)
+#[derive(Accounts)]
+pub struct RespondToReview<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [Review::SEED_PREFIX, review.job.as_ref()],
+        bump = review.bump,
+        constraint = review.agent == agent.key() @ RegistryError::Unauthorized,
+        constraint = !review.has_response() @ RegistryError::AlreadyResponded
+    )]
+    pub review: Account<'info, Review>,
+}
+
+pub fn handler(ctx: Context<RespondToReview>, response_hash: [u8; 32]) -> Result<()> {
+    let review = &mut ctx.accounts.review;
+    review.response_hash = response_hash;
+    review.responded_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Review response: agent={}, review={}",
+        ctx.accounts.agent.agent_id,
+        review.key()
+    );
+
+    Ok(())
+}
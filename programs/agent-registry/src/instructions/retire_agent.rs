@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentStatus, AgentWatchers};
+use crate::errors::RegistryError;
+
+/// Permanently withdraw an agent (owner only)
+/// Existing jobs and challenges can still resolve; everything else is rejected
+#[derive(Accounts)]
+pub struct RetireAgent<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status == AgentStatus::Active @ RegistryError::InvalidStatusTransition
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<RetireAgent>) -> Result<()> {
+    retire(&mut ctx.accounts.agent, &ctx.accounts.watchers)
+}
+
+/// Retire an Active agent, shared with the multisig `Retire` action
+pub(crate) fn retire(agent: &mut AgentAccount, watchers: &AccountInfo) -> Result<()> {
+    require!(agent.status == AgentStatus::Active, RegistryError::InvalidStatusTransition);
+    agent.status = AgentStatus::Retired;
+    agent.status_changed_at = Clock::get()?.unix_timestamp;
+    agent.bump_seq();
+    AgentWatchers::notify(watchers, AgentWatchers::FLAG_STATUS)?;
+
+    msg!("Agent retired: id={}, name={}", agent.agent_id, agent.name);
+
+    Ok(())
+}
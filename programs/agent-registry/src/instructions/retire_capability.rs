@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{CapabilityDefinition, RegistryConfig, RegistryState};
+use crate::errors::RegistryError;
+
+/// Retire a capability so agents can no longer declare it (admin only)
+/// Agents already declaring it keep the bit until they next set their flags
+#[derive(Accounts)]
+pub struct RetireCapability<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [CapabilityDefinition::SEED_PREFIX, &[capability.id]],
+        bump = capability.bump
+    )]
+    pub capability: Account<'info, CapabilityDefinition>,
+}
+
+pub fn handler(ctx: Context<RetireCapability>) -> Result<()> {
+    let capability = &mut ctx.accounts.capability;
+    capability.active = false;
+    capability.updated_at = Clock::get()?.unix_timestamp;
+
+    let config = &mut ctx.accounts.config;
+    config.defined_capabilities &= !CapabilityDefinition::flag(capability.id);
+
+    msg!("Capability retired: id={}", capability.id);
+
+    Ok(())
+}
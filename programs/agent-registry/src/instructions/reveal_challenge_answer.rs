@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, AgentWatchers, AuditMerkleTree, Challenge, ChallengeStatus, RegistryConfig,
+    ReputationHistory, ReputationHooks, ReputationSource,
+};
+use crate::errors::RegistryError;
+use crate::events::{ChallengeAnchored, ReputationChanged};
+
+/// The challenger opens its answer commitment and the challenge is resolved against the
+/// recorded response (reputation + escrow + challenge stream)
+/// A challenger that lets REVEAL_WINDOW pass forfeits the escrow through `void_challenge`
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct RevealChallengeAnswer<'info> {
+    /// The challenger (pays for the challenge stream on first resolution)
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    /// Registry config (per-type reputation weights)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// The agent that was challenged
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// The challenge being revealed
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenger.key().as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.status == ChallengeStatus::AwaitingReveal @ RegistryError::ChallengeNotAwaitingReveal
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// CHECK: The agent owner - receives the escrow if the response matches
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// Optional reputation history; the change is appended when passed
+    #[account(
+        mut,
+        constraint = history.load()?.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub history: Option<AccountLoader<'info, ReputationHistory>>,
+
+    /// The agent's challenge-result audit stream (created on first resolution)
+    #[account(
+        init_if_needed,
+        payer = challenger,
+        space = 8 + AuditMerkleTree::INIT_SPACE,
+        seeds = [AuditMerkleTree::SEED_PREFIX, agent.key().as_ref(), AuditMerkleTree::CHALLENGE_STREAM],
+        bump
+    )]
+    pub challenge_stream: Account<'info, AuditMerkleTree>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's ReputationHooks PDA, which may not exist; once it does the
+    /// change is recorded there and delivered to hook programs passed as remaining accounts
+    #[account(
+        mut,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub hooks: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RevealChallengeAnswer<'info>>,
+    _nonce: u64,
+    expected_hash: String,
+    salt: [u8; 32],
+) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let agent = &mut ctx.accounts.agent;
+    let config = &ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    require!(
+        !challenge.reveal_overdue(clock.unix_timestamp),
+        RegistryError::ChallengeExpired
+    );
+    require!(
+        expected_hash.len() == 64,
+        RegistryError::InvalidExpectedHash
+    );
+    require!(
+        challenge.opens(&expected_hash, &salt),
+        RegistryError::AnswerCommitmentMismatch
+    );
+
+    // Verify the response (escrow to owner on pass, back to challenger on fail)
+    let passed = challenge.response_hash == expected_hash;
+    let owner_info = ctx.accounts.owner.to_account_info();
+    let challenger_info = ctx.accounts.challenger.to_account_info();
+    let old_reputation = agent.reputation_score;
+    let escrow = Challenge::settle(
+        challenge,
+        agent,
+        config,
+        passed,
+        &owner_info,
+        &challenger_info,
+        clock.unix_timestamp,
+    )?;
+    agent.credit_reputation(ReputationSource::ChallengeProgram, old_reputation);
+    ReputationHistory::record(
+        ctx.accounts.history.as_ref(),
+        old_reputation,
+        agent.reputation_score,
+        ReputationSource::ChallengeProgram,
+        clock.unix_timestamp,
+    )?;
+    emit_cpi!(ReputationChanged {
+        agent: agent.key(),
+        agent_id: agent.agent_id,
+        old_score: old_reputation,
+        new_score: agent.reputation_score,
+        source: ReputationSource::ChallengeProgram as u8,
+        timestamp: clock.unix_timestamp,
+    });
+    ReputationHooks::notify_agent(
+        &ctx.accounts.hooks,
+        agent,
+        ctx.remaining_accounts,
+        old_reputation,
+        clock.unix_timestamp,
+    )?;
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_REPUTATION)?;
+
+    // Link the result into the agent's audit history
+    let leaf = challenge.result_leaf(
+        &challenge.key(),
+        old_reputation,
+        agent.reputation_score,
+        clock.unix_timestamp,
+    );
+    let stream = &mut ctx.accounts.challenge_stream;
+    let leaf_index =
+        stream.record(agent.key(), ctx.bumps.challenge_stream, leaf, clock.unix_timestamp)?;
+    emit_cpi!(ChallengeAnchored {
+        agent: agent.key(),
+        challenge: challenge.key(),
+        leaf_index,
+        leaf,
+        root: stream.root,
+        timestamp: clock.unix_timestamp,
+    });
+
+    if passed {
+        msg!(
+            "Challenge PASSED! Agent {} reputation: {}, escrow released: {}",
+            agent.agent_id,
+            agent.reputation_score,
+            escrow
+        );
+    } else {
+        msg!(
+            "Challenge FAILED. Agent {} reputation: {}, escrow refunded: {}",
+            agent.agent_id,
+            agent.reputation_score,
+            escrow
+        );
+    }
+
+    Ok(())
+}
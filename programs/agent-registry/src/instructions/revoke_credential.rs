@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::CredentialAttestation;
+use crate::errors::RegistryError;
+
+/// Revoke a credential (issuer only); the record is kept so the revocation is visible
+#[derive(Accounts)]
+pub struct RevokeCredential<'info> {
+    pub issuer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            CredentialAttestation::SEED_PREFIX,
+            credential.subject.as_ref(),
+            issuer.key().as_ref(),
+            credential.credential_type.to_le_bytes().as_ref()
+        ],
+        bump = credential.bump,
+        constraint = !credential.revoked @ RegistryError::InvalidCredential
+    )]
+    pub credential: Account<'info, CredentialAttestation>,
+}
+
+pub fn handler(ctx: Context<RevokeCredential>) -> Result<()> {
+    let credential = &mut ctx.accounts.credential;
+    credential.revoked = true;
+
+    msg!(
+        "Credential revoked: subject={}, issuer={}, type={}",
+        credential.subject,
+        credential.issuer,
+        credential.credential_type
+    );
+
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{AgentAccount, AgentAuditSummary, ChallengeType, RegistryConfig, RegistryState};
+use crate::errors::RegistryError;
+use super::register_agent::init_agent;
+
+/// Seed sample agents for localnet / devnet demos (admin only, `devnet` feature)
+/// Remaining accounts: [agent, audit_summary] PDA pairs for the next agent ids, owned
+/// by the admin. Profiles are picked by agent id, so reruns on a fresh validator
+/// produce the same registry.
+#[derive(Accounts)]
+pub struct SeedDemoData<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sample agent: metadata, reputation, verification and challenge / audit record
+struct DemoProfile {
+    name: &'static str,
+    capabilities: &'static str,
+    reputation: u32,
+    verified: bool,
+    passed: u32,
+    failed: u32,
+    audits: u64,
+    avg_risk: u8,
+    max_risk: u8,
+    alerts: u32,
+}
+
+const PROFILES: [DemoProfile; 8] = [
+    DemoProfile { name: "Atlas Research", capabilities: "analysis,research", reputation: 9400, verified: true, passed: 48, failed: 2, audits: 120, avg_risk: 4, max_risk: 20, alerts: 0 },
+    DemoProfile { name: "Quill Writer", capabilities: "writing,summarization", reputation: 7800, verified: true, passed: 21, failed: 4, audits: 60, avg_risk: 8, max_risk: 30, alerts: 0 },
+    DemoProfile { name: "Vector Trader", capabilities: "trading,analysis", reputation: 6100, verified: false, passed: 12, failed: 7, audits: 85, avg_risk: 22, max_risk: 80, alerts: 2 },
+    DemoProfile { name: "Sentinel Guard", capabilities: "security,monitoring", reputation: 8800, verified: true, passed: 35, failed: 1, audits: 300, avg_risk: 3, max_risk: 15, alerts: 0 },
+    DemoProfile { name: "Forge Coder", capabilities: "coding,review", reputation: 7000, verified: false, passed: 15, failed: 5, audits: 40, avg_risk: 10, max_risk: 35, alerts: 0 },
+    DemoProfile { name: "Echo Support", capabilities: "support,translation", reputation: 5000, verified: false, passed: 0, failed: 0, audits: 0, avg_risk: 0, max_risk: 0, alerts: 0 },
+    DemoProfile { name: "Drift Oracle", capabilities: "forecasting,data", reputation: 3200, verified: false, passed: 4, failed: 11, audits: 25, avg_risk: 41, max_risk: 90, alerts: 5 },
+    DemoProfile { name: "Nova Planner", capabilities: "planning,coding", reputation: 8200, verified: true, passed: 27, failed: 3, audits: 150, avg_risk: 6, max_risk: 25, alerts: 0 },
+];
+
+/// Agents per call that fit comfortably in one transaction's compute budget
+pub const MAX_DEMO_BATCH: usize = 8;
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SeedDemoData<'info>>) -> Result<()> {
+    let pairs = ctx.remaining_accounts;
+    require!(
+        !pairs.is_empty() && pairs.len().is_multiple_of(2) && pairs.len() / 2 <= MAX_DEMO_BATCH,
+        RegistryError::InvalidDemoAccounts
+    );
+
+    let admin = ctx.accounts.admin.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    for pair in pairs.chunks(2) {
+        let (agent_info, summary_info) = (&pair[0], &pair[1]);
+        let agent_id = ctx.accounts.registry.total_agents;
+        let profile = &PROFILES[(agent_id % PROFILES.len() as u64) as usize];
+
+        let id_bytes = agent_id.to_le_bytes();
+        let (agent_key, agent_bump) = Pubkey::find_program_address(
+            &[AgentAccount::SEED_PREFIX, admin.as_ref(), &id_bytes],
+            &crate::ID,
+        );
+        let (summary_key, summary_bump) = Pubkey::find_program_address(
+            &[AgentAuditSummary::SEED_PREFIX, agent_key.as_ref()],
+            &crate::ID,
+        );
+        require!(
+            agent_info.key() == agent_key
+                && summary_info.key() == summary_key
+                && agent_info.data_is_empty()
+                && summary_info.data_is_empty(),
+            RegistryError::InvalidDemoAccounts
+        );
+
+        let mut agent = AgentAccount::default();
+        init_agent(
+            &mut agent,
+            &mut ctx.accounts.registry,
+            &ctx.accounts.config,
+            admin,
+            Pubkey::default(),
+            format!("{} #{}", profile.name, agent_id),
+            demo_model_hash(agent_id),
+            profile.capabilities.to_string(),
+            agent_bump,
+            now,
+        )?;
+        agent.set_reputation(ctx.accounts.config.clamp_reputation(profile.reputation as i64));
+        if profile.verified {
+            agent.verified = true;
+            agent.verification_level = AgentAccount::VERIFICATION_ADMIN;
+        }
+        // Failures first, then an unbroken run of passes
+        let accuracy = ChallengeType::Accuracy.index();
+        agent.challenges_passed = profile.passed;
+        agent.challenges_failed = profile.failed;
+        agent.passed_by_type[accuracy] = profile.passed;
+        agent.failed_by_type[accuracy] = profile.failed;
+        agent.current_streak = profile.passed;
+        agent.best_streak = profile.passed;
+        agent.win_rate_bps = AgentAccount::rate_bps(profile.passed as u64, profile.failed as u64);
+
+        let summary = AgentAuditSummary {
+            agent: agent_key,
+            total_entries: profile.audits,
+            security_alerts: profile.alerts,
+            avg_risk_score: profile.avg_risk,
+            max_risk_score: profile.max_risk,
+            last_audit_at: if profile.audits > 0 { now } else { 0 },
+            safe_streak: if profile.alerts == 0 { profile.audits as u32 } else { 0 },
+            bump: summary_bump,
+        };
+
+        let agent_seeds: &[&[u8]] = &[AgentAccount::SEED_PREFIX, admin.as_ref(), &id_bytes, &[agent_bump]];
+        let summary_seeds: &[&[u8]] = &[AgentAuditSummary::SEED_PREFIX, agent_key.as_ref(), &[summary_bump]];
+        write_pda(
+            &ctx.accounts.admin,
+            &ctx.accounts.system_program,
+            agent_info,
+            agent_seeds,
+            AgentAccount::space_for(agent.name.len(), agent.capabilities.len()),
+            &agent,
+        )?;
+        write_pda(
+            &ctx.accounts.admin,
+            &ctx.accounts.system_program,
+            summary_info,
+            summary_seeds,
+            8 + AgentAuditSummary::INIT_SPACE,
+            &summary,
+        )?;
+
+        msg!(
+            "Demo agent seeded: id={}, reputation={}, verified={}, audits={}",
+            agent_id,
+            agent.reputation_score,
+            agent.verified,
+            profile.audits
+        );
+    }
+
+    Ok(())
+}
+
+/// Stable `sha256:<hex>` model hash per agent id
+fn demo_model_hash(agent_id: u64) -> String {
+    let digest = solana_sha256_hasher::hashv(&[b"demo-model", &agent_id.to_le_bytes()]).to_bytes();
+    let hex: String = digest.iter().map(|b| format!("{b:02x}")).collect();
+    format!("sha256:{hex}")
+}
+
+/// Create a program-owned PDA at `info` and serialize `value` into it
+fn write_pda<'info, T: AccountSerialize>(
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    info: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    space: usize,
+    value: &T,
+) -> Result<()> {
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::CreateAccount {
+                from: payer.to_account_info(),
+                to: info.clone(),
+            },
+            &[seeds],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    )?;
+
+    let mut data = info.try_borrow_mut_data()?;
+    value.try_serialize(&mut &mut data[..])
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AuditSelection, RegistrySnapshot, RegistryState, SwitchboardRandomness};
+use crate::errors::RegistryError;
+
+/// Settle an audit selection round using the revealed randomness (permissionless)
+/// The draw indexes a leaf of the committed snapshot; the caller proves which agent sits
+/// there. A closed or inactive agent consumes the draw and the next one is taken from the
+/// same randomness, so call again with the new leaf until a target is selected. After
+/// `AuditSelection::MAX_REDRAWS` discarded draws the round ends untargeted and the bond is
+/// refunded
+#[derive(Accounts)]
+#[instruction(round: u64)]
+pub struct SelectChallengeTarget<'info> {
+    /// Anyone can settle a round
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [AuditSelection::SEED_PREFIX, round.to_le_bytes().as_ref()],
+        bump = selection.bump,
+        constraint = !selection.settled @ RegistryError::SelectionAlreadySettled,
+        constraint = !selection.expired @ RegistryError::SelectionExpired
+    )]
+    pub selection: Account<'info, AuditSelection>,
+
+    /// CHECK: Must be the randomness account committed at request time
+    #[account(address = selection.randomness_account @ RegistryError::InvalidRandomnessAccount)]
+    pub randomness_account: UncheckedAccount<'info>,
+
+    /// The snapshot the round committed to
+    #[account(
+        seeds = [RegistrySnapshot::SEED_PREFIX, selection.snapshot.to_le_bytes().as_ref()],
+        bump = snapshot.bump
+    )]
+    pub snapshot: Account<'info, RegistrySnapshot>,
+
+    /// CHECK: the agent at the drawn leaf (proven against the snapshot in the handler);
+    /// may have been closed since
+    pub agent: UncheckedAccount<'info>,
+
+    /// CHECK: The requester - receives the bond back once a target is selected
+    #[account(mut, address = selection.requester @ RegistryError::Unauthorized)]
+    pub requester: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SelectChallengeTarget>,
+    _round: u64,
+    reputation_score: u32,
+    verified: bool,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let selection = &mut ctx.accounts.selection;
+
+    // Record the value the first time; later draws reuse it, so the account being
+    // re-committed afterwards can't change the outcome
+    if !selection.revealed {
+        let randomness = SwitchboardRandomness::parse(&ctx.accounts.randomness_account)
+            .ok_or(RegistryError::InvalidRandomnessAccount)?;
+        // The account must still carry the commitment we recorded (no re-commit swap)
+        require!(
+            randomness.seed_slot == selection.commit_slot,
+            RegistryError::RandomnessStale
+        );
+        require!(
+            randomness.revealed_for(selection.commit_slot) && randomness.reveal_slot <= clock.slot,
+            RegistryError::RandomnessNotRevealed
+        );
+        selection.randomness = randomness.value;
+        selection.revealed = true;
+    }
+
+    let index = AuditSelection::pick(&selection.randomness, selection.redraws, selection.population);
+    let agent_info = &ctx.accounts.agent;
+    let leaf = RegistrySnapshot::leaf_hash(&agent_info.key(), reputation_score, verified);
+    require!(
+        ctx.accounts.snapshot.verify_proof(leaf, index, &proof),
+        RegistryError::InvalidSnapshotProof
+    );
+
+    let live = if agent_info.data_is_empty() {
+        None
+    } else {
+        require_keys_eq!(*agent_info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        let agent = AgentAccount::try_deserialize(&mut &agent_info.try_borrow_data()?[..])?;
+        agent.accepts_work(clock.unix_timestamp).then_some(agent.agent_id)
+    };
+
+    let Some(agent_id) = live else {
+        selection.redraws = selection.redraws.saturating_add(1);
+        msg!(
+            "Audit draw discarded: round={}, leaf={}, agent={} is closed or inactive (redraws: {})",
+            selection.round,
+            index,
+            agent_info.key(),
+            selection.redraws
+        );
+        if selection.redraws >= AuditSelection::MAX_REDRAWS {
+            // The reveal was honest, so the requester isn't the one to pay for it
+            selection.expired = true;
+            refund_bond(selection, &ctx.accounts.requester)?;
+            msg!("Audit selection exhausted its redraws: round={}", selection.round);
+        }
+        return Ok(());
+    };
+
+    selection.selected_agent = agent_info.key();
+    selection.selected_agent_id = agent_id;
+    selection.selected_at = clock.unix_timestamp;
+    selection.settled = true;
+    refund_bond(selection, &ctx.accounts.requester)?;
+
+    msg!(
+        "Audit target selected: round={}, agent_id={} (leaf {} of {}, redraws: {})",
+        selection.round,
+        agent_id,
+        index,
+        selection.population,
+        selection.redraws
+    );
+
+    Ok(())
+}
+
+/// Return the requester's bond
+fn refund_bond(selection: &mut Account<AuditSelection>, requester: &AccountInfo) -> Result<()> {
+    let bond = selection.bond;
+    selection.bond = 0;
+    **selection.to_account_info().try_borrow_mut_lamports()? -= bond;
+    **requester.try_borrow_mut_lamports()? += bond;
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{AttestationKind, Attester, RegistryState};
+use crate::errors::RegistryError;
+
+/// Register, update or deactivate a TEE verifier / auditor (admin only)
+/// Deactivation keeps the record; its attestations stop counting for `auto_verify`
+#[derive(Accounts)]
+#[instruction(attester_authority: Pubkey)]
+pub struct SetAttester<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Attester::INIT_SPACE,
+        seeds = [Attester::SEED_PREFIX, attester_authority.as_ref()],
+        bump
+    )]
+    pub attester: Account<'info, Attester>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetAttester>,
+    attester_authority: Pubkey,
+    kind: AttestationKind,
+    active: bool,
+) -> Result<()> {
+    let attester = &mut ctx.accounts.attester;
+
+    if attester.authority == Pubkey::default() {
+        attester.authority = attester_authority;
+        attester.registered_at = Clock::get()?.unix_timestamp;
+        attester.bump = ctx.bumps.attester;
+    }
+    attester.kind = kind;
+    attester.active = active;
+
+    msg!(
+        "Attester set: {} kind={:?} active={}",
+        attester_authority,
+        kind,
+        active
+    );
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentWatchers, RegistryConfig};
+use crate::errors::RegistryError;
+
+/// Set an agent's structured capability flags (owner only)
+/// Every set bit must reference an active CapabilityDefinition
+#[derive(Accounts)]
+pub struct SetCapabilityFlags<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SetCapabilityFlags>, flags: u64) -> Result<()> {
+    require!(
+        ctx.accounts.config.capabilities_defined(flags),
+        RegistryError::UndefinedCapability
+    );
+
+    let agent = &mut ctx.accounts.agent;
+    agent.set_capability_flags(flags)?;
+    agent.touch(Clock::get()?.unix_timestamp);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_METADATA)?;
+
+    msg!("Capability flags set: id={}, flags={:#x}", agent.agent_id, flags);
+
+    Ok(())
+}
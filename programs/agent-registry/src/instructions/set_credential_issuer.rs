@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{CredentialIssuer, RegistryState};
+use crate::errors::RegistryError;
+
+/// Approve or deactivate a credential issuer (admin only)
+#[derive(Accounts)]
+#[instruction(issuer_authority: Pubkey)]
+pub struct SetCredentialIssuer<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + CredentialIssuer::INIT_SPACE,
+        seeds = [CredentialIssuer::SEED_PREFIX, issuer_authority.as_ref()],
+        bump
+    )]
+    pub issuer: Account<'info, CredentialIssuer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetCredentialIssuer>, issuer_authority: Pubkey, active: bool) -> Result<()> {
+    let issuer = &mut ctx.accounts.issuer;
+
+    if issuer.authority == Pubkey::default() {
+        issuer.authority = issuer_authority;
+        issuer.registered_at = Clock::get()?.unix_timestamp;
+        issuer.bump = ctx.bumps.issuer;
+    }
+    issuer.active = active;
+
+    msg!("Credential issuer set: {} active={}", issuer_authority, active);
+
+    Ok(())
+}
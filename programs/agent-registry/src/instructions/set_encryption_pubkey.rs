@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentWatchers};
+use crate::errors::RegistryError;
+
+/// Publish or rotate the agent's X25519 encryption key (owner only)
+/// All zeros withdraws the key
+#[derive(Accounts)]
+pub struct SetEncryptionPubkey<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SetEncryptionPubkey>, encryption_pubkey: [u8; 32]) -> Result<()> {
+    let agent = &mut ctx.accounts.agent;
+    agent.encryption_pubkey = encryption_pubkey;
+    agent.touch(Clock::get()?.unix_timestamp);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_METADATA)?;
+
+    msg!(
+        "Encryption key set: id={}, published={}",
+        agent.agent_id,
+        agent.has_encryption_pubkey()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminActionLog, Moderator, RegistryState};
+use crate::errors::RegistryError;
+
+/// Grant or revoke the moderator role (admin only)
+#[derive(Accounts)]
+#[instruction(moderator_authority: Pubkey)]
+pub struct SetModerator<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Moderator::INIT_SPACE,
+        seeds = [Moderator::SEED_PREFIX, moderator_authority.as_ref()],
+        bump
+    )]
+    pub moderator: Account<'info, Moderator>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetModerator>, moderator_authority: Pubkey, active: bool) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_SET_MODERATOR,
+        ctx.accounts.admin.key(),
+        moderator_authority,
+        active as u64,
+        now,
+    )?;
+
+    let moderator = &mut ctx.accounts.moderator;
+
+    if moderator.authority == Pubkey::default() {
+        moderator.authority = moderator_authority;
+        moderator.registered_at = now;
+        moderator.bump = ctx.bumps.moderator;
+    }
+    moderator.active = active;
+
+    msg!("Moderator set: {} active={}", moderator_authority, active);
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminActionLog, RegistryState, ReputationAuthority};
+use crate::errors::RegistryError;
+
+/// Grant, re-budget or revoke a reputation authority (admin only)
+#[derive(Accounts)]
+#[instruction(reputation_authority: Pubkey)]
+pub struct SetReputationAuthority<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ReputationAuthority::INIT_SPACE,
+        seeds = [ReputationAuthority::SEED_PREFIX, reputation_authority.as_ref()],
+        bump
+    )]
+    pub authority: Account<'info, ReputationAuthority>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetReputationAuthority>,
+    reputation_authority: Pubkey,
+    active: bool,
+    epoch_budget: u32,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_SET_REPUTATION_AUTHORITY,
+        ctx.accounts.admin.key(),
+        reputation_authority,
+        if active { epoch_budget as u64 } else { 0 },
+        now,
+    )?;
+
+    let authority = &mut ctx.accounts.authority;
+
+    if authority.authority == Pubkey::default() {
+        authority.authority = reputation_authority;
+        authority.registered_at = now;
+        authority.bump = ctx.bumps.authority;
+    }
+    authority.active = active;
+    authority.epoch_budget = epoch_budget;
+
+    msg!(
+        "Reputation authority set: {} active={} epoch_budget={}",
+        reputation_authority,
+        active,
+        epoch_budget
+    );
+
+    Ok(())
+}
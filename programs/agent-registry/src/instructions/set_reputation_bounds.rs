@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminActionLog, AgentAccount, RegistryConfig, RegistryState};
+use crate::errors::RegistryError;
+use crate::events::ReputationBoundsUpdated;
+
+/// Change the reputation floor and ceiling (admin only)
+/// Existing scores outside the new range are clamped on their next change
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetReputationBounds<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler(ctx: Context<SetReputationBounds>, floor: u32, ceiling: u32) -> Result<()> {
+    require!(
+        floor < ceiling && ceiling <= AgentAccount::MAX_REPUTATION,
+        RegistryError::InvalidReputationBounds
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_CONFIG_CHANGE,
+        ctx.accounts.admin.key(),
+        ctx.accounts.config.key(),
+        0,
+        now,
+    )?;
+
+    let config = &mut ctx.accounts.config;
+    let (old_floor, old_ceiling) = (config.reputation_floor, config.reputation_ceiling);
+    config.reputation_floor = floor;
+    config.reputation_ceiling = ceiling;
+
+    emit_cpi!(ReputationBoundsUpdated {
+        old_floor,
+        old_ceiling,
+        floor,
+        ceiling,
+        timestamp: now,
+    });
+
+    msg!(
+        "Reputation bounds updated: [{}, {}] -> [{}, {}]",
+        old_floor,
+        old_ceiling,
+        floor,
+        ceiling
+    );
+
+    Ok(())
+}
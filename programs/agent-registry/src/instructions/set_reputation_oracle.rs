@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{RegistryState, ReputationOracle};
+use crate::errors::RegistryError;
+
+/// Approve, update or deactivate an external reputation oracle (admin only)
+#[derive(Accounts)]
+#[instruction(oracle_authority: Pubkey)]
+pub struct SetReputationOracle<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ReputationOracle::INIT_SPACE,
+        seeds = [ReputationOracle::SEED_PREFIX, oracle_authority.as_ref()],
+        bump
+    )]
+    pub oracle: Account<'info, ReputationOracle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetReputationOracle>,
+    oracle_authority: Pubkey,
+    source_id: u8,
+    active: bool,
+) -> Result<()> {
+    let oracle = &mut ctx.accounts.oracle;
+
+    if oracle.authority == Pubkey::default() {
+        oracle.authority = oracle_authority;
+        oracle.registered_at = Clock::get()?.unix_timestamp;
+        oracle.bump = ctx.bumps.oracle;
+    }
+    oracle.source_id = source_id;
+    oracle.active = active;
+
+    msg!(
+        "Reputation oracle set: {} source={} active={}",
+        oracle_authority,
+        source_id,
+        active
+    );
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminActionLog, RegistryState, ZkVerifier, ZkVerifyingKey};
+use crate::errors::RegistryError;
+
+/// Register, replace or deactivate an inference circuit's verifying key (admin only)
+#[derive(Accounts)]
+#[instruction(circuit_id: u32)]
+pub struct SetZkVerifier<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ZkVerifier::INIT_SPACE,
+        seeds = [ZkVerifier::SEED_PREFIX, circuit_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub verifier: Box<Account<'info, ZkVerifier>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetZkVerifier>,
+    circuit_id: u32,
+    vk: ZkVerifyingKey,
+    active: bool,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_SET_ZK_VERIFIER,
+        ctx.accounts.admin.key(),
+        ctx.accounts.verifier.key(),
+        circuit_id as u64,
+        now,
+    )?;
+
+    let verifier = &mut ctx.accounts.verifier;
+    if verifier.registered_at == 0 {
+        verifier.circuit_id = circuit_id;
+        verifier.registered_at = now;
+        verifier.bump = ctx.bumps.verifier;
+    }
+    verifier.vk = vk;
+    verifier.active = active;
+
+    msg!("ZK verifier set: circuit={}, active={}", circuit_id, active);
+
+    Ok(())
+}
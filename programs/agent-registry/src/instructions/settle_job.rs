@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, AgentWatchers, Job, JobStatus, RegistryConfig, ReputationHistory,
+    ReputationHooks, ReputationSource,
+};
+use crate::errors::RegistryError;
+use crate::events::ReputationChanged;
+
+/// Settle a delivered job: escrow to the owner, reputation to the agent
+/// The consumer may settle at any time; the owner only once the review window has lapsed
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleJob<'info> {
+    pub caller: Signer<'info>,
+
+    /// Registry config (reputation bounds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            Job::SEED_PREFIX,
+            job.listing.as_ref(),
+            job.consumer.as_ref(),
+            job.job_id.to_le_bytes().as_ref()
+        ],
+        bump = job.bump,
+        constraint = job.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub job: Account<'info, Job>,
+
+    /// CHECK: agent owner wallet, receives the escrow on settlement
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: consumer wallet, receives the escrow on refund
+    #[account(mut, address = job.consumer @ RegistryError::Unauthorized)]
+    pub consumer: UncheckedAccount<'info>,
+
+    /// Optional reputation history; the change is appended when passed
+    #[account(
+        mut,
+        constraint = history.load()?.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub history: Option<AccountLoader<'info, ReputationHistory>>,
+
+    /// CHECK: the agent's ReputationHooks PDA, which may not exist; once it does the
+    /// change is recorded there and delivered to hook programs passed as remaining accounts
+    #[account(
+        mut,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub hooks: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SettleJob<'info>>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let caller = ctx.accounts.caller.key();
+    let job = &ctx.accounts.job;
+
+    require!(job.status == JobStatus::Delivered, RegistryError::InvalidJobStatus);
+    if caller != job.consumer {
+        require_keys_eq!(caller, ctx.accounts.agent.owner, RegistryError::Unauthorized);
+        require!(
+            now > job.delivered_at + Job::REVIEW_WINDOW,
+            RegistryError::JobDeadlineNotReached
+        );
+    }
+
+    let old_reputation = ctx.accounts.agent.reputation_score;
+    let paid = Job::finalize(
+        &mut ctx.accounts.job,
+        &mut ctx.accounts.agent,
+        &ctx.accounts.config,
+        true,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.consumer.to_account_info(),
+        now,
+    )?;
+    ctx.accounts.agent.credit_reputation(ReputationSource::JobSettlement, old_reputation);
+    ReputationHistory::record(
+        ctx.accounts.history.as_ref(),
+        old_reputation,
+        ctx.accounts.agent.reputation_score,
+        ReputationSource::JobSettlement,
+        now,
+    )?;
+    emit_cpi!(ReputationChanged {
+        agent: ctx.accounts.agent.key(),
+        agent_id: ctx.accounts.agent.agent_id,
+        old_score: old_reputation,
+        new_score: ctx.accounts.agent.reputation_score,
+        source: ReputationSource::JobSettlement as u8,
+        timestamp: now,
+    });
+    ReputationHooks::notify_agent(
+        &ctx.accounts.hooks,
+        &ctx.accounts.agent,
+        ctx.remaining_accounts,
+        old_reputation,
+        now,
+    )?;
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_REPUTATION)?;
+
+    msg!(
+        "Job settled: agent={}, job={}, paid={}, reputation={}",
+        ctx.accounts.agent.agent_id,
+        ctx.accounts.job.job_id,
+        paid,
+        ctx.accounts.agent.reputation_score
+    );
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, EpochRewards, EpochSnapshot};
+use crate::errors::RegistryError;
+
+/// Capture an agent's epoch snapshot (permissionless crank)
+/// One snapshot per agent per epoch, taken within `EpochSnapshot::CAPTURE_WINDOW_SECS`
+/// of the epoch boundary; eligible snapshots are counted toward the epoch's reward
+/// emission, which `open_epoch_rewards` must have opened. Retired, restricted and
+/// deactivated agents are not snapshotted
+#[derive(Accounts)]
+pub struct SnapshotAgentEpoch<'info> {
+    /// Anyone can crank a snapshot (pays the snapshot rent)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.accepts_work(Clock::get()?.unix_timestamp) @ RegistryError::AgentNotActive
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + EpochSnapshot::INIT_SPACE,
+        seeds = [
+            EpochSnapshot::SEED_PREFIX,
+            agent.key().as_ref(),
+            Clock::get()?.epoch.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub snapshot: Account<'info, EpochSnapshot>,
+
+    /// The epoch's emission
+    #[account(
+        mut,
+        seeds = [EpochRewards::SEED_PREFIX, Clock::get()?.epoch.to_le_bytes().as_ref()],
+        bump = epoch_rewards.bump
+    )]
+    pub epoch_rewards: Account<'info, EpochRewards>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SnapshotAgentEpoch>) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(EpochSnapshot::capture_open(&clock), RegistryError::SnapshotWindowClosed);
+
+    let epoch_end = EpochSnapshot::epoch_end(&clock, EpochSchedule::get()?.slots_per_epoch);
+    let agent = &ctx.accounts.agent;
+    let snapshot = &mut ctx.accounts.snapshot;
+
+    snapshot.agent = agent.key();
+    snapshot.epoch = clock.epoch;
+    snapshot.reputation_score = agent.reputation_score;
+    snapshot.challenges_passed = agent.challenges_passed;
+    snapshot.challenges_failed = agent.challenges_failed;
+    snapshot.staked_lamports = agent.staked_lamports;
+    snapshot.locked_stake_lamports = EpochSnapshot::locked_stake(agent, epoch_end);
+    snapshot.verified = agent.verified;
+    snapshot.slot = clock.slot;
+    snapshot.captured_at = clock.unix_timestamp;
+    snapshot.bump = ctx.bumps.snapshot;
+
+    let rewards = &mut ctx.accounts.epoch_rewards;
+    let eligible = rewards.is_eligible(snapshot);
+    if eligible {
+        rewards.total_reputation = rewards
+            .total_reputation
+            .saturating_add(snapshot.reputation_score as u64);
+        rewards.eligible_agents = rewards.eligible_agents.saturating_add(1);
+    }
+
+    msg!(
+        "Epoch snapshot: agent={}, epoch={}, reputation={}, stake={} (locked {}), reward_eligible={}",
+        agent.agent_id,
+        snapshot.epoch,
+        snapshot.reputation_score,
+        snapshot.staked_lamports,
+        snapshot.locked_stake_lamports,
+        eligible
+    );
+
+    Ok(())
+}
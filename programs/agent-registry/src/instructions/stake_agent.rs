@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{AgentAccount, AgentWatchers, RegistryConfig};
+use crate::errors::RegistryError;
+
+/// Stake lamports behind an agent (owner only)
+/// Stake is held in the agent PDA and tracked in `staked_lamports`; each deposit
+/// re-locks the whole stake for the configured lockup
+#[derive(Accounts)]
+pub struct StakeAgent<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (stake lockup)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<StakeAgent>, amount: u64) -> Result<()> {
+    require!(amount > 0, RegistryError::InvalidStakeAmount);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.agent.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let agent = &mut ctx.accounts.agent;
+    agent.staked_lamports = agent
+        .staked_lamports
+        .checked_add(amount)
+        .ok_or(RegistryError::InvalidStakeAmount)?;
+    let now = Clock::get()?.unix_timestamp;
+    agent.stake_locked_until = agent
+        .stake_locked_until
+        .max(now.saturating_add(ctx.accounts.config.stake_lockup_secs));
+    agent.touch(now);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_STAKE)?;
+
+    msg!(
+        "Agent staked: id={}, amount={}, total={}, locked_until={}",
+        agent.agent_id,
+        amount,
+        agent.staked_lamports,
+        agent.stake_locked_until
+    );
+
+    Ok(())
+}
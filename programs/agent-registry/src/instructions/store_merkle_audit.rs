@@ -1,13 +1,19 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, MerkleAuditRoot, MerkleAuditSummary};
+use crate::state::{AgentAccount, AuditDelegate, MerkleAuditRoot, MerkleAuditSummary, RegistryConfig, RegistryState};
+use crate::errors::RegistryError;
+use crate::events::MerkleAuditStored;
+
+use super::log_audit::charge_audit_fee;
 
 /// Accounts for storing a Merkle audit root
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(merkle_root: [u8; 32], entries_count: u32)]
 pub struct StoreMerkleAudit<'info> {
-    /// The agent owner (must own the agent being audited)
+    /// The agent owner, or an audit delegate when `audit_delegate` is passed
+    /// Pays the audit fee and any rent
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub submitter: Signer<'info>,
 
     /// The agent being audited
     #[account(
@@ -17,14 +23,38 @@ pub struct StoreMerkleAudit<'info> {
             agent.agent_id.to_le_bytes().as_ref()
         ],
         bump = agent.bump,
-        constraint = agent.owner == owner.key() @ StoreMerkleAuditError::NotAgentOwner
+        constraint = agent.accepts_work(Clock::get()?.unix_timestamp) @ RegistryError::AgentNotActive,
+        constraint = !agent.is_audit_quarantined() @ RegistryError::AuditQuarantined
     )]
     pub agent: Account<'info, AgentAccount>,
 
+    /// The submitter's delegation from the agent owner (required unless the owner submits)
+    #[account(
+        mut,
+        seeds = [AuditDelegate::SEED_PREFIX, agent.key().as_ref(), submitter.key().as_ref()],
+        bump = audit_delegate.bump
+    )]
+    pub audit_delegate: Option<Account<'info, AuditDelegate>>,
+
+    /// Registry config (audit fee and discount schedule)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Treasury PDA receiving the audit fee
+    #[account(
+        mut,
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
     /// The Merkle audit summary for this agent (created if first batch)
     #[account(
         init_if_needed,
-        payer = owner,
+        payer = submitter,
         space = 8 + MerkleAuditSummary::INIT_SPACE,
         seeds = [MerkleAuditSummary::SEED_PREFIX, agent.key().as_ref()],
         bump
@@ -34,7 +64,7 @@ pub struct StoreMerkleAudit<'info> {
     /// The new Merkle audit root entry
     #[account(
         init,
-        payer = owner,
+        payer = submitter,
         space = 8 + MerkleAuditRoot::INIT_SPACE,
         seeds = [
             MerkleAuditRoot::SEED_PREFIX,
@@ -50,7 +80,7 @@ pub struct StoreMerkleAudit<'info> {
 
 #[error_code]
 pub enum StoreMerkleAuditError {
-    #[msg("Only agent owner can store audit roots")]
+    #[msg("Only the agent owner or an audit delegate can store audit roots")]
     NotAgentOwner,
     #[msg("Entries count must be greater than 0")]
     EmptyBatch,
@@ -64,6 +94,21 @@ pub fn handler(
     require!(entries_count > 0, StoreMerkleAuditError::EmptyBatch);
 
     let clock = Clock::get()?;
+    if ctx.accounts.agent.owner != ctx.accounts.submitter.key() {
+        ctx.accounts
+            .audit_delegate
+            .as_mut()
+            .ok_or(StoreMerkleAuditError::NotAgentOwner)?
+            .record_submission(AuditDelegate::SCOPE_MERKLE_BATCH, clock.unix_timestamp)?;
+    }
+    let audit_fee = charge_audit_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.agent,
+        &ctx.accounts.submitter.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        clock.unix_timestamp,
+    )?;
     let agent_key = ctx.accounts.agent.key();
 
     // Initialize summary if first batch
@@ -86,12 +131,24 @@ pub fn handler(
     summary.total_batches = summary.total_batches.saturating_add(1);
     summary.total_entries = summary.total_entries.saturating_add(entries_count as u64);
     summary.last_batch_at = clock.unix_timestamp;
+    summary.fold_root(&merkle_root);
+
+    let batch_index = root.batch_index;
+    emit_cpi!(MerkleAuditStored {
+        agent: agent_key,
+        batch_index,
+        merkle_root,
+        entries_count,
+        running_root: summary.running_root,
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!(
-        "Merkle audit root stored: agent={}, batch={}, entries={}, root={:?}",
+        "Merkle audit root stored: agent={}, batch={}, entries={}, fee={}, root={:?}",
         agent_key,
-        root.batch_index,
+        batch_index,
         entries_count,
+        audit_fee,
         &merkle_root[..8] // Log first 8 bytes for brevity
     );
 
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Challenge, ChallengeEvidence, EvidenceKind, EvidenceParty};
+use crate::errors::RegistryError;
+
+/// Anchor an evidence hash to an unresolved challenge (challenger or agent owner)
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct SubmitChallengeEvidence<'info> {
+    /// Challenger or agent owner (pays for the evidence account on first use)
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = !challenge.is_resolved() @ RegistryError::ChallengeAlreadyResolved
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        init_if_needed,
+        payer = submitter,
+        space = 8 + ChallengeEvidence::INIT_SPACE,
+        seeds = [ChallengeEvidence::SEED_PREFIX, challenge.key().as_ref()],
+        bump
+    )]
+    pub evidence: Account<'info, ChallengeEvidence>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SubmitChallengeEvidence>,
+    _nonce: u64,
+    kind: EvidenceKind,
+    evidence_hash: [u8; 32],
+) -> Result<()> {
+    let submitter = ctx.accounts.submitter.key();
+    let party = if submitter == ctx.accounts.challenge.challenger {
+        EvidenceParty::Challenger
+    } else if submitter == ctx.accounts.agent.owner {
+        EvidenceParty::Agent
+    } else {
+        return err!(RegistryError::NotChallengeParty);
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    let evidence = &mut ctx.accounts.evidence;
+    evidence.challenge = ctx.accounts.challenge.key();
+    evidence.bump = ctx.bumps.evidence;
+    evidence.push(party, kind, evidence_hash, now)?;
+
+    msg!(
+        "Challenge evidence anchored: challenge={}, party={:?}, kind={:?}, items={}",
+        evidence.challenge,
+        party,
+        kind,
+        evidence.items.len()
+    );
+
+    Ok(())
+}
@@ -2,11 +2,13 @@ use anchor_lang::prelude::*;
 use crate::state::{AgentAccount, Challenge, ChallengeStatus, RegistryState};
 use crate::errors::RegistryError;
 
+/// Record the agent's response to a pending challenge
+/// Nothing is decided here: judged challenges go to the committee, the rest wait for the
+/// challenger to reveal the committed answer (`reveal_challenge_answer`)
 #[derive(Accounts)]
 #[instruction(response_hash: String, nonce: u64)]
 pub struct SubmitResponse<'info> {
     /// Agent owner submitting the response
-    #[account(mut)]
     pub owner: Signer<'info>,
 
     /// The registry (for validation)
@@ -18,7 +20,6 @@ pub struct SubmitResponse<'info> {
 
     /// The agent account (must be owned by signer)
     #[account(
-        mut,
         seeds = [
             AgentAccount::SEED_PREFIX,
             owner.key().as_ref(),
@@ -50,7 +51,7 @@ pub fn handler(
     _nonce: u64,
 ) -> Result<()> {
     let challenge = &mut ctx.accounts.challenge;
-    let agent = &mut ctx.accounts.agent;
+    let agent = &ctx.accounts.agent;
     let clock = Clock::get()?;
 
     // Check if challenge has expired
@@ -68,32 +69,20 @@ pub fn handler(
     // Record response time
     challenge.responded_at = clock.unix_timestamp;
 
-    // Verify the response
-    if response_hash == challenge.expected_hash {
-        // Challenge passed
-        challenge.status = ChallengeStatus::Passed;
-        agent.challenges_passed = agent.challenges_passed.saturating_add(1);
-        agent.adjust_reputation(Challenge::PASS_REPUTATION_DELTA);
-        agent.updated_at = clock.unix_timestamp;
-
-        msg!(
-            "Challenge PASSED! Agent {} reputation: {}",
-            agent.agent_id,
-            agent.reputation_score
-        );
+    // Committee-judged challenges wait for judge votes; the rest for the challenger to
+    // reveal the committed answer, which the agent could not read beforehand
+    challenge.response_hash = response_hash;
+    challenge.status = if challenge.judged {
+        ChallengeStatus::AwaitingVerdict
     } else {
-        // Challenge failed
-        challenge.status = ChallengeStatus::Failed;
-        agent.challenges_failed = agent.challenges_failed.saturating_add(1);
-        agent.adjust_reputation(Challenge::FAIL_REPUTATION_DELTA);
-        agent.updated_at = clock.unix_timestamp;
+        ChallengeStatus::AwaitingReveal
+    };
 
-        msg!(
-            "Challenge FAILED. Agent {} reputation: {}",
-            agent.agent_id,
-            agent.reputation_score
-        );
-    }
+    msg!(
+        "Response recorded for agent {}. Awaiting {}",
+        agent.agent_id,
+        if challenge.judged { "committee verdict" } else { "challenger reveal" }
+    );
 
     Ok(())
 }
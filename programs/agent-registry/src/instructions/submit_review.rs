@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{Job, JobStatus, Review};
+use crate::errors::RegistryError;
+
+/// Review a settled or refunded job (consumer only, once per job)
+#[derive(Accounts)]
+pub struct SubmitReview<'info> {
+    #[account(mut)]
+    pub consumer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Job::SEED_PREFIX,
+            job.listing.as_ref(),
+            consumer.key().as_ref(),
+            job.job_id.to_le_bytes().as_ref()
+        ],
+        bump = job.bump,
+        constraint = job.consumer == consumer.key() @ RegistryError::Unauthorized,
+        constraint = matches!(job.status, JobStatus::Settled | JobStatus::Refunded)
+            @ RegistryError::InvalidJobStatus
+    )]
+    pub job: Account<'info, Job>,
+
+    #[account(
+        init,
+        payer = consumer,
+        space = 8 + Review::INIT_SPACE,
+        seeds = [Review::SEED_PREFIX, job.key().as_ref()],
+        bump
+    )]
+    pub review: Account<'info, Review>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SubmitReview>, rating: u8, comment_hash: [u8; 32]) -> Result<()> {
+    require!(
+        (Review::MIN_RATING..=Review::MAX_RATING).contains(&rating),
+        RegistryError::InvalidRating
+    );
+
+    let review = &mut ctx.accounts.review;
+    review.job = ctx.accounts.job.key();
+    review.agent = ctx.accounts.job.agent;
+    review.reviewer = ctx.accounts.consumer.key();
+    review.rating = rating;
+    review.comment_hash = comment_hash;
+    review.created_at = Clock::get()?.unix_timestamp;
+    review.response_hash = [0u8; 32];
+    review.responded_at = 0;
+    review.bump = ctx.bumps.review;
+
+    msg!(
+        "Review submitted: agent={}, job={}, rating={}",
+        review.agent,
+        ctx.accounts.job.job_id,
+        rating
+    );
+
+    Ok(())
+}
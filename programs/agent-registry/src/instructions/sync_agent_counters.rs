@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentCounters};
+
+/// Re-copy an agent's hot counters after other instructions changed the agent (permissionless)
+#[derive(Accounts)]
+pub struct SyncAgentCounters<'info> {
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [AgentCounters::SEED_PREFIX, agent.key().as_ref()],
+        bump = counters.load()?.bump
+    )]
+    pub counters: AccountLoader<'info, AgentCounters>,
+}
+
+pub fn handler(ctx: Context<SyncAgentCounters>) -> Result<()> {
+    let mut counters = ctx.accounts.counters.load_mut()?;
+    let stale_seq = counters.synced_seq;
+    counters.copy_from(&ctx.accounts.agent);
+
+    msg!(
+        "Agent counters synced: agent={}, seq {} -> {}",
+        counters.agent_id,
+        stale_seq,
+        counters.synced_seq
+    );
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::{EvalBatch, MerkleAuditRoot, ReputationAuthority};
+use crate::errors::RegistryError;
+
+/// Tag a stored audit root as a batch of eval results (active reputation authority only)
+/// The evaluator vouches for every leaf; `prove_capability` then accepts proofs against it
+#[derive(Accounts)]
+pub struct TagEvalBatch<'info> {
+    #[account(mut)]
+    pub evaluator: Signer<'info>,
+
+    #[account(
+        seeds = [ReputationAuthority::SEED_PREFIX, evaluator.key().as_ref()],
+        bump = reputation_authority.bump,
+        constraint = reputation_authority.active @ RegistryError::NotReputationAuthority
+    )]
+    pub reputation_authority: Account<'info, ReputationAuthority>,
+
+    #[account(
+        seeds = [
+            MerkleAuditRoot::SEED_PREFIX,
+            audit_root.agent.as_ref(),
+            audit_root.batch_index.to_le_bytes().as_ref()
+        ],
+        bump = audit_root.bump
+    )]
+    pub audit_root: Account<'info, MerkleAuditRoot>,
+
+    #[account(
+        init,
+        payer = evaluator,
+        space = 8 + EvalBatch::INIT_SPACE,
+        seeds = [EvalBatch::SEED_PREFIX, audit_root.key().as_ref()],
+        bump
+    )]
+    pub eval_batch: Account<'info, EvalBatch>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<TagEvalBatch>) -> Result<()> {
+    let batch = &mut ctx.accounts.eval_batch;
+    batch.audit_root = ctx.accounts.audit_root.key();
+    batch.agent = ctx.accounts.audit_root.agent;
+    batch.evaluator = ctx.accounts.evaluator.key();
+    batch.tagged_at = Clock::get()?.unix_timestamp;
+    batch.bump = ctx.bumps.eval_batch;
+
+    msg!(
+        "Eval batch tagged: agent={}, batch={}, evaluator={}",
+        batch.agent,
+        ctx.accounts.audit_root.batch_index,
+        batch.evaluator
+    );
+
+    Ok(())
+}
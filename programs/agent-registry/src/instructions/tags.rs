@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentWatchers, TagIndex};
+use crate::errors::RegistryError;
+
+/// Replace an agent's discovery tags (owner only)
+/// TagIndex accounts passed as writable remaining accounts are brought in line with
+/// the new tags: the agent is added to indexes of tags it now carries and dropped
+/// from the rest
+#[derive(Accounts)]
+pub struct SetAgentTags<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+/// Add a tagged agent to the index for that tag (permissionless, payer funds rent)
+#[derive(Accounts)]
+#[instruction(tag: String)]
+pub struct IndexAgentTag<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.has_tag(&tag) @ RegistryError::TagNotSet
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TagIndex::INIT_SPACE,
+        seeds = [TagIndex::SEED_PREFIX, tag.as_bytes()],
+        bump
+    )]
+    pub tag_index: Account<'info, TagIndex>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Drop an agent that was closed or no longer carries the tag (permissionless)
+#[derive(Accounts)]
+pub struct PruneTagIndex<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TagIndex::SEED_PREFIX, tag_index.tag.as_bytes()],
+        bump = tag_index.bump
+    )]
+    pub tag_index: Account<'info, TagIndex>,
+
+    /// CHECK: the indexed agent; closed (no data) or checked against its tags in the handler
+    pub agent: UncheckedAccount<'info>,
+}
+
+pub fn set_agent_tags(ctx: Context<SetAgentTags>, tags: Vec<String>) -> Result<()> {
+    let agent = &mut ctx.accounts.agent;
+    agent.set_tags(tags)?;
+    agent.touch(Clock::get()?.unix_timestamp);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_METADATA)?;
+
+    for info in ctx.remaining_accounts {
+        sync_tag_index(info, agent)?;
+    }
+
+    msg!(
+        "Agent tags set: id={}, tags={:?}, indexes synced={}",
+        agent.agent_id,
+        agent.tags,
+        ctx.remaining_accounts.len()
+    );
+
+    Ok(())
+}
+
+pub fn index_agent_tag(ctx: Context<IndexAgentTag>, tag: String) -> Result<()> {
+    let index = &mut ctx.accounts.tag_index;
+    if index.agents.is_empty() {
+        index.tag = tag;
+        index.bump = ctx.bumps.tag_index;
+    }
+    index.add(ctx.accounts.agent.key())?;
+
+    msg!(
+        "Agent indexed by tag: agent={}, tag={}, agents={}",
+        ctx.accounts.agent.agent_id,
+        index.tag,
+        index.agents.len()
+    );
+
+    Ok(())
+}
+
+pub fn prune_tag_index(ctx: Context<PruneTagIndex>) -> Result<()> {
+    let info = ctx.accounts.agent.to_account_info();
+    let index = &mut ctx.accounts.tag_index;
+
+    if !info.data_is_empty() {
+        require_keys_eq!(*info.owner, crate::ID, RegistryError::AgentNotFound);
+        let data = info.try_borrow_data()?;
+        let agent = AgentAccount::try_deserialize(&mut &data[..])?;
+        require!(!agent.has_tag(&index.tag), RegistryError::AgentStillTagged);
+    }
+    require!(index.remove(&info.key()), RegistryError::AgentNotIndexed);
+
+    msg!(
+        "Agent pruned from tag index: agent={}, tag={}, remaining={}",
+        info.key(),
+        index.tag,
+        index.agents.len()
+    );
+
+    Ok(())
+}
+
+/// Add `agent` to, or drop it from, the TagIndex in `info` according to its tags
+fn sync_tag_index(info: &AccountInfo, agent: &Account<AgentAccount>) -> Result<()> {
+    require!(
+        info.owner == &crate::ID && info.is_writable,
+        RegistryError::InvalidTagIndex
+    );
+    let mut index = {
+        let data = info.try_borrow_data()?;
+        TagIndex::try_deserialize(&mut &data[..]).map_err(|_| RegistryError::InvalidTagIndex)?
+    };
+    let expected = Pubkey::create_program_address(
+        &[TagIndex::SEED_PREFIX, index.tag.as_bytes(), &[index.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| RegistryError::InvalidTagIndex)?;
+    require_keys_eq!(expected, info.key(), RegistryError::InvalidTagIndex);
+
+    let agent_key = agent.key();
+    if !agent.has_tag(&index.tag) {
+        index.remove(&agent_key);
+    } else if !index.agents.contains(&agent_key) {
+        index.add(agent_key)?;
+    }
+
+    let mut data = info.try_borrow_mut_data()?;
+    index.try_serialize(&mut &mut data[..])
+}
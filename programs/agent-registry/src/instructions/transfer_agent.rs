@@ -0,0 +1,346 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::{
+    AgentAccount, AgentCounters, AgentStatus, AgentTransfer, AgentWatchers, OwnerIndex,
+    RegistryConfig, ReputationHistory, ReputationHook, ReputationHooks, ReputationSource,
+    ReputationTransferPolicy,
+};
+use crate::errors::RegistryError;
+
+use super::register_agent::index_owner;
+
+/// Move an agent to a new owner (current owner and new owner both sign; new owner funds rent)
+/// Agent PDAs are derived from the owner, so the agent continues under the same ID in a
+/// fresh account and the old one is retired: in-flight jobs and challenges resolve there,
+/// and `close_agent` returns its rent and stake to the previous owner. Reputation carries
+/// over per `RegistryConfig::reputation_transfer_policy`, recorded in an AgentTransfer.
+/// PDAs keyed by the old address move with the agent: hooks, watchers and history are
+/// copied to the successor's, counters are dropped, and the old accounts are closed with
+/// their rent going to the new owner
+#[derive(Accounts)]
+pub struct TransferAgent<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    /// Registry config (reputation transfer policy)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status == AgentStatus::Active @ RegistryError::InvalidStatusTransition,
+        constraint = new_owner.key() != owner.key() @ RegistryError::InvalidNewOwner
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Sized like the agent, which `update_agent` may have grown past INIT_SPACE
+    #[account(
+        init,
+        payer = new_owner,
+        space = AgentAccount::space_for(agent.name.len(), agent.capabilities.len()),
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            new_owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub successor: Box<Account<'info, AgentAccount>>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        space = 8 + AgentTransfer::INIT_SPACE,
+        seeds = [AgentTransfer::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub transfer: Account<'info, AgentTransfer>,
+
+    /// New owner's agent index; the agent's ID is appended when passed
+    #[account(
+        init_if_needed,
+        payer = new_owner,
+        space = 8 + OwnerIndex::INIT_SPACE,
+        seeds = [OwnerIndex::SEED_PREFIX, new_owner.key().as_ref()],
+        bump
+    )]
+    pub owner_index: Option<Account<'info, OwnerIndex>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's ReputationHooks PDA, which may not exist; moved to
+    /// `successor_hooks` and closed when it does
+    #[account(
+        mut,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub hooks: UncheckedAccount<'info>,
+
+    /// The successor's hooks (required iff the agent has hooks)
+    #[account(
+        init,
+        payer = new_owner,
+        space = 8 + ReputationHooks::INIT_SPACE,
+        seeds = [ReputationHooks::SEED_PREFIX, successor.key().as_ref()],
+        bump
+    )]
+    pub successor_hooks: Option<Box<Account<'info, ReputationHooks>>>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; moved to
+    /// `successor_watchers` and closed when it does
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+
+    /// The successor's watchers (required iff the agent has watchers)
+    #[account(
+        init,
+        payer = new_owner,
+        space = 8 + AgentWatchers::INIT_SPACE,
+        seeds = [AgentWatchers::SEED_PREFIX, successor.key().as_ref()],
+        bump
+    )]
+    pub successor_watchers: Option<Box<Account<'info, AgentWatchers>>>,
+
+    /// CHECK: the agent's ReputationHistory PDA, which may not exist; copied to
+    /// `successor_history` and closed when it does
+    #[account(
+        mut,
+        seeds = [ReputationHistory::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub history: UncheckedAccount<'info>,
+
+    /// The successor's history (required iff the agent has a history)
+    #[account(
+        init,
+        payer = new_owner,
+        space = ReputationHistory::SPACE,
+        seeds = [ReputationHistory::SEED_PREFIX, successor.key().as_ref()],
+        bump
+    )]
+    pub successor_history: Option<AccountLoader<'info, ReputationHistory>>,
+
+    /// CHECK: the agent's AgentCounters PDA, which may not exist; closed when it does
+    /// (the successor starts over with `init_agent_counters`)
+    #[account(
+        mut,
+        seeds = [AgentCounters::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub counters: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<TransferAgent>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &ctx.accounts.config;
+    let new_owner = ctx.accounts.new_owner.key();
+
+    let agent = &mut ctx.accounts.agent;
+    let reputation_before = agent.reputation_score;
+    let reputation_after = config.transferred_reputation(reputation_before);
+
+    // Identity, history and verification carry over; stake, multisig and nonces do not
+    let successor = &mut ctx.accounts.successor;
+    ***successor = (**agent).clone();
+    successor.owner = new_owner;
+    successor.bump = ctx.bumps.successor;
+    successor.set_reputation(reputation_after);
+    if config.reputation_transfer_policy == ReputationTransferPolicy::Reset {
+        successor.reputation_by_source = [0; ReputationSource::COUNT];
+    } else {
+        successor.scale_reputation_by_source(reputation_before, reputation_after);
+    }
+    successor.staked_lamports = 0;
+    successor.stake_locked_until = 0;
+    successor.multisig = Pubkey::default();
+    successor.nonce = 0;
+    successor.seq = 0;
+    successor.status_changed_at = now;
+    successor.touch(now);
+
+    agent.status = AgentStatus::Retired;
+    agent.status_changed_at = now;
+    agent.bump_seq();
+
+    index_owner(
+        ctx.accounts.owner_index.as_deref_mut(),
+        ctx.bumps.owner_index,
+        new_owner,
+        successor.agent_id,
+    )?;
+
+    let successor_key = successor.key();
+    let rent_to = ctx.accounts.new_owner.to_account_info();
+    move_hooks(
+        &ctx.accounts.hooks,
+        ctx.accounts.successor_hooks.as_deref_mut(),
+        successor_key,
+        ctx.bumps.successor_hooks,
+        &rent_to,
+    )?;
+    let mut changed = AgentWatchers::FLAG_STATUS;
+    if reputation_after != reputation_before {
+        changed |= AgentWatchers::FLAG_REPUTATION;
+    }
+    move_watchers(
+        &ctx.accounts.watchers,
+        ctx.accounts.successor_watchers.as_deref_mut(),
+        successor_key,
+        ctx.bumps.successor_watchers,
+        changed,
+        &rent_to,
+    )?;
+    move_history(
+        &ctx.accounts.history,
+        ctx.accounts.successor_history.as_ref(),
+        successor_key,
+        ctx.bumps.successor_history,
+        &rent_to,
+    )?;
+    if !ctx.accounts.counters.data_is_empty() {
+        close_pda(&ctx.accounts.counters, &rent_to)?;
+    }
+
+    let transfer = &mut ctx.accounts.transfer;
+    transfer.agent = agent.key();
+    transfer.successor = successor.key();
+    transfer.agent_id = agent.agent_id;
+    transfer.from_owner = agent.owner;
+    transfer.to_owner = new_owner;
+    transfer.policy = config.reputation_transfer_policy;
+    transfer.haircut_bps = config.transfer_haircut_bps;
+    transfer.reputation_before = reputation_before;
+    transfer.reputation_after = reputation_after;
+    transfer.transferred_at = now;
+    transfer.bump = ctx.bumps.transfer;
+
+    msg!(
+        "Agent transferred: id={}, from={}, to={}, policy={:?}, reputation={} -> {}",
+        agent.agent_id,
+        agent.owner,
+        new_owner,
+        config.reputation_transfer_policy,
+        reputation_before,
+        reputation_after
+    );
+
+    Ok(())
+}
+
+/// Whether the optional `old` PDA exists, checking the successor's copy is passed exactly then
+fn migrating<T>(old: &AccountInfo, successor: Option<&T>) -> Result<bool> {
+    let exists = !old.data_is_empty();
+    require!(exists == successor.is_some(), RegistryError::SuccessorAccountMismatch);
+    if exists {
+        require_keys_eq!(*old.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    }
+    Ok(exists)
+}
+
+/// Register the agent's hooks on the successor; delivery state starts over there
+fn move_hooks<'info>(
+    old: &AccountInfo<'info>,
+    successor: Option<&mut Account<'info, ReputationHooks>>,
+    successor_key: Pubkey,
+    bump: Option<u8>,
+    rent_to: &AccountInfo<'info>,
+) -> Result<()> {
+    if !migrating(old, successor.as_ref())? {
+        return Ok(());
+    }
+    let hooks = ReputationHooks::try_deserialize(&mut &old.try_borrow_data()?[..])?;
+    let moved = successor.unwrap();
+    moved.agent = successor_key;
+    moved.hooks = hooks
+        .hooks
+        .iter()
+        .map(|hook| ReputationHook {
+            program: hook.program,
+            delivered_seq: 0,
+            missed: 0,
+            over_budget: false,
+        })
+        .collect();
+    moved.seq = 0;
+    moved.bump = bump.unwrap_or_default();
+    close_pda(old, rent_to)
+}
+
+/// Subscribe the agent's watchers to the successor, pending flags included, and flag
+/// the transfer's `changed` categories
+fn move_watchers<'info>(
+    old: &AccountInfo<'info>,
+    successor: Option<&mut Account<'info, AgentWatchers>>,
+    successor_key: Pubkey,
+    bump: Option<u8>,
+    changed: u16,
+    rent_to: &AccountInfo<'info>,
+) -> Result<()> {
+    if !migrating(old, successor.as_ref())? {
+        return Ok(());
+    }
+    let watchers = AgentWatchers::try_deserialize(&mut &old.try_borrow_data()?[..])?;
+    let moved = successor.unwrap();
+    moved.agent = successor_key;
+    moved.slots = watchers.slots;
+    moved.bump = bump.unwrap_or_default();
+    moved.flag(changed);
+    close_pda(old, rent_to)
+}
+
+/// Copy the agent's reputation history to the successor
+fn move_history<'info>(
+    old: &AccountInfo<'info>,
+    successor: Option<&AccountLoader<'info, ReputationHistory>>,
+    successor_key: Pubkey,
+    bump: Option<u8>,
+    rent_to: &AccountInfo<'info>,
+) -> Result<()> {
+    if !migrating(old, successor)? {
+        return Ok(());
+    }
+    {
+        let data = old.try_borrow_data()?;
+        require!(
+            data.len() >= ReputationHistory::SPACE && data[..8] == *ReputationHistory::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        let history: &ReputationHistory = bytemuck::from_bytes(&data[8..ReputationHistory::SPACE]);
+        let mut moved = successor.unwrap().load_init()?;
+        moved.agent = successor_key;
+        moved.entries = history.entries;
+        moved.head = history.head;
+        moved.count = history.count;
+        moved.total_recorded = history.total_recorded;
+        moved.bump = bump.unwrap_or_default();
+    }
+    close_pda(old, rent_to)
+}
+
+/// Close a registry-owned PDA held as a raw account, sending its rent to `rent_to`
+pub(crate) fn close_pda<'info>(account: &AccountInfo<'info>, rent_to: &AccountInfo<'info>) -> Result<()> {
+    require_keys_eq!(*account.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    **rent_to.try_borrow_mut_lamports()? += account.lamports();
+    **account.try_borrow_mut_lamports()? = 0;
+    account.assign(&System::id());
+    account.resize(0)?;
+    Ok(())
+}
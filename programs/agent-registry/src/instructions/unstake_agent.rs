@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentWatchers, RegistryConfig, UnstakeRequest};
+use crate::errors::RegistryError;
+
+/// Withdraw staked lamports from an agent back to the owner (owner only)
+/// Only available while no unstake cooldown is configured; otherwise use `request_unstake`
+#[derive(Accounts)]
+pub struct UnstakeAgent<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (unstake cooldown)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's UnstakeRequest PDA, which may not exist; lamports pending there
+    /// don't count towards the agent account's rent
+    #[account(
+        seeds = [UnstakeRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub unstake_request: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<UnstakeAgent>, amount: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let agent = &mut ctx.accounts.agent;
+    check_unstake(agent, amount, now)?;
+    require!(
+        ctx.accounts.config.unstake_cooldown_secs == 0,
+        RegistryError::UnstakeCooldownRequired
+    );
+
+    agent.staked_lamports -= amount;
+    agent.touch(now);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_STAKE)?;
+
+    // Agent PDA is program-owned, so lamports can be moved directly
+    let agent_info = agent.to_account_info();
+    **agent_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += amount;
+    agent.require_rent_exempt(&agent_info, UnstakeRequest::pending(&ctx.accounts.unstake_request)?)?;
+
+    msg!(
+        "Agent unstaked: id={}, amount={}, remaining={}",
+        agent.agent_id,
+        amount,
+        agent.staked_lamports
+    );
+
+    Ok(())
+}
+
+/// Validate an unstake amount against the agent's stake and lockup
+pub(crate) fn check_unstake(agent: &AgentAccount, amount: u64, now: i64) -> Result<()> {
+    require!(amount > 0, RegistryError::InvalidStakeAmount);
+    require!(agent.staked_lamports >= amount, RegistryError::InsufficientStake);
+    require!(now >= agent.stake_locked_until, RegistryError::StakeLocked);
+    Ok(())
+}
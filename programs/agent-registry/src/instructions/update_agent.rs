@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::AgentAccount;
+use anchor_lang::system_program;
+use crate::state::{AgentAccount, AgentWatchers, UnstakeRequest};
+use crate::validation;
 use crate::errors::RegistryError;
 
 #[derive(Accounts)]
@@ -15,9 +17,30 @@ pub struct UpdateAgent<'info> {
             agent.agent_id.to_le_bytes().as_ref()
         ],
         bump = agent.bump,
-        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
     )]
     pub agent: Account<'info, AgentAccount>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's UnstakeRequest PDA, which may not exist; lamports pending there
+    /// don't count towards the agent account's rent
+    #[account(
+        seeds = [UnstakeRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub unstake_request: UncheckedAccount<'info>,
 }
 
 pub fn handler(
@@ -25,22 +48,82 @@ pub fn handler(
     name: Option<String>,
     capabilities: Option<String>,
 ) -> Result<()> {
-    let agent = &mut ctx.accounts.agent;
+    apply_update(
+        &mut ctx.accounts.agent,
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        UnstakeRequest::pending(&ctx.accounts.unstake_request)?,
+        name,
+        capabilities,
+    )?;
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_METADATA)?;
+    Ok(())
+}
+
+/// Validate and write new metadata, growing the account (payer funds rent) when it no longer fits
+/// Shared with `update_agent_with_signature`. `pending` is the agent's pending unstake,
+/// which like the stake sits in the account but can't pay its rent
+pub(crate) fn apply_update<'info>(
+    agent: &mut Account<'info, AgentAccount>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    pending: u64,
+    name: Option<String>,
+    capabilities: Option<String>,
+) -> Result<()> {
     let clock = Clock::get()?;
 
+    if name.is_some() {
+        agent.require_unfrozen(AgentAccount::FIELD_NAME)?;
+    }
+    if capabilities.is_some() {
+        agent.require_unfrozen(AgentAccount::FIELD_CAPABILITIES)?;
+    }
+    let name = name
+        .map(|name| validation::agent_name(&name, AgentAccount::MAX_NAME_LEN))
+        .transpose()?;
+    let capabilities = capabilities
+        .map(|capabilities| validation::agent_capabilities(&capabilities, AgentAccount::MAX_CAPABILITIES_LEN))
+        .transpose()?;
+
+    // Grow the account when the new metadata no longer fits
+    let required_len = AgentAccount::space_for(
+        name.as_ref().map_or(agent.name.len(), |n| n.len()),
+        capabilities.as_ref().map_or(agent.capabilities.len(), |c| c.len()),
+    );
+    let info = agent.to_account_info();
+    if required_len > info.data_len() {
+        let shortfall = agent.rent_shortfall(
+            info.lamports(),
+            pending,
+            Rent::get()?.minimum_balance(required_len),
+        );
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program,
+                    system_program::Transfer {
+                        from: payer,
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        info.resize(required_len)?;
+    }
+
     // Update name if provided
     if let Some(new_name) = name {
-        require!(new_name.len() <= 64, RegistryError::NameTooLong);
         agent.name = new_name;
     }
 
     // Update capabilities if provided
     if let Some(new_capabilities) = capabilities {
-        require!(new_capabilities.len() <= 256, RegistryError::CapabilitiesTooLong);
         agent.capabilities = new_capabilities;
     }
 
-    agent.updated_at = clock.unix_timestamp;
+    agent.touch(clock.unix_timestamp);
 
     msg!("Agent updated: id={}", agent.agent_id);
 
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    update_agent_message, verify_ed25519_signature, AgentAccount, AgentWatchers, UnstakeRequest,
+};
+use crate::errors::RegistryError;
+
+use super::update_agent::apply_update;
+
+/// Relayed metadata update authorized by an owner ed25519 signature
+/// The transaction must carry an ed25519 program instruction right before this one,
+/// signed by the agent owner over `update_agent_message(agent, nonce, name, capabilities)`
+#[derive(Accounts)]
+pub struct UpdateAgentWithSignature<'info> {
+    /// Any relayer; pays fees and any rent for account growth
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: address is pinned to the instructions sysvar
+    #[account(address = solana_sdk_ids::sysvar::instructions::ID @ RegistryError::InvalidSignature)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's UnstakeRequest PDA, which may not exist; lamports pending there
+    /// don't count towards the agent account's rent
+    #[account(
+        seeds = [UnstakeRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub unstake_request: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateAgentWithSignature>,
+    name: Option<String>,
+    capabilities: Option<String>,
+    nonce: u64,
+) -> Result<()> {
+    let message = update_agent_message(&ctx.accounts.agent.key(), nonce, &name, &capabilities);
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &ctx.accounts.agent.owner,
+        &message,
+    )?;
+
+    ctx.accounts.agent.consume_nonce(nonce)?;
+
+    msg!(
+        "Relayed update: agent={}, relayer={}, nonce={}",
+        ctx.accounts.agent.agent_id,
+        ctx.accounts.relayer.key(),
+        nonce
+    );
+
+    apply_update(
+        &mut ctx.accounts.agent,
+        ctx.accounts.relayer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        UnstakeRequest::pending(&ctx.accounts.unstake_request)?,
+        name,
+        capabilities,
+    )?;
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_METADATA)
+}
@@ -0,0 +1,407 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AdminActionLog, AgentAccount, ChallengeType, ExpiryPolicy, RegistryConfig, RegistryState,
+    ReputationTransferPolicy,
+};
+use crate::errors::RegistryError;
+
+/// Update registry config parameters (admin only)
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+}
+
+/// Config fields to change - `None` leaves the current value untouched
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateConfigParams {
+    pub pass_weights_bps: Option<[u16; ChallengeType::COUNT]>,
+    pub fail_weights_bps: Option<[u16; ChallengeType::COUNT]>,
+    pub challenge_duration: Option<i64>,
+    pub challenge_escrow_lamports: Option<u64>,
+    pub challenge_escrow_usd: Option<u64>,
+    pub registration_fee_lamports: Option<u64>,
+    pub registration_fee_usd: Option<u64>,
+    pub allowlist_only: Option<bool>,
+    pub unique_models: Option<bool>,
+    pub gate_mint: Option<Pubkey>,
+    pub gate_min_balance: Option<u64>,
+    pub referral_share_bps: Option<u16>,
+    pub verification_fee_lamports: Option<u64>,
+    pub audit_fee_lamports: Option<u64>,
+    pub verified_fee_discount_bps: Option<u16>,
+    pub reputation_fee_discount_min: Option<u32>,
+    pub reputation_fee_discount_bps: Option<u16>,
+    pub auto_verify_min_stake_lamports: Option<u64>,
+    pub auto_verify_min_reputation: Option<u32>,
+    pub stake_lockup_secs: Option<i64>,
+    pub unstake_cooldown_secs: Option<i64>,
+    pub price_feed_id: Option<[u8; 32]>,
+    pub max_price_age_secs: Option<u64>,
+    pub max_price_conf_bps: Option<u16>,
+    pub expiry_policy: Option<ExpiryPolicy>,
+    pub expiry_slash_bps: Option<u16>,
+    pub judge_quorum: Option<u8>,
+    pub reputation_decay_bps_per_day: Option<u16>,
+    pub decay_grace_secs: Option<i64>,
+    pub external_reputation_weight_bps: Option<u16>,
+    pub reputation_cosigner: Option<Pubkey>,
+    pub cosign_delta_threshold: Option<u32>,
+    pub emission_lamports_per_epoch: Option<u64>,
+    pub emission_start_epoch: Option<u64>,
+    pub emission_halving_epochs: Option<u64>,
+    pub emission_min_reputation: Option<u32>,
+    pub emission_min_stake_lamports: Option<u64>,
+    pub challenge_program: Option<Pubkey>,
+    pub gc_idle_secs: Option<i64>,
+    pub max_registrations_per_day: Option<u16>,
+    pub verification_bond_lamports: Option<u64>,
+    pub verification_bond_clean_secs: Option<i64>,
+    pub reputation_transfer_policy: Option<ReputationTransferPolicy>,
+    pub transfer_haircut_bps: Option<u16>,
+}
+
+pub fn handler(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_CONFIG_CHANGE,
+        ctx.accounts.admin.key(),
+        ctx.accounts.config.key(),
+        0,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    apply_config_params(&mut ctx.accounts.config, params)
+}
+
+/// Validate and apply `params` to `config`, logging the resulting values
+/// Shared with `initialize`, which bootstraps the config through the same checks
+pub(crate) fn apply_config_params(
+    config: &mut RegistryConfig,
+    params: UpdateConfigParams,
+) -> Result<()> {
+    if let Some(weights) = params.pass_weights_bps {
+        require!(
+            weights.iter().all(|w| *w <= RegistryConfig::MAX_WEIGHT_BPS),
+            RegistryError::InvalidWeight
+        );
+        config.pass_weights_bps = weights;
+    }
+
+    if let Some(weights) = params.fail_weights_bps {
+        require!(
+            weights.iter().all(|w| *w <= RegistryConfig::MAX_WEIGHT_BPS),
+            RegistryError::InvalidWeight
+        );
+        config.fail_weights_bps = weights;
+    }
+
+    if let Some(duration) = params.challenge_duration {
+        require!(
+            (RegistryConfig::MIN_CHALLENGE_DURATION..=RegistryConfig::MAX_CHALLENGE_DURATION)
+                .contains(&duration),
+            RegistryError::InvalidChallengeDuration
+        );
+        config.challenge_duration = duration;
+    }
+
+    if let Some(escrow) = params.challenge_escrow_lamports {
+        config.challenge_escrow_lamports = escrow;
+    }
+
+    if let Some(escrow_usd) = params.challenge_escrow_usd {
+        config.challenge_escrow_usd = escrow_usd;
+    }
+
+    if let Some(fee) = params.registration_fee_lamports {
+        config.registration_fee_lamports = fee;
+    }
+
+    if let Some(fee_usd) = params.registration_fee_usd {
+        config.registration_fee_usd = fee_usd;
+    }
+
+    if let Some(allowlist_only) = params.allowlist_only {
+        config.allowlist_only = allowlist_only;
+    }
+
+    if let Some(unique_models) = params.unique_models {
+        config.unique_models = unique_models;
+    }
+
+    if let Some(gate_mint) = params.gate_mint {
+        config.gate_mint = gate_mint;
+    }
+
+    if let Some(min_balance) = params.gate_min_balance {
+        config.gate_min_balance = min_balance;
+    }
+
+    if let Some(share) = params.referral_share_bps {
+        require!(
+            (share as u32) <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidReferralShare
+        );
+        config.referral_share_bps = share;
+    }
+
+    if let Some(fee) = params.verification_fee_lamports {
+        config.verification_fee_lamports = fee;
+    }
+
+    if let Some(fee) = params.audit_fee_lamports {
+        config.audit_fee_lamports = fee;
+    }
+
+    if let Some(discount) = params.verified_fee_discount_bps {
+        require!(
+            (discount as u32) <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidFeeDiscount
+        );
+        config.verified_fee_discount_bps = discount;
+    }
+
+    if let Some(min_reputation) = params.reputation_fee_discount_min {
+        require!(
+            min_reputation <= config.reputation_ceiling,
+            RegistryError::InvalidReputationThreshold
+        );
+        config.reputation_fee_discount_min = min_reputation;
+    }
+
+    if let Some(discount) = params.reputation_fee_discount_bps {
+        require!(
+            (discount as u32) <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidFeeDiscount
+        );
+        config.reputation_fee_discount_bps = discount;
+    }
+
+    if let Some(min_stake) = params.auto_verify_min_stake_lamports {
+        config.auto_verify_min_stake_lamports = min_stake;
+    }
+
+    if let Some(min_reputation) = params.auto_verify_min_reputation {
+        require!(
+            min_reputation <= config.reputation_ceiling,
+            RegistryError::InvalidReputationThreshold
+        );
+        config.auto_verify_min_reputation = min_reputation;
+    }
+
+    if let Some(lockup) = params.stake_lockup_secs {
+        require!(
+            (0..=RegistryConfig::MAX_STAKE_LOCK_SECS).contains(&lockup),
+            RegistryError::InvalidStakeLockup
+        );
+        config.stake_lockup_secs = lockup;
+    }
+
+    if let Some(cooldown) = params.unstake_cooldown_secs {
+        require!(
+            (0..=RegistryConfig::MAX_STAKE_LOCK_SECS).contains(&cooldown),
+            RegistryError::InvalidStakeLockup
+        );
+        config.unstake_cooldown_secs = cooldown;
+    }
+
+    if let Some(feed_id) = params.price_feed_id {
+        config.price_feed_id = feed_id;
+    }
+
+    if let Some(max_age) = params.max_price_age_secs {
+        require!(max_age > 0, RegistryError::InvalidPriceParams);
+        config.max_price_age_secs = max_age;
+    }
+
+    if let Some(max_conf) = params.max_price_conf_bps {
+        require!(
+            max_conf > 0 && (max_conf as u32) <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidPriceParams
+        );
+        config.max_price_conf_bps = max_conf;
+    }
+
+    if let Some(policy) = params.expiry_policy {
+        config.expiry_policy = policy;
+    }
+
+    if let Some(slash) = params.expiry_slash_bps {
+        require!(
+            (slash as u32) <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidSlashShare
+        );
+        config.expiry_slash_bps = slash;
+    }
+
+    if let Some(quorum) = params.judge_quorum {
+        require!(quorum > 0, RegistryError::InvalidQuorum);
+        require!(
+            RegistryConfig::quorum_fits(quorum, config.active_judges),
+            RegistryError::QuorumExceedsJudges
+        );
+        config.judge_quorum = quorum;
+    }
+
+    if let Some(decay) = params.reputation_decay_bps_per_day {
+        require!(
+            (decay as u32) <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidDecayParams
+        );
+        config.reputation_decay_bps_per_day = decay;
+    }
+
+    if let Some(grace) = params.decay_grace_secs {
+        require!(grace >= 0, RegistryError::InvalidDecayParams);
+        config.decay_grace_secs = grace;
+    }
+
+    if let Some(weight) = params.external_reputation_weight_bps {
+        require!(
+            (weight as u32) <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidWeight
+        );
+        config.external_reputation_weight_bps = weight;
+    }
+
+    if let Some(cosigner) = params.reputation_cosigner {
+        config.reputation_cosigner = cosigner;
+    }
+
+    if let Some(threshold) = params.cosign_delta_threshold {
+        config.cosign_delta_threshold = threshold;
+    }
+
+    if let Some(emission) = params.emission_lamports_per_epoch {
+        config.emission_lamports_per_epoch = emission;
+    }
+
+    if let Some(start) = params.emission_start_epoch {
+        config.emission_start_epoch = start;
+    }
+
+    if let Some(halving) = params.emission_halving_epochs {
+        config.emission_halving_epochs = halving;
+    }
+
+    if let Some(min_reputation) = params.emission_min_reputation {
+        require!(
+            min_reputation <= config.reputation_ceiling,
+            RegistryError::InvalidReputationThreshold
+        );
+        config.emission_min_reputation = min_reputation;
+    }
+
+    if let Some(min_stake) = params.emission_min_stake_lamports {
+        config.emission_min_stake_lamports = min_stake;
+    }
+
+    if let Some(program) = params.challenge_program {
+        config.challenge_program = program;
+    }
+
+    if let Some(idle) = params.gc_idle_secs {
+        // Shorter windows could collect agents with jobs or challenges still in flight
+        require!(
+            idle == 0 || idle >= AgentAccount::CLOSE_DELAY_SECS,
+            RegistryError::InvalidGcWindow
+        );
+        config.gc_idle_secs = idle;
+    }
+
+    if let Some(limit) = params.max_registrations_per_day {
+        config.max_registrations_per_day = limit;
+    }
+
+    if let Some(bond) = params.verification_bond_lamports {
+        config.verification_bond_lamports = bond;
+    }
+
+    if let Some(clean) = params.verification_bond_clean_secs {
+        require!(
+            (0..=RegistryConfig::MAX_STAKE_LOCK_SECS).contains(&clean),
+            RegistryError::InvalidStakeLockup
+        );
+        config.verification_bond_clean_secs = clean;
+    }
+
+    if let Some(policy) = params.reputation_transfer_policy {
+        config.reputation_transfer_policy = policy;
+    }
+
+    if let Some(haircut) = params.transfer_haircut_bps {
+        require!(
+            (haircut as u32) <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidTransferHaircut
+        );
+        config.transfer_haircut_bps = haircut;
+    }
+
+    msg!(
+        "Registry config updated: pass_weights={:?}, fail_weights={:?}, duration={}, escrow={} (usd_micros={}), reg_fee={} (usd_micros={}), allowlist_only={}, unique_models={}, token_gate=({}, min {}), referral={}bps, verification_fee={}, audit_fee={}, fee_discounts=(verified {}bps, reputation>={} {}bps), auto_verify=(stake>={}, reputation>={}), stake_lockup={}s, unstake_cooldown={}s, expiry={:?} (slash {}bps), quorum={}, decay={}bps/day after {}s, external_weight={}bps, cosign=({} above {}), emissions=({} from epoch {}, halving every {}, reputation>={}, stake>={}), challenge_program={}, gc_idle={}s, max_registrations_per_day={}, verification_bond={} (clean {}s), reputation_transfer={:?} (haircut {}bps)",
+        config.pass_weights_bps,
+        config.fail_weights_bps,
+        config.challenge_duration,
+        config.challenge_escrow_lamports,
+        config.challenge_escrow_usd,
+        config.registration_fee_lamports,
+        config.registration_fee_usd,
+        config.allowlist_only,
+        config.unique_models,
+        config.gate_mint,
+        config.gate_min_balance,
+        config.referral_share_bps,
+        config.verification_fee_lamports,
+        config.audit_fee_lamports,
+        config.verified_fee_discount_bps,
+        config.reputation_fee_discount_min,
+        config.reputation_fee_discount_bps,
+        config.auto_verify_min_stake_lamports,
+        config.auto_verify_min_reputation,
+        config.stake_lockup_secs,
+        config.unstake_cooldown_secs,
+        config.expiry_policy,
+        config.expiry_slash_bps,
+        config.judge_quorum,
+        config.reputation_decay_bps_per_day,
+        config.decay_grace_secs,
+        config.external_reputation_weight_bps,
+        config.reputation_cosigner,
+        config.cosign_delta_threshold,
+        config.emission_lamports_per_epoch,
+        config.emission_start_epoch,
+        config.emission_halving_epochs,
+        config.emission_min_reputation,
+        config.emission_min_stake_lamports,
+        config.challenge_program,
+        config.gc_idle_secs,
+        config.max_registrations_per_day,
+        config.verification_bond_lamports,
+        config.verification_bond_clean_secs,
+        config.reputation_transfer_policy,
+        config.transfer_haircut_bps
+    );
+
+    Ok(())
+}
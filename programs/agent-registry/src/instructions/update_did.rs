@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::AgentDid;
+use crate::error_context::{require_within, ErrorField};
+use crate::errors::RegistryError;
+
+/// Update an anchored DID document and optionally rotate its controller
+/// (current DID controller only)
+#[derive(Accounts)]
+pub struct UpdateDid<'info> {
+    pub controller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AgentDid::SEED_PREFIX, did.agent.as_ref()],
+        bump = did.bump,
+        constraint = did.controller == controller.key() @ RegistryError::Unauthorized
+    )]
+    pub did: Account<'info, AgentDid>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateDid>,
+    did_uri: Option<String>,
+    did_doc_hash: [u8; 32],
+    new_controller: Option<Pubkey>,
+) -> Result<()> {
+    let did = &mut ctx.accounts.did;
+
+    if let Some(uri) = did_uri {
+        require_within(ErrorField::Uri, uri.len(), AgentDid::MAX_URI_LEN, RegistryError::UriTooLong)?;
+        did.did_uri = uri;
+    }
+    if let Some(controller) = new_controller {
+        did.controller = controller;
+    }
+    did.did_doc_hash = did_doc_hash;
+    did.revision = did.revision.saturating_add(1);
+    did.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "DID updated: agent={}, revision={}, controller={}",
+        did.agent,
+        did.revision,
+        did.controller
+    );
+
+    Ok(())
+}
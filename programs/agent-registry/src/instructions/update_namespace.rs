@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::RegistryNamespace;
+use crate::errors::RegistryError;
+
+/// Update a namespace's membership policy or hand it to a new admin (namespace admin only)
+/// Policy changes apply to future joins; existing members stay tagged
+#[derive(Accounts)]
+pub struct UpdateNamespace<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RegistryNamespace::SEED_PREFIX, namespace.name_hash.as_ref()],
+        bump = namespace.bump,
+        constraint = namespace.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub namespace: Account<'info, RegistryNamespace>,
+}
+
+/// Namespace fields to change - `None` leaves the current value untouched
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateNamespaceParams {
+    pub admin: Option<Pubkey>,
+    pub join_fee_lamports: Option<u64>,
+    pub min_reputation: Option<u32>,
+    pub verified_only: Option<bool>,
+    pub approval_required: Option<bool>,
+}
+
+pub fn handler(ctx: Context<UpdateNamespace>, params: UpdateNamespaceParams) -> Result<()> {
+    let namespace = &mut ctx.accounts.namespace;
+
+    if let Some(admin) = params.admin {
+        namespace.admin = admin;
+    }
+
+    if let Some(fee) = params.join_fee_lamports {
+        namespace.join_fee_lamports = fee;
+    }
+
+    if let Some(min_reputation) = params.min_reputation {
+        namespace.min_reputation = min_reputation;
+    }
+
+    if let Some(verified_only) = params.verified_only {
+        namespace.verified_only = verified_only;
+    }
+
+    if let Some(approval_required) = params.approval_required {
+        namespace.approval_required = approval_required;
+    }
+
+    msg!(
+        "Namespace updated: {} admin={}, join_fee={}, min_reputation={}, verified_only={}, approval_required={}",
+        namespace.name,
+        namespace.admin,
+        namespace.join_fee_lamports,
+        namespace.min_reputation,
+        namespace.verified_only,
+        namespace.approval_required
+    );
+
+    Ok(())
+}
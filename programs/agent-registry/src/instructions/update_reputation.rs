@@ -1,29 +1,54 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, RegistryState};
+use crate::state::{
+    AgentAccount, AgentWatchers, RegistryConfig, RegistryState, ReputationAuthority,
+    ReputationHistory, ReputationHooks, ReputationSource,
+};
+use crate::constants::MAX_REPUTATION_DELTA;
 use crate::errors::RegistryError;
+use crate::events::ReputationChanged;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateReputation<'info> {
     /// Authority for reputation updates - SECURITY NOTICE
     ///
-    /// HACKATHON LIMITATION: Currently only admin can update reputation.
+    /// The registry admin, or an evaluation service whitelisted as a ReputationAuthority
+    /// (`set_reputation_authority`) and limited to its per-epoch delta budget.
     ///
     /// PRODUCTION REQUIREMENTS:
-    /// 1. Add a separate "authorized_verifiers" PDA to store allowed callers
-    /// 2. Allow challenge program PDAs to update reputation via CPI
-    /// 3. Consider time-locked updates or multi-sig for large reputation changes
-    /// 4. Implement rate limiting per agent to prevent reputation farming
+    /// 1. Challenge program PDAs update reputation via CPI (`update_reputation_by_program`)
+    /// 2. Consider time-locked updates for large reputation changes (co-signing is below)
+    /// 3. Implement rate limiting per agent to prevent reputation farming
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    /// Designated co-signer; required for deltas above the config's co-sign threshold
+    #[account(
+        constraint = cosigner.key() == config.reputation_cosigner @ RegistryError::InvalidCosigner
+    )]
+    pub cosigner: Option<Signer<'info>>,
+
     #[account(
         seeds = [RegistryState::SEED_PREFIX],
-        bump = registry.bump,
-        // Currently admin-only. For production, expand to include verified challenge programs
-        constraint = registry.admin == authority.key() @ RegistryError::Unauthorized
+        bump = registry.bump
     )]
     pub registry: Account<'info, RegistryState>,
 
+    /// Required unless the authority is the admin; charged for |delta|
+    #[account(
+        mut,
+        seeds = [ReputationAuthority::SEED_PREFIX, authority.key().as_ref()],
+        bump = reputation_authority.bump
+    )]
+    pub reputation_authority: Option<Account<'info, ReputationAuthority>>,
+
+    /// Registry config (reputation bounds, co-signing policy)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
     #[account(
         mut,
         seeds = [
@@ -31,41 +56,152 @@ pub struct UpdateReputation<'info> {
             agent.owner.as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
-        bump = agent.bump
+        bump = agent.bump,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
     )]
     pub agent: Account<'info, AgentAccount>,
+
+    /// Optional reputation history; the change is appended when passed
+    #[account(
+        mut,
+        constraint = history.load()?.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub history: Option<AccountLoader<'info, ReputationHistory>>,
+
+    /// CHECK: the agent's ReputationHooks PDA, which may not exist; once it does the
+    /// change is recorded there and delivered to hook programs passed as remaining accounts
+    #[account(
+        mut,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub hooks: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
 }
 
-pub fn handler(ctx: Context<UpdateReputation>, delta: i32) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateReputation<'info>>,
+    delta: i32,
+) -> Result<ReputationUpdateResponse> {
     // Limit reputation changes to prevent abuse
     require!(
-        delta.abs() <= 1000,
+        delta.abs() <= MAX_REPUTATION_DELTA,
         RegistryError::ReputationDeltaTooLarge
     );
+    require!(
+        !ctx.accounts.config.requires_cosign(delta) || ctx.accounts.cosigner.is_some(),
+        RegistryError::CosignerRequired
+    );
+
+    let clock = Clock::get()?;
+    // The challenge program uses `update_reputation_by_program` instead
+    let source = charge_authority(
+        &ctx.accounts.authority.key(),
+        &ctx.accounts.registry,
+        ctx.accounts.reputation_authority.as_deref_mut(),
+        delta,
+        clock.epoch,
+    )?;
 
     let agent = &mut ctx.accounts.agent;
     let old_reputation = agent.reputation_score;
 
-    // Update challenge counters based on delta
-    if delta > 0 {
-        agent.challenges_passed = agent.challenges_passed.saturating_add(1);
-    } else if delta < 0 {
-        agent.challenges_failed = agent.challenges_failed.saturating_add(1);
-    }
-
-    // Apply reputation change
-    agent.adjust_reputation(delta);
+    apply_reputation_delta(agent, &ctx.accounts.config, delta, clock.unix_timestamp);
+    agent.credit_reputation(source, old_reputation);
+    ReputationHistory::record(
+        ctx.accounts.history.as_ref(),
+        old_reputation,
+        agent.reputation_score,
+        source,
+        clock.unix_timestamp,
+    )?;
+    emit_cpi!(ReputationChanged {
+        agent: agent.key(),
+        agent_id: agent.agent_id,
+        old_score: old_reputation,
+        new_score: agent.reputation_score,
+        source: source as u8,
+        timestamp: clock.unix_timestamp,
+    });
 
-    let clock = Clock::get()?;
-    agent.updated_at = clock.unix_timestamp;
+    agent.touch(clock.unix_timestamp);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_REPUTATION)?;
 
     msg!(
-        "Reputation updated: agent={}, old={}, new={}, delta={}",
+        "Reputation updated: agent={}, old={}, new={}, delta={}, source={:?}",
         agent.agent_id,
         old_reputation,
         agent.reputation_score,
-        delta
+        delta,
+        source
     );
 
-    Ok(())
+    let response = ReputationUpdateResponse {
+        agent_id: agent.agent_id,
+        old_reputation,
+        new_reputation: agent.reputation_score,
+    };
+
+    ReputationHooks::notify_agent(
+        &ctx.accounts.hooks,
+        &ctx.accounts.agent,
+        ctx.remaining_accounts,
+        old_reputation,
+        clock.unix_timestamp,
+    )?;
+
+    Ok(response)
+}
+
+/// Count a non-zero delta as a pass or failure (counters, streaks, win rate), then
+/// apply it within the configured bounds
+/// Shared with `update_reputation_by_program`
+pub(crate) fn apply_reputation_delta(
+    agent: &mut AgentAccount,
+    config: &RegistryConfig,
+    delta: i32,
+    now: i64,
+) {
+    if delta > 0 {
+        agent.challenges_passed = agent.challenges_passed.saturating_add(1);
+        agent.update_stats(true);
+    } else if delta < 0 {
+        agent.challenges_failed = agent.challenges_failed.saturating_add(1);
+        agent.update_stats(false);
+    }
+    agent.adjust_reputation(delta, config, now);
+}
+
+/// Source of a manual reputation update by `authority`: the registry admin, or a
+/// ReputationAuthority whose epoch budget is charged for |delta|
+pub(crate) fn charge_authority(
+    authority: &Pubkey,
+    registry: &RegistryState,
+    reputation_authority: Option<&mut ReputationAuthority>,
+    delta: i32,
+    epoch: u64,
+) -> Result<ReputationSource> {
+    if *authority == registry.admin {
+        return Ok(ReputationSource::AdminManual);
+    }
+    reputation_authority
+        .ok_or(RegistryError::NotReputationAuthority)?
+        .charge(delta, epoch)?;
+    Ok(ReputationSource::Oracle)
+}
+
+/// Return data for `update_reputation` (readable by CPI callers and simulators)
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReputationUpdateResponse {
+    pub agent_id: u64,
+    pub old_reputation: u32,
+    pub new_reputation: u32,
 }
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, AgentWatchers, RegistryConfig, ReputationHistory, ReputationHooks,
+    ReputationSource,
+};
+use crate::constants::{CHALLENGE_AUTHORITY_SEED, MAX_REPUTATION_DELTA};
+use crate::errors::RegistryError;
+use crate::events::ReputationChanged;
+use super::update_reputation::{apply_reputation_delta, ReputationUpdateResponse};
+
+/// Reputation update signed by the companion challenge program rather than the admin
+/// The challenge program CPIs in and signs with its `[CHALLENGE_AUTHORITY_SEED]` PDA
+/// (see `interface::challenge_program`); no human key can produce that signature
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateReputationByProgram<'info> {
+    /// The challenge program's authority PDA
+    #[account(
+        seeds = [CHALLENGE_AUTHORITY_SEED],
+        bump,
+        seeds::program = config.challenge_program
+    )]
+    pub program_authority: Signer<'info>,
+
+    /// Registry config (reputation bounds, authorized challenge program)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.challenge_program != Pubkey::default() @ RegistryError::Unauthorized
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Optional reputation history; the change is appended when passed
+    #[account(
+        mut,
+        constraint = history.load()?.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub history: Option<AccountLoader<'info, ReputationHistory>>,
+
+    /// CHECK: the agent's ReputationHooks PDA, which may not exist; once it does the
+    /// change is recorded there and delivered to hook programs passed as remaining accounts
+    #[account(
+        mut,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub hooks: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateReputationByProgram<'info>>,
+    delta: i32,
+) -> Result<ReputationUpdateResponse> {
+    require!(
+        delta.abs() <= MAX_REPUTATION_DELTA,
+        RegistryError::ReputationDeltaTooLarge
+    );
+
+    let agent = &mut ctx.accounts.agent;
+    let old_reputation = agent.reputation_score;
+    let now = Clock::get()?.unix_timestamp;
+    apply_reputation_delta(agent, &ctx.accounts.config, delta, now);
+    agent.touch(now);
+
+    agent.credit_reputation(ReputationSource::ChallengeProgram, old_reputation);
+    ReputationHistory::record(
+        ctx.accounts.history.as_ref(),
+        old_reputation,
+        agent.reputation_score,
+        ReputationSource::ChallengeProgram,
+        now,
+    )?;
+    emit_cpi!(ReputationChanged {
+        agent: agent.key(),
+        agent_id: agent.agent_id,
+        old_score: old_reputation,
+        new_score: agent.reputation_score,
+        source: ReputationSource::ChallengeProgram as u8,
+        timestamp: now,
+    });
+    ReputationHooks::notify_agent(
+        &ctx.accounts.hooks,
+        agent,
+        ctx.remaining_accounts,
+        old_reputation,
+        now,
+    )?;
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_REPUTATION)?;
+
+    msg!(
+        "Reputation updated by challenge program: agent={}, old={}, new={}, delta={}",
+        agent.agent_id,
+        old_reputation,
+        agent.reputation_score,
+        delta
+    );
+
+    Ok(ReputationUpdateResponse {
+        agent_id: agent.agent_id,
+        old_reputation,
+        new_reputation: agent.reputation_score,
+    })
+}
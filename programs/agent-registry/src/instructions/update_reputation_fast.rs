@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentCounters, AgentWatchers, RegistryConfig, RegistryState, ReputationAuthority,
+    ReputationHistory, ReputationHooks,
+};
+use crate::constants::MAX_REPUTATION_DELTA;
+use crate::errors::RegistryError;
+use crate::events::ReputationChanged;
+use super::update_reputation::{apply_reputation_delta, charge_authority, ReputationUpdateResponse};
+
+/// Compute-optimized `update_reputation` for Active agents with AgentCounters
+/// The delta runs through the same reputation logic on a copy built from the zero-copy
+/// counters, and the result is patched into the agent in place instead of deserializing
+/// and rewriting it; PDA derivations are replaced by the counters binding.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateReputationFast<'info> {
+    /// Reputation authority: the registry admin or a ReputationAuthority, as for
+    /// `update_reputation`
+    pub authority: Signer<'info>,
+
+    /// Designated co-signer; required for deltas above the config's co-sign threshold
+    #[account(
+        constraint = cosigner.key() == config.reputation_cosigner @ RegistryError::InvalidCosigner
+    )]
+    pub cosigner: Option<Signer<'info>>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Required unless the authority is the admin; charged for |delta|
+    #[account(
+        mut,
+        seeds = [ReputationAuthority::SEED_PREFIX, authority.key().as_ref()],
+        bump = reputation_authority.bump
+    )]
+    pub reputation_authority: Option<Account<'info, ReputationAuthority>>,
+
+    /// Registry config (reputation bounds, co-signing policy)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: registry-owned AgentAccount, validated and patched by AgentCounters;
+    /// `init_agent_counters` bound the counters to this address
+    #[account(mut, owner = crate::ID)]
+    pub agent: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = counters.load()?.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub counters: AccountLoader<'info, AgentCounters>,
+
+    /// Optional reputation history; the change is appended when passed
+    #[account(
+        mut,
+        constraint = history.load()?.agent == agent.key() @ RegistryError::Unauthorized
+    )]
+    pub history: Option<AccountLoader<'info, ReputationHistory>>,
+
+    /// CHECK: the agent's ReputationHooks PDA, which may not exist; once it does the
+    /// change is recorded there and delivered to hook programs passed as remaining accounts
+    #[account(
+        mut,
+        seeds = [ReputationHooks::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub hooks: UncheckedAccount<'info>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateReputationFast<'info>>,
+    delta: i32,
+) -> Result<ReputationUpdateResponse> {
+    require!(
+        delta.abs() <= MAX_REPUTATION_DELTA,
+        RegistryError::ReputationDeltaTooLarge
+    );
+    require!(
+        !ctx.accounts.config.requires_cosign(delta) || ctx.accounts.cosigner.is_some(),
+        RegistryError::CosignerRequired
+    );
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let source = charge_authority(
+        &ctx.accounts.authority.key(),
+        &ctx.accounts.registry,
+        ctx.accounts.reputation_authority.as_deref_mut(),
+        delta,
+        clock.epoch,
+    )?;
+
+    let (agent_id, old_reputation, new_reputation) = {
+        let mut counters = ctx.accounts.counters.load_mut()?;
+        let mut data = ctx.accounts.agent.try_borrow_mut_data()?;
+        let offset = counters.check_agent(&data)?;
+
+        let mut agent = counters.to_agent();
+        let old_reputation = agent.reputation_score;
+        apply_reputation_delta(&mut agent, &ctx.accounts.config, delta, now);
+        agent.touch(now);
+        counters.record_update(&agent);
+        counters.write_through(&mut data, offset);
+        counters.credit_through(&mut data, source, old_reputation)?;
+        (agent.agent_id, old_reputation, agent.reputation_score)
+    };
+
+    ReputationHistory::record(
+        ctx.accounts.history.as_ref(),
+        old_reputation,
+        new_reputation,
+        source,
+        now,
+    )?;
+    emit_cpi!(ReputationChanged {
+        agent: ctx.accounts.agent.key(),
+        agent_id,
+        old_score: old_reputation,
+        new_score: new_reputation,
+        source: source as u8,
+        timestamp: now,
+    });
+
+    // The agent was patched in place, so hooks already read the new score
+    ReputationHooks::notify(
+        &ctx.accounts.hooks,
+        &ctx.accounts.agent,
+        ctx.remaining_accounts,
+        old_reputation,
+        new_reputation,
+        now,
+    )?;
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_REPUTATION)?;
+
+    msg!(
+        "Reputation updated: agent={}, old={}, new={}, delta={}, source={:?}",
+        agent_id,
+        old_reputation,
+        new_reputation,
+        delta,
+        source
+    );
+
+    Ok(ReputationUpdateResponse {
+        agent_id,
+        old_reputation,
+        new_reputation,
+    })
+}
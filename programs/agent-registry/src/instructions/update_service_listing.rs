@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, PricingModel, ServiceListing};
+use crate::errors::RegistryError;
+
+/// Change a service listing's pricing, endpoint or availability (owner only)
+#[derive(Accounts)]
+pub struct UpdateServiceListing<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.has_multisig() @ RegistryError::MultisigRequired,
+        constraint = agent.accepts_work(Clock::get()?.unix_timestamp) @ RegistryError::AgentNotActive
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            ServiceListing::SEED_PREFIX,
+            agent.key().as_ref(),
+            listing.listing_id.to_le_bytes().as_ref()
+        ],
+        bump = listing.bump
+    )]
+    pub listing: Account<'info, ServiceListing>,
+}
+
+/// Listing fields to change - `None` leaves the current value untouched
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateServiceListingParams {
+    pub capability_flags: Option<u64>,
+    pub pricing: Option<PricingModel>,
+    pub price_lamports: Option<u64>,
+    pub endpoint_hash: Option<[u8; 32]>,
+    pub active: Option<bool>,
+    /// Metering key for prepaid allowances (default pubkey = the owner)
+    pub meter: Option<Pubkey>,
+}
+
+pub fn handler(ctx: Context<UpdateServiceListing>, params: UpdateServiceListingParams) -> Result<()> {
+    let agent = &ctx.accounts.agent;
+    let listing = &mut ctx.accounts.listing;
+
+    if let Some(flags) = params.capability_flags {
+        require!(
+            flags != 0 && flags & !agent.capability_flags == 0,
+            RegistryError::InvalidServiceCapabilities
+        );
+        listing.capability_flags = flags;
+    }
+
+    if let Some(pricing) = params.pricing {
+        require!(ServiceListing::is_valid_pricing(&pricing), RegistryError::InvalidPricing);
+        listing.pricing = pricing;
+    }
+
+    if let Some(price) = params.price_lamports {
+        listing.price_lamports = price;
+    }
+
+    if let Some(endpoint_hash) = params.endpoint_hash {
+        listing.endpoint_hash = endpoint_hash;
+    }
+
+    if let Some(active) = params.active {
+        listing.active = active;
+    }
+
+    if let Some(meter) = params.meter {
+        listing.meter = meter;
+    }
+
+    listing.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Service listing updated: agent={}, listing={}, price={}, active={}",
+        agent.agent_id,
+        listing.listing_id,
+        listing.price_lamports,
+        listing.active
+    );
+
+    Ok(())
+}
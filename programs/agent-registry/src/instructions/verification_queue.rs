@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, RegistryState, VerificationQueue, VerificationRequest};
+use crate::errors::RegistryError;
+
+/// Create the priority verification queue (admin only, one-time)
+/// Once it exists, `request_verification` callers may queue their requests in it
+#[derive(Accounts)]
+pub struct InitVerificationQueue<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = VerificationQueue::SPACE,
+        seeds = [VerificationQueue::SEED_PREFIX],
+        bump
+    )]
+    pub queue: AccountLoader<'info, VerificationQueue>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Read-only view of the queue head
+#[derive(Accounts)]
+pub struct GetNextInQueue<'info> {
+    #[account(
+        seeds = [VerificationQueue::SEED_PREFIX],
+        bump = queue.load()?.bump
+    )]
+    pub queue: AccountLoader<'info, VerificationQueue>,
+}
+
+/// Drop a retired, banned or closed agent's request, which can no longer be processed
+/// and would otherwise hold its place at the head (permissionless)
+#[derive(Accounts)]
+pub struct PruneVerificationQueue<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VerificationQueue::SEED_PREFIX],
+        bump = queue.load()?.bump
+    )]
+    pub queue: AccountLoader<'info, VerificationQueue>,
+
+    /// CHECK: the queued agent; must be closed or no longer mutable (checked in handler)
+    pub agent: UncheckedAccount<'info>,
+}
+
+pub fn init_verification_queue(ctx: Context<InitVerificationQueue>) -> Result<()> {
+    let mut queue = ctx.accounts.queue.load_init()?;
+    queue.next_ticket = 1;
+    queue.bump = ctx.bumps.queue;
+
+    msg!("Verification queue created: capacity={}", VerificationQueue::CAPACITY);
+
+    Ok(())
+}
+
+pub fn prune_verification_queue(ctx: Context<PruneVerificationQueue>) -> Result<()> {
+    let agent_info = &ctx.accounts.agent;
+    if !agent_info.data_is_empty() {
+        require_keys_eq!(*agent_info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        let agent = AgentAccount::try_deserialize(&mut &agent_info.try_borrow_data()?[..])?;
+        require!(!agent.status.is_mutable(), RegistryError::QueuedAgentActive);
+    }
+
+    let mut queue = ctx.accounts.queue.load_mut()?;
+    let entry = queue.remove_agent(&agent_info.key())?;
+
+    msg!(
+        "Verification request pruned: agent={}, ticket={}, remaining={}",
+        entry.agent,
+        entry.ticket,
+        queue.len
+    );
+
+    Ok(())
+}
+
+/// Returns the request the admin must process next (zeroed when the queue is empty)
+pub fn next_in_queue(ctx: Context<GetNextInQueue>) -> Result<NextInQueueResponse> {
+    let queue = ctx.accounts.queue.load()?;
+    let now = Clock::get()?.unix_timestamp;
+
+    let Some(head) = queue.head(now) else {
+        return Ok(NextInQueueResponse::default());
+    };
+    Ok(NextInQueueResponse {
+        agent: head.agent,
+        request: Pubkey::find_program_address(
+            &[VerificationRequest::SEED_PREFIX, head.agent.as_ref()],
+            &crate::ID,
+        )
+        .0,
+        priority: head.priority,
+        ticket: head.ticket,
+        enqueued_at: head.enqueued_at,
+        overdue: head.is_overdue(now),
+        queue_len: queue.len,
+    })
+}
+
+/// Response struct for queue head queries
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct NextInQueueResponse {
+    pub agent: Pubkey,
+    pub request: Pubkey,
+    pub priority: u64,
+    pub ticket: u64,
+    pub enqueued_at: i64,
+    /// Waited past STARVATION_SECS, so it outranks higher-priority requests
+    pub overdue: bool,
+    pub queue_len: u32,
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, RegistryState};
+use crate::state::{AdminActionLog, AgentAccount, AgentWatchers, RegistryState};
 use crate::errors::RegistryError;
 
 #[derive(Accounts)]
@@ -14,6 +14,14 @@ pub struct VerifyAgent<'info> {
     )]
     pub registry: Account<'info, RegistryState>,
 
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
     #[account(
         mut,
         seeds = [
@@ -21,20 +29,43 @@ pub struct VerifyAgent<'info> {
             agent.owner.as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
-        bump = agent.bump
+        bump = agent.bump,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
     )]
     pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<VerifyAgent>) -> Result<()> {
     let agent = &mut ctx.accounts.agent;
 
-    require!(!agent.verified, RegistryError::AlreadyVerified);
+    require!(
+        agent.verification_level < AgentAccount::VERIFICATION_ADMIN,
+        RegistryError::AlreadyVerified
+    );
 
     agent.verified = true;
+    agent.verification_level = AgentAccount::VERIFICATION_ADMIN;
 
     let clock = Clock::get()?;
-    agent.updated_at = clock.unix_timestamp;
+    agent.touch(clock.unix_timestamp);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_VERIFICATION)?;
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_VERIFY,
+        ctx.accounts.admin.key(),
+        agent.key(),
+        agent.verification_level as u64,
+        clock.unix_timestamp,
+    )?;
 
     msg!("Agent verified: id={}, name={}", agent.agent_id, agent.name);
 
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::merkle::{verify_batch_proof, AuditLeaf};
+use crate::state::{ActionType, AgentAccount, MerkleAuditRoot};
+
+/// Check an audit entry's inclusion in a stored Merkle audit batch (view function)
+/// The leaf is rebuilt with `merkle::AuditLeaf`, so callers pass the entry, not a hash
+#[derive(Accounts)]
+pub struct VerifyAuditEntry<'info> {
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// The batch the entry is claimed to be in
+    #[account(
+        seeds = [
+            MerkleAuditRoot::SEED_PREFIX,
+            agent.key().as_ref(),
+            audit_root.batch_index.to_le_bytes().as_ref()
+        ],
+        bump = audit_root.bump
+    )]
+    pub audit_root: Account<'info, MerkleAuditRoot>,
+}
+
+/// An audit entry and its sibling path in the batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AuditEntryProof {
+    pub actor: Pubkey,
+    pub audit_index: u64,
+    pub action_type: ActionType,
+    pub risk_score: u8,
+    pub timestamp: i64,
+    /// SHA256 of the off-chain action details (raw digest)
+    pub details_hash: [u8; 32],
+    /// Position of the entry in the batch
+    pub leaf_index: u64,
+    /// Sibling path, bottom-up (exactly `merkle::batch_depth(entries_count)` long)
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl AuditEntryProof {
+    /// Canonical leaf for this entry under `agent`
+    pub fn leaf(&self, agent: &Pubkey) -> AuditLeaf {
+        AuditLeaf {
+            agent: agent.to_bytes(),
+            actor: self.actor.to_bytes(),
+            audit_index: self.audit_index,
+            action_type: self.action_type as u8,
+            risk_score: self.risk_score,
+            timestamp: self.timestamp,
+            details_hash: self.details_hash,
+        }
+    }
+}
+
+pub fn handler(ctx: Context<VerifyAuditEntry>, entry: AuditEntryProof) -> Result<AuditEntryVerification> {
+    let agent = &ctx.accounts.agent;
+    let root = &ctx.accounts.audit_root;
+    let leaf = entry.leaf(&agent.key()).hash();
+    let included = verify_batch_proof(
+        &root.merkle_root,
+        leaf,
+        entry.leaf_index,
+        root.entries_count as u64,
+        &entry.proof,
+    );
+
+    Ok(AuditEntryVerification {
+        leaf,
+        batch_index: root.batch_index,
+        included,
+        quarantined: agent.is_audit_quarantined(),
+    })
+}
+
+/// Response struct for audit entry verification
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AuditEntryVerification {
+    /// Canonical leaf hash of the entry
+    pub leaf: [u8; 32],
+    pub batch_index: u64,
+    /// Whether the proof places the leaf in the batch
+    pub included: bool,
+    /// The agent's audit history is quarantined; included entries are evidence only
+    pub quarantined: bool,
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentWatchers, Groth16Proof, ZkInference, ZkVerifier};
+use crate::errors::RegistryError;
+
+/// Record a Groth16-proven inference: the agent's committed model produced
+/// `output_commitment` (permissionless, payer funds the record)
+/// Public inputs: [ZkVerifier::model_commitment(agent.model_hash), output_commitment]
+#[derive(Accounts)]
+#[instruction(proof: Groth16Proof, output_commitment: [u8; 32])]
+pub struct VerifyInference<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.status.is_mutable() @ RegistryError::AgentRetired
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [ZkVerifier::SEED_PREFIX, verifier.circuit_id.to_le_bytes().as_ref()],
+        bump = verifier.bump,
+        constraint = verifier.active @ RegistryError::ZkVerifierInactive
+    )]
+    pub verifier: Box<Account<'info, ZkVerifier>>,
+
+    /// One record per (circuit, output) across all agents: the public inputs don't name the
+    /// agent, so agents sharing a model hash could otherwise each count the same proof
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ZkInference::INIT_SPACE,
+        seeds = [
+            ZkInference::SEED_PREFIX,
+            verifier.circuit_id.to_le_bytes().as_ref(),
+            output_commitment.as_ref()
+        ],
+        bump
+    )]
+    pub inference: Account<'info, ZkInference>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<VerifyInference>,
+    proof: Groth16Proof,
+    output_commitment: [u8; 32],
+) -> Result<()> {
+    let agent = &mut ctx.accounts.agent;
+    let model_commitment = ZkVerifier::model_commitment(&agent.model_hash);
+    require!(
+        ctx.accounts.verifier.verify(&proof, &[model_commitment, output_commitment]),
+        RegistryError::InvalidZkProof
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let verifier = &mut ctx.accounts.verifier;
+    verifier.verified_proofs = verifier.verified_proofs.saturating_add(1);
+
+    let inference = &mut ctx.accounts.inference;
+    inference.agent = agent.key();
+    inference.circuit_id = verifier.circuit_id;
+    inference.model_commitment = model_commitment;
+    inference.output_commitment = output_commitment;
+    inference.verified_at = now;
+    inference.bump = ctx.bumps.inference;
+
+    agent.zk_verified_inferences = agent.zk_verified_inferences.saturating_add(1);
+    agent.touch(now);
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_VERIFICATION)?;
+
+    msg!(
+        "Inference verified: id={}, circuit={}, total={}",
+        agent.agent_id,
+        verifier.circuit_id,
+        agent.zk_verified_inferences
+    );
+
+    Ok(())
+}
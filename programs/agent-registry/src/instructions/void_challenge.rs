@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{Challenge, ChallengeStatus};
+use crate::errors::RegistryError;
+
+/// Void a challenge whose flow was abandoned and refund the challenger (permissionless)
+/// Applies to judged challenges whose committee missed the VERDICT_WINDOW, and to
+/// expired pending challenges whose agent was closed (`expire_challenge` needs the agent).
+/// A challenger that missed the REVEAL_WINDOW gets nothing back: the challenge becomes
+/// Unrevealed and its escrow is left for the agent owner's `claim_challenge_escrow`.
+/// Reputation is untouched
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct VoidChallenge<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            challenge.agent.as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// CHECK: the challenged agent; only inspected for whether it still exists
+    #[account(address = challenge.agent @ RegistryError::ChallengeMismatch)]
+    pub agent: UncheckedAccount<'info>,
+
+    /// CHECK: The challenger - receives the escrow refund
+    #[account(mut, address = challenge.challenger @ RegistryError::ChallengeMismatch)]
+    pub challenger: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<VoidChallenge>, _nonce: u64) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let now = Clock::get()?.unix_timestamp;
+
+    if challenge.status == ChallengeStatus::AwaitingReveal {
+        require!(challenge.reveal_overdue(now), RegistryError::ChallengeNotVoidable);
+        challenge.status = ChallengeStatus::Unrevealed;
+        msg!(
+            "Challenge UNREVEALED: agent={}, escrow forfeited to the owner: {}",
+            challenge.agent,
+            challenge.escrow
+        );
+        return Ok(());
+    }
+
+    let voidable = match challenge.status {
+        ChallengeStatus::AwaitingVerdict => challenge.verdict_overdue(now),
+        ChallengeStatus::Pending => {
+            challenge.is_expired(now) && ctx.accounts.agent.data_is_empty()
+        }
+        _ => false,
+    };
+    require!(voidable, RegistryError::ChallengeNotVoidable);
+
+    let previous = challenge.status;
+    challenge.status = ChallengeStatus::Void;
+    let challenger_info = ctx.accounts.challenger.to_account_info();
+    let refunded = Challenge::release_escrow(challenge, &challenger_info)?;
+
+    msg!(
+        "Challenge VOIDED from {:?}: agent={}, escrow refunded: {}",
+        previous,
+        challenge.agent,
+        refunded
+    );
+
+    Ok(())
+}
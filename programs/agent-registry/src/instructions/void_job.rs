@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{Job, JobStatus};
+use crate::errors::RegistryError;
+
+/// Refund an unfinished job whose agent was closed (permissionless)
+/// `cancel_job`, `settle_job` and `resolve_job_dispute` need the agent, which
+/// `garbage_collect_agent` or `close_agent` may have removed with the job still in flight.
+/// The whole escrow goes back to the consumer; open jobs end Cancelled, the rest Refunded
+#[derive(Accounts)]
+pub struct VoidJob<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Job::SEED_PREFIX,
+            job.listing.as_ref(),
+            job.consumer.as_ref(),
+            job.job_id.to_le_bytes().as_ref()
+        ],
+        bump = job.bump
+    )]
+    pub job: Account<'info, Job>,
+
+    /// CHECK: the job's agent; only inspected for whether it still exists
+    #[account(address = job.agent @ RegistryError::Unauthorized)]
+    pub agent: UncheckedAccount<'info>,
+
+    /// CHECK: consumer wallet, receives the escrow refund
+    #[account(mut, address = job.consumer @ RegistryError::Unauthorized)]
+    pub consumer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<VoidJob>) -> Result<()> {
+    require!(ctx.accounts.agent.data_is_empty(), RegistryError::JobNotVoidable);
+
+    let job = &mut ctx.accounts.job;
+    let previous = job.status;
+    job.status = match previous {
+        JobStatus::Open => JobStatus::Cancelled,
+        JobStatus::Accepted | JobStatus::Delivered | JobStatus::Disputed => JobStatus::Refunded,
+        _ => return err!(RegistryError::InvalidJobStatus),
+    };
+    job.closed_at = Clock::get()?.unix_timestamp;
+    let refunded = Job::release_escrow(job, &ctx.accounts.consumer.to_account_info())?;
+
+    msg!(
+        "Job VOIDED from {:?}: job={}, agent={} closed, refunded={}",
+        previous,
+        job.job_id,
+        job.agent,
+        refunded
+    );
+
+    Ok(())
+}
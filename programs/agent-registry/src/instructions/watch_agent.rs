@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentWatchers};
+use crate::errors::RegistryError;
+
+/// Subscribe to (or re-scope a subscription to) an agent's changes (anyone)
+/// The first watcher funds the AgentWatchers account
+#[derive(Accounts)]
+pub struct WatchAgent<'info> {
+    #[account(mut)]
+    pub watcher: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = watcher,
+        space = 8 + AgentWatchers::INIT_SPACE,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: Account<'info, AgentWatchers>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Drop a subscription (the watcher, or the agent owner evicting one)
+#[derive(Accounts)]
+pub struct UnwatchAgent<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.owner.as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump = watchers.bump
+    )]
+    pub watchers: Account<'info, AgentWatchers>,
+}
+
+/// Acknowledge changes by clearing flags from the watcher's bitmap (the watcher)
+#[derive(Accounts)]
+pub struct ClearWatchFlags<'info> {
+    pub watcher: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, watchers.agent.as_ref()],
+        bump = watchers.bump
+    )]
+    pub watchers: Account<'info, AgentWatchers>,
+}
+
+pub fn watch_agent(ctx: Context<WatchAgent>, mask: u16) -> Result<()> {
+    require!(
+        mask != 0 && mask & !AgentWatchers::ALL_FLAGS == 0,
+        RegistryError::InvalidWatchMask
+    );
+
+    let watcher = ctx.accounts.watcher.key();
+    let watchers = &mut ctx.accounts.watchers;
+    watchers.watch(ctx.accounts.agent.key(), ctx.bumps.watchers, watcher, mask)?;
+
+    msg!(
+        "Watching agent: agent={}, watcher={}, mask={:#07b}, watchers={}",
+        ctx.accounts.agent.agent_id,
+        watcher,
+        mask,
+        watchers.slots.len()
+    );
+
+    Ok(())
+}
+
+pub fn unwatch_agent(ctx: Context<UnwatchAgent>, watcher: Pubkey) -> Result<()> {
+    let caller = ctx.accounts.caller.key();
+    require!(
+        caller == watcher || caller == ctx.accounts.agent.owner,
+        RegistryError::Unauthorized
+    );
+    require!(
+        ctx.accounts.watchers.unwatch(&watcher),
+        RegistryError::NotWatching
+    );
+
+    msg!(
+        "Unwatched agent: agent={}, watcher={}, by={}",
+        ctx.accounts.agent.agent_id,
+        watcher,
+        caller
+    );
+
+    Ok(())
+}
+
+pub fn clear_watch_flags(ctx: Context<ClearWatchFlags>, flags: u16) -> Result<()> {
+    let watcher = ctx.accounts.watcher.key();
+    let watchers = &mut ctx.accounts.watchers;
+    let cleared = watchers.clear(&watcher, flags)?;
+
+    msg!(
+        "Watch flags cleared: agent={}, watcher={}, cleared={:#07b}",
+        watchers.agent,
+        watcher,
+        cleared
+    );
+
+    Ok(())
+}
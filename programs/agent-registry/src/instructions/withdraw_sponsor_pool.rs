@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::SponsorPool;
+use crate::errors::RegistryError;
+
+/// Withdraw unspent lamports from a sponsorship pool (sponsor only)
+#[derive(Accounts)]
+pub struct WithdrawSponsorPool<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        seeds = [SponsorPool::SEED_PREFIX, sponsor.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.sponsor == sponsor.key() @ RegistryError::Unauthorized
+    )]
+    pub pool: Account<'info, SponsorPool>,
+
+    #[account(
+        mut,
+        seeds = [SponsorPool::VAULT_SEED, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<WithdrawSponsorPool>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.vault.lamports() >= amount,
+        RegistryError::InsufficientSponsorFunds
+    );
+
+    SponsorPool::pay_from_vault(
+        &ctx.accounts.pool,
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.sponsor.to_account_info(),
+        amount,
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    msg!(
+        "Sponsor pool withdrawal: sponsor={}, amount={}",
+        ctx.accounts.sponsor.key(),
+        amount
+    );
+
+    Ok(())
+}
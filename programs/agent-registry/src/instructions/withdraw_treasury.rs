@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{AdminActionLog, RegistryConfig, RegistryState};
+use crate::errors::RegistryError;
+
+/// Withdraw collected fees from the treasury PDA (admin only)
+/// Lamports owed to referrers and reserved for epoch emissions stay behind
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// Admin action log (this action is recorded)
+    #[account(
+        mut,
+        seeds = [AdminActionLog::SEED_PREFIX],
+        bump = admin_log.load()?.bump
+    )]
+    pub admin_log: AccountLoader<'info, AdminActionLog>,
+
+    /// Registry config (treasury liabilities)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [RegistryState::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// Where the withdrawn lamports go
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let free = ctx.accounts.config.treasury_free(&ctx.accounts.treasury)?;
+    require!(free >= amount, RegistryError::InsufficientTreasury);
+
+    let bump = [ctx.bumps.treasury];
+    let signer_seeds: &[&[&[u8]]] = &[&[RegistryState::TREASURY_SEED, &bump]];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.treasury.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+    AdminActionLog::record(
+        &ctx.accounts.admin_log,
+        AdminActionLog::ACTION_TREASURY_WITHDRAWAL,
+        ctx.accounts.admin.key(),
+        ctx.accounts.recipient.key(),
+        amount,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    msg!(
+        "Treasury withdrawal: {} lamports to {}",
+        amount,
+        ctx.accounts.recipient.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentWatchers, UnstakeRequest};
+use crate::errors::RegistryError;
+
+/// Pay out a pending unstake once its cooldown has passed (owner only)
+/// The request is closed and its rent returned to the owner
+#[derive(Accounts)]
+pub struct WithdrawUnstake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [UnstakeRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump = unstake_request.bump
+    )]
+    pub unstake_request: Account<'info, UnstakeRequest>,
+
+    /// CHECK: the agent's AgentWatchers PDA, which may not exist; once it does its
+    /// subscribers' change flags are set
+    #[account(
+        mut,
+        seeds = [AgentWatchers::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub watchers: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<WithdrawUnstake>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let request = &ctx.accounts.unstake_request;
+    require!(now >= request.available_at, RegistryError::UnstakeCooldownActive);
+
+    let amount = request.amount;
+    let agent = &mut ctx.accounts.agent;
+    agent.touch(now);
+
+    // Agent PDA is program-owned, so lamports can be moved directly
+    let agent_info = agent.to_account_info();
+    **agent_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += amount;
+    // The request is paid out in full, so nothing stays pending
+    agent.require_rent_exempt(&agent_info, 0)?;
+    AgentWatchers::notify(&ctx.accounts.watchers, AgentWatchers::FLAG_STAKE)?;
+
+    msg!(
+        "Unstake withdrawn: id={}, amount={}",
+        agent.agent_id,
+        amount
+    );
+
+    Ok(())
+}
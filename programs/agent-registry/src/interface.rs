@@ -0,0 +1,296 @@
+//! Stable integration interface for other Anchor programs
+//!
+//! Enable the `cpi` feature and depend on this crate to call the registry via CPI
+//! or gate your own instructions on an agent's standing, without vendoring the source:
+//!
+//! ```ignore
+//! let score = agent_registry::interface::update_reputation(
+//!     ctx.accounts.registry_program.to_account_info(),
+//!     agent_registry::interface::UpdateReputationAccounts {
+//!         authority,
+//!         cosigner: None,
+//!         registry,
+//!         reputation_authority: None,
+//!         config,
+//!         agent,
+//!         history: None,
+//!         // the agent's ReputationHooks PDA, whether or not it exists yet
+//!         hooks,
+//!         // the agent's AgentWatchers PDA of [b"watchers", agent], likewise
+//!         watchers,
+//!         // emit_cpi! accounts: PDA of [b"__event_authority"] and the registry program itself
+//!         event_authority,
+//!         program,
+//!     },
+//!     delta,
+//!     &[],
+//! )?
+//! .new_reputation;
+//! ```
+//!
+//! To read registry accounts passed to your program, use `try_deserialize_agent` /
+//! `try_deserialize_config` instead of decoding the raw bytes yourself.
+
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+pub use crate::cpi::accounts::UpdateReputation as UpdateReputationAccounts;
+pub use crate::instructions::register_agent::RegisterAgentResponse;
+pub use crate::instructions::update_reputation::ReputationUpdateResponse;
+pub use crate::state::{AgentAccount, ChallengeType, RegistryConfig, RegistryState, ReputationSource};
+pub use crate::ID as PROGRAM_ID;
+
+/// Registry state PDA
+pub fn registry_address() -> Pubkey {
+    Pubkey::find_program_address(&[RegistryState::SEED_PREFIX], &crate::ID).0
+}
+
+/// Registry config PDA
+pub fn config_address() -> Pubkey {
+    Pubkey::find_program_address(&[RegistryConfig::SEED_PREFIX], &crate::ID).0
+}
+
+/// Agent PDA for (owner, agent_id)
+pub fn agent_address(owner: &Pubkey, agent_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[AgentAccount::SEED_PREFIX, owner.as_ref(), &agent_id.to_le_bytes()],
+        &crate::ID,
+    )
+    .0
+}
+
+/// Read an agent account owned by this program, checking the owner program, the
+/// discriminator and that it is at the current layout version
+/// Accounts awaiting `migrate_agent` fail with `AgentNeedsMigration` rather than
+/// being misread under the new layout
+pub fn try_deserialize_agent(info: &AccountInfo) -> Result<AgentAccount> {
+    require_keys_eq!(*info.owner, crate::ID, RegistryError::AgentNotFound);
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && &data[..8] == AgentAccount::DISCRIMINATOR,
+        RegistryError::AgentNotFound
+    );
+
+    // Older layouts are shorter or ordered differently, so a decode failure past a
+    // matching discriminator means the account hasn't been migrated yet
+    let agent = AgentAccount::try_deserialize(&mut &data[..])
+        .map_err(|_| error!(RegistryError::AgentNeedsMigration))?;
+    require!(
+        agent.version == AgentAccount::CURRENT_VERSION,
+        RegistryError::AgentNeedsMigration
+    );
+    Ok(agent)
+}
+
+/// Read the registry config, checking the owner program, the discriminator and that it
+/// is at the layout version this crate was built against
+pub fn try_deserialize_config(info: &AccountInfo) -> Result<RegistryConfig> {
+    require_keys_eq!(*info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && &data[..8] == RegistryConfig::DISCRIMINATOR,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    // As for agents, a decode failure past a matching discriminator is a layout mismatch
+    let config = RegistryConfig::try_deserialize(&mut &data[..])
+        .map_err(|_| error!(RegistryError::ConfigVersionMismatch))?;
+    require!(
+        config.version == RegistryConfig::CURRENT_VERSION,
+        RegistryError::ConfigVersionMismatch
+    );
+    Ok(config)
+}
+
+/// CPI into `update_reputation`, returning the post-update score
+/// Pass `signer_seeds` when the authority is a PDA of the calling program
+pub fn update_reputation<'info>(
+    registry_program: AccountInfo<'info>,
+    accounts: UpdateReputationAccounts<'info>,
+    delta: i32,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<ReputationUpdateResponse> {
+    let result = crate::cpi::update_reputation(
+        CpiContext::new_with_signer(registry_program, accounts, signer_seeds),
+        delta,
+    )?;
+    Ok(result.get())
+}
+
+/// CPI surface for the companion challenge program
+///
+/// The registry admin names the program in `RegistryConfig::challenge_program`; from
+/// then on the program itself is the reputation authority, signing with its
+/// `[CHALLENGE_AUTHORITY_SEED]` PDA:
+///
+/// ```ignore
+/// use agent_registry::interface::challenge_program;
+///
+/// let (_, bump) = challenge_program::authority_address(&crate::ID);
+/// challenge_program::update_reputation_cpi(
+///     ctx.accounts.registry_program.to_account_info(),
+///     challenge_program::UpdateReputationByProgramAccounts {
+///         program_authority,
+///         config,
+///         agent,
+///         history: None,
+///         hooks,
+///         watchers,
+///         event_authority,
+///         program,
+///     },
+///     delta,
+///     bump,
+/// )?;
+/// ```
+pub mod challenge_program {
+    use anchor_lang::prelude::*;
+
+    pub use crate::constants::CHALLENGE_AUTHORITY_SEED;
+    pub use crate::cpi::accounts::UpdateReputationByProgram as UpdateReputationByProgramAccounts;
+    use super::ReputationUpdateResponse;
+
+    /// The challenge program's signing PDA and bump
+    pub fn authority_address(challenge_program: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[CHALLENGE_AUTHORITY_SEED], challenge_program)
+    }
+
+    /// CPI into `update_reputation_by_program`, signed by the calling program's
+    /// authority PDA (`authority_bump` from `authority_address`)
+    pub fn update_reputation_cpi<'info>(
+        registry_program: AccountInfo<'info>,
+        accounts: UpdateReputationByProgramAccounts<'info>,
+        delta: i32,
+        authority_bump: u8,
+    ) -> Result<ReputationUpdateResponse> {
+        let seeds: &[&[u8]] = &[CHALLENGE_AUTHORITY_SEED, &[authority_bump]];
+        let result = crate::cpi::update_reputation_by_program(
+            CpiContext::new_with_signer(registry_program, accounts, &[seeds]),
+            delta,
+        )?;
+        Ok(result.get())
+    }
+}
+
+/// CPI surface for programs reacting to reputation changes
+///
+/// A hook program subscribes itself to an agent, signing with its `[HOOK_AUTHORITY_SEED]`
+/// PDA, and implements an Anchor `on_reputation_change(ReputationHookArgs)` instruction
+/// that takes the agent account read-only:
+///
+/// ```ignore
+/// use agent_registry::interface::reputation_hooks;
+///
+/// let (_, bump) = reputation_hooks::authority_address(&crate::ID);
+/// reputation_hooks::register_cpi(
+///     ctx.accounts.registry_program.to_account_info(),
+///     reputation_hooks::RegisterReputationHookAccounts {
+///         payer,
+///         hook_program,
+///         hook_authority,
+///         agent,
+///         hooks,
+///         system_program,
+///     },
+///     bump,
+/// )?;
+/// ```
+///
+/// Changes the updating transaction didn't deliver (see `ReputationHooks::notify`) are
+/// picked up with the permissionless `dispatch_reputation_hook`
+pub mod reputation_hooks {
+    use anchor_lang::prelude::*;
+
+    pub use crate::constants::HOOK_AUTHORITY_SEED;
+    pub use crate::cpi::accounts::RegisterReputationHook as RegisterReputationHookAccounts;
+    pub use crate::cpi::accounts::RemoveReputationHook as RemoveReputationHookAccounts;
+    pub use crate::state::ReputationHookArgs;
+
+    /// The hook program's signing PDA and bump
+    pub fn authority_address(hook_program: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[HOOK_AUTHORITY_SEED], hook_program)
+    }
+
+    /// CPI into `register_reputation_hook`, signed by the calling program's authority
+    /// PDA (`authority_bump` from `authority_address`)
+    pub fn register_cpi<'info>(
+        registry_program: AccountInfo<'info>,
+        accounts: RegisterReputationHookAccounts<'info>,
+        authority_bump: u8,
+    ) -> Result<()> {
+        let seeds: &[&[u8]] = &[HOOK_AUTHORITY_SEED, &[authority_bump]];
+        crate::cpi::register_reputation_hook(CpiContext::new_with_signer(
+            registry_program,
+            accounts,
+            &[seeds],
+        ))
+    }
+
+    /// CPI into `remove_reputation_hook` for the calling program, signed as for
+    /// `register_cpi`
+    pub fn remove_cpi<'info>(
+        registry_program: AccountInfo<'info>,
+        accounts: RemoveReputationHookAccounts<'info>,
+        hook_program: Pubkey,
+        authority_bump: u8,
+    ) -> Result<()> {
+        let seeds: &[&[u8]] = &[HOOK_AUTHORITY_SEED, &[authority_bump]];
+        crate::cpi::remove_reputation_hook(
+            CpiContext::new_with_signer(registry_program, accounts, &[seeds]),
+            hook_program,
+        )
+    }
+}
+
+/// The agent fields integrators typically gate on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AgentConstraints {
+    pub agent_id: u64,
+    pub owner: Pubkey,
+    pub reputation_score: u32,
+    pub verified: bool,
+    pub verification_level: u8,
+    pub challenges_passed: u32,
+    pub challenges_failed: u32,
+    pub staked_lamports: u64,
+    /// Net reputation per `ReputationSource`, e.g. to discount `AdminManual` gains
+    pub reputation_by_source: [i64; ReputationSource::COUNT],
+}
+
+impl From<&AgentAccount> for AgentConstraints {
+    fn from(agent: &AgentAccount) -> Self {
+        Self {
+            agent_id: agent.agent_id,
+            owner: agent.owner,
+            reputation_score: agent.reputation_score,
+            verified: agent.verified,
+            verification_level: agent.verification_level,
+            challenges_passed: agent.challenges_passed,
+            challenges_failed: agent.challenges_failed,
+            staked_lamports: agent.staked_lamports,
+            reputation_by_source: agent.reputation_by_source,
+        }
+    }
+}
+
+impl AgentConstraints {
+    /// Read constraints from a registry agent account (see `try_deserialize_agent`)
+    pub fn load(info: &AccountInfo) -> Result<Self> {
+        Ok(Self::from(&try_deserialize_agent(info)?))
+    }
+
+    /// Require a minimum reputation and, optionally, admin verification (auto-verified
+    /// `VERIFICATION_BASE` agents don't qualify)
+    pub fn require(&self, min_reputation: u32, require_verified: bool) -> Result<()> {
+        require!(
+            self.reputation_score >= min_reputation,
+            RegistryError::AgentConstraintNotMet
+        );
+        require!(
+            !require_verified
+                || (self.verified && self.verification_level == AgentAccount::VERIFICATION_ADMIN),
+            RegistryError::AgentConstraintNotMet
+        );
+        Ok(())
+    }
+}
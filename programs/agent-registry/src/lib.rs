@@ -1,8 +1,16 @@
 use anchor_lang::prelude::*;
 
+pub mod constants;
 pub mod instructions;
 pub mod state;
 pub mod errors;
+pub mod error_context;
+pub mod events;
+pub mod validation;
+pub mod merkle;
+
+#[cfg(feature = "cpi")]
+pub mod interface;
 
 use instructions::*;
 
@@ -12,9 +20,10 @@ declare_id!("EQ2Zv3cTDBzY1PafPz2WDoup6niUv6X8t9id4PBACL38");
 pub mod agent_registry {
     use super::*;
 
-    /// Initialize the global registry state
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        instructions::initialize::handler(ctx)
+    /// Bootstrap the registry: state, config (defaults plus overrides), admin log and
+    /// optional initial moderator / attester roles, atomically (upgrade authority only, one-time)
+    pub fn initialize(ctx: Context<Initialize>, params: InitializeParams) -> Result<()> {
+        instructions::initialize::handler(ctx, params)
     }
 
     /// Set the NFT collection address for agent identities (admin only, one-time)
@@ -25,16 +34,31 @@ pub mod agent_registry {
 
     /// Register a new AI agent with identity NFT reference
     /// The NFT should be created off-chain first using Metaplex SDK
+    /// Charges the configured registration fee (USD fees priced via Pyth)
+    /// An optional referrer stats account accrues a share of the fee
+    /// Returns the new agent's address and ID as return data
     pub fn register_agent(
         ctx: Context<RegisterAgent>,
         name: String,
         model_hash: String,
         capabilities: String,
-    ) -> Result<()> {
+    ) -> Result<instructions::register_agent::RegisterAgentResponse> {
         instructions::register_agent::handler(ctx, name, model_hash, capabilities)
     }
 
+    /// Register a derivative of an existing agent, recording parent and parent model hash
+    /// Charges the registration fee; increments the parent's fork count
+    pub fn fork_agent(
+        ctx: Context<ForkAgent>,
+        name: String,
+        model_hash: String,
+        capabilities: String,
+    ) -> Result<instructions::register_agent::RegisterAgentResponse> {
+        instructions::fork_agent::handler(ctx, name, model_hash, capabilities)
+    }
+
     /// Update an agent's metadata
+    /// Grows the account (owner-funded) when longer metadata no longer fits
     pub fn update_agent(
         ctx: Context<UpdateAgent>,
         name: Option<String>,
@@ -43,30 +67,322 @@ pub mod agent_registry {
         instructions::update_agent::handler(ctx, name, capabilities)
     }
 
+    /// Relayer-submitted metadata update authorized by an owner ed25519 signature
+    /// Requires a preceding ed25519 program instruction and the agent's current nonce
+    pub fn update_agent_with_signature(
+        ctx: Context<UpdateAgentWithSignature>,
+        name: Option<String>,
+        capabilities: Option<String>,
+        nonce: u64,
+    ) -> Result<()> {
+        instructions::update_agent_with_signature::handler(ctx, name, capabilities, nonce)
+    }
+
+    /// Create the registry config with default scoring parameters (admin only, one-time)
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        instructions::initialize_config::handler(ctx)
+    }
+
+    /// Create the admin action log (admin only, one-time)
+    /// Verifications, moderation, config changes, moderator grants and treasury
+    /// withdrawals all require it and append an entry
+    pub fn init_admin_log(ctx: Context<InitAdminLog>) -> Result<()> {
+        instructions::init_admin_log::handler(ctx)
+    }
+
+    /// Update registry config parameters (admin only)
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        params: UpdateConfigParams,
+    ) -> Result<()> {
+        instructions::update_config::handler(ctx, params)
+    }
+
+    /// Change the reputation floor and ceiling, e.g. 0-100 instead of 0-10000 (admin only)
+    /// New agents start at the midpoint; existing scores are clamped on their next change
+    pub fn set_reputation_bounds(ctx: Context<SetReputationBounds>, floor: u32, ceiling: u32) -> Result<()> {
+        instructions::set_reputation_bounds::handler(ctx, floor, ceiling)
+    }
+
+    /// Opt in as a referrer (creates the referrer's stats account)
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::register_referrer::handler(ctx)
+    }
+
+    /// Claim accrued referral rewards from the treasury
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+        instructions::claim_referral_rewards::handler(ctx)
+    }
+
+    /// Withdraw collected registry fees from the treasury (admin only)
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::withdraw_treasury::handler(ctx, amount)
+    }
+
     /// Verify an agent (admin only)
     pub fn verify_agent(ctx: Context<VerifyAgent>) -> Result<()> {
         instructions::verify_agent::handler(ctx)
     }
 
-    /// Update agent reputation (called by challenge program)
-    pub fn update_reputation(
-        ctx: Context<UpdateReputation>,
-        delta: i32,
+    /// Pay the verification fee and queue the agent for admin review (owner only)
+    /// A rejected or revoked request may be re-submitted; each attempt pays the fee
+    /// The configured verification bond is escrowed in the request account
+    /// Passing the verification queue orders the request by stake plus fee
+    pub fn request_verification(ctx: Context<RequestVerification>) -> Result<()> {
+        instructions::request_verification::handler(ctx)
+    }
+
+    /// Approve a pending verification request and mark the agent verified (admin only)
+    /// Queued requests must be the queue head
+    pub fn approve_verification(ctx: Context<ProcessVerification>, reason: String) -> Result<()> {
+        instructions::process_verification::approve_verification(ctx, reason)
+    }
+
+    /// Reject a pending verification request with a reason (admin only)
+    pub fn reject_verification(ctx: Context<ProcessVerification>, reason: String) -> Result<()> {
+        instructions::process_verification::reject_verification(ctx, reason)
+    }
+
+    /// Return an approved request's verification bond after its clean period (anyone may submit)
+    pub fn release_verification_bond(ctx: Context<ReleaseVerificationBond>) -> Result<()> {
+        instructions::process_verification::release_verification_bond(ctx)
+    }
+
+    /// Revoke an approved verification (admin only)
+    /// With `fraud`, a bond still in its clean period is forfeited to the treasury
+    pub fn revoke_verification(ctx: Context<RevokeVerification>, fraud: bool, reason: String) -> Result<()> {
+        instructions::process_verification::revoke_verification(ctx, fraud, reason)
+    }
+
+    /// Create the stake-ordered verification queue (admin only, one-time)
+    pub fn init_verification_queue(ctx: Context<InitVerificationQueue>) -> Result<()> {
+        instructions::verification_queue::init_verification_queue(ctx)
+    }
+
+    /// Drop a retired, banned or closed agent's queued request (permissionless)
+    pub fn prune_verification_queue(ctx: Context<PruneVerificationQueue>) -> Result<()> {
+        instructions::verification_queue::prune_verification_queue(ctx)
+    }
+
+    /// Get the verification request to process next (view function)
+    pub fn next_in_queue(
+        ctx: Context<GetNextInQueue>,
+    ) -> Result<instructions::verification_queue::NextInQueueResponse> {
+        instructions::verification_queue::next_in_queue(ctx)
+    }
+
+    /// Register, update or deactivate a TEE verifier / auditor (admin only)
+    pub fn set_attester(
+        ctx: Context<SetAttester>,
+        attester_authority: Pubkey,
+        kind: state::AttestationKind,
+        active: bool,
     ) -> Result<()> {
+        instructions::set_attester::handler(ctx, attester_authority, kind, active)
+    }
+
+    /// Attest an agent's current model (active attesters only, renewable)
+    pub fn attest_agent(ctx: Context<AttestAgent>, report_hash: [u8; 32], expires_at: i64) -> Result<()> {
+        instructions::attest_agent::handler(ctx, report_hash, expires_at)
+    }
+
+    /// Grant base verification once stake, reputation and a valid attestation
+    /// meet the configured thresholds (permissionless)
+    pub fn auto_verify(ctx: Context<AutoVerify>) -> Result<()> {
+        instructions::auto_verify::handler(ctx)
+    }
+
+    /// Update agent reputation (admin, or a whitelisted reputation authority within its
+    /// per-epoch budget)
+    /// Returns the post-update score as return data
+    /// Programs registered as reputation hooks are notified (see `ReputationHooks::notify`)
+    pub fn update_reputation<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateReputation<'info>>,
+        delta: i32,
+    ) -> Result<instructions::update_reputation::ReputationUpdateResponse> {
         instructions::update_reputation::handler(ctx, delta)
     }
 
+    /// Compute-optimized reputation update for Active agents (admin or reputation authority)
+    /// Patches the agent in place from its AgentCounters
+    pub fn update_reputation_fast<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateReputationFast<'info>>,
+        delta: i32,
+    ) -> Result<instructions::update_reputation::ReputationUpdateResponse> {
+        instructions::update_reputation_fast::handler(ctx, delta)
+    }
+
+    /// Reputation update from the companion challenge program (CPI signed by its
+    /// authority PDA; see `interface::challenge_program`)
+    pub fn update_reputation_by_program<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateReputationByProgram<'info>>,
+        delta: i32,
+    ) -> Result<instructions::update_reputation::ReputationUpdateResponse> {
+        instructions::update_reputation_by_program::handler(ctx, delta)
+    }
+
+    /// Stake lamports behind an agent (owner only)
+    pub fn stake_agent(ctx: Context<StakeAgent>, amount: u64) -> Result<()> {
+        instructions::stake_agent::handler(ctx, amount)
+    }
+
+    /// Withdraw staked lamports back to the owner (owner only, no unstake cooldown configured)
+    pub fn unstake_agent(ctx: Context<UnstakeAgent>, amount: u64) -> Result<()> {
+        instructions::unstake_agent::handler(ctx, amount)
+    }
+
+    /// Start the unstake cooldown for part of an agent's stake (owner only)
+    /// The amount stays in the agent PDA, and slashable (`AgentAccount::slash_stake`, on
+    /// `expire_challenge`), until `withdraw_unstake`
+    pub fn request_unstake(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+        instructions::request_unstake::handler(ctx, amount)
+    }
+
+    /// Pay out a pending unstake once the cooldown has passed (owner only)
+    pub fn withdraw_unstake(ctx: Context<WithdrawUnstake>) -> Result<()> {
+        instructions::withdraw_unstake::handler(ctx)
+    }
+
+    /// Open the current epoch's rewards, reserving its emission in the treasury
+    /// Permissionless crank - once per epoch, before its snapshots
+    pub fn open_epoch_rewards(ctx: Context<OpenEpochRewards>) -> Result<()> {
+        instructions::open_epoch_rewards::handler(ctx)
+    }
+
+    /// Capture an agent's reputation, challenge counts and stake for the current epoch
+    /// Permissionless crank - one snapshot per agent per epoch, at the epoch boundary
+    pub fn snapshot_agent_epoch(ctx: Context<SnapshotAgentEpoch>) -> Result<()> {
+        instructions::snapshot_agent_epoch::handler(ctx)
+    }
+
+    /// Pay an agent's share of a finished epoch's emission from the treasury (owner only)
+    /// Staked, verified snapshots share the emission in proportion to their reputation
+    pub fn claim_epoch_rewards(ctx: Context<ClaimEpochRewards>, epoch: u64) -> Result<()> {
+        instructions::claim_epoch_rewards::handler(ctx, epoch)
+    }
+
+    /// Free an epoch's unclaimed emission from the treasury liabilities once its claim
+    /// window has closed (permissionless crank)
+    pub fn release_epoch_rewards(ctx: Context<ReleaseEpochRewards>, epoch: u64) -> Result<()> {
+        instructions::release_epoch_rewards::handler(ctx, epoch)
+    }
+
+    /// Commit a Merkle root over all agents' (pubkey, reputation, verified) standings (admin only)
+    /// Lets light clients and other chains verify an agent with one proof
+    pub fn commit_registry_snapshot(
+        ctx: Context<CommitRegistrySnapshot>,
+        merkle_root: [u8; 32],
+        agent_count: u64,
+        slot: u64,
+    ) -> Result<()> {
+        instructions::commit_registry_snapshot::handler(ctx, merkle_root, agent_count, slot)
+    }
+
+    /// Upgrade an agent account created under an older layout version
+    /// Reallocs to the current size (payer tops up rent) and bumps `version`
+    pub fn migrate_agent(ctx: Context<MigrateAgent>) -> Result<()> {
+        instructions::migrate_agent::handler(ctx)
+    }
+
+    /// Get an agent's reputation after inactivity decay (view function)
+    pub fn get_effective_reputation(
+        ctx: Context<GetAgentView>,
+    ) -> Result<instructions::agent_views::EffectiveReputationResponse> {
+        instructions::agent_views::get_effective_reputation(ctx)
+    }
+
+    /// Get an agent's raw and type-weighted challenge win rate (view function)
+    pub fn get_win_rate(
+        ctx: Context<GetAgentView>,
+    ) -> Result<instructions::agent_views::WinRateResponse> {
+        instructions::agent_views::get_win_rate(ctx)
+    }
+
+    /// Create an agent's reputation history ring buffer (permissionless, payer funds rent)
+    /// Reputation-changing instructions append to it when it is passed in
+    pub fn init_reputation_history(ctx: Context<InitReputationHistory>) -> Result<()> {
+        instructions::init_reputation_history::handler(ctx)
+    }
+
+    /// Create an agent's hot counters for `update_reputation_fast` (permissionless, payer funds rent)
+    pub fn init_agent_counters(ctx: Context<InitAgentCounters>) -> Result<()> {
+        instructions::init_agent_counters::handler(ctx)
+    }
+
+    /// Re-copy an agent's hot counters after other writes to the agent (permissionless)
+    pub fn sync_agent_counters(ctx: Context<SyncAgentCounters>) -> Result<()> {
+        instructions::sync_agent_counters::handler(ctx)
+    }
+
+    /// Get an agent's decayed reputation blended with imported external scores (view function)
+    pub fn get_composite_reputation(
+        ctx: Context<GetCompositeReputation>,
+    ) -> Result<instructions::agent_views::CompositeReputationResponse> {
+        instructions::agent_views::get_composite_reputation(ctx)
+    }
+
+    /// Get (pubkey, effective reputation, tier, status) for up to 26 agents passed as remaining
+    /// accounts, packed into return data (view function)
+    pub fn get_agents_summary(
+        ctx: Context<GetAgentsSummary>,
+    ) -> Result<Vec<instructions::agent_views::AgentSummary>> {
+        instructions::agent_views::get_agents_summary(ctx)
+    }
+
+    /// Get an agent's effective reputation, tier, win rate and fees under the current config
+    /// and under the config `params` would produce, without applying it (view function)
+    pub fn simulate_policy(
+        ctx: Context<GetAgentView>,
+        params: UpdateConfigParams,
+    ) -> Result<instructions::agent_views::PolicySimulation> {
+        instructions::agent_views::simulate_policy(ctx, params)
+    }
+
     /// Create a new challenge for an agent (nonce enables multiple challenges per pair)
+    /// The challenge type selects the reputation weight applied on resolution
+    /// Judged challenges are resolved by the judge committee instead of hash match
+    /// Other challenges commit to the answer (`Challenge::commit_answer`) and the
+    /// challenger reveals it after the response
+    /// Pass a ChallengeTemplate to run a standardized test with comparable scoring
     pub fn create_challenge(
         ctx: Context<CreateChallenge>,
         question: String,
-        expected_hash: String,
+        answer_commitment: String,
         nonce: u64,
+        challenge_type: state::ChallengeType,
+        judged: bool,
     ) -> Result<()> {
-        instructions::create_challenge::handler(ctx, question, expected_hash, nonce)
+        instructions::create_challenge::handler(
+            ctx,
+            question,
+            answer_commitment,
+            nonce,
+            challenge_type,
+            judged,
+        )
     }
 
-    /// Submit a response to a challenge (verifies and updates reputation)
+    /// Publish a reusable challenge spec keyed by its hash (anyone; curated when the admin
+    /// publishes). Challenges created from it share its type, judging mode, minimum stake,
+    /// scoring weights and judge set (empty = any committee judge)
+    pub fn create_challenge_template(
+        ctx: Context<CreateChallengeTemplate>,
+        spec_hash: [u8; 32],
+        params: ChallengeTemplateParams,
+    ) -> Result<()> {
+        instructions::challenge_template::create_challenge_template(ctx, spec_hash, params)
+    }
+
+    /// Open or retire a challenge template for new challenges (its creator or the admin)
+    pub fn set_challenge_template_active(
+        ctx: Context<SetChallengeTemplateActive>,
+        active: bool,
+    ) -> Result<()> {
+        instructions::challenge_template::set_challenge_template_active(ctx, active)
+    }
+
+    /// Submit a response to a challenge (recorded for the committee verdict or the
+    /// challenger's reveal)
     pub fn submit_response(
         ctx: Context<SubmitResponse>,
         response_hash: String,
@@ -75,20 +391,845 @@ pub mod agent_registry {
         instructions::submit_response::handler(ctx, response_hash, nonce)
     }
 
+    /// Reveal the committed answer to a responded challenge and resolve it against the
+    /// response (challenger only, within `Challenge::REVEAL_WINDOW`)
+    pub fn reveal_challenge_answer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RevealChallengeAnswer<'info>>,
+        nonce: u64,
+        expected_hash: String,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_challenge_answer::handler(ctx, nonce, expected_hash, salt)
+    }
+
     /// Expire a challenge that was not responded to in time
     /// Can be called by anyone - permissionless cleanup
-    /// Agent receives penalty for not responding
-    pub fn expire_challenge(ctx: Context<ExpireChallenge>, nonce: u64) -> Result<()> {
+    /// Agent is penalized and part of its stake slashed to the challenger (or the
+    /// challenge voided, per config); escrow is refunded
+    pub fn expire_challenge<'info>(ctx: Context<'_, '_, 'info, 'info, ExpireChallenge<'info>>, nonce: u64) -> Result<()> {
         instructions::expire_challenge::handler(ctx, nonce)
     }
 
     /// Close a resolved challenge and reclaim rent (~0.012 SOL per challenge)
-    /// Only the original challenger can close, only after challenge is resolved
+    /// Only the original challenger can close, only after challenge is resolved and its escrow paid out
     /// Critical mainnet optimization: reduces per-challenge cost from 0.012 SOL to ~0 SOL
     pub fn close_challenge(ctx: Context<CloseChallenge>, nonce: u64) -> Result<()> {
         instructions::close_challenge::handler(ctx, nonce)
     }
 
+    /// Void an abandoned challenge and refund the challenger (permissionless)
+    /// Judged challenges past the verdict window, or expired ones whose agent was closed
+    /// Unrevealed challenges past the reveal window forfeit the escrow to the agent owner
+    pub fn void_challenge(ctx: Context<VoidChallenge>, nonce: u64) -> Result<()> {
+        instructions::void_challenge::handler(ctx, nonce)
+    }
+
+    /// Pay out escrow left in a resolved challenge to whoever its outcome entitles (permissionless)
+    pub fn claim_challenge_escrow(ctx: Context<ClaimChallengeEscrow>, nonce: u64) -> Result<()> {
+        instructions::claim_challenge_escrow::handler(ctx, nonce)
+    }
+
+    /// Anchor an evidence hash (transcript, output, eval artifact) to an unresolved
+    /// challenge (challenger or agent owner, max 8 each); judge votes record the evidence digest
+    pub fn submit_challenge_evidence(
+        ctx: Context<SubmitChallengeEvidence>,
+        nonce: u64,
+        kind: state::EvidenceKind,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::submit_challenge_evidence::handler(ctx, nonce, kind, evidence_hash)
+    }
+
+    // ============================================
+    // Agent Lifecycle
+    // ============================================
+
+    /// Permanently retire an agent (owner only)
+    /// In-flight jobs and challenges still resolve; all other mutations are rejected
+    pub fn retire_agent(ctx: Context<RetireAgent>) -> Result<()> {
+        instructions::retire_agent::handler(ctx)
+    }
+
+    /// Take an agent offline for maintenance (owner only), until `until` when set,
+    /// otherwise until reactivated
+    /// New work is rejected and reputation decay is paused meanwhile
+    pub fn deactivate_agent(ctx: Context<DeactivateAgent>, until: Option<i64>) -> Result<()> {
+        instructions::deactivation::deactivate_agent(ctx, until)
+    }
+
+    /// Bring a deactivated agent back online (owner at any time; permissionless once
+    /// the scheduled window has passed)
+    pub fn reactivate_agent(ctx: Context<ReactivateAgent>) -> Result<()> {
+        instructions::deactivation::reactivate_agent(ctx)
+    }
+
+    /// Move an agent to a new owner under the same ID (current and new owner sign)
+    /// The old account is retired; reputation carries over per the registry's transfer
+    /// policy and an AgentTransfer provenance record is left. The agent's hooks, watchers
+    /// and history move to the successor
+    pub fn transfer_agent(ctx: Context<TransferAgent>) -> Result<()> {
+        instructions::transfer_agent::handler(ctx)
+    }
+
+    /// Close a retired agent after the close delay, returning rent and stake (owner only)
+    pub fn close_agent(ctx: Context<CloseAgent>) -> Result<()> {
+        instructions::close_agent::handler(ctx)
+    }
+
+    /// Close a retired agent into a compact AgentArchive (owner only, after the close delay)
+    pub fn archive_agent(ctx: Context<ArchiveAgent>) -> Result<()> {
+        instructions::archive_agent::handler(ctx)
+    }
+
+    /// Close a stale agent that was never verified or challenged, holds no stake and has
+    /// been idle for the configured window (permissionless)
+    /// Half the reclaimed rent goes to the caller, the rest to the treasury
+    pub fn garbage_collect_agent(ctx: Context<GarbageCollectAgent>) -> Result<()> {
+        instructions::garbage_collect_agent::handler(ctx)
+    }
+
+    /// Grant or revoke the moderator role (admin only)
+    pub fn set_moderator(ctx: Context<SetModerator>, moderator_authority: Pubkey, active: bool) -> Result<()> {
+        instructions::set_moderator::handler(ctx, moderator_authority, active)
+    }
+
+    /// Grant, re-budget or revoke a reputation authority (admin only)
+    /// `epoch_budget` caps the total |delta| it may apply per epoch
+    pub fn set_reputation_authority(
+        ctx: Context<SetReputationAuthority>,
+        reputation_authority: Pubkey,
+        active: bool,
+        epoch_budget: u32,
+    ) -> Result<()> {
+        instructions::set_reputation_authority::handler(ctx, reputation_authority, active, epoch_budget)
+    }
+
+    /// Suspend an agent with a reason code, optionally for a fixed duration (admin or moderator)
+    /// While in effect the agent can't gain reputation, list services, take jobs,
+    /// store audits or be challenged
+    pub fn suspend_agent(
+        ctx: Context<ModerateAgent>,
+        reason_code: u16,
+        duration_secs: Option<i64>,
+    ) -> Result<()> {
+        instructions::moderate_agent::suspend_agent(ctx, reason_code, duration_secs)
+    }
+
+    /// Permanently ban an agent with a reason code (admin or moderator)
+    pub fn ban_agent(ctx: Context<ModerateAgent>, reason_code: u16) -> Result<()> {
+        instructions::moderate_agent::ban_agent(ctx, reason_code)
+    }
+
+    /// Lift a suspension early (admin or moderator)
+    pub fn reinstate_agent(ctx: Context<ModerateAgent>) -> Result<()> {
+        instructions::moderate_agent::reinstate_agent(ctx)
+    }
+
+    /// Quarantine an agent's audit history once fraud is proven (admin or moderator)
+    /// Audit accounts are kept as evidence but take no further writes and stop counting
+    /// toward the trust flag and audit badges; emits AuditHistoryQuarantined
+    pub fn quarantine_audit_history(ctx: Context<QuarantineAuditHistory>, reason_code: u16) -> Result<()> {
+        instructions::quarantine_audit_history::handler(ctx, reason_code)
+    }
+
+    // ============================================
+    // Registration Allowlist
+    // ============================================
+
+    /// Approve a wallet for allowlist-only registration (admin only)
+    pub fn add_allowlist_entry(ctx: Context<AddAllowlistEntry>, registrant: Pubkey) -> Result<()> {
+        instructions::add_allowlist_entry::handler(ctx, registrant)
+    }
+
+    /// Revoke a wallet's registration approval (admin only)
+    pub fn remove_allowlist_entry(ctx: Context<RemoveAllowlistEntry>) -> Result<()> {
+        instructions::remove_allowlist_entry::handler(ctx)
+    }
+
+    // ============================================
+    // Owner Credentials
+    // ============================================
+
+    /// Approve or deactivate a KYC / verifiable-credential issuer (admin only)
+    pub fn set_credential_issuer(
+        ctx: Context<SetCredentialIssuer>,
+        issuer_authority: Pubkey,
+        active: bool,
+    ) -> Result<()> {
+        instructions::set_credential_issuer::handler(ctx, issuer_authority, active)
+    }
+
+    /// Record an issuer-signed credential for an agent owner (relayable)
+    /// Requires an ed25519 instruction signed by the issuer immediately before this one
+    pub fn record_credential(
+        ctx: Context<RecordCredential>,
+        subject: Pubkey,
+        credential_type: u16,
+        credential_hash: [u8; 32],
+        issued_at: i64,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::record_credential::handler(
+            ctx,
+            subject,
+            credential_type,
+            credential_hash,
+            issued_at,
+            expires_at,
+        )
+    }
+
+    /// Revoke a credential (issuer only)
+    pub fn revoke_credential(ctx: Context<RevokeCredential>) -> Result<()> {
+        instructions::revoke_credential::handler(ctx)
+    }
+
+    // ============================================
+    // DID Anchoring
+    // ============================================
+
+    /// Anchor a DID document to an agent and name its controller key (owner only, once)
+    pub fn anchor_did(
+        ctx: Context<AnchorDid>,
+        did_uri: String,
+        did_doc_hash: [u8; 32],
+        controller: Pubkey,
+    ) -> Result<()> {
+        instructions::anchor_did::handler(ctx, did_uri, did_doc_hash, controller)
+    }
+
+    /// Update the anchored DID document, optionally rotating the controller
+    /// (current DID controller only)
+    pub fn update_did(
+        ctx: Context<UpdateDid>,
+        did_uri: Option<String>,
+        did_doc_hash: [u8; 32],
+        new_controller: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_did::handler(ctx, did_uri, did_doc_hash, new_controller)
+    }
+
+    // ============================================
+    // External Reputation
+    // ============================================
+
+    /// Approve, update or deactivate an external reputation oracle (admin only)
+    pub fn set_reputation_oracle(
+        ctx: Context<SetReputationOracle>,
+        oracle_authority: Pubkey,
+        source_id: u8,
+        active: bool,
+    ) -> Result<()> {
+        instructions::set_reputation_oracle::handler(ctx, oracle_authority, source_id, active)
+    }
+
+    /// Record an off-chain score (0-10000) for an agent under the oracle's source
+    /// (active oracles only); kept separate from challenge-based reputation
+    pub fn import_external_reputation(ctx: Context<ImportExternalReputation>, score: u32) -> Result<()> {
+        instructions::import_external_reputation::handler(ctx, score)
+    }
+
+    // ============================================
+    // Model Index
+    // ============================================
+
+    /// Add an agent to the lookup index for its model hash (permissionless, payer funds rent)
+    pub fn index_agent_model(ctx: Context<IndexAgentModel>) -> Result<()> {
+        instructions::index_agent_model::index_agent_model(ctx)
+    }
+
+    /// Drop a closed agent from a model index (permissionless)
+    pub fn prune_model_index(ctx: Context<PruneModelIndex>) -> Result<()> {
+        instructions::index_agent_model::prune_model_index(ctx)
+    }
+
+    // ============================================
+    // Owner Index
+    // ============================================
+
+    /// Add an existing agent to its owner's "my agents" index (permissionless, payer funds rent)
+    /// `register_agent` and `fork_agent` append new agents themselves when the index is passed
+    pub fn index_owner_agent(ctx: Context<IndexOwnerAgent>) -> Result<()> {
+        instructions::owner_index::index_owner_agent(ctx)
+    }
+
+    /// Drop a closed agent from its owner's index (permissionless)
+    pub fn prune_owner_index(ctx: Context<PruneOwnerIndex>, agent_id: u64) -> Result<()> {
+        instructions::owner_index::prune_owner_index(ctx, agent_id)
+    }
+
+    // ============================================
+    // Tag Discovery
+    // ============================================
+
+    /// Replace an agent's discovery tags (owner only, max 4)
+    /// Tag indexes passed as writable remaining accounts are synced to the new tags
+    pub fn set_agent_tags(ctx: Context<SetAgentTags>, tags: Vec<String>) -> Result<()> {
+        instructions::tags::set_agent_tags(ctx, tags)
+    }
+
+    /// Add a tagged agent to the index for that tag (permissionless, payer funds rent)
+    pub fn index_agent_tag(ctx: Context<IndexAgentTag>, tag: String) -> Result<()> {
+        instructions::tags::index_agent_tag(ctx, tag)
+    }
+
+    /// Drop a closed or untagged agent from a tag index (permissionless)
+    pub fn prune_tag_index(ctx: Context<PruneTagIndex>) -> Result<()> {
+        instructions::tags::prune_tag_index(ctx)
+    }
+
+    // ============================================
+    // Encrypted Communication
+    // ============================================
+
+    /// Publish, rotate or (with all zeros) withdraw the agent's X25519 key that consumers
+    /// encrypt task payloads to (owner only)
+    pub fn set_encryption_pubkey(ctx: Context<SetEncryptionPubkey>, encryption_pubkey: [u8; 32]) -> Result<()> {
+        instructions::set_encryption_pubkey::handler(ctx, encryption_pubkey)
+    }
+
+    // ============================================
+    // Reputation Hooks
+    // ============================================
+
+    /// Subscribe a program to an agent's reputation changes (signed by the hook
+    /// program's authority PDA, max 4)
+    /// The program must implement an Anchor `on_reputation_change` instruction
+    pub fn register_reputation_hook(ctx: Context<RegisterReputationHook>) -> Result<()> {
+        instructions::register_reputation_hook::handler(ctx)
+    }
+
+    /// Unsubscribe a program from an agent's reputation changes (signed by the hook
+    /// program's authority PDA)
+    pub fn remove_reputation_hook(ctx: Context<RemoveReputationHook>, hook_program: Pubkey) -> Result<()> {
+        instructions::remove_reputation_hook::handler(ctx, hook_program)
+    }
+
+    /// Evict a hook from an agent's slots (admin or moderator), for spam registrations
+    /// the owner can't veto
+    pub fn evict_reputation_hook(ctx: Context<EvictReputationHook>, hook_program: Pubkey) -> Result<()> {
+        instructions::evict_reputation_hook::handler(ctx, hook_program)
+    }
+
+    /// Deliver an agent's latest reputation change to a hook that missed it (permissionless)
+    pub fn dispatch_reputation_hook(ctx: Context<DispatchReputationHook>) -> Result<()> {
+        instructions::dispatch_reputation_hook::handler(ctx)
+    }
+
+    // ============================================
+    // Watchlists
+    // ============================================
+
+    /// Subscribe to an agent's changes in `mask` (AgentWatchers::FLAG_* bits), or re-scope
+    /// an existing subscription; mutating instructions set the flags when the watcher
+    /// account is passed (anyone, max 8 per agent)
+    pub fn watch_agent(ctx: Context<WatchAgent>, mask: u16) -> Result<()> {
+        instructions::watch_agent::watch_agent(ctx, mask)
+    }
+
+    /// Drop a subscription (the watcher, or the agent owner evicting it)
+    pub fn unwatch_agent(ctx: Context<UnwatchAgent>, watcher: Pubkey) -> Result<()> {
+        instructions::watch_agent::unwatch_agent(ctx, watcher)
+    }
+
+    /// Clear handled flags from the caller's dirty bitmap (the watcher)
+    pub fn clear_watch_flags(ctx: Context<ClearWatchFlags>, flags: u16) -> Result<()> {
+        instructions::watch_agent::clear_watch_flags(ctx, flags)
+    }
+
+    // ============================================
+    // Registry Namespaces
+    // ============================================
+
+    /// Create a namespaced sub-registry with its own admin (registry admin only)
+    pub fn create_namespace(
+        ctx: Context<CreateNamespace>,
+        name: String,
+        namespace_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::create_namespace::handler(ctx, name, namespace_admin)
+    }
+
+    /// Update a namespace's membership policy or admin (namespace admin only)
+    pub fn update_namespace(
+        ctx: Context<UpdateNamespace>,
+        params: UpdateNamespaceParams,
+    ) -> Result<()> {
+        instructions::update_namespace::handler(ctx, params)
+    }
+
+    /// Tag an agent with a namespace, subject to its policy and join fee (owner only)
+    pub fn join_namespace(ctx: Context<JoinNamespace>) -> Result<()> {
+        instructions::join_namespace::handler(ctx)
+    }
+
+    /// Remove an agent's namespace tag (owner only)
+    pub fn leave_namespace(ctx: Context<LeaveNamespace>) -> Result<()> {
+        instructions::leave_namespace::handler(ctx)
+    }
+
+    /// Sunset the deployment (registry admin) or a namespace (its admin), naming a successor
+    pub fn deprecate_registry(ctx: Context<DeprecateRegistry>, successor: Pubkey) -> Result<()> {
+        instructions::deprecate_registry::handler(ctx, successor)
+    }
+
+    /// Carry an agent's ID, reputation and verification out of a deprecated registry,
+    /// leaving an on-chain provenance record (owner only)
+    pub fn migrate_agent_to_registry(ctx: Context<MigrateAgentToRegistry>) -> Result<()> {
+        instructions::migrate_agent_to_registry::handler(ctx)
+    }
+
+    // ============================================
+    // Milestone Badges
+    // ============================================
+
+    /// Create the non-transferable Token-2022 mint for a badge kind (admin only, one-time)
+    pub fn create_badge_mint(ctx: Context<CreateBadgeMint>, kind: state::BadgeKind) -> Result<()> {
+        instructions::create_badge_mint::handler(ctx, kind)
+    }
+
+    /// Mint a soulbound badge to the agent owner once the milestone's on-chain counter
+    /// is reached (once per agent and kind)
+    pub fn claim_badge(ctx: Context<ClaimBadge>, kind: state::BadgeKind) -> Result<()> {
+        instructions::claim_badge::handler(ctx, kind)
+    }
+
+    // ============================================
+    // Sponsored Registration
+    // ============================================
+
+    /// Create a sponsorship pool with an initial deposit and optional Merkle whitelist
+    pub fn create_sponsor_pool(
+        ctx: Context<CreateSponsorPool>,
+        deposit: u64,
+        whitelist: state::RegistrantWhitelist,
+        registrations_per_registrant: u16,
+    ) -> Result<()> {
+        instructions::create_sponsor_pool::handler(ctx, deposit, whitelist, registrations_per_registrant)
+    }
+
+    /// Top up a sponsorship pool and optionally replace its whitelist (sponsor only)
+    pub fn fund_sponsor_pool(
+        ctx: Context<FundSponsorPool>,
+        amount: u64,
+        whitelist: Option<state::RegistrantWhitelist>,
+        registrations_per_registrant: Option<u16>,
+    ) -> Result<()> {
+        instructions::fund_sponsor_pool::handler(ctx, amount, whitelist, registrations_per_registrant)
+    }
+
+    /// Whitelist a registrant for a number of sponsored registrations (sponsor only)
+    pub fn add_sponsored_registrant(
+        ctx: Context<AddSponsoredRegistrant>,
+        registrant: Pubkey,
+        registrations: u16,
+    ) -> Result<()> {
+        instructions::add_sponsored_registrant::handler(ctx, registrant, registrations)
+    }
+
+    /// Withdraw unspent lamports from a sponsorship pool (sponsor only)
+    pub fn withdraw_sponsor_pool(ctx: Context<WithdrawSponsorPool>, amount: u64) -> Result<()> {
+        instructions::withdraw_sponsor_pool::handler(ctx, amount)
+    }
+
+    /// Register an agent with rent and fee paid by a sponsorship pool
+    /// Pass a Merkle proof on first use when the registrant is whitelisted by root
+    pub fn register_agent_sponsored(
+        ctx: Context<RegisterAgentSponsored>,
+        name: String,
+        model_hash: String,
+        capabilities: String,
+        leaf_index: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<instructions::register_agent::RegisterAgentResponse> {
+        instructions::register_agent_sponsored::handler(ctx, name, model_hash, capabilities, leaf_index, proof)
+    }
+
+    // ============================================
+    // Capability Taxonomy
+    // ============================================
+
+    /// Define or redefine a capability in the curated taxonomy (admin only)
+    pub fn define_capability(
+        ctx: Context<DefineCapability>,
+        id: u8,
+        label_hash: [u8; 32],
+        description_uri: String,
+    ) -> Result<()> {
+        instructions::define_capability::handler(ctx, id, label_hash, description_uri)
+    }
+
+    /// Retire a capability so it can no longer be declared (admin only)
+    pub fn retire_capability(ctx: Context<RetireCapability>) -> Result<()> {
+        instructions::retire_capability::handler(ctx)
+    }
+
+    /// Set an agent's structured capability flags (owner only, defined capabilities only)
+    pub fn set_capability_flags(ctx: Context<SetCapabilityFlags>, flags: u64) -> Result<()> {
+        instructions::set_capability_flags::handler(ctx, flags)
+    }
+
+    /// Permanently lock agent fields (AgentAccount::FIELD_* bits) against updates
+    /// (owner or admin)
+    pub fn freeze_fields(ctx: Context<FreezeFields>, fields: u8) -> Result<()> {
+        instructions::freeze_fields::handler(ctx, fields)
+    }
+
+    /// Tag a stored Merkle audit root as a batch of eval results (active reputation authority)
+    pub fn tag_eval_batch(ctx: Context<TagEvalBatch>) -> Result<()> {
+        instructions::tag_eval_batch::handler(ctx)
+    }
+
+    /// Mark a declared capability as proven with a Merkle proof against a tagged eval
+    /// batch (permissionless); sets its bit in `proven_capabilities`
+    pub fn prove_capability(
+        ctx: Context<ProveCapability>,
+        capability_id: u8,
+        leaf_index: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::prove_capability::handler(ctx, capability_id, leaf_index, proof)
+    }
+
+    /// Register, replace or deactivate an inference circuit's Groth16 verifying key (admin only)
+    pub fn set_zk_verifier(
+        ctx: Context<SetZkVerifier>,
+        circuit_id: u32,
+        vk: state::ZkVerifyingKey,
+        active: bool,
+    ) -> Result<()> {
+        instructions::set_zk_verifier::handler(ctx, circuit_id, vk, active)
+    }
+
+    /// Verify a Groth16 proof that the agent's model produced `output_commitment` over the
+    /// alt_bn128 syscalls (permissionless); counts it in `zk_verified_inferences`
+    pub fn verify_inference(
+        ctx: Context<VerifyInference>,
+        proof: state::Groth16Proof,
+        output_commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::verify_inference::handler(ctx, proof, output_commitment)
+    }
+
+    // ============================================
+    // Benchmark Scores
+    // ============================================
+
+    /// Approve or deactivate an evaluator whose signed benchmark results are accepted (admin only)
+    pub fn set_benchmark_evaluator(
+        ctx: Context<SetBenchmarkEvaluator>,
+        evaluator_authority: Pubkey,
+        active: bool,
+    ) -> Result<()> {
+        instructions::benchmark::set_benchmark_evaluator(ctx, evaluator_authority, active)
+    }
+
+    /// Define, redefine or retire a benchmark suite (admin only)
+    /// Changing the suite hash or max score resets its leaderboard
+    pub fn define_benchmark(
+        ctx: Context<DefineBenchmark>,
+        benchmark_id: u32,
+        suite_hash: [u8; 32],
+        max_score: u32,
+        active: bool,
+    ) -> Result<()> {
+        instructions::benchmark::define_benchmark(ctx, benchmark_id, suite_hash, max_score, active)
+    }
+
+    /// Record an evaluator-signed benchmark result for the agent's current model and
+    /// update the benchmark's top-10 leaderboard (relayable)
+    /// Requires an ed25519 instruction signed by the evaluator immediately before this one
+    pub fn record_benchmark_score(
+        ctx: Context<RecordBenchmarkScore>,
+        score: u32,
+        evaluated_at: i64,
+    ) -> Result<()> {
+        instructions::benchmark::record_benchmark_score(ctx, score, evaluated_at)
+    }
+
+    // ============================================
+    // Service Listings
+    // ============================================
+
+    /// Publish a priced service for an agent (owner only)
+    /// Capability flags must be a non-empty subset of the agent's flags
+    pub fn create_service_listing(
+        ctx: Context<CreateServiceListing>,
+        listing_id: u64,
+        capability_flags: u64,
+        pricing: state::PricingModel,
+        price_lamports: u64,
+        endpoint_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::create_service_listing::handler(
+            ctx,
+            listing_id,
+            capability_flags,
+            pricing,
+            price_lamports,
+            endpoint_hash,
+        )
+    }
+
+    /// Change a service listing's pricing, endpoint or availability (owner only)
+    pub fn update_service_listing(
+        ctx: Context<UpdateServiceListing>,
+        params: UpdateServiceListingParams,
+    ) -> Result<()> {
+        instructions::update_service_listing::handler(ctx, params)
+    }
+
+    /// Remove a service listing and reclaim rent (owner only)
+    pub fn close_service_listing(ctx: Context<CloseServiceListing>) -> Result<()> {
+        instructions::close_service_listing::handler(ctx)
+    }
+
+    /// Buy or top up a prepaid call package on a per-call listing (consumer pays the owner)
+    /// Fails if the package would cost more than `max_cost` lamports
+    pub fn purchase_allowance(
+        ctx: Context<PurchaseAllowance>,
+        calls: u64,
+        duration_secs: i64,
+        max_cost: u64,
+    ) -> Result<()> {
+        instructions::allowance::purchase_allowance(ctx, calls, duration_secs, max_cost)
+    }
+
+    /// Draw calls from a consumer's allowance (listing metering key, or the owner)
+    pub fn meter_usage(ctx: Context<MeterUsage>, calls: u64) -> Result<()> {
+        instructions::allowance::meter_usage(ctx, calls)
+    }
+
+    /// Close a used-up or expired allowance and reclaim its rent (consumer)
+    pub fn close_allowance(ctx: Context<CloseAllowance>) -> Result<()> {
+        instructions::allowance::close_allowance(ctx)
+    }
+
+    /// Open a payment channel to an agent with an initial deposit (consumer)
+    pub fn open_channel(ctx: Context<OpenChannel>, channel_id: u64, deposit: u64) -> Result<()> {
+        instructions::payment_channel::open_channel(ctx, channel_id, deposit)
+    }
+
+    /// Add to an open channel's deposit (consumer)
+    pub fn fund_channel(ctx: Context<FundChannel>, amount: u64) -> Result<()> {
+        instructions::payment_channel::fund_channel(ctx, amount)
+    }
+
+    /// Redeem the consumer's latest signed cumulative voucher (agent owner)
+    pub fn redeem_voucher(ctx: Context<RedeemVoucher>, cumulative: u64) -> Result<()> {
+        instructions::payment_channel::redeem_voucher(ctx, cumulative)
+    }
+
+    /// Start a unilateral close; final after the challenge period (consumer)
+    pub fn request_channel_close(ctx: Context<RequestChannelClose>) -> Result<()> {
+        instructions::payment_channel::request_channel_close(ctx)
+    }
+
+    /// Close a channel and refund the unredeemed deposit (owner any time, consumer after
+    /// the challenge period)
+    pub fn close_channel(ctx: Context<CloseChannel>) -> Result<()> {
+        instructions::payment_channel::close_channel(ctx)
+    }
+
+    // ============================================
+    // Jobs (Escrowed Service Work)
+    // ============================================
+
+    /// Purchase a job from a service listing, escrowing the listed price
+    pub fn create_job(
+        ctx: Context<CreateJob>,
+        job_id: u64,
+        request_hash: [u8; 32],
+        accept_window: i64,
+        delivery_window: i64,
+    ) -> Result<()> {
+        instructions::create_job::handler(ctx, job_id, request_hash, accept_window, delivery_window)
+    }
+
+    /// Accept an open job before its accept deadline (agent owner only)
+    pub fn accept_job(ctx: Context<AcceptJob>) -> Result<()> {
+        instructions::accept_job::handler(ctx)
+    }
+
+    /// Commit the deliverable hash before the delivery deadline (agent owner only)
+    pub fn deliver_job(ctx: Context<DeliverJob>, deliverable_hash: [u8; 32]) -> Result<()> {
+        instructions::deliver_job::handler(ctx, deliverable_hash)
+    }
+
+    /// Settle a delivered job: escrow to the owner, reputation bonus to the agent
+    /// Consumer any time, owner once the review window lapses
+    pub fn settle_job<'info>(ctx: Context<'_, '_, 'info, 'info, SettleJob<'info>>) -> Result<()> {
+        instructions::settle_job::handler(ctx)
+    }
+
+    /// Dispute a delivery during the review window (consumer only)
+    pub fn dispute_job(ctx: Context<DisputeJob>) -> Result<()> {
+        instructions::dispute_job::handler(ctx)
+    }
+
+    /// Resolve a disputed job (admin only)
+    pub fn resolve_job_dispute<'info>(ctx: Context<'_, '_, 'info, 'info, ResolveJobDispute<'info>>, in_favor_of_agent: bool) -> Result<()> {
+        instructions::resolve_job_dispute::handler(ctx, in_favor_of_agent)
+    }
+
+    /// Cancel an open job, or reclaim escrow after a missed delivery deadline (consumer only)
+    pub fn cancel_job<'info>(ctx: Context<'_, '_, 'info, 'info, CancelJob<'info>>) -> Result<()> {
+        instructions::cancel_job::handler(ctx)
+    }
+
+    /// Refund an unfinished job whose agent was closed (permissionless)
+    pub fn void_job(ctx: Context<VoidJob>) -> Result<()> {
+        instructions::void_job::handler(ctx)
+    }
+
+    /// Rate a settled or refunded job (consumer only, once per job)
+    pub fn submit_review(ctx: Context<SubmitReview>, rating: u8, comment_hash: [u8; 32]) -> Result<()> {
+        instructions::submit_review::handler(ctx, rating, comment_hash)
+    }
+
+    /// Attach the owner's single response to a review (owner only)
+    pub fn respond_to_review(ctx: Context<RespondToReview>, response_hash: [u8; 32]) -> Result<()> {
+        instructions::respond_to_review::handler(ctx, response_hash)
+    }
+
+    // ============================================
+    // Multi-Owner Agents
+    // ============================================
+
+    /// Hand an agent over to an m-of-n owner set (current owner only)
+    /// Owner-key updates and unstaking are disabled afterwards
+    pub fn create_agent_multisig(
+        ctx: Context<CreateAgentMultisig>,
+        owners: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::create_agent_multisig::handler(ctx, owners, threshold)
+    }
+
+    /// Execute a multisig action with threshold owners co-signing this transaction
+    /// Owner signers are passed as remaining accounts
+    pub fn execute_multisig_action<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteMultisigAction<'info>>,
+        action: state::MultisigAction,
+    ) -> Result<()> {
+        instructions::execute_multisig_action::handler(ctx, action)
+    }
+
+    /// Propose a multisig action for asynchronous approval (owners only)
+    pub fn propose_multisig_action(
+        ctx: Context<ProposeMultisigAction>,
+        action: state::MultisigAction,
+    ) -> Result<()> {
+        instructions::propose_multisig_action::handler(ctx, action)
+    }
+
+    /// Approve a pending multisig proposal (owners only)
+    pub fn approve_multisig_proposal(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+        instructions::approve_multisig_proposal::handler(ctx)
+    }
+
+    /// Execute a proposal once it has threshold approvals (anyone)
+    pub fn execute_multisig_proposal(ctx: Context<ExecuteMultisigProposal>) -> Result<()> {
+        instructions::execute_multisig_proposal::handler(ctx)
+    }
+
+    // ============================================
+    // Registry Governance
+    // ============================================
+
+    /// Create or retune the thresholds for agent-voted config changes (admin only)
+    pub fn set_governance(
+        ctx: Context<SetGovernance>,
+        active: bool,
+        weighting: state::VoteWeighting,
+        quorum_weight: u64,
+        majority_bps: u16,
+        voting_period_secs: i64,
+    ) -> Result<()> {
+        instructions::governance::set_governance(
+            ctx,
+            active,
+            weighting,
+            quorum_weight,
+            majority_bps,
+            voting_period_secs,
+        )
+    }
+
+    /// Put an `update_config` change to a vote (owner of a verified agent)
+    pub fn create_governance_proposal(
+        ctx: Context<CreateGovernanceProposal>,
+        params: UpdateConfigParams,
+        description_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::governance::create_governance_proposal(ctx, params, description_hash)
+    }
+
+    /// Vote on an open proposal, weighted by the agent's reputation and/or stake
+    /// (owner of a verified agent registered before the proposal, once per agent)
+    /// Stake-weighted votes lock the agent's stake until voting ends
+    pub fn cast_governance_vote(ctx: Context<CastGovernanceVote>, approve: bool) -> Result<()> {
+        instructions::governance::cast_governance_vote(ctx, approve)
+    }
+
+    /// Apply a proposal that met quorum and majority once voting has closed (anyone)
+    pub fn execute_governance_proposal(ctx: Context<ExecuteGovernanceProposal>) -> Result<()> {
+        instructions::governance::execute_governance_proposal(ctx)
+    }
+
+    // ============================================
+    // Challenge Judging Committee
+    // ============================================
+
+    /// Add a judge to the judging committee (admin only)
+    pub fn register_judge(ctx: Context<RegisterJudge>, judge_authority: Pubkey) -> Result<()> {
+        instructions::register_judge::handler(ctx, judge_authority)
+    }
+
+    /// Deactivate a judge (admin only, vote history is preserved)
+    pub fn remove_judge(ctx: Context<RemoveJudge>) -> Result<()> {
+        instructions::remove_judge::handler(ctx)
+    }
+
+    /// Vote on a judged challenge's result (active judges only)
+    /// Resolves the challenge automatically once a verdict reaches quorum
+    pub fn cast_judge_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CastJudgeVote<'info>>,
+        nonce: u64,
+        passed: bool,
+    ) -> Result<()> {
+        instructions::cast_judge_vote::handler(ctx, nonce, passed)
+    }
+
+    // ============================================
+    // Random Audit Selection (Switchboard VRF)
+    // ============================================
+
+    /// Start a random audit round by committing to a Switchboard randomness account
+    /// and the latest registry snapshot, posting a bond
+    /// Permissionless - the commitment is made before the value is revealed
+    pub fn request_audit_selection(ctx: Context<RequestAuditSelection>) -> Result<()> {
+        instructions::request_audit_selection::handler(ctx)
+    }
+
+    /// Pick the audit target from the revealed randomness (permissionless)
+    /// Proves the drawn snapshot leaf; closed or inactive agents are re-drawn
+    pub fn select_challenge_target(
+        ctx: Context<SelectChallengeTarget>,
+        round: u64,
+        reputation_score: u32,
+        verified: bool,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::select_challenge_target::handler(ctx, round, reputation_score, verified, proof)
+    }
+
+    /// Expire a round left unrevealed past its settle window, forfeiting the requester's
+    /// bond to the treasury (permissionless)
+    pub fn expire_audit_selection(ctx: Context<ExpireAuditSelection>, round: u64) -> Result<()> {
+        instructions::expire_audit_selection::handler(ctx, round)
+    }
+
     // ============================================
     // SentinelAgent Security Layer Instructions
     // ============================================
@@ -125,4 +1266,52 @@ pub mod agent_registry {
     ) -> Result<()> {
         instructions::store_merkle_audit::handler(ctx, merkle_root, entries_count)
     }
+
+    /// Append a single audit leaf to the agent's on-chain incremental Merkle tree
+    /// Per-event anchoring without off-chain batching: the root is updated every call
+    pub fn append_audit_leaf(ctx: Context<AppendAuditLeaf>, leaf: [u8; 32]) -> Result<()> {
+        instructions::append_audit_leaf::handler(ctx, leaf)
+    }
+
+    /// Check an audit entry's inclusion in a stored batch (view function)
+    /// The leaf is hashed per `merkle::AuditLeaf`, the canonical audit leaf encoding
+    pub fn verify_audit_entry(
+        ctx: Context<VerifyAuditEntry>,
+        entry: AuditEntryProof,
+    ) -> Result<instructions::verify_audit_entry::AuditEntryVerification> {
+        instructions::verify_audit_entry::handler(ctx, entry)
+    }
+
+    // ============================================
+    // Audit Delegates
+    // ============================================
+
+    /// Let a logging service submit audits for an agent within `scopes`
+    /// (AuditDelegate::SCOPE_* bits) until `expires_at` (0 = until revoked) (owner only)
+    pub fn set_audit_delegate(
+        ctx: Context<SetAuditDelegate>,
+        delegate: Pubkey,
+        scopes: u8,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::audit_delegate::set_audit_delegate(ctx, delegate, scopes, expires_at)
+    }
+
+    /// Revoke an audit delegation and reclaim its rent (owner only)
+    pub fn revoke_audit_delegate(ctx: Context<RevokeAuditDelegate>) -> Result<()> {
+        instructions::audit_delegate::revoke_audit_delegate(ctx)
+    }
+
+    // ============================================
+    // Demo Data (devnet feature only)
+    // ============================================
+
+    /// Seed sample agents with varied reputation, verification and audit history
+    /// Remaining accounts: [agent, audit_summary] PDA pairs (admin only, localnet/devnet builds)
+    #[cfg(feature = "devnet")]
+    pub fn seed_demo_data<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SeedDemoData<'info>>,
+    ) -> Result<()> {
+        instructions::seed_demo_data::handler(ctx)
+    }
 }
@@ -0,0 +1,281 @@
+//! Canonical Merkle leaf encodings and node hashing
+//!
+//! The single definition of how leaves are laid out and hashed, used by the program and
+//! re-exported to off-chain clients so proofs built anywhere verify on-chain:
+//! - Every leaf preimage starts with a one-byte domain separator (`*_LEAF_PREFIX`), so a
+//!   leaf of one kind can never be replayed as another
+//! - Fields follow in a fixed order with fixed widths: integers little-endian, pubkeys and
+//!   digests as raw 32 bytes, enums as their variant index; no length prefixes or padding
+//! - Parent nodes are SHA256(NODE_PREFIX || left || right), so an internal node can't
+//!   pass as a leaf; proofs are sibling paths, bottom-up, with the leaf index's low bit
+//!   selecting the side at each level, and must be exactly as long as the tree is deep
+//!
+//! Only `core` and the SHA256 syscall wrapper are used, so the module also builds for
+//! no_std targets.
+
+/// Domain separator for agent standing leaves (`RegistrySnapshot`) and sponsor whitelist
+/// leaves, which differ in length
+pub const STANDING_LEAF_PREFIX: u8 = 0x00;
+
+/// Domain separator for capability eval leaves (`EvalBatch`)
+pub const EVAL_LEAF_PREFIX: u8 = 0x01;
+
+/// Domain separator for challenge result leaves (`Challenge::result_leaf`)
+pub const CHALLENGE_RESULT_LEAF_PREFIX: u8 = 0x02;
+
+/// Domain separator for audit entry leaves (`AuditLeaf`)
+pub const AUDIT_ENTRY_LEAF_PREFIX: u8 = 0x03;
+
+/// Domain separator for internal nodes (distinct from every leaf prefix)
+pub const NODE_PREFIX: u8 = 0xff;
+
+/// Hash two sibling nodes into their parent: SHA256(NODE_PREFIX || left || right)
+pub fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    solana_sha256_hasher::hashv(&[&[NODE_PREFIX], left, right]).to_bytes()
+}
+
+/// Depth of a batch tree over `leaf_count` leaves (odd levels duplicate the last node,
+/// a single leaf is its own root)
+pub fn batch_depth(leaf_count: u64) -> usize {
+    match leaf_count {
+        0 | 1 => 0,
+        count => (u64::BITS - (count - 1).leading_zeros()) as usize,
+    }
+}
+
+/// Verify a sibling path from `leaf` at `index` up to `root` in a tree `depth` levels
+/// deep; shorter or longer paths, and indexes past the tree, are rejected
+pub fn verify_proof(root: &[u8; 32], leaf: [u8; 32], mut index: u64, depth: usize, proof: &[[u8; 32]]) -> bool {
+    if proof.len() != depth || (depth < 64 && index >> depth != 0) {
+        return false;
+    }
+    let mut node = leaf;
+    for sibling in proof {
+        node = if index & 1 == 0 {
+            hash_pair(&node, sibling)
+        } else {
+            hash_pair(sibling, &node)
+        };
+        index >>= 1;
+    }
+    node == *root
+}
+
+/// Verify a proof for the leaf at `index` in a batch tree over `leaf_count` leaves
+pub fn verify_batch_proof(
+    root: &[u8; 32],
+    leaf: [u8; 32],
+    index: u64,
+    leaf_count: u64,
+    proof: &[[u8; 32]],
+) -> bool {
+    index < leaf_count && verify_proof(root, leaf, index, batch_depth(leaf_count), proof)
+}
+
+/// One audit entry as committed in a Merkle audit batch or the incremental audit tree
+/// Mirrors `AuditEntry`, with the details hash as raw digest bytes rather than hex
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AuditLeaf {
+    /// The agent the entry belongs to
+    pub agent: [u8; 32],
+
+    /// The wallet that triggered the action
+    pub actor: [u8; 32],
+
+    /// Sequential audit index for the agent
+    pub audit_index: u64,
+
+    /// `ActionType` variant index
+    pub action_type: u8,
+
+    /// Risk score (0-100)
+    pub risk_score: u8,
+
+    /// Unix timestamp when the action occurred
+    pub timestamp: i64,
+
+    /// SHA256 of the off-chain action details
+    pub details_hash: [u8; 32],
+}
+
+impl AuditLeaf {
+    /// Preimage size: prefix, agent, actor, index, action, risk, timestamp, details hash
+    pub const ENCODED_LEN: usize = 1 + 32 + 32 + 8 + 1 + 1 + 8 + 32;
+
+    /// Canonical preimage, in field order
+    pub fn encode(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut out = [0u8; Self::ENCODED_LEN];
+        out[0] = AUDIT_ENTRY_LEAF_PREFIX;
+        out[1..33].copy_from_slice(&self.agent);
+        out[33..65].copy_from_slice(&self.actor);
+        out[65..73].copy_from_slice(&self.audit_index.to_le_bytes());
+        out[73] = self.action_type;
+        out[74] = self.risk_score;
+        out[75..83].copy_from_slice(&self.timestamp.to_le_bytes());
+        out[83..115].copy_from_slice(&self.details_hash);
+        out
+    }
+
+    /// Leaf hash: SHA256 of the canonical preimage
+    pub fn hash(&self) -> [u8; 32] {
+        solana_sha256_hasher::hash(&self.encode()).to_bytes()
+    }
+}
+
+/// Fixed vectors pinning the encodings above, checked by this crate's tests and the
+/// client's, and computed independently of this module (plain SHA256 over the
+/// documented layout) so a change to either side shows up as a mismatch
+pub mod vectors {
+    use super::AuditLeaf;
+
+    /// Audit leaf with a distinct byte in every field and a negative timestamp
+    pub const AUDIT_LEAF: AuditLeaf = AuditLeaf {
+        agent: [0x11; 32],
+        actor: [0x22; 32],
+        audit_index: 0x0102_0304_0506_0708,
+        action_type: 3,
+        risk_score: 42,
+        timestamp: -2,
+        details_hash: [0x33; 32],
+    };
+
+    /// `AUDIT_LEAF.encode()`
+    pub const AUDIT_LEAF_ENCODED: [u8; AuditLeaf::ENCODED_LEN] = bytes(concat!(
+        "03",
+        "1111111111111111111111111111111111111111111111111111111111111111",
+        "2222222222222222222222222222222222222222222222222222222222222222",
+        "0807060504030201",
+        "03",
+        "2a",
+        "feffffffffffffff",
+        "3333333333333333333333333333333333333333333333333333333333333333",
+    ));
+
+    /// `AUDIT_LEAF.hash()`
+    pub const AUDIT_LEAF_HASH: [u8; 32] =
+        bytes("4bad51425d885aeada86f56f471368f83f48fd81adf318e6ad388eeea4a08a6d");
+
+    /// `hash_pair(&[0x01; 32], &[0x02; 32])`
+    pub const PAIR_HASH: [u8; 32] =
+        bytes("eac346c82cf994405f0b901a9157328d2f40d90216f93a719be49386c487843d");
+
+    /// `(leaf_count, batch_depth(leaf_count))`
+    pub const BATCH_DEPTHS: &[(u64, usize)] =
+        &[(0, 0), (1, 0), (2, 1), (3, 2), (4, 2), (5, 3), (8, 3), (9, 4), (1 << 20, 20)];
+
+    /// Leaf `i` of the three-leaf batch below
+    pub const fn batch_entry(i: u8) -> AuditLeaf {
+        AuditLeaf {
+            audit_index: i as u64,
+            action_type: 0,
+            risk_score: 10 * i,
+            timestamp: 1_700_000_000 + i as i64,
+            ..AUDIT_LEAF
+        }
+    }
+
+    /// Hashes of `batch_entry(0..3)`
+    pub const BATCH_LEAVES: [[u8; 32]; 3] = [
+        bytes("5f6e437eb8786b180e84b95a357f729f1bf5ff910bb662b063e73b2ae4199d0a"),
+        bytes("7eb2b75270d20de7bbea6c33df1b70f7d3deee0ed7085087d631493eca1ffccd"),
+        bytes("e57110dd21456164384c787d2453b9fc617d987a0771192a0312bce2b9c828f0"),
+    ];
+
+    /// Root over BATCH_LEAVES: H(H(l0, l1), H(l2, l2))
+    pub const BATCH_ROOT: [u8; 32] =
+        bytes("66b9e078a98687941a1940e8593382971befcbd457ab49e83e1bcefc80f7b2a8");
+
+    /// Sibling path for leaf 2: its duplicate, then H(l0, l1)
+    pub const BATCH_PROOF_2: [[u8; 32]; 2] = [
+        BATCH_LEAVES[2],
+        bytes("959f63f326ccd251c1f137263cf3d86f5d963507acec45645a47a6997a1e522d"),
+    ];
+
+    /// Decode lowercase hex at compile time
+    const fn bytes<const N: usize>(hex: &str) -> [u8; N] {
+        let hex = hex.as_bytes();
+        assert!(hex.len() == 2 * N, "hex length");
+        let mut out = [0u8; N];
+        let mut i = 0;
+        while i < N {
+            out[i] = (nibble(hex[2 * i]) << 4) | nibble(hex[2 * i + 1]);
+            i += 1;
+        }
+        out
+    }
+
+    const fn nibble(digit: u8) -> u8 {
+        match digit {
+            b'0'..=b'9' => digit - b'0',
+            b'a'..=b'f' => digit - b'a' + 10,
+            _ => panic!("hex digit"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::vectors::*;
+    use super::*;
+
+    #[test]
+    fn audit_leaf_encoding_pinned() {
+        assert_eq!(AUDIT_LEAF.encode(), AUDIT_LEAF_ENCODED);
+        assert_eq!(AUDIT_LEAF.hash(), AUDIT_LEAF_HASH);
+    }
+
+    #[test]
+    fn node_hash_is_domain_separated() {
+        let (left, right) = ([0x01; 32], [0x02; 32]);
+        assert_eq!(hash_pair(&left, &right), PAIR_HASH);
+        assert_ne!(
+            hash_pair(&left, &right),
+            solana_sha256_hasher::hashv(&[&left, &right]).to_bytes()
+        );
+        assert_ne!(hash_pair(&right, &left), PAIR_HASH);
+    }
+
+    #[test]
+    fn batch_depths_pinned() {
+        for &(leaf_count, depth) in BATCH_DEPTHS {
+            assert_eq!(batch_depth(leaf_count), depth, "{} leaves", leaf_count);
+        }
+    }
+
+    #[test]
+    fn batch_vectors_verify() {
+        for (i, leaf) in BATCH_LEAVES.iter().enumerate() {
+            assert_eq!(batch_entry(i as u8).hash(), *leaf);
+        }
+        assert_eq!(
+            hash_pair(&BATCH_PROOF_2[1], &hash_pair(&BATCH_LEAVES[2], &BATCH_LEAVES[2])),
+            BATCH_ROOT
+        );
+
+        assert!(verify_batch_proof(&BATCH_ROOT, BATCH_LEAVES[2], 2, 3, &BATCH_PROOF_2));
+        assert!(verify_proof(&BATCH_ROOT, BATCH_LEAVES[2], 2, 2, &BATCH_PROOF_2));
+        let proof_0 = [BATCH_LEAVES[1], hash_pair(&BATCH_LEAVES[2], &BATCH_LEAVES[2])];
+        assert!(verify_batch_proof(&BATCH_ROOT, BATCH_LEAVES[0], 0, 3, &proof_0));
+    }
+
+    #[test]
+    fn batch_proofs_rejected_when_altered() {
+        // Wrong leaf, wrong side
+        assert!(!verify_batch_proof(&BATCH_ROOT, BATCH_LEAVES[1], 2, 3, &BATCH_PROOF_2));
+        assert!(!verify_batch_proof(&BATCH_ROOT, BATCH_LEAVES[2], 0, 3, &BATCH_PROOF_2));
+
+        // Slot 3 holds the duplicated last leaf: the path checks out, but it isn't an entry
+        assert!(verify_proof(&BATCH_ROOT, BATCH_LEAVES[2], 3, 2, &BATCH_PROOF_2));
+        assert!(!verify_batch_proof(&BATCH_ROOT, BATCH_LEAVES[2], 3, 3, &BATCH_PROOF_2));
+
+        // Too short, too long, or a leaf count that implies another depth
+        assert!(!verify_batch_proof(&BATCH_ROOT, BATCH_LEAVES[2], 2, 3, &BATCH_PROOF_2[..1]));
+        let long = [BATCH_PROOF_2[0], BATCH_PROOF_2[1], [0; 32]];
+        assert!(!verify_batch_proof(&BATCH_ROOT, BATCH_LEAVES[2], 2, 3, &long));
+        assert!(!verify_batch_proof(&BATCH_ROOT, BATCH_LEAVES[2], 2, 5, &BATCH_PROOF_2));
+
+        // An internal node can't be passed off as a leaf one level up
+        let internal = hash_pair(&BATCH_LEAVES[0], &BATCH_LEAVES[1]);
+        assert!(!verify_proof(&BATCH_ROOT, internal, 0, 2, &[internal, BATCH_PROOF_2[1]]));
+    }
+}
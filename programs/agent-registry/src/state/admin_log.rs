@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+
+/// One privileged action (88 bytes, no padding holes for zero-copy)
+#[zero_copy]
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct AdminAction {
+    /// Signer that performed the action (admin or moderator)
+    pub actor: Pubkey,
+
+    /// What the action applied to (agent, config, recipient or role holder)
+    pub target: Pubkey,
+
+    /// Unix timestamp of the action
+    pub timestamp: i64,
+
+    /// Action-specific value (AdminActionLog::ACTION_* documents each)
+    pub value: u64,
+
+    /// What was done (AdminActionLog::ACTION_*)
+    pub action: u8,
+
+    pub _padding: [u8; 7],
+}
+
+/// AdminActionLog - registry-wide ring buffer of privileged actions
+/// Every admin and moderator instruction that changes standing, config or funds
+/// requires it, so the administrators themselves leave an on-chain trail
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct AdminActionLog {
+    /// Actions, oldest overwritten first (`head` is the next slot to write)
+    pub entries: [AdminAction; AdminActionLog::CAPACITY],
+
+    /// Next slot to write
+    pub head: u32,
+
+    /// Number of valid entries (saturates at CAPACITY)
+    pub count: u32,
+
+    /// Total actions ever recorded
+    pub total_recorded: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    pub _padding: [u8; 7],
+}
+
+impl AdminActionLog {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::ADMIN_LOG_SEED;
+
+    /// Number of actions retained
+    pub const CAPACITY: usize = 64;
+
+    /// Account size (with discriminator)
+    pub const SPACE: usize = 8 + std::mem::size_of::<AdminActionLog>();
+
+    /// Agent verified (directly or by approving a request); value = verification level
+    pub const ACTION_VERIFY: u8 = 0;
+
+    /// Verification request rejected; value unused
+    pub const ACTION_REJECT_VERIFICATION: u8 = 1;
+
+    /// Agent suspended; value = reason code
+    pub const ACTION_SUSPEND: u8 = 2;
+
+    /// Agent banned; value = reason code
+    pub const ACTION_BAN: u8 = 3;
+
+    /// Agent reinstated; value unused
+    pub const ACTION_REINSTATE: u8 = 4;
+
+    /// Registry config changed (`update_config`, `set_reputation_bounds`); value unused,
+    /// or the proposal id when a governance proposal is executed (actor = the proposal)
+    pub const ACTION_CONFIG_CHANGE: u8 = 5;
+
+    /// Treasury withdrawal; value = lamports
+    pub const ACTION_TREASURY_WITHDRAWAL: u8 = 6;
+
+    /// Moderator role granted (value 1) or revoked (value 0)
+    pub const ACTION_SET_MODERATOR: u8 = 7;
+
+    /// Deployment or namespace sunset (`deprecate_registry`); target = the
+    /// deprecated config or namespace, value unused
+    pub const ACTION_DEPRECATE: u8 = 8;
+
+    /// Reputation authority granted or revoked; value = per-epoch budget (0 = revoked)
+    pub const ACTION_SET_REPUTATION_AUTHORITY: u8 = 9;
+
+    /// Inference circuit verifying key set; target = the ZkVerifier, value = circuit id
+    pub const ACTION_SET_ZK_VERIFIER: u8 = 10;
+
+    /// Governance thresholds set; target = the Governance account, value = quorum weight
+    /// (0 = proposals disabled)
+    pub const ACTION_SET_GOVERNANCE: u8 = 11;
+
+    /// Approved verification revoked; value = bond forfeited to the treasury (lamports)
+    pub const ACTION_REVOKE_VERIFICATION: u8 = 12;
+
+    /// Audit history quarantined for fraud; target = the agent, value = reason code
+    pub const ACTION_QUARANTINE_AUDIT: u8 = 13;
+
+    /// Reputation hook evicted from an agent; target = the hook program, value = agent id
+    pub const ACTION_EVICT_HOOK: u8 = 14;
+
+    /// Append an action, overwriting the oldest entry once full
+    pub fn push(&mut self, entry: AdminAction) {
+        self.entries[self.head as usize] = entry;
+        self.head = (self.head + 1) % Self::CAPACITY as u32;
+        self.count = (self.count + 1).min(Self::CAPACITY as u32);
+        self.total_recorded = self.total_recorded.saturating_add(1);
+    }
+
+    /// Valid entries, newest first
+    pub fn recent(&self) -> impl Iterator<Item = &AdminAction> {
+        (1..=self.count as usize).map(move |back| {
+            &self.entries[(self.head as usize + Self::CAPACITY - back) % Self::CAPACITY]
+        })
+    }
+
+    /// Record an action in the registry's log
+    pub fn record(
+        log: &AccountLoader<AdminActionLog>,
+        action: u8,
+        actor: Pubkey,
+        target: Pubkey,
+        value: u64,
+        now: i64,
+    ) -> Result<()> {
+        log.load_mut()?.push(AdminAction {
+            actor,
+            target,
+            timestamp: now,
+            value,
+            action,
+            _padding: [0; 7],
+        });
+        Ok(())
+    }
+}
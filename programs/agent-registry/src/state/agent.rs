@@ -1,15 +1,85 @@
 use anchor_lang::prelude::*;
+use std::mem::size_of;
+use super::{ChallengeType, Job, RegistryConfig, ReputationSource, UnstakeRequest};
+use crate::errors::RegistryError;
+
+/// Agent lifecycle status
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub enum AgentStatus {
+    /// Normal operation
+    #[default]
+    Active,
+    /// Barred from audits, new work and reputation gains by a moderator
+    /// (until `suspended_until` when set, otherwise until reinstated)
+    Suspended,
+    /// Withdrawn by the owner; only in-flight work resolution and `close_agent` remain
+    Retired,
+    /// Permanently barred by a moderator
+    Banned,
+    /// Taken offline by the owner for maintenance (until `suspended_until` when set,
+    /// otherwise until reactivated)
+    Deactivated,
+}
+
+impl AgentStatus {
+    /// Whether the agent's metadata, stake and reputation may still change
+    pub fn is_mutable(&self) -> bool {
+        matches!(self, Self::Active | Self::Suspended | Self::Deactivated)
+    }
+
+    /// Moderation transitions (retirement is the owner's call via `retire_agent`)
+    /// Re-suspending replaces the terms; Banned and Retired are terminal
+    pub fn can_moderate_to(&self, next: Self) -> bool {
+        matches!(
+            (self, next),
+            (Self::Active | Self::Suspended | Self::Deactivated, Self::Suspended)
+                | (Self::Suspended, Self::Active)
+                | (Self::Active | Self::Suspended | Self::Deactivated, Self::Banned)
+        )
+    }
+}
 
 /// Agent account - represents a registered AI agent
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct AgentAccount {
+    // Index header: everything up to `name` has a fixed offset for getProgramAccounts
+    // memcmp filters and Geyser plugins (see AgentAccount::*_OFFSET). Header fields
+    // never move or change size; new fixed fields are appended to the header only.
+
     /// Unique agent ID (auto-incremented)
     pub agent_id: u64,
 
     /// Owner wallet pubkey
     pub owner: Pubkey,
 
+    /// Share of challenges passed (basis points, 0 if untested)
+    pub win_rate_bps: u16,
+
+    /// Consecutive passes since the last failure
+    pub current_streak: u32,
+
+    /// Longest pass streak ever reached
+    pub best_streak: u32,
+
+    /// Lifecycle status (see `AgentStatus`)
+    pub status: AgentStatus,
+
+    /// How the agent was verified (VERIFICATION_NONE / _BASE / _ADMIN)
+    pub verification_level: u8,
+
+    /// Reputation tier (see `AgentAccount::tier_for`), kept in step with `reputation_score`
+    pub tier: u8,
+
+    /// Structured capabilities (bit i = CapabilityDefinition id i)
+    pub capability_flags: u64,
+
+    /// RegistryNamespace the agent belongs to (its `name_hash`; zero = none)
+    pub namespace: [u8; 32],
+
+    /// Change sequence number: 0 at creation, +1 on every write (last-writer-wins for indexers)
+    pub seq: u64,
+
     /// Agent name (max 64 chars)
     #[max_len(64)]
     pub name: String,
@@ -31,7 +101,7 @@ pub struct AgentAccount {
     /// Number of challenges failed
     pub challenges_failed: u32,
 
-    /// Whether the agent has been verified by admin
+    /// Whether the agent has been verified (by admin or `auto_verify`; see `verification_level`)
     pub verified: bool,
 
     /// Unix timestamp when agent was created
@@ -45,30 +115,584 @@ pub struct AgentAccount {
 
     /// Bump seed for PDA derivation
     pub bump: u8,
+
+    // Fields below were appended after the original release, in order: the body from
+    // `name` through `bump` keeps the released layout
+
+    /// Challenges passed per type (indexed by ChallengeType::index)
+    pub passed_by_type: [u32; ChallengeType::COUNT],
+
+    /// Challenges failed per type (indexed by ChallengeType::index)
+    pub failed_by_type: [u32; ChallengeType::COUNT],
+
+    /// Lamports staked by the owner (held in this PDA above rent)
+    pub staked_lamports: u64,
+
+    /// Account layout version (0 = created before versioning; see `migrate_agent`)
+    pub version: u8,
+
+    /// Next nonce expected in an owner-signed off-chain action (replay protection)
+    pub nonce: u64,
+
+    /// Agent this one was forked from (default pubkey = original registration)
+    pub parent_agent: Pubkey,
+
+    /// Parent's model hash at fork time
+    #[max_len(72)]
+    pub parent_model_hash: String,
+
+    /// Number of agents forked from this one
+    pub fork_count: u32,
+
+    /// Controlling AgentMultisig (default pubkey = single owner)
+    pub multisig: Pubkey,
+
+    /// Unix timestamp of the last status change (0 = never changed)
+    pub status_changed_at: i64,
+
+    /// Moderator's reason code for the current suspension or ban (0 = none)
+    pub status_reason: u16,
+
+    /// When a suspension or deactivation lapses on its own (0 = until reinstated or reactivated)
+    pub suspended_until: i64,
+
+    /// Stake cannot be withdrawn before this timestamp (extended by each `stake_agent`)
+    pub stake_locked_until: i64,
+
+    /// Capability bits backed by a tagged eval batch (`prove_capability`); always a
+    /// subset of `capability_flags`
+    pub proven_capabilities: u64,
+
+    /// Inferences proven with a Groth16 proof against the model hash (`verify_inference`)
+    pub zk_verified_inferences: u64,
+
+    /// Fields permanently locked against updates (AgentAccount::FIELD_* bits; `freeze_fields`)
+    pub frozen_fields: u8,
+
+    /// Discovery tags (max 4, 1-16 chars of [a-z0-9-]; `set_agent_tags`)
+    #[max_len(4, 16)]
+    pub tags: Vec<String>,
+
+    /// Net reputation applied per ReputationSource (indexed by `ReputationSource::index`)
+    /// Reputation from before attribution, and the initial score, count toward no source
+    pub reputation_by_source: [i64; ReputationSource::COUNT],
+
+    /// X25519 public key consumers encrypt task payloads to (`set_encryption_pubkey`;
+    /// all zeros = none published)
+    pub encryption_pubkey: [u8; 32],
+
+    /// When the audit history was quarantined for proven fraud (`quarantine_audit_history`;
+    /// 0 = never). The audit accounts are kept as evidence but no longer count
+    pub audit_quarantined_at: i64,
 }
 
 impl AgentAccount {
-    pub const SEED_PREFIX: &'static [u8] = b"agent";
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::AGENT_SEED;
+
+    /// Byte offset (with discriminator) of `win_rate_bps`
+    pub const WIN_RATE_OFFSET: usize = crate::constants::AGENT_WIN_RATE_OFFSET as usize;
+
+    /// Byte offset (with discriminator) of `current_streak`
+    pub const CURRENT_STREAK_OFFSET: usize = crate::constants::AGENT_CURRENT_STREAK_OFFSET as usize;
+
+    /// Byte offset (with discriminator) of `owner`
+    pub const OWNER_OFFSET: usize = crate::constants::AGENT_OWNER_OFFSET as usize;
+
+    /// Byte offset (with discriminator) of `best_streak`
+    pub const BEST_STREAK_OFFSET: usize = crate::constants::AGENT_BEST_STREAK_OFFSET as usize;
+
+    /// Byte offset (with discriminator) of `status`
+    pub const STATUS_OFFSET: usize = crate::constants::AGENT_STATUS_OFFSET as usize;
+
+    /// Byte offset (with discriminator) of `verification_level`
+    pub const VERIFICATION_LEVEL_OFFSET: usize = crate::constants::AGENT_VERIFICATION_LEVEL_OFFSET as usize;
+
+    /// Byte offset (with discriminator) of `tier`
+    pub const TIER_OFFSET: usize = crate::constants::AGENT_TIER_OFFSET as usize;
+
+    /// Byte offset (with discriminator) of `capability_flags`
+    pub const CAPABILITY_FLAGS_OFFSET: usize = crate::constants::AGENT_CAPABILITY_FLAGS_OFFSET as usize;
+
+    /// Byte offset (with discriminator) of `namespace`
+    pub const NAMESPACE_OFFSET: usize = crate::constants::AGENT_NAMESPACE_OFFSET as usize;
+
+    /// Byte offset (with discriminator) of `seq`
+    pub const SEQ_OFFSET: usize = crate::constants::AGENT_SEQ_OFFSET as usize;
+
+    /// Byte offset (with discriminator) of the `name` length prefix, where the header ends
+    pub const NAME_OFFSET: usize = Self::SEQ_OFFSET + 8;
+
+    // Body distances, summed from the field types in declaration order (Borsh writes fixed
+    // types at their in-memory size); they must follow any change to the fields they span
+
+    /// Distance from `reputation_score` to `updated_at`: the three counters, `verified`
+    /// and `created_at`
+    pub const UPDATED_AT_FROM_REPUTATION: usize =
+        3 * size_of::<u32>() + size_of::<bool>() + size_of::<i64>();
+
+    /// Distance from `reputation_score` to `version`: through `updated_at`, `nft_mint`,
+    /// `bump`, both per-type arrays and `staked_lamports`
+    pub const VERSION_FROM_REPUTATION: usize = Self::UPDATED_AT_FROM_REPUTATION
+        + size_of::<i64>()
+        + size_of::<Pubkey>()
+        + size_of::<u8>()
+        + 2 * size_of::<[u32; ChallengeType::COUNT]>()
+        + size_of::<u64>();
+
+    /// Distance from `version` to the `parent_model_hash` length prefix: `version`,
+    /// `nonce` and `parent_agent`
+    pub const PARENT_MODEL_HASH_FROM_VERSION: usize =
+        size_of::<u8>() + size_of::<u64>() + size_of::<Pubkey>();
+
+    /// Width of `fork_count` through `frozen_fields`, between the parent model hash and
+    /// the `tags` length prefix
+    pub const FORK_COUNT_TO_TAGS: usize = size_of::<u32>()
+        + size_of::<Pubkey>()
+        + size_of::<i64>()
+        + size_of::<u16>()
+        + 2 * size_of::<i64>()
+        + 2 * size_of::<u64>()
+        + size_of::<u8>();
+
+    /// Layout version written by this program build (0 = the original release's layout)
+    /// Bump when fields are appended and extend `migrate_agent` accordingly
+    pub const CURRENT_VERSION: u8 = 1;
+
+    /// Not verified
+    pub const VERIFICATION_NONE: u8 = 0;
+
+    /// Verified automatically from stake, reputation and an attestation
+    pub const VERIFICATION_BASE: u8 = 1;
+
+    /// Verified by the admin
+    pub const VERIFICATION_ADMIN: u8 = 2;
+
+    /// `frozen_fields` bit for `name`
+    pub const FIELD_NAME: u8 = 1 << 0;
+
+    /// `frozen_fields` bit for `model_hash` (no instruction rewrites it today; freezing
+    /// makes that a guarantee)
+    pub const FIELD_MODEL_HASH: u8 = 1 << 1;
+
+    /// `frozen_fields` bit for `capabilities`
+    pub const FIELD_CAPABILITIES: u8 = 1 << 2;
+
+    /// `frozen_fields` bit for `capability_flags`
+    pub const FIELD_CAPABILITY_FLAGS: u8 = 1 << 3;
+
+    /// `frozen_fields` bit for `tags`
+    pub const FIELD_TAGS: u8 = 1 << 4;
+
+    /// Every freezable field
+    pub const FREEZABLE_FIELDS: u8 = Self::FIELD_NAME
+        | Self::FIELD_MODEL_HASH
+        | Self::FIELD_CAPABILITIES
+        | Self::FIELD_CAPABILITY_FLAGS
+        | Self::FIELD_TAGS;
+
+    /// Wait after retirement before the account can be closed, so in-flight jobs and
+    /// challenges (each bounded by a 30-day window) can still resolve against it
+    pub const CLOSE_DELAY_SECS: i64 = 2 * Job::MAX_WINDOW + Job::REVIEW_WINDOW;
+
+    /// Longest timed suspension (1 year); use no duration for indefinite
+    pub const MAX_SUSPENSION_SECS: i64 = 365 * 24 * 3600;
+
+    /// Longest scheduled deactivation (1 year); use no end for indefinite
+    pub const MAX_DEACTIVATION_SECS: i64 = 365 * 24 * 3600;
+
+    /// Initial reputation score under the default range (50%)
+    pub const INITIAL_REPUTATION: u32 = crate::constants::INITIAL_REPUTATION;
+
+    /// Highest reputation ceiling a deployment can configure (100%)
+    pub const MAX_REPUTATION: u32 = crate::constants::MAX_REPUTATION;
+
+    /// Lowest reputation floor a deployment can configure (0%)
+    pub const MIN_REPUTATION: u32 = crate::constants::MIN_REPUTATION;
+
+    /// Name length covered by INIT_SPACE (registration limit)
+    pub const BASE_NAME_LEN: usize = crate::constants::BASE_NAME_LEN as usize;
+
+    /// Capabilities length covered by INIT_SPACE (registration limit)
+    pub const BASE_CAPABILITIES_LEN: usize = crate::constants::BASE_CAPABILITIES_LEN as usize;
+
+    /// Longest name `update_agent` will grow the account to fit
+    pub const MAX_NAME_LEN: usize = crate::constants::MAX_NAME_LEN as usize;
+
+    /// Longest capabilities string `update_agent` will grow the account to fit
+    pub const MAX_CAPABILITIES_LEN: usize = crate::constants::MAX_CAPABILITIES_LEN as usize;
+
+    /// Account size (with discriminator) needed to hold the given metadata lengths
+    pub fn space_for(name_len: usize, capabilities_len: usize) -> usize {
+        8 + Self::INIT_SPACE
+            + name_len.saturating_sub(Self::BASE_NAME_LEN)
+            + capabilities_len.saturating_sub(Self::BASE_CAPABILITIES_LEN)
+    }
+
+    /// Byte offset of `reputation_score` in serialized agent `data`: past the header and
+    /// the name, model hash and capabilities strings. `challenges_passed` and
+    /// `challenges_failed` follow it. None if `data` is too short
+    pub fn reputation_offset(data: &[u8]) -> Option<usize> {
+        let mut offset = Self::NAME_OFFSET;
+        for _ in 0..3 {
+            let len = u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?);
+            offset = offset.checked_add(4 + len as usize)?;
+        }
+        (offset + 12 <= data.len()).then_some(offset)
+    }
+
+    /// Byte offset of `reputation_by_source` in serialized agent `data`, walking the
+    /// parent model hash and tags. None if `data` is too short or not at the current
+    /// layout version
+    pub fn reputation_by_source_offset(data: &[u8]) -> Option<usize> {
+        let read_len = |offset: usize| -> Option<usize> {
+            Some(u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?) as usize)
+        };
+
+        let version = Self::reputation_offset(data)? + Self::VERSION_FROM_REPUTATION;
+        if *data.get(version)? != Self::CURRENT_VERSION {
+            return None;
+        }
+
+        let mut offset = version + Self::PARENT_MODEL_HASH_FROM_VERSION;
+        offset = offset.checked_add(4 + read_len(offset)?)?;
+        offset += Self::FORK_COUNT_TO_TAGS;
+        let tags = read_len(offset).filter(|count| *count <= Self::MAX_TAGS)?;
+        offset += 4;
+        for _ in 0..tags {
+            offset = offset.checked_add(4 + read_len(offset)?)?;
+        }
+        (offset + 8 * ReputationSource::COUNT <= data.len()).then_some(offset)
+    }
 
-    /// Initial reputation score (50%)
-    pub const INITIAL_REPUTATION: u32 = 5000;
+    /// Scale the per-source totals by `new_score / old_score`, keeping the attribution
+    /// proportional after a reputation haircut
+    pub fn scale_reputation_by_source(&mut self, old_score: u32, new_score: u32) {
+        if old_score == 0 || old_score == new_score {
+            return;
+        }
+        for total in &mut self.reputation_by_source {
+            *total = (*total as i128 * new_score as i128 / old_score as i128) as i64;
+        }
+    }
+
+    /// Attribute the change from `old_score` to the current score to `source`
+    /// Call after any reputation change, once clamping and suspension gating applied
+    pub fn credit_reputation(&mut self, source: ReputationSource, old_score: u32) {
+        let total = &mut self.reputation_by_source[source.index()];
+        *total = total.saturating_add(self.reputation_score as i64 - old_score as i64);
+    }
+
+    /// Whether the owner has published an encryption key
+    pub fn has_encryption_pubkey(&self) -> bool {
+        self.encryption_pubkey != [0; 32]
+    }
 
-    /// Maximum reputation score (100%)
-    pub const MAX_REPUTATION: u32 = 10000;
+    /// Whether the audit history has been quarantined
+    pub fn is_audit_quarantined(&self) -> bool {
+        self.audit_quarantined_at != 0
+    }
 
-    /// Minimum reputation score (0%)
-    pub const MIN_REPUTATION: u32 = 0;
+    /// Net reputation applied by `source`
+    pub fn reputation_from(&self, source: ReputationSource) -> i64 {
+        self.reputation_by_source[source.index()]
+    }
 
     /// Calculate reputation percentage (0.00 - 100.00)
     pub fn reputation_percentage(&self) -> f64 {
         (self.reputation_score as f64) / 100.0
     }
 
-    /// Update reputation with bounds checking
-    pub fn adjust_reputation(&mut self, delta: i32) {
-        let new_score = (self.reputation_score as i64) + (delta as i64);
-        self.reputation_score = new_score
-            .max(Self::MIN_REPUTATION as i64)
-            .min(Self::MAX_REPUTATION as i64) as u32;
+    /// Whether a suspension or ban is in effect at `now`
+    pub fn is_restricted(&self, now: i64) -> bool {
+        match self.status {
+            AgentStatus::Banned => true,
+            AgentStatus::Suspended => self.suspended_until == 0 || now < self.suspended_until,
+            AgentStatus::Active | AgentStatus::Retired | AgentStatus::Deactivated => false,
+        }
+    }
+
+    /// Whether an owner deactivation is in effect at `now`
+    pub fn is_deactivated(&self, now: i64) -> bool {
+        self.status == AgentStatus::Deactivated
+            && (self.suspended_until == 0 || now < self.suspended_until)
+    }
+
+    /// Last activity for reputation decay; a lapsed deactivation window counts as activity
+    pub fn last_active_at(&self) -> i64 {
+        match self.status {
+            AgentStatus::Deactivated => self.updated_at.max(self.suspended_until),
+            _ => self.updated_at,
+        }
+    }
+
+    /// Whether the agent may store audits, list services, take jobs and be challenged
+    pub fn accepts_work(&self, now: i64) -> bool {
+        self.status != AgentStatus::Retired && !self.is_restricted(now) && !self.is_deactivated(now)
+    }
+
+    /// Width of a reputation tier in score points (tiers 0-4 over 0-10000)
+    pub const TIER_STEP: u32 = 2000;
+
+    /// Highest reputation tier
+    pub const MAX_TIER: u8 = 4;
+
+    /// Reputation tier for a score: 0 below 20%, then one tier per 20%, 4 from 80%
+    pub fn tier_for(reputation_score: u32) -> u8 {
+        (reputation_score / Self::TIER_STEP).min(Self::MAX_TIER as u32) as u8
+    }
+
+    /// Set the reputation score and the header tier together
+    pub fn set_reputation(&mut self, reputation_score: u32) {
+        self.reputation_score = reputation_score;
+        self.tier = Self::tier_for(reputation_score);
+    }
+
+    /// Update reputation, clamped to the configured floor and ceiling
+    /// Gains are dropped while a suspension or ban is in effect; penalties still apply
+    pub fn adjust_reputation(&mut self, delta: i32, config: &RegistryConfig, now: i64) {
+        if delta > 0 && self.is_restricted(now) {
+            return;
+        }
+        self.set_reputation(config.clamp_reputation((self.reputation_score as i64) + (delta as i64)));
+    }
+
+    /// Record a challenge outcome: total and per-type counters plus reputation
+    pub fn record_challenge(
+        &mut self,
+        challenge_type: ChallengeType,
+        passed: bool,
+        delta: i32,
+        config: &RegistryConfig,
+        now: i64,
+    ) {
+        let idx = challenge_type.index();
+        if passed {
+            self.challenges_passed = self.challenges_passed.saturating_add(1);
+            self.passed_by_type[idx] = self.passed_by_type[idx].saturating_add(1);
+        } else {
+            self.challenges_failed = self.challenges_failed.saturating_add(1);
+            self.failed_by_type[idx] = self.failed_by_type[idx].saturating_add(1);
+        }
+        self.update_stats(passed);
+        self.adjust_reputation(delta, config, now);
+    }
+
+    /// Refresh the streaks and stored win rate once a pass or failure has been counted
+    pub fn update_stats(&mut self, passed: bool) {
+        if passed {
+            self.current_streak = self.current_streak.saturating_add(1);
+            self.best_streak = self.best_streak.max(self.current_streak);
+        } else {
+            self.current_streak = 0;
+        }
+        self.win_rate_bps = Self::rate_bps(self.challenges_passed as u64, self.challenges_failed as u64);
+    }
+
+    /// `passed / (passed + failed)` in basis points
+    pub fn rate_bps(passed: u64, failed: u64) -> u16 {
+        let total = passed + failed;
+        if total == 0 {
+            return 0;
+        }
+        (passed * 10000 / total) as u16
+    }
+
+    /// Whether this agent was created by `fork_agent`
+    pub fn is_fork(&self) -> bool {
+        self.parent_agent != Pubkey::default()
+    }
+
+    /// Advance the change sequence number (call once per instruction that writes the agent)
+    pub fn bump_seq(&mut self) {
+        self.seq = self.seq.saturating_add(1);
+    }
+
+    /// Record a write at `now`: refresh `updated_at` and advance `seq`
+    pub fn touch(&mut self, now: i64) {
+        self.updated_at = now;
+        self.bump_seq();
+    }
+
+    /// Fail if any of the `frozen_fields` bits in `fields` is set
+    pub fn require_unfrozen(&self, fields: u8) -> Result<()> {
+        require!(self.frozen_fields & fields == 0, RegistryError::FieldFrozen);
+        Ok(())
+    }
+
+    /// Permanently lock `fields` (AgentAccount::FIELD_* bits)
+    pub fn freeze(&mut self, fields: u8) -> Result<()> {
+        require!(
+            fields != 0 && fields & !Self::FREEZABLE_FIELDS == 0,
+            RegistryError::InvalidFrozenFields
+        );
+        self.frozen_fields |= fields;
+        Ok(())
+    }
+
+    /// Replace the declared capability flags unless frozen; proofs of dropped
+    /// capabilities lapse
+    pub fn set_capability_flags(&mut self, flags: u64) -> Result<()> {
+        self.require_unfrozen(Self::FIELD_CAPABILITY_FLAGS)?;
+        self.capability_flags = flags;
+        self.proven_capabilities &= flags;
+        Ok(())
+    }
+
+    /// Most discovery tags per agent
+    pub const MAX_TAGS: usize = crate::constants::MAX_AGENT_TAGS as usize;
+
+    /// Longest discovery tag
+    pub const MAX_TAG_LEN: usize = crate::constants::MAX_TAG_LEN as usize;
+
+    /// Tags are 1-16 characters of lowercase ASCII letters, digits and '-'
+    pub fn is_valid_tag(tag: &str) -> bool {
+        (1..=Self::MAX_TAG_LEN).contains(&tag.len())
+            && tag.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+    }
+
+    /// Whether the agent carries discovery tag `tag`
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    /// Replace the discovery tags unless frozen
+    pub fn set_tags(&mut self, tags: Vec<String>) -> Result<()> {
+        self.require_unfrozen(Self::FIELD_TAGS)?;
+        let unique = tags.iter().enumerate().all(|(i, tag)| !tags[..i].contains(tag));
+        require!(
+            tags.len() <= Self::MAX_TAGS && unique && tags.iter().all(|tag| Self::is_valid_tag(tag)),
+            RegistryError::InvalidTags
+        );
+        self.tags = tags;
+        Ok(())
+    }
+
+    /// Whether the agent can be garbage collected: never verified or challenged, holding
+    /// no stake, not under a multisig or ban, and untouched for `idle_secs`
+    pub fn is_collectable(&self, idle_secs: i64, now: i64) -> bool {
+        idle_secs > 0
+            && !self.verified
+            && self.verification_level == Self::VERIFICATION_NONE
+            && self.challenges_passed == 0
+            && self.challenges_failed == 0
+            && self.staked_lamports == 0
+            && !self.has_multisig()
+            && self.status != AgentStatus::Banned
+            && now.saturating_sub(self.updated_at) >= idle_secs
+    }
+
+    /// Whether the agent is tagged with a RegistryNamespace
+    pub fn in_namespace(&self) -> bool {
+        self.namespace != [0u8; 32]
+    }
+
+    /// Whether owner-key mutations are disabled in favour of a multisig
+    pub fn has_multisig(&self) -> bool {
+        self.multisig != Pubkey::default()
+    }
+
+    /// Accept a signed action only if it carries the expected nonce, then advance it
+    pub fn consume_nonce(&mut self, nonce: u64) -> Result<()> {
+        require!(nonce == self.nonce, RegistryError::InvalidNonce);
+        self.nonce = self.nonce.checked_add(1).ok_or(RegistryError::InvalidNonce)?;
+        Ok(())
+    }
+
+    /// Lamports still needed for an agent PDA holding `lamports` to meet `minimum_balance`
+    /// The stake and a `pending` unstake share the account but belong to the owner, so
+    /// only the rest counts towards rent
+    pub fn rent_shortfall(&self, lamports: u64, pending: u64, minimum_balance: u64) -> u64 {
+        let own = lamports
+            .saturating_sub(self.staked_lamports)
+            .saturating_sub(pending);
+        minimum_balance.saturating_sub(own)
+    }
+
+    /// Fail unless the agent PDA `info` stays rent-exempt without its stake and `pending`
+    /// unstake; checked after stake leaves the account
+    pub fn require_rent_exempt(&self, info: &AccountInfo, pending: u64) -> Result<()> {
+        let minimum_balance = Rent::get()?.minimum_balance(info.data_len());
+        require!(
+            self.rent_shortfall(info.lamports(), pending, minimum_balance) == 0,
+            RegistryError::AgentRentNotCovered
+        );
+        Ok(())
+    }
+
+    /// Slash `bps` of the stake and of the pending unstake, if any, moving the lamports
+    /// out of the agent PDA to `recipient`
+    /// Returns the amount slashed
+    pub fn slash_stake<'info>(
+        agent: &mut Account<'info, AgentAccount>,
+        pending: Option<&mut UnstakeRequest>,
+        bps: u16,
+        recipient: &AccountInfo<'info>,
+    ) -> Result<u64> {
+        let share = |amount: u64| {
+            (amount as u128 * bps as u128 / RegistryConfig::BPS_DENOMINATOR as u128) as u64
+        };
+
+        let mut slashed = share(agent.staked_lamports);
+        agent.staked_lamports -= slashed;
+        if let Some(request) = pending {
+            let cut = share(request.amount);
+            request.amount -= cut;
+            slashed += cut;
+        }
+        if slashed == 0 {
+            return Ok(0);
+        }
+
+        // Agent PDA is program-owned, so lamports can be moved directly
+        let agent_info = agent.to_account_info();
+        **agent_info.try_borrow_mut_lamports()? -= slashed;
+        **recipient.try_borrow_mut_lamports()? += slashed;
+
+        Ok(slashed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stake_does_not_pay_for_growth() {
+        let rent = Rent::default();
+        let initial_len = AgentAccount::space_for(AgentAccount::BASE_NAME_LEN, 0);
+        let grown_len = AgentAccount::space_for(AgentAccount::MAX_NAME_LEN, 0);
+        let stake = 10 * rent.minimum_balance(grown_len);
+
+        // Registered rent-exempt at the base size, then staked
+        let mut agent = AgentAccount {
+            staked_lamports: stake,
+            ..Default::default()
+        };
+        let mut lamports = rent.minimum_balance(initial_len) + stake;
+
+        // Growing the metadata is paid for by the payer, not taken from the stake
+        let shortfall = agent.rent_shortfall(lamports, 0, rent.minimum_balance(grown_len));
+        assert_eq!(
+            shortfall,
+            rent.minimum_balance(grown_len) - rent.minimum_balance(initial_len)
+        );
+        lamports += shortfall;
+
+        // Unstaking everything leaves the grown account rent-exempt
+        agent.staked_lamports = 0;
+        lamports -= stake;
+        assert_eq!(agent.rent_shortfall(lamports, 0, rent.minimum_balance(grown_len)), 0);
+    }
+
+    #[test]
+    fn pending_unstake_does_not_pay_rent() {
+        let agent = AgentAccount {
+            staked_lamports: 300,
+            ..Default::default()
+        };
+        assert_eq!(agent.rent_shortfall(1_500, 0, 1_000), 0);
+        assert_eq!(agent.rent_shortfall(1_500, 200, 1_000), 0);
+        assert_eq!(agent.rent_shortfall(1_500, 400, 1_000), 200);
+        assert_eq!(agent.rent_shortfall(100, 400, 1_000), 1_000);
     }
 }
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+/// AgentArchive - compact record left behind by `archive_agent`
+/// Keeps a retired agent's headline numbers on-chain after the full account is closed;
+/// `state_hash` commits to everything else, so an archived snapshot of the account
+/// (e.g. from an indexer) can be checked against it
+#[account]
+#[derive(InitSpace)]
+pub struct AgentArchive {
+    /// Address of the closed AgentAccount
+    pub agent: Pubkey,
+
+    pub agent_id: u64,
+
+    pub owner: Pubkey,
+
+    /// SHA256 of the agent's final serialization (discriminator included, unused
+    /// trailing space excluded)
+    pub state_hash: [u8; 32],
+
+    // Final values of the AgentAccount fields of the same name
+    pub reputation_score: u32,
+
+    pub challenges_passed: u32,
+
+    pub challenges_failed: u32,
+
+    pub win_rate_bps: u16,
+
+    pub best_streak: u32,
+
+    pub tier: u8,
+
+    pub verification_level: u8,
+
+    pub proven_capabilities: u64,
+
+    pub zk_verified_inferences: u64,
+
+    pub created_at: i64,
+
+    /// When the agent was retired
+    pub retired_at: i64,
+
+    /// Agent `seq` at archive time
+    pub final_seq: u64,
+
+    /// Unix timestamp of archiving
+    pub archived_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl AgentArchive {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::AGENT_ARCHIVE_SEED;
+}
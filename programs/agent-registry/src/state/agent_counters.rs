@@ -0,0 +1,305 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use super::{AgentAccount, AgentStatus, ReputationSource};
+use crate::errors::RegistryError;
+
+/// AgentCounters - an agent's hot reputation counters in a small zero-copy account
+/// `update_reputation_fast` updates these and patches the same fields into the agent
+/// in place, so the hot path never round-trips the full AgentAccount (strings
+/// included) through Borsh. `synced_seq` pins the agent `seq` the copy matches; any
+/// other write to the agent leaves it stale until `sync_agent_counters`
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct AgentCounters {
+    /// The agent these counters mirror
+    pub agent: Pubkey,
+
+    /// The agent's id (for events, without reading the agent)
+    pub agent_id: u64,
+
+    /// Agent `seq` at the last sync or fast update
+    pub synced_seq: u64,
+
+    /// Fast updates applied since creation
+    pub updates: u64,
+
+    /// Unix timestamp of the last fast update (0 = none yet)
+    pub last_update_at: i64,
+
+    // Copies of the AgentAccount fields of the same name
+    pub reputation_score: u32,
+
+    pub challenges_passed: u32,
+
+    pub challenges_failed: u32,
+
+    pub current_streak: u32,
+
+    pub best_streak: u32,
+
+    pub win_rate_bps: u16,
+
+    pub tier: u8,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AgentCounters {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::AGENT_COUNTERS_SEED;
+
+    /// Account size (with discriminator)
+    pub const SPACE: usize = 8 + std::mem::size_of::<AgentCounters>();
+
+    /// Refresh the copy from a deserialized agent
+    pub fn copy_from(&mut self, agent: &AgentAccount) {
+        self.agent_id = agent.agent_id;
+        self.synced_seq = agent.seq;
+        self.reputation_score = agent.reputation_score;
+        self.challenges_passed = agent.challenges_passed;
+        self.challenges_failed = agent.challenges_failed;
+        self.current_streak = agent.current_streak;
+        self.best_streak = agent.best_streak;
+        self.win_rate_bps = agent.win_rate_bps;
+        self.tier = agent.tier;
+    }
+
+    /// Check serialized agent `data` is an Active agent this copy is in sync with
+    /// Returns the offset of its `reputation_score`
+    pub fn check_agent(&self, data: &[u8]) -> Result<usize> {
+        require!(
+            data.get(..8) == Some(AgentAccount::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        let offset = AgentAccount::reputation_offset(data)
+            .filter(|offset| offset + AgentAccount::UPDATED_AT_FROM_REPUTATION + 8 <= data.len())
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+        // Suspensions gate gains on `suspended_until`, so only Active agents take the hot path
+        require!(
+            data[AgentAccount::STATUS_OFFSET] == AgentStatus::Active as u8,
+            RegistryError::AgentNotActive
+        );
+        require!(
+            read_u64(data, AgentAccount::SEQ_OFFSET) == self.synced_seq,
+            RegistryError::AgentCountersStale
+        );
+        Ok(offset)
+    }
+
+    /// The mirrored fields as an Active AgentAccount (everything else default), so a fast
+    /// update runs the same reputation logic as `update_reputation`
+    pub fn to_agent(&self) -> AgentAccount {
+        AgentAccount {
+            agent_id: self.agent_id,
+            seq: self.synced_seq,
+            status: AgentStatus::Active,
+            reputation_score: self.reputation_score,
+            challenges_passed: self.challenges_passed,
+            challenges_failed: self.challenges_failed,
+            current_streak: self.current_streak,
+            best_streak: self.best_streak,
+            win_rate_bps: self.win_rate_bps,
+            tier: self.tier,
+            ..Default::default()
+        }
+    }
+
+    /// Take the result of a fast update applied to `to_agent`'s copy
+    pub fn record_update(&mut self, agent: &AgentAccount) {
+        self.copy_from(agent);
+        self.updates = self.updates.saturating_add(1);
+        self.last_update_at = agent.updated_at;
+    }
+
+    /// Write the counters, `seq` and `updated_at` into serialized agent `data` in place
+    /// (`offset` from `check_agent`)
+    pub fn write_through(&self, data: &mut [u8], offset: usize) {
+        write(data, AgentAccount::WIN_RATE_OFFSET, &self.win_rate_bps.to_le_bytes());
+        write(data, AgentAccount::CURRENT_STREAK_OFFSET, &self.current_streak.to_le_bytes());
+        write(data, AgentAccount::BEST_STREAK_OFFSET, &self.best_streak.to_le_bytes());
+        data[AgentAccount::TIER_OFFSET] = self.tier;
+        write(data, AgentAccount::SEQ_OFFSET, &self.synced_seq.to_le_bytes());
+        write(data, offset, &self.reputation_score.to_le_bytes());
+        write(data, offset + 4, &self.challenges_passed.to_le_bytes());
+        write(data, offset + 8, &self.challenges_failed.to_le_bytes());
+        write(data, offset + AgentAccount::UPDATED_AT_FROM_REPUTATION, &self.last_update_at.to_le_bytes());
+    }
+
+    /// Attribute the change from `old_score` to `source` in serialized agent `data`,
+    /// like `AgentAccount::credit_reputation` (agents must be at the current layout)
+    pub fn credit_through(&self, data: &mut [u8], source: ReputationSource, old_score: u32) -> Result<()> {
+        let offset = AgentAccount::reputation_by_source_offset(data)
+            .ok_or(RegistryError::AgentNeedsMigration)?
+            + 8 * source.index();
+        let total = read_u64(data, offset) as i64;
+        let total = total.saturating_add(self.reputation_score as i64 - old_score as i64);
+        write(data, offset, &total.to_le_bytes());
+        Ok(())
+    }
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+fn write(data: &mut [u8], offset: usize, bytes: &[u8]) {
+    data[offset..offset + bytes.len()].copy_from_slice(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::ChallengeType;
+
+    /// An agent at the current layout with a distinct value in every patched field
+    fn sample_agent() -> AgentAccount {
+        AgentAccount {
+            agent_id: 7,
+            owner: Pubkey::new_from_array([1; 32]),
+            win_rate_bps: 0x0203,
+            current_streak: 0x0405_0607,
+            best_streak: 0x0809_0a0b,
+            status: AgentStatus::Active,
+            verification_level: AgentAccount::VERIFICATION_ADMIN,
+            tier: 3,
+            capability_flags: 0x0c0d_0e0f,
+            namespace: [2; 32],
+            seq: 41,
+            name: "agent".to_string(),
+            model_hash: format!("sha256:{}", "ab".repeat(32)),
+            capabilities: "analysis,coding".to_string(),
+            reputation_score: 6100,
+            challenges_passed: 11,
+            challenges_failed: 13,
+            verified: true,
+            created_at: 1_700_000_000,
+            updated_at: 1_700_000_500,
+            nft_mint: Pubkey::new_from_array([3; 32]),
+            bump: 254,
+            passed_by_type: [1; ChallengeType::COUNT],
+            failed_by_type: [2; ChallengeType::COUNT],
+            staked_lamports: 5_000_000_000,
+            version: AgentAccount::CURRENT_VERSION,
+            nonce: 17,
+            parent_agent: Pubkey::new_from_array([4; 32]),
+            parent_model_hash: "sha256:parent".to_string(),
+            fork_count: 19,
+            multisig: Pubkey::new_from_array([5; 32]),
+            status_changed_at: 23,
+            status_reason: 29,
+            suspended_until: 31,
+            stake_locked_until: 37,
+            proven_capabilities: 43,
+            zk_verified_inferences: 47,
+            frozen_fields: AgentAccount::FIELD_TAGS,
+            tags: vec!["defi".to_string(), "research-agent".to_string()],
+            reputation_by_source: [100, -200, 300, -400, 500],
+            encryption_pubkey: [6; 32],
+            audit_quarantined_at: 53,
+        }
+    }
+
+    fn serialize(agent: &AgentAccount) -> Vec<u8> {
+        let mut data = Vec::new();
+        agent.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    fn read<const N: usize>(data: &[u8], offset: usize) -> [u8; N] {
+        data[offset..offset + N].try_into().unwrap()
+    }
+
+    #[test]
+    fn offsets_match_serialized_layout() {
+        let agent = sample_agent();
+        let data = serialize(&agent);
+
+        assert_eq!(read::<32>(&data, AgentAccount::OWNER_OFFSET), agent.owner.to_bytes());
+        assert_eq!(read::<2>(&data, AgentAccount::WIN_RATE_OFFSET), agent.win_rate_bps.to_le_bytes());
+        assert_eq!(
+            read::<4>(&data, AgentAccount::CURRENT_STREAK_OFFSET),
+            agent.current_streak.to_le_bytes()
+        );
+        assert_eq!(read::<4>(&data, AgentAccount::BEST_STREAK_OFFSET), agent.best_streak.to_le_bytes());
+        assert_eq!(data[AgentAccount::STATUS_OFFSET], agent.status as u8);
+        assert_eq!(data[AgentAccount::VERIFICATION_LEVEL_OFFSET], agent.verification_level);
+        assert_eq!(data[AgentAccount::TIER_OFFSET], agent.tier);
+        assert_eq!(
+            read::<8>(&data, AgentAccount::CAPABILITY_FLAGS_OFFSET),
+            agent.capability_flags.to_le_bytes()
+        );
+        assert_eq!(read::<32>(&data, AgentAccount::NAMESPACE_OFFSET), agent.namespace);
+        assert_eq!(read::<8>(&data, AgentAccount::SEQ_OFFSET), agent.seq.to_le_bytes());
+        assert_eq!(
+            read::<4>(&data, AgentAccount::NAME_OFFSET),
+            (agent.name.len() as u32).to_le_bytes()
+        );
+
+        let reputation = AgentAccount::reputation_offset(&data).unwrap();
+        assert_eq!(read::<4>(&data, reputation), agent.reputation_score.to_le_bytes());
+        assert_eq!(read::<4>(&data, reputation + 4), agent.challenges_passed.to_le_bytes());
+        assert_eq!(read::<4>(&data, reputation + 8), agent.challenges_failed.to_le_bytes());
+        assert_eq!(
+            read::<8>(&data, reputation + AgentAccount::UPDATED_AT_FROM_REPUTATION),
+            agent.updated_at.to_le_bytes()
+        );
+        assert_eq!(data[reputation + AgentAccount::VERSION_FROM_REPUTATION], agent.version);
+
+        let by_source = AgentAccount::reputation_by_source_offset(&data).unwrap();
+        for (index, total) in agent.reputation_by_source.iter().enumerate() {
+            assert_eq!(read::<8>(&data, by_source + 8 * index), total.to_le_bytes());
+        }
+    }
+
+    #[test]
+    fn by_source_offset_requires_current_version() {
+        let mut agent = sample_agent();
+        agent.version = AgentAccount::CURRENT_VERSION + 1;
+        assert_eq!(AgentAccount::reputation_by_source_offset(&serialize(&agent)), None);
+    }
+
+    #[test]
+    fn write_through_matches_deserialized_update() {
+        let mut expected = sample_agent();
+        let mut data = serialize(&expected);
+
+        let mut counters = AgentCounters {
+            agent: Pubkey::new_unique(),
+            agent_id: 0,
+            synced_seq: 0,
+            updates: 0,
+            last_update_at: 0,
+            reputation_score: 0,
+            challenges_passed: 0,
+            challenges_failed: 0,
+            current_streak: 0,
+            best_streak: 0,
+            win_rate_bps: 0,
+            tier: 0,
+            bump: 255,
+        };
+        counters.copy_from(&expected);
+        let offset = counters.check_agent(&data).unwrap();
+
+        // The fast path's copy, changed as a failed challenge would change the agent
+        let mut agent = counters.to_agent();
+        let old_score = agent.reputation_score;
+        agent.challenges_failed += 1;
+        agent.update_stats(false);
+        agent.set_reputation(old_score - 250);
+        agent.touch(1_700_000_900);
+        counters.record_update(&agent);
+        counters.write_through(&mut data, offset);
+        counters.credit_through(&mut data, ReputationSource::Oracle, old_score).unwrap();
+
+        expected.challenges_failed += 1;
+        expected.update_stats(false);
+        expected.set_reputation(old_score - 250);
+        expected.touch(1_700_000_900);
+        expected.credit_reputation(ReputationSource::Oracle, old_score);
+
+        let patched = AgentAccount::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(serialize(&patched), serialize(&expected));
+        assert_eq!(counters.synced_seq, expected.seq);
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use super::ReputationTransferPolicy;
+
+/// AgentTransfer - provenance record left by `transfer_agent` (one per source agent)
+/// Links the retired account to its successor under the new owner and records how
+/// the reputation was carried across
+#[account]
+#[derive(InitSpace)]
+pub struct AgentTransfer {
+    /// Retired agent account under the previous owner
+    pub agent: Pubkey,
+
+    /// Agent account under the new owner
+    pub successor: Pubkey,
+
+    /// Agent ID (kept across the transfer)
+    pub agent_id: u64,
+
+    pub from_owner: Pubkey,
+    pub to_owner: Pubkey,
+
+    /// Policy applied (`RegistryConfig::reputation_transfer_policy` at transfer time)
+    pub policy: ReputationTransferPolicy,
+
+    /// Haircut applied under `ReputationTransferPolicy::Haircut` (basis points)
+    pub haircut_bps: u16,
+
+    /// Score before and after the transfer
+    pub reputation_before: u32,
+    pub reputation_after: u32,
+
+    /// Unix timestamp of the transfer
+    pub transferred_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AgentTransfer {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::AGENT_TRANSFER_SEED;
+}
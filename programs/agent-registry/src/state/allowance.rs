@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// Allowance - a consumer's prepaid call package for one per-call ServiceListing
+/// Bought with `purchase_allowance` (paid to the agent owner up front) and drawn down
+/// by the listing's metering key with `meter_usage`, so the remaining balance is
+/// enforced on-chain rather than by the service's own accounting
+#[account]
+#[derive(InitSpace)]
+pub struct Allowance {
+    /// Consumer who bought the calls
+    pub consumer: Pubkey,
+
+    /// The agent serving them
+    pub agent: Pubkey,
+
+    /// Per-call listing the calls were bought from
+    pub listing: Pubkey,
+
+    /// Calls left to meter
+    pub remaining_calls: u64,
+
+    /// Calls metered so far
+    pub used_calls: u64,
+
+    /// Unused calls lapse at this timestamp
+    pub expires_at: i64,
+
+    /// Unix timestamp of the last purchase
+    pub purchased_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Allowance {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::ALLOWANCE_SEED;
+
+    /// Longest validity a single purchase can set (1 year)
+    pub const MAX_DURATION_SECS: i64 = 365 * 24 * 3600;
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Add `calls` valid until at least `expires_at`; calls left on an expired
+    /// package are forfeited first
+    pub fn top_up(&mut self, calls: u64, expires_at: i64, now: i64) {
+        if self.is_expired(now) {
+            self.remaining_calls = 0;
+        }
+        self.remaining_calls = self.remaining_calls.saturating_add(calls);
+        self.expires_at = self.expires_at.max(expires_at);
+        self.purchased_at = now;
+    }
+
+    /// Draw `calls` from the package
+    pub fn consume(&mut self, calls: u64, now: i64) -> Result<()> {
+        require!(!self.is_expired(now), RegistryError::AllowanceExpired);
+        require!(calls <= self.remaining_calls, RegistryError::AllowanceExhausted);
+        self.remaining_calls -= calls;
+        self.used_calls = self.used_calls.saturating_add(calls);
+        Ok(())
+    }
+}
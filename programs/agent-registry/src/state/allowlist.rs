@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// AllowlistEntry - admin approval for one wallet to register agents
+/// Only consulted while the registry config has `allowlist_only` set
+#[account]
+#[derive(InitSpace)]
+pub struct AllowlistEntry {
+    /// Wallet approved to register
+    pub registrant: Pubkey,
+
+    /// Admin who approved the wallet
+    pub added_by: Pubkey,
+
+    /// Unix timestamp when approved
+    pub added_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AllowlistEntry {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::ALLOWLIST_SEED;
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use super::AgentAccount;
+
+/// What an attester vouches for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum AttestationKind {
+    /// Remote attestation that the model runs inside a trusted execution environment
+    Tee,
+    /// Independent audit of the model and its deployment
+    Audit,
+}
+
+/// Attester account - a TEE verifier or auditor trusted to attest agents
+/// Registered by the admin; kept (deactivated) on removal for accountability
+#[account]
+#[derive(InitSpace)]
+pub struct Attester {
+    /// The attester's signing key
+    pub authority: Pubkey,
+
+    /// Kind of attestation this attester issues
+    pub kind: AttestationKind,
+
+    /// Whether the attester's attestations are currently accepted
+    pub active: bool,
+
+    /// Total attestations issued
+    pub attestations_issued: u64,
+
+    /// Unix timestamp when the attester was registered
+    pub registered_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Attester {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::ATTESTER_SEED;
+}
+
+/// Attestation - an attester's statement about one agent's model (one per agent and attester)
+/// Bound to the model hash at issue time, so a model change invalidates it
+#[account]
+#[derive(InitSpace)]
+pub struct Attestation {
+    /// The attested agent
+    pub agent: Pubkey,
+
+    /// The attester's signing key
+    pub attester: Pubkey,
+
+    /// Kind of attestation (copied from the attester)
+    pub kind: AttestationKind,
+
+    /// SHA256 of the attestation report (stored off-chain)
+    pub report_hash: [u8; 32],
+
+    /// Agent's model hash at issue time
+    #[max_len(72)]
+    pub model_hash: String,
+
+    /// Unix timestamp when issued
+    pub issued_at: i64,
+
+    /// Unix timestamp after which the attestation is no longer valid
+    pub expires_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Attestation {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::ATTESTATION_SEED;
+
+    /// Longest allowed validity period (1 year)
+    pub const MAX_VALIDITY_SECS: i64 = 365 * 24 * 3600;
+
+    /// Whether the attestation still covers the agent's current model
+    pub fn is_valid_for(&self, agent: &AgentAccount, now: i64) -> bool {
+        now < self.expires_at && self.model_hash == agent.model_hash
+    }
+}
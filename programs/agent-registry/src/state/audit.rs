@@ -98,7 +98,7 @@ pub struct AuditEntry {
 }
 
 impl AuditEntry {
-    pub const SEED_PREFIX: &'static [u8] = b"audit";
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::AUDIT_SEED;
 
     /// Calculate risk score based on action type and context
     pub fn calculate_risk_score(action_type: &ActionType, context_risk: u8) -> u8 {
@@ -149,7 +149,7 @@ pub struct AgentAuditSummary {
 }
 
 impl AgentAuditSummary {
-    pub const SEED_PREFIX: &'static [u8] = b"audit_summary";
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::AUDIT_SUMMARY_SEED;
 
     /// Update summary with new audit entry
     pub fn record_entry(&mut self, risk_score: u8, is_alert: bool, timestamp: i64) {
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// AuditDelegate - a third-party logging service the owner lets submit audits for an agent
+/// Scoped to audit submission only (AuditDelegate::SCOPE_* bits); the delegate pays the
+/// audit fee and rent and gets no other rights over the agent
+#[account]
+#[derive(InitSpace)]
+pub struct AuditDelegate {
+    pub agent: Pubkey,
+
+    /// Wallet allowed to submit
+    pub delegate: Pubkey,
+
+    /// Permitted audit instructions (AuditDelegate::SCOPE_* bits)
+    pub scopes: u8,
+
+    /// Authorization lapses at this timestamp (0 = until revoked)
+    pub expires_at: i64,
+
+    /// Audits submitted under this authorization
+    pub submissions: u64,
+
+    /// Unix timestamp of the last submission (0 = none yet)
+    pub last_submitted_at: i64,
+
+    /// Unix timestamp of the (latest) authorization
+    pub authorized_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl AuditDelegate {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::AUDIT_DELEGATE_SEED;
+
+    /// `store_merkle_audit`
+    pub const SCOPE_MERKLE_BATCH: u8 = 1 << 0;
+
+    /// `append_audit_leaf`
+    pub const SCOPE_AUDIT_LEAF: u8 = 1 << 1;
+
+    /// Every defined scope bit
+    pub const ALL_SCOPES: u8 = Self::SCOPE_MERKLE_BATCH | Self::SCOPE_AUDIT_LEAF;
+
+    /// Whether the authorization covers `scope` at `now`
+    pub fn permits(&self, scope: u8, now: i64) -> bool {
+        self.scopes & scope == scope && (self.expires_at == 0 || now < self.expires_at)
+    }
+
+    /// Count a delegated submission of `scope`, failing unless the authorization covers it
+    pub fn record_submission(&mut self, scope: u8, now: i64) -> Result<()> {
+        require!(self.permits(scope, now), RegistryError::AuditDelegateNotPermitted);
+        self.submissions = self.submissions.saturating_add(1);
+        self.last_submitted_at = now;
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+
+/// Switchboard On-Demand program (same address on devnet and mainnet)
+pub const SWITCHBOARD_ON_DEMAND_PROGRAM_ID: Pubkey =
+    pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+
+/// Random audit target selection round (VRF commit-reveal)
+///
+/// Pattern: request commits to a Switchboard randomness account seeded in the
+/// previous slot (value not yet known) and to the latest RegistrySnapshot, and posts
+/// REQUEST_BOND_LAMPORTS → oracle reveals → anyone calls `select_challenge_target`, which
+/// maps the value onto a snapshot leaf and proves which agent it is. Leaves whose agent
+/// was closed or no longer accepts work are re-drawn, up to MAX_REDRAWS times. Neither the
+/// requester nor the admin can predict which agent gets audited, and once revealed the
+/// round can't be discarded: the bond is refunded on selection or when the redraws run
+/// out, and forfeited to the treasury by `expire_audit_selection` if nothing was revealed
+/// within SETTLE_WINDOW.
+#[account]
+#[derive(InitSpace)]
+pub struct AuditSelection {
+    /// Sequential round number
+    pub round: u64,
+
+    /// Switchboard randomness account committed to for this round
+    pub randomness_account: Pubkey,
+
+    /// Seed slot of the randomness account at commit time
+    pub commit_slot: u64,
+
+    /// Who requested the round
+    pub requester: Pubkey,
+
+    /// Unix timestamp when requested
+    pub requested_at: i64,
+
+    /// RegistrySnapshot the target is drawn from
+    pub snapshot: u64,
+
+    /// Lamports posted by the requester (held in this PDA above rent)
+    pub bond: u64,
+
+    /// Whether the randomness has been revealed and recorded
+    pub revealed: bool,
+
+    /// Whether the target has been selected
+    pub settled: bool,
+
+    /// Whether the round ended without a target: it lapsed unrevealed (bond forfeited)
+    /// or used up its redraws (bond refunded)
+    pub expired: bool,
+
+    /// Revealed randomness used for selection
+    pub randomness: [u8; 32],
+
+    /// Number of agents in the snapshot
+    pub population: u64,
+
+    /// Draws discarded because their agent was closed or inactive
+    pub redraws: u32,
+
+    /// Selected agent (valid once settled)
+    pub selected_agent: Pubkey,
+
+    /// Selected agent ID (valid once settled)
+    pub selected_agent_id: u64,
+
+    /// Unix timestamp when selected
+    pub selected_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AuditSelection {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::AUDIT_SELECTION_SEED;
+
+    /// Bond posted with each request, at stake if the round is never revealed (0.01 SOL)
+    pub const REQUEST_BOND_LAMPORTS: u64 = 10_000_000;
+
+    /// Time the requester has to get the randomness revealed (1 hour)
+    pub const SETTLE_WINDOW: i64 = 3600;
+
+    /// Discarded draws after which a revealed round ends without a target, so a snapshot
+    /// of mostly closed agents can't hold the bond forever
+    pub const MAX_REDRAWS: u32 = 32;
+
+    /// Map revealed randomness onto a snapshot leaf index in [0, population) for the
+    /// `draw`-th attempt
+    pub fn pick(randomness: &[u8; 32], draw: u32, population: u64) -> u64 {
+        let digest = solana_sha256_hasher::hashv(&[randomness, &draw.to_le_bytes()]).to_bytes();
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&digest[..8]);
+        u64::from_le_bytes(word) % population
+    }
+
+    /// Whether an unrevealed round may be expired at `now`
+    pub fn lapsed(&self, now: i64) -> bool {
+        !self.revealed && !self.settled && now > self.requested_at.saturating_add(Self::SETTLE_WINDOW)
+    }
+}
+
+/// Fields read from a Switchboard On-Demand `RandomnessAccountData`
+///
+/// Layout after the 8-byte `RandomnessAccountData` discriminator:
+/// authority(32) queue(32) seed_slothash(32) seed_slot(8) oracle(32) reveal_slot(8) value(32)
+pub struct SwitchboardRandomness {
+    pub seed_slot: u64,
+    pub reveal_slot: u64,
+    pub value: [u8; 32],
+}
+
+impl SwitchboardRandomness {
+    /// Anchor discriminator of `RandomnessAccountData`: SHA256("account:RandomnessAccountData")[..8]
+    pub const DISCRIMINATOR: [u8; 8] = [10, 66, 229, 135, 220, 239, 217, 114];
+
+    const SEED_SLOT_OFFSET: usize = 8 + 32 + 32 + 32;
+    const REVEAL_SLOT_OFFSET: usize = Self::SEED_SLOT_OFFSET + 8 + 32;
+    const VALUE_OFFSET: usize = Self::REVEAL_SLOT_OFFSET + 8;
+    const MIN_LEN: usize = Self::VALUE_OFFSET + 32;
+
+    /// Parse a randomness account, checking it is a Switchboard On-Demand
+    /// `RandomnessAccountData` (owner and discriminator)
+    pub fn parse(info: &AccountInfo) -> Option<Self> {
+        if info.owner != &SWITCHBOARD_ON_DEMAND_PROGRAM_ID {
+            return None;
+        }
+        let data = info.try_borrow_data().ok()?;
+        if data.len() < Self::MIN_LEN || data[..8] != Self::DISCRIMINATOR {
+            return None;
+        }
+
+        let read_u64 = |offset: usize| {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&data[offset..offset + 8]);
+            u64::from_le_bytes(word)
+        };
+        let mut value = [0u8; 32];
+        value.copy_from_slice(&data[Self::VALUE_OFFSET..Self::VALUE_OFFSET + 32]);
+
+        Some(Self {
+            seed_slot: read_u64(Self::SEED_SLOT_OFFSET),
+            reveal_slot: read_u64(Self::REVEAL_SLOT_OFFSET),
+            value,
+        })
+    }
+
+    /// Whether the account carries a reveal for the commitment made at `commit_slot`
+    pub fn revealed_for(&self, commit_slot: u64) -> bool {
+        self.seed_slot == commit_slot && self.reveal_slot != 0
+    }
+}
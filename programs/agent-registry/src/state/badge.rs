@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+use super::{ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID};
+
+/// Milestones that earn a soulbound badge
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum BadgeKind {
+    /// Agent verified for the first time (admin or `auto_verify`)
+    FirstVerification,
+    /// 100 challenges passed
+    CenturyPasses,
+    /// 1M audit entries committed (individual, batched and tree leaves combined)
+    MillionAudits,
+}
+
+impl BadgeKind {
+    /// Challenges an agent must pass for `CenturyPasses`
+    pub const CENTURY_PASSES: u32 = 100;
+
+    /// Audit entries an agent must commit for `MillionAudits`
+    pub const MILLION_AUDITS: u64 = 1_000_000;
+
+    /// Seed byte for per-kind PDAs
+    pub fn seed(&self) -> u8 {
+        match self {
+            BadgeKind::FirstVerification => 0,
+            BadgeKind::CenturyPasses => 1,
+            BadgeKind::MillionAudits => 2,
+        }
+    }
+}
+
+/// BadgeClaim - records that an agent's owner was minted a badge
+/// Its `init` is what stops the same milestone from being claimed twice, even across
+/// transfers (it is keyed by agent ID)
+#[account]
+#[derive(InitSpace)]
+pub struct BadgeClaim {
+    /// Agent that reached the milestone
+    pub agent: Pubkey,
+
+    /// Wallet the badge was minted to (the agent owner at claim time)
+    pub owner: Pubkey,
+
+    /// Milestone reached
+    pub kind: BadgeKind,
+
+    /// Unix timestamp of the claim
+    pub claimed_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl BadgeClaim {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::BADGE_CLAIM_SEED;
+}
+
+/// Badge mints are Token-2022 mints with the non-transferable extension, one per
+/// kind, created by `create_badge_mint`. Each mint PDA is its own mint authority
+pub struct BadgeMint;
+
+impl BadgeMint {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::BADGE_MINT_SEED;
+
+    /// Mint padded to the token account length, account type byte, then the
+    /// (empty) NonTransferable extension TLV header
+    pub const SPACE: usize = 165 + 1 + 4;
+
+    /// Badges are whole tokens
+    pub const DECIMALS: u8 = 0;
+
+    /// Token-2022 `InitializeNonTransferableMint` (must precede `InitializeMint2`)
+    pub fn initialize_non_transferable_ix(mint: Pubkey) -> Instruction {
+        Instruction {
+            program_id: TOKEN_2022_PROGRAM_ID,
+            accounts: vec![AccountMeta::new(mint, false)],
+            data: vec![32],
+        }
+    }
+
+    /// Token-2022 `InitializeMint2` with `mint` as its own authority and no freeze authority
+    pub fn initialize_mint_ix(mint: Pubkey) -> Instruction {
+        let mut data = Vec::with_capacity(35);
+        data.push(20);
+        data.push(Self::DECIMALS);
+        data.extend_from_slice(mint.as_ref());
+        data.push(0);
+        Instruction {
+            program_id: TOKEN_2022_PROGRAM_ID,
+            accounts: vec![AccountMeta::new(mint, false)],
+            data,
+        }
+    }
+
+    /// Associated token program `CreateIdempotent` for `wallet`'s badge account
+    pub fn create_token_account_ix(payer: Pubkey, token_account: Pubkey, wallet: Pubkey, mint: Pubkey) -> Instruction {
+        Instruction {
+            program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(payer, true),
+                AccountMeta::new(token_account, false),
+                AccountMeta::new_readonly(wallet, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+            ],
+            data: vec![1],
+        }
+    }
+
+    /// Token-2022 `MintTo` of a single badge, signed by the mint PDA
+    pub fn mint_one_ix(mint: Pubkey, token_account: Pubkey) -> Instruction {
+        let mut data = Vec::with_capacity(9);
+        data.push(7);
+        data.extend_from_slice(&1u64.to_le_bytes());
+        Instruction {
+            program_id: TOKEN_2022_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(mint, false),
+                AccountMeta::new(token_account, false),
+                AccountMeta::new_readonly(mint, true),
+            ],
+            data,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+
+/// Domain separator for evaluator-signed benchmark results
+pub const BENCHMARK_DOMAIN: &[u8] = b"agent-registry:benchmark_score:v1";
+
+/// BenchmarkEvaluator - an eval runner approved by the admin to sign benchmark results
+/// Kept (deactivated) on removal; its recorded scores stay on-chain
+#[account]
+#[derive(InitSpace)]
+pub struct BenchmarkEvaluator {
+    /// The evaluator's ed25519 signing key
+    pub authority: Pubkey,
+
+    /// Whether the evaluator's results are currently accepted
+    pub active: bool,
+
+    /// Total scores recorded
+    pub scores_recorded: u64,
+
+    /// Unix timestamp when the evaluator was registered
+    pub registered_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl BenchmarkEvaluator {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::BENCHMARK_EVALUATOR_SEED;
+}
+
+/// One leaderboard slot
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct LeaderboardEntry {
+    pub agent: Pubkey,
+    pub score: u32,
+}
+
+/// Benchmark - a standardized eval suite (e.g. an MMLU-style set) and its leaderboard
+#[account]
+#[derive(InitSpace)]
+pub struct Benchmark {
+    /// Benchmark identifier (PDA seed)
+    pub benchmark_id: u32,
+
+    /// SHA256 of the suite definition (tasks, scoring rules, version)
+    pub suite_hash: [u8; 32],
+
+    /// Highest attainable score
+    pub max_score: u32,
+
+    /// Whether new results are accepted
+    pub active: bool,
+
+    /// Results recorded, re-evaluations included
+    pub submissions: u64,
+
+    /// Best agents by their latest score, highest first
+    #[max_len(10)]
+    pub leaderboard: Vec<LeaderboardEntry>,
+
+    /// Unix timestamp of the first definition
+    pub created_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Benchmark {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::BENCHMARK_SEED;
+
+    /// Leaderboard size
+    pub const LEADERBOARD_LEN: usize = 10;
+
+    /// Record `agent`'s latest score: its previous entry is replaced and the board
+    /// re-sorted, keeping the top LEADERBOARD_LEN (earlier entries win ties)
+    pub fn rank(&mut self, agent: Pubkey, score: u32) {
+        self.leaderboard.retain(|entry| entry.agent != agent);
+        let position = self
+            .leaderboard
+            .iter()
+            .position(|entry| entry.score < score)
+            .unwrap_or(self.leaderboard.len());
+        if position < Self::LEADERBOARD_LEN {
+            self.leaderboard.insert(position, LeaderboardEntry { agent, score });
+            self.leaderboard.truncate(Self::LEADERBOARD_LEN);
+        }
+    }
+}
+
+/// BenchmarkScore - an agent's latest evaluator-signed result on one benchmark
+#[account]
+#[derive(InitSpace)]
+pub struct BenchmarkScore {
+    pub agent: Pubkey,
+    pub benchmark_id: u32,
+
+    /// Evaluator's signing key
+    pub evaluator: Pubkey,
+
+    pub score: u32,
+
+    /// sha256 of the agent's `model_hash` when evaluated (the model version scored)
+    pub model_digest: [u8; 32],
+
+    /// Evaluator's ed25519 signature over `benchmark_message`
+    pub signature: [u8; 64],
+
+    /// Evaluation time signed by the evaluator (re-evaluations must be newer)
+    pub evaluated_at: i64,
+
+    /// Unix timestamp when recorded on-chain
+    pub recorded_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl BenchmarkScore {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::BENCHMARK_SCORE_SEED;
+}
+
+/// Message an evaluator signs to attest a benchmark result
+/// domain || program id || agent || benchmark_id (LE) || score (LE) || model_digest
+/// || evaluated_at (LE)
+pub fn benchmark_message(
+    agent: &Pubkey,
+    benchmark_id: u32,
+    score: u32,
+    model_digest: &[u8; 32],
+    evaluated_at: i64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(BENCHMARK_DOMAIN.len() + 112);
+    message.extend_from_slice(BENCHMARK_DOMAIN);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.extend_from_slice(agent.as_ref());
+    message.extend_from_slice(&benchmark_id.to_le_bytes());
+    message.extend_from_slice(&score.to_le_bytes());
+    message.extend_from_slice(model_digest);
+    message.extend_from_slice(&evaluated_at.to_le_bytes());
+    message
+}
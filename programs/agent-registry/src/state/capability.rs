@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Capability definition - one entry in the admin-curated capability vocabulary
+/// Agents reference definitions by ID through the bits of `capability_flags`
+#[account]
+#[derive(InitSpace)]
+pub struct CapabilityDefinition {
+    /// Capability ID (bit position in `capability_flags`)
+    pub id: u8,
+
+    /// SHA256 of the canonical label (e.g., sha256("code-review"))
+    pub label_hash: [u8; 32],
+
+    /// URI of the human-readable description
+    #[max_len(200)]
+    pub description_uri: String,
+
+    /// Whether agents may currently declare this capability
+    pub active: bool,
+
+    /// Unix timestamp when first defined
+    pub created_at: i64,
+
+    /// Unix timestamp of the last change
+    pub updated_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl CapabilityDefinition {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::CAPABILITY_SEED;
+
+    /// Highest capability ID (flags are a u64 bitmask)
+    pub const MAX_ID: u8 = 63;
+
+    /// Bit for a capability ID
+    pub fn flag(id: u8) -> u64 {
+        1u64 << id
+    }
+}
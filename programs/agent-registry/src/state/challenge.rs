@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
+use super::{AgentAccount, RegistryConfig};
 
 /// Challenge status enum
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
 pub enum ChallengeStatus {
     /// Waiting for agent response
+    #[default]
     Pending,
     /// Agent passed the challenge
     Passed,
@@ -11,11 +13,43 @@ pub enum ChallengeStatus {
     Failed,
     /// Challenge expired without response
     Expired,
+    /// Challenge voided (expired with no penalty, escrow refunded)
+    Void,
+    /// Agent responded, waiting for the judging committee to reach quorum
+    AwaitingVerdict,
+    /// Agent responded, waiting for the challenger to reveal the committed answer
+    AwaitingReveal,
+    /// Challenger never revealed the answer: no verdict, escrow forfeited to the agent owner
+    Unrevealed,
 }
 
-impl Default for ChallengeStatus {
-    fn default() -> Self {
-        ChallengeStatus::Pending
+/// Challenge type taxonomy - what aspect of the agent a challenge tests
+/// Each type carries its own reputation weight in RegistryConfig
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub enum ChallengeType {
+    /// Correctness of the answer (default)
+    #[default]
+    Accuracy,
+    /// Behaviour under adversarial or malformed input
+    Robustness,
+    /// Refusal of harmful or policy-violating requests
+    Safety,
+    /// Responding within the expected time budget
+    Latency,
+}
+
+impl ChallengeType {
+    /// Number of challenge types (size of per-type arrays)
+    pub const COUNT: usize = 4;
+
+    /// Index into per-type arrays (weights, counters)
+    pub fn index(&self) -> usize {
+        match self {
+            ChallengeType::Accuracy => 0,
+            ChallengeType::Robustness => 1,
+            ChallengeType::Safety => 2,
+            ChallengeType::Latency => 3,
+        }
     }
 }
 
@@ -33,9 +67,27 @@ pub struct Challenge {
     #[max_len(256)]
     pub question: String,
 
-    /// SHA256 hash of the expected answer (for verification)
+    /// Commitment to the expected answer: hex SHA256(expected_hash || salt), see
+    /// `Challenge::commit_answer` (version < 3: the expected answer hash itself)
+    /// Revealed by the challenger after the response, so the agent can't read it first
     #[max_len(64)]
-    pub expected_hash: String,
+    pub answer_commitment: String,
+
+    /// What the challenge tests (drives reputation weighting)
+    pub challenge_type: ChallengeType,
+
+    /// Whether the result is decided by the judge committee instead of hash match
+    pub judged: bool,
+
+    /// SHA256 hash of the agent's response (kept until the verdict or reveal)
+    #[max_len(64)]
+    pub response_hash: String,
+
+    /// Judge votes for a pass verdict
+    pub votes_pass: u8,
+
+    /// Judge votes for a fail verdict
+    pub votes_fail: u8,
 
     /// Current status of the challenge
     pub status: ChallengeStatus,
@@ -49,27 +101,182 @@ pub struct Challenge {
     /// Unix timestamp when agent responded (if any)
     pub responded_at: i64,
 
+    /// Lamports escrowed by the challenger (held in this PDA above rent)
+    pub escrow: u64,
+
     /// Nonce for unique PDA derivation (allows multiple challenges per agent-challenger pair)
     pub nonce: u64,
 
     /// Bump seed for PDA derivation
     pub bump: u8,
+
+    /// Account layout version (0 = created before versioning)
+    pub version: u8,
+
+    /// ChallengeTemplate the challenge was created from (default pubkey = none)
+    pub template: Pubkey,
+
+    /// The template's scoring weights at creation (unused without a template)
+    pub pass_weight_bps: u16,
+    pub fail_weight_bps: u16,
 }
 
 impl Challenge {
-    pub const SEED_PREFIX: &'static [u8] = b"challenge";
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::CHALLENGE_SEED;
+
+    /// Domain separator for challenge result leaves
+    pub const RESULT_LEAF_PREFIX: u8 = crate::merkle::CHALLENGE_RESULT_LEAF_PREFIX;
+
+    /// Layout version written by this program build
+    /// v3: the expected answer is committed and revealed, not stored in the clear
+    pub const CURRENT_VERSION: u8 = 3;
+
+    /// First version whose `answer_commitment` is salted
+    pub const COMMITTED_ANSWER_VERSION: u8 = 3;
 
     /// Default challenge duration (1 hour in seconds)
     pub const DEFAULT_DURATION: i64 = 3600;
 
+    /// Time judges have after the response to reach quorum before anyone may
+    /// `void_challenge` it (7 days)
+    pub const VERDICT_WINDOW: i64 = 7 * 24 * 3600;
+
+    /// Time the challenger has after the response to `reveal_challenge_answer` before
+    /// anyone may `void_challenge` it, forfeiting the escrow to the agent owner (1 day)
+    pub const REVEAL_WINDOW: i64 = 24 * 3600;
+
     /// Reputation gain for passing a challenge
     pub const PASS_REPUTATION_DELTA: i32 = 100;
 
     /// Reputation loss for failing a challenge
     pub const FAIL_REPUTATION_DELTA: i32 = -50;
 
+    /// Whether the challenge was created from a ChallengeTemplate
+    pub fn has_template(&self) -> bool {
+        self.template != Pubkey::default()
+    }
+
+    /// Reputation delta for a verdict: the template's weights when created from one,
+    /// otherwise the config's per-type weights
+    pub fn reputation_delta(&self, config: &RegistryConfig, passed: bool) -> i32 {
+        if !self.has_template() {
+            return config.challenge_delta(self.challenge_type, passed);
+        }
+        let weight = if passed { self.pass_weight_bps } else { self.fail_weight_bps };
+        RegistryConfig::weighted_delta(passed, weight)
+    }
+
     /// Check if challenge has expired
     pub fn is_expired(&self, current_time: i64) -> bool {
         current_time > self.expires_at
     }
+
+    /// Whether a judged challenge's committee missed its VERDICT_WINDOW
+    pub fn verdict_overdue(&self, current_time: i64) -> bool {
+        current_time > self.responded_at.saturating_add(Self::VERDICT_WINDOW)
+    }
+
+    /// Whether the challenger missed its REVEAL_WINDOW
+    pub fn reveal_overdue(&self, current_time: i64) -> bool {
+        current_time > self.responded_at.saturating_add(Self::REVEAL_WINDOW)
+    }
+
+    /// Commitment to an expected answer hash: hex SHA256(expected_hash || salt)
+    pub fn commit_answer(expected_hash: &str, salt: &[u8; 32]) -> String {
+        solana_sha256_hasher::hashv(&[expected_hash.as_bytes(), salt])
+            .to_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    /// Whether `expected_hash` and `salt` open the stored commitment
+    pub fn opens(&self, expected_hash: &str, salt: &[u8; 32]) -> bool {
+        if self.version < Self::COMMITTED_ANSWER_VERSION {
+            return self.answer_commitment == expected_hash;
+        }
+        self.answer_commitment == Self::commit_answer(expected_hash, salt)
+    }
+
+    /// Whether a resolved challenge's escrow belongs to the agent owner (passed, or the
+    /// challenger never revealed) rather than the challenger (failed, expired or voided)
+    pub fn escrow_to_owner(&self) -> bool {
+        matches!(self.status, ChallengeStatus::Passed | ChallengeStatus::Unrevealed)
+    }
+
+    /// Check if challenge has reached a terminal state
+    pub fn is_resolved(&self) -> bool {
+        !matches!(
+            self.status,
+            ChallengeStatus::Pending | ChallengeStatus::AwaitingVerdict | ChallengeStatus::AwaitingReveal
+        )
+    }
+
+    /// Apply a final verdict: status, agent counters and reputation, escrow payout
+    /// Escrow goes to the agent owner on pass and back to the challenger on fail
+    /// Returns the amount of escrow moved
+    pub fn settle<'info>(
+        challenge: &mut Account<'info, Challenge>,
+        agent: &mut AgentAccount,
+        config: &RegistryConfig,
+        passed: bool,
+        owner: &AccountInfo<'info>,
+        challenger: &AccountInfo<'info>,
+        now: i64,
+    ) -> Result<u64> {
+        challenge.status = if passed {
+            ChallengeStatus::Passed
+        } else {
+            ChallengeStatus::Failed
+        };
+
+        let delta = challenge.reputation_delta(config, passed);
+        agent.record_challenge(challenge.challenge_type, passed, delta, config, now);
+        agent.touch(now);
+
+        let recipient = if challenge.escrow_to_owner() { owner } else { challenger };
+        Challenge::release_escrow(challenge, recipient)
+    }
+
+    /// Leaf committed to the agent's challenge stream once the challenge is resolved:
+    /// the challenge address and nonce, the verdict, the judge tally and the agent's
+    /// reputation before and after
+    pub fn result_leaf(
+        &self,
+        challenge: &Pubkey,
+        old_score: u32,
+        new_score: u32,
+        resolved_at: i64,
+    ) -> [u8; 32] {
+        solana_sha256_hasher::hashv(&[
+            &[Self::RESULT_LEAF_PREFIX],
+            challenge.as_ref(),
+            &self.nonce.to_le_bytes(),
+            &[self.status as u8],
+            &[self.votes_pass, self.votes_fail],
+            &old_score.to_le_bytes(),
+            &new_score.to_le_bytes(),
+            &resolved_at.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+
+    /// Move the escrowed lamports out of the challenge PDA to `recipient`
+    /// Returns the amount released (0 if nothing was escrowed)
+    pub fn release_escrow<'info>(
+        challenge: &mut Account<'info, Challenge>,
+        recipient: &AccountInfo<'info>,
+    ) -> Result<u64> {
+        let amount = challenge.escrow;
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        challenge.escrow = 0;
+        let challenge_info = challenge.to_account_info();
+        **challenge_info.try_borrow_mut_lamports()? -= amount;
+        **recipient.try_borrow_mut_lamports()? += amount;
+
+        Ok(amount)
+    }
 }
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// Which side of a challenge submitted a piece of evidence
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum EvidenceParty {
+    Challenger,
+    /// The agent owner
+    Agent,
+}
+
+/// What a piece of evidence is
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum EvidenceKind {
+    /// Conversation or interaction transcript
+    Transcript,
+    /// Raw agent output
+    Output,
+    /// Evaluation harness artifact (scores, logs, test vectors)
+    EvalArtifact,
+    Other,
+}
+
+/// One anchored evidence hash
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct EvidenceItem {
+    pub party: EvidenceParty,
+    pub kind: EvidenceKind,
+    /// SHA256 of the off-chain artifact
+    pub hash: [u8; 32],
+    pub submitted_at: i64,
+}
+
+/// ChallengeEvidence - evidence hashes both sides attached to a challenge before it
+/// was resolved. `digest` chains every item in order; judges' votes record the
+/// digest they saw, so auditors can check a ruling against the evidence it was made on
+#[account]
+#[derive(InitSpace)]
+pub struct ChallengeEvidence {
+    /// The challenge this evidence belongs to
+    pub challenge: Pubkey,
+
+    /// Items in submission order
+    #[max_len(16)]
+    pub items: Vec<EvidenceItem>,
+
+    /// Items submitted by the challenger
+    pub challenger_count: u8,
+
+    /// Items submitted by the agent owner
+    pub agent_count: u8,
+
+    /// sha256(previous digest || party || kind || hash) over all items (zero = none)
+    pub digest: [u8; 32],
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ChallengeEvidence {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::CHALLENGE_EVIDENCE_SEED;
+
+    /// Most items each side may submit
+    pub const MAX_PER_PARTY: u8 = 8;
+
+    /// Append an item and extend the digest
+    pub fn push(&mut self, party: EvidenceParty, kind: EvidenceKind, hash: [u8; 32], now: i64) -> Result<()> {
+        let count = match party {
+            EvidenceParty::Challenger => &mut self.challenger_count,
+            EvidenceParty::Agent => &mut self.agent_count,
+        };
+        require!(*count < Self::MAX_PER_PARTY, RegistryError::TooMuchEvidence);
+        *count += 1;
+
+        self.digest = solana_sha256_hasher::hashv(&[
+            &self.digest,
+            &[party as u8, kind as u8],
+            &hash,
+        ])
+        .to_bytes();
+        self.items.push(EvidenceItem {
+            party,
+            kind,
+            hash,
+            submitted_at: now,
+        });
+        Ok(())
+    }
+}
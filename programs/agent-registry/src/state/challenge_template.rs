@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use super::{ChallengeType, RegistryConfig};
+
+/// ChallengeTemplate - a reusable, standardized challenge spec (one per spec hash)
+/// Challenges created from a template share its type, judging mode, minimum stake,
+/// scoring weights and judge set, so results are comparable across agents
+/// Anyone may publish one; templates published by the admin are marked `curated`
+#[account]
+#[derive(InitSpace)]
+pub struct ChallengeTemplate {
+    /// SHA256 of the off-chain test spec (PDA seed)
+    pub spec_hash: [u8; 32],
+
+    /// Who published the template
+    pub creator: Pubkey,
+
+    /// Published by the registry admin
+    pub curated: bool,
+
+    pub challenge_type: ChallengeType,
+
+    /// Whether challenges are decided by judges instead of hash match
+    pub judged: bool,
+
+    /// Minimum escrow per challenge; the config escrow still applies when higher
+    pub default_stake: u64,
+
+    /// Scoring weights replacing the config's per-type weights (basis points)
+    pub pass_weight_bps: u16,
+    pub fail_weight_bps: u16,
+
+    /// Committee judges allowed to vote on judged challenges (empty = any active judge)
+    #[max_len(8)]
+    pub judges: Vec<Pubkey>,
+
+    /// Whether new challenges may use the template
+    pub active: bool,
+
+    /// Challenges created from the template
+    pub challenges_created: u64,
+
+    /// Unix timestamp of publication
+    pub created_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ChallengeTemplate {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::CHALLENGE_TEMPLATE_SEED;
+
+    /// Maximum judges in a template's judge set
+    pub const MAX_JUDGES: usize = 8;
+
+    /// Weights within the config's weight cap and a usable judge set: no duplicates and,
+    /// for judged templates, enough judges to reach the current quorum
+    pub fn is_valid(
+        judged: bool,
+        pass_weight_bps: u16,
+        fail_weight_bps: u16,
+        judges: &[Pubkey],
+        config: &RegistryConfig,
+    ) -> bool {
+        let unique = judges
+            .iter()
+            .enumerate()
+            .all(|(i, judge)| !judges[..i].contains(judge));
+        pass_weight_bps <= RegistryConfig::MAX_WEIGHT_BPS
+            && fail_weight_bps <= RegistryConfig::MAX_WEIGHT_BPS
+            && judges.len() <= Self::MAX_JUDGES
+            && unique
+            && (judges.is_empty() || (judged && judges.len() >= config.judge_quorum as usize))
+    }
+
+    /// Whether `judge` may vote on the template's challenges
+    pub fn allows_judge(&self, judge: &Pubkey) -> bool {
+        self.judges.is_empty() || self.judges.contains(judge)
+    }
+}
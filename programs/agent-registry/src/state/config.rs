@@ -0,0 +1,470 @@
+use anchor_lang::prelude::*;
+use super::{AgentAccount, Challenge, ChallengeType, ExternalScores, PythPrice, SOL_USD_FEED_ID};
+use crate::errors::RegistryError;
+
+/// What happens to a challenge nobody answered before its deadline
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub enum ExpiryPolicy {
+    /// Treat as a failure: agent is penalized and `expiry_slash_bps` of its stake goes to
+    /// the challenger, escrow returns to challenger
+    #[default]
+    Fail,
+    /// Void the challenge: no penalty, escrow returns to challenger
+    Void,
+}
+
+/// What an agent's reputation becomes when it moves to a new owner (`transfer_agent`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub enum ReputationTransferPolicy {
+    /// Keep the score unchanged
+    #[default]
+    CarryOver,
+    /// Cut the score by `transfer_haircut_bps`
+    Haircut,
+    /// Start over from the initial reputation
+    Reset,
+}
+
+/// Registry configuration - tunable scoring parameters (admin managed)
+/// Kept separate from RegistryState so new knobs don't change the registry layout
+#[account]
+#[derive(InitSpace)]
+pub struct RegistryConfig {
+    /// Reputation weight applied to a pass, per challenge type (basis points of the base delta)
+    pub pass_weights_bps: [u16; ChallengeType::COUNT],
+
+    /// Reputation weight applied to a failure, per challenge type (basis points of the base delta)
+    pub fail_weights_bps: [u16; ChallengeType::COUNT],
+
+    /// Seconds an agent has to answer a challenge
+    pub challenge_duration: i64,
+
+    /// Lamports a challenger must escrow when creating a challenge (0 = none)
+    /// Paid to the agent owner on pass, refunded to the challenger otherwise
+    pub challenge_escrow_lamports: u64,
+
+    /// Challenge escrow in micro-USD, converted via Pyth at execution time
+    /// Takes precedence over `challenge_escrow_lamports` when non-zero
+    pub challenge_escrow_usd: u64,
+
+    /// Registration fee in lamports, paid to the treasury (0 = free)
+    pub registration_fee_lamports: u64,
+
+    /// Registration fee in micro-USD, converted via Pyth at execution time
+    /// Takes precedence over `registration_fee_lamports` when non-zero
+    pub registration_fee_usd: u64,
+
+    /// Only wallets with an AllowlistEntry may register or fork agents
+    pub allowlist_only: bool,
+
+    /// Each model hash may back only one agent (claimed through a ModelRecord);
+    /// forks that keep their parent's model are linked instead of rejected
+    pub unique_models: bool,
+
+    /// Mint registrants must hold to register agents (default pubkey = no token gate)
+    pub gate_mint: Pubkey,
+
+    /// Minimum `gate_mint` balance (raw token units) required while the gate is set
+    pub gate_min_balance: u64,
+
+    /// Share of the registration fee accrued to the referrer (basis points)
+    pub referral_share_bps: u16,
+
+    /// Fee in lamports for queuing a verification request, paid to the treasury (0 = free)
+    pub verification_fee_lamports: u64,
+
+    /// Fee in lamports per audit commit (entry, batch root or tree leaf), paid to the treasury (0 = free)
+    pub audit_fee_lamports: u64,
+
+    /// Discount on agent-bound fees for verified agents (basis points, 10000 = exempt)
+    pub verified_fee_discount_bps: u16,
+
+    /// Effective reputation at which `reputation_fee_discount_bps` applies
+    pub reputation_fee_discount_min: u32,
+
+    /// Discount on agent-bound fees for high-reputation agents (basis points, 10000 = exempt)
+    pub reputation_fee_discount_bps: u16,
+
+    /// Minimum stake for `auto_verify` (lamports)
+    pub auto_verify_min_stake_lamports: u64,
+
+    /// Minimum reputation score for `auto_verify`
+    pub auto_verify_min_reputation: u32,
+
+    /// Seconds new stake stays locked after each `stake_agent`
+    pub stake_lockup_secs: i64,
+
+    /// Seconds between `request_unstake` and `withdraw_unstake` (0 = instant `unstake_agent`)
+    /// Pending withdrawals stay in the agent PDA so they remain slashable
+    /// (`AgentAccount::slash_stake`)
+    pub unstake_cooldown_secs: i64,
+
+    /// Pyth feed ID used for USD conversion (SOL/USD)
+    pub price_feed_id: [u8; 32],
+
+    /// Maximum age of the Pyth price in seconds
+    pub max_price_age_secs: u64,
+
+    /// Maximum Pyth confidence interval as basis points of the price
+    pub max_price_conf_bps: u16,
+
+    /// How unanswered challenges are resolved by `expire_challenge`
+    pub expiry_policy: ExpiryPolicy,
+
+    /// Share of the agent's stake, pending unstakes included, slashed to the challenger
+    /// when a challenge expires under `ExpiryPolicy::Fail` (basis points)
+    pub expiry_slash_bps: u16,
+
+    /// Matching judge votes required to resolve a judged challenge
+    pub judge_quorum: u8,
+
+    /// Reputation decay per idle day once the grace period has passed (basis points)
+    pub reputation_decay_bps_per_day: u16,
+
+    /// Seconds of inactivity before reputation starts to decay
+    pub decay_grace_secs: i64,
+
+    /// Weight of the averaged external scores in `composite_reputation` (basis points)
+    pub external_reputation_weight_bps: u16,
+
+    /// Lowest reputation score an agent can fall to (changed via `set_reputation_bounds`)
+    pub reputation_floor: u32,
+
+    /// Highest reputation score an agent can reach (changed via `set_reputation_bounds`)
+    pub reputation_ceiling: u32,
+
+    /// Key that must co-sign `update_reputation` deltas above `cosign_delta_threshold`
+    /// (default pubkey = no co-signing)
+    pub reputation_cosigner: Pubkey,
+
+    /// Largest reputation delta (absolute) the admin may apply alone while a co-signer is set
+    pub cosign_delta_threshold: u32,
+
+    /// Lamports paid out of the treasury per epoch to eligible agents (0 = no emissions)
+    pub emission_lamports_per_epoch: u64,
+
+    /// First epoch that emits rewards
+    pub emission_start_epoch: u64,
+
+    /// Epochs between emission halvings (0 = constant emissions)
+    pub emission_halving_epochs: u64,
+
+    /// Minimum snapshot reputation for a staked, verified agent to share an epoch's emission
+    pub emission_min_reputation: u32,
+
+    /// Minimum stake, locked through the end of the epoch, for an agent to share an
+    /// epoch's emission (so `stake_lockup_secs` needs to span an epoch)
+    pub emission_min_stake_lamports: u64,
+
+    /// When the deployment was sunset by `deprecate_registry` (0 = active)
+    pub deprecated_at: i64,
+
+    /// Program agents should move to once deprecated (default pubkey = none named)
+    pub successor_program: Pubkey,
+
+    /// Companion challenge program whose CHALLENGE_AUTHORITY_SEED PDA may sign
+    /// `update_reputation_by_program` (default pubkey = none)
+    pub challenge_program: Pubkey,
+
+    /// Idle time after which a never-verified, never-tested agent can be garbage
+    /// collected (0 = disabled)
+    pub gc_idle_secs: i64,
+
+    /// Registrations one owner may make per `OwnerCounter` window (0 = unlimited)
+    pub max_registrations_per_day: u16,
+
+    /// Refundable bond escrowed with each verification request (lamports, 0 = none)
+    pub verification_bond_lamports: u64,
+
+    /// How long an approved request's bond stays forfeitable before it can be released
+    /// (0 = returned on approval)
+    pub verification_bond_clean_secs: i64,
+
+    /// Reputation handling when an agent changes owner
+    pub reputation_transfer_policy: ReputationTransferPolicy,
+
+    /// Share of the score cut under `ReputationTransferPolicy::Haircut` (basis points)
+    pub transfer_haircut_bps: u16,
+
+    /// Number of currently active judges
+    pub active_judges: u16,
+
+    /// Bitmask of active capability definitions (bit i = CapabilityDefinition id i)
+    pub defined_capabilities: u64,
+
+    /// Number of random audit selection rounds started (next round index)
+    pub audit_rounds: u64,
+
+    /// Number of registry-wide snapshots committed (next snapshot index)
+    pub registry_snapshots: u64,
+
+    /// Treasury lamports owed to referrers and reserved for epoch emissions;
+    /// `withdraw_treasury` can't touch them
+    pub treasury_liabilities: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Layout version (`CURRENT_VERSION` when written by this build)
+    pub version: u8,
+}
+
+impl RegistryConfig {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::CONFIG_SEED;
+
+    /// Layout version written by this program build; bump when fields are appended so
+    /// `interface::try_deserialize_config` rejects configs it would misread
+    pub const CURRENT_VERSION: u8 = 1;
+
+    /// 100% weight
+    pub const BPS_DENOMINATOR: u32 = 10000;
+
+    /// Maximum weight per type (5x the base delta)
+    pub const MAX_WEIGHT_BPS: u16 = 50000;
+
+    /// Default pass weights: [accuracy, robustness, safety, latency]
+    pub const DEFAULT_PASS_WEIGHTS_BPS: [u16; ChallengeType::COUNT] = [10000, 12000, 10000, 5000];
+
+    /// Default fail weights: safety failures cost 3x, latency misses half
+    pub const DEFAULT_FAIL_WEIGHTS_BPS: [u16; ChallengeType::COUNT] = [10000, 10000, 30000, 5000];
+
+    /// Shortest allowed challenge window (1 minute)
+    pub const MIN_CHALLENGE_DURATION: i64 = 60;
+
+    /// Longest allowed challenge window (30 days)
+    pub const MAX_CHALLENGE_DURATION: i64 = 30 * 24 * 3600;
+
+    /// Default judge quorum
+    pub const DEFAULT_JUDGE_QUORUM: u8 = 2;
+
+    /// Default maximum price age (60 seconds)
+    pub const DEFAULT_MAX_PRICE_AGE_SECS: u64 = 60;
+
+    /// Default maximum confidence interval (2% of price)
+    pub const DEFAULT_MAX_PRICE_CONF_BPS: u16 = 200;
+
+    /// Default referral share (10% of the registration fee)
+    pub const DEFAULT_REFERRAL_SHARE_BPS: u16 = 1000;
+
+    /// Default decay: 0.5% per idle day
+    pub const DEFAULT_REPUTATION_DECAY_BPS_PER_DAY: u16 = 50;
+
+    /// Default grace period before decay starts (7 days)
+    pub const DEFAULT_DECAY_GRACE_SECS: i64 = 7 * 24 * 3600;
+
+    /// Default external score weight (20% of the composite)
+    pub const DEFAULT_EXTERNAL_REPUTATION_WEIGHT_BPS: u16 = 2000;
+
+    /// Default auto-verify stake threshold (10 SOL)
+    pub const DEFAULT_AUTO_VERIFY_MIN_STAKE_LAMPORTS: u64 = 10_000_000_000;
+
+    /// Default auto-verify reputation threshold (75%)
+    pub const DEFAULT_AUTO_VERIFY_MIN_REPUTATION: u32 = 7500;
+
+    /// Default minimum locked stake to share an epoch's emission (1 SOL)
+    pub const DEFAULT_EMISSION_MIN_STAKE_LAMPORTS: u64 = 1_000_000_000;
+
+    /// Default unstake cooldown (7 days)
+    pub const DEFAULT_UNSTAKE_COOLDOWN_SECS: i64 = 7 * 24 * 3600;
+
+    /// Longest configurable stake lockup, unstake cooldown or verification bond clean period (1 year)
+    pub const MAX_STAKE_LOCK_SECS: i64 = 365 * 24 * 3600;
+
+    /// Share of a garbage-collected agent's rent paid to the caller; the rest goes to the treasury
+    pub const GC_CALLER_SHARE_BPS: u16 = 5000;
+
+    /// Default Pyth feed
+    pub const DEFAULT_PRICE_FEED_ID: [u8; 32] = SOL_USD_FEED_ID;
+
+    /// Whether `quorum` can be paired with `active_judges`: at most the committee size,
+    /// or anything while there is no committee (judged challenges are refused until there is)
+    pub fn quorum_fits(quorum: u8, active_judges: u16) -> bool {
+        quorum > 0 && (active_judges == 0 || quorum as u16 <= active_judges)
+    }
+
+    /// Whether the committee is large enough to reach `judge_quorum`
+    pub fn quorum_reachable(&self) -> bool {
+        self.judge_quorum > 0 && self.judge_quorum as u16 <= self.active_judges
+    }
+
+    /// Registration fee in lamports (USD-denominated fees are priced through Pyth)
+    pub fn registration_fee(&self, price_feed: Option<&AccountInfo>, now: i64) -> Result<u64> {
+        self.lamports_for(self.registration_fee_lamports, self.registration_fee_usd, price_feed, now)
+    }
+
+    /// Challenge escrow in lamports (USD-denominated escrow is priced through Pyth)
+    pub fn challenge_escrow(&self, price_feed: Option<&AccountInfo>, now: i64) -> Result<u64> {
+        self.lamports_for(self.challenge_escrow_lamports, self.challenge_escrow_usd, price_feed, now)
+    }
+
+    /// Resolve a lamport/micro-USD config pair into lamports
+    fn lamports_for(
+        &self,
+        lamports: u64,
+        usd_micros: u64,
+        price_feed: Option<&AccountInfo>,
+        now: i64,
+    ) -> Result<u64> {
+        if usd_micros == 0 {
+            return Ok(lamports);
+        }
+
+        let feed = price_feed.ok_or(RegistryError::PriceFeedRequired)?;
+        let price = PythPrice::load(feed, &self.price_feed_id, now, self.max_price_age_secs)?;
+        price.check_confidence(self.max_price_conf_bps)?;
+        price.usd_to_lamports(usd_micros)
+    }
+
+    /// Reputation delta for a challenge outcome, scaled by the per-type weight
+    pub fn challenge_delta(&self, challenge_type: ChallengeType, passed: bool) -> i32 {
+        let idx = challenge_type.index();
+        let weight = if passed {
+            self.pass_weights_bps[idx]
+        } else {
+            self.fail_weights_bps[idx]
+        };
+        Self::weighted_delta(passed, weight)
+    }
+
+    /// Base pass or fail delta scaled by `weight_bps`
+    pub fn weighted_delta(passed: bool, weight_bps: u16) -> i32 {
+        let base = if passed {
+            Challenge::PASS_REPUTATION_DELTA
+        } else {
+            Challenge::FAIL_REPUTATION_DELTA
+        };
+        ((base as i64) * (weight_bps as i64) / (Self::BPS_DENOMINATOR as i64)) as i32
+    }
+
+    /// Lamports emitted for `epoch` under the halving schedule (0 before the start epoch)
+    pub fn epoch_emission(&self, epoch: u64) -> u64 {
+        if epoch < self.emission_start_epoch {
+            return 0;
+        }
+        if self.emission_halving_epochs == 0 {
+            return self.emission_lamports_per_epoch;
+        }
+        let halvings = (epoch - self.emission_start_epoch) / self.emission_halving_epochs;
+        self.emission_lamports_per_epoch.checked_shr(halvings.min(64) as u32).unwrap_or(0)
+    }
+
+    /// Whether a reputation delta needs the co-signer as well as the admin
+    pub fn requires_cosign(&self, delta: i32) -> bool {
+        self.reputation_cosigner != Pubkey::default() && delta.unsigned_abs() > self.cosign_delta_threshold
+    }
+
+    /// Whether the deployment has been sunset (no new registrations)
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated_at != 0
+    }
+
+    /// Whether registration requires holding `gate_mint`
+    pub fn token_gated(&self) -> bool {
+        self.gate_mint != Pubkey::default()
+    }
+
+    /// Whether every set bit in `flags` is an active capability definition
+    pub fn capabilities_defined(&self, flags: u64) -> bool {
+        flags & !self.defined_capabilities == 0
+    }
+
+    /// Clamp a score into the configured reputation range
+    pub fn clamp_reputation(&self, score: i64) -> u32 {
+        score.max(self.reputation_floor as i64).min(self.reputation_ceiling as i64) as u32
+    }
+
+    /// Starting score for new agents: the midpoint of the configured range
+    pub fn initial_reputation(&self) -> u32 {
+        self.reputation_floor + (self.reputation_ceiling - self.reputation_floor) / 2
+    }
+
+    /// Score an agent with `score` carries to a new owner under the transfer policy
+    pub fn transferred_reputation(&self, score: u32) -> u32 {
+        match self.reputation_transfer_policy {
+            ReputationTransferPolicy::CarryOver => score,
+            ReputationTransferPolicy::Haircut => {
+                let cut = score as u64 * self.transfer_haircut_bps as u64 / Self::BPS_DENOMINATOR as u64;
+                self.clamp_reputation(score as i64 - cut as i64)
+            }
+            ReputationTransferPolicy::Reset => self.initial_reputation(),
+        }
+    }
+
+    /// Fee discount an agent qualifies for; the best applicable tier wins
+    pub fn fee_discount_bps(&self, agent: &AgentAccount, now: i64) -> u16 {
+        let mut discount = 0;
+        if agent.verified {
+            discount = self.verified_fee_discount_bps;
+        }
+        if self.reputation_fee_discount_bps > discount
+            && self.effective_reputation(agent, now) >= self.reputation_fee_discount_min
+        {
+            discount = self.reputation_fee_discount_bps;
+        }
+        discount
+    }
+
+    /// `fee` after the agent's tier discount
+    pub fn discounted_fee(&self, fee: u64, agent: &AgentAccount, now: i64) -> u64 {
+        let discount = (self.fee_discount_bps(agent, now) as u32).min(Self::BPS_DENOMINATOR);
+        let retained = (Self::BPS_DENOMINATOR - discount) as u128;
+        ((fee as u128) * retained / (Self::BPS_DENOMINATOR as u128)) as u64
+    }
+
+    /// Treasury lamports not owed to anyone: the balance less rent and liabilities
+    pub fn treasury_free(&self, treasury: &AccountInfo) -> Result<u64> {
+        let rent = Rent::get()?.minimum_balance(treasury.data_len());
+        Ok(treasury
+            .lamports()
+            .saturating_sub(rent)
+            .saturating_sub(self.treasury_liabilities))
+    }
+
+    /// Referrer reward for a registration that paid `fee`
+    pub fn referral_reward(&self, fee: u64) -> u64 {
+        ((fee as u128) * (self.referral_share_bps as u128) / (Self::BPS_DENOMINATOR as u128)) as u64
+    }
+
+    /// Reputation after inactivity decay (the canonical formula behind `get_effective_reputation`)
+    /// Decay is paused while an owner deactivation is in effect
+    pub fn effective_reputation(&self, agent: &AgentAccount, now: i64) -> u32 {
+        let idle_secs = now.saturating_sub(agent.last_active_at()) - self.decay_grace_secs;
+        if idle_secs <= 0 || self.reputation_decay_bps_per_day == 0 || agent.is_deactivated(now) {
+            return agent.reputation_score;
+        }
+
+        let idle_days = (idle_secs / 86400) as u64;
+        let decay_bps = (idle_days * self.reputation_decay_bps_per_day as u64)
+            .min(Self::BPS_DENOMINATOR as u64);
+        let retained = Self::BPS_DENOMINATOR as u64 - decay_bps;
+        (agent.reputation_score as u64 * retained / Self::BPS_DENOMINATOR as u64) as u32
+    }
+
+    /// Decayed challenge reputation blended with the agent's averaged external scores
+    /// Agents without imported scores keep their effective reputation unchanged
+    pub fn composite_reputation(
+        &self,
+        agent: &AgentAccount,
+        external: Option<&ExternalScores>,
+        now: i64,
+    ) -> u32 {
+        let effective = self.effective_reputation(agent, now) as u64;
+        let Some(external_avg) = external.and_then(|e| e.average()) else {
+            return effective as u32;
+        };
+        let weight = self.external_reputation_weight_bps as u64;
+        let denominator = Self::BPS_DENOMINATOR as u64;
+        ((effective * (denominator - weight) + external_avg as u64 * weight) / denominator) as u32
+    }
+
+    /// Win rate with each outcome scaled by its per-type weight (basis points, 0 if untested)
+    pub fn weighted_win_rate_bps(&self, agent: &AgentAccount) -> u16 {
+        let mut passed: u64 = 0;
+        let mut failed: u64 = 0;
+        for idx in 0..ChallengeType::COUNT {
+            passed += agent.passed_by_type[idx] as u64 * self.pass_weights_bps[idx] as u64;
+            failed += agent.failed_by_type[idx] as u64 * self.fail_weights_bps[idx] as u64;
+        }
+        AgentAccount::rate_bps(passed, failed)
+    }
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+/// Domain separator for issuer-signed credential attestations
+pub const CREDENTIAL_DOMAIN: &[u8] = b"agent-registry:credential:v1";
+
+/// Well-known credential types (other values are issuer-defined)
+pub const CREDENTIAL_TYPE_KYC: u16 = 1;
+pub const CREDENTIAL_TYPE_KYB: u16 = 2;
+pub const CREDENTIAL_TYPE_ACCREDITED: u16 = 3;
+
+/// CredentialIssuer - a KYC / verifiable-credential provider approved by the admin
+/// Kept (deactivated) on removal; its existing credentials stop counting as valid
+#[account]
+#[derive(InitSpace)]
+pub struct CredentialIssuer {
+    /// The issuer's ed25519 signing key
+    pub authority: Pubkey,
+
+    /// Whether the issuer's credentials are currently accepted
+    pub active: bool,
+
+    /// Total credentials recorded
+    pub credentials_issued: u64,
+
+    /// Unix timestamp when the issuer was registered
+    pub registered_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl CredentialIssuer {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::CREDENTIAL_ISSUER_SEED;
+}
+
+/// CredentialAttestation - an issuer's signed statement that an agent owner holds a credential
+/// Only the credential hash is stored; the credential itself (and any PII) stays off-chain
+#[account]
+#[derive(InitSpace)]
+pub struct CredentialAttestation {
+    /// Wallet the credential was issued to (an agent owner)
+    pub subject: Pubkey,
+
+    /// Issuer's signing key
+    pub issuer: Pubkey,
+
+    /// Credential type (see CREDENTIAL_TYPE_*)
+    pub credential_type: u16,
+
+    /// SHA256 of the off-chain credential
+    pub credential_hash: [u8; 32],
+
+    /// Issuer's ed25519 signature over `credential_message`
+    pub signature: [u8; 64],
+
+    /// Issue time signed by the issuer (re-issues must be newer)
+    pub issued_at: i64,
+
+    /// Unix timestamp after which the credential is no longer valid
+    pub expires_at: i64,
+
+    /// Whether the issuer revoked the credential
+    pub revoked: bool,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl CredentialAttestation {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::CREDENTIAL_SEED;
+
+    /// Whether the credential is unrevoked and unexpired
+    /// Callers should also check the issuer is still active
+    pub fn is_valid(&self, now: i64) -> bool {
+        !self.revoked && now < self.expires_at
+    }
+}
+
+/// Message an issuer signs to attest a credential
+/// domain || program id || subject || credential_type (LE) || credential_hash
+/// || issued_at (LE) || expires_at (LE)
+pub fn credential_message(
+    subject: &Pubkey,
+    credential_type: u16,
+    credential_hash: &[u8; 32],
+    issued_at: i64,
+    expires_at: i64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(128);
+    message.extend_from_slice(CREDENTIAL_DOMAIN);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.extend_from_slice(subject.as_ref());
+    message.extend_from_slice(&credential_type.to_le_bytes());
+    message.extend_from_slice(credential_hash);
+    message.extend_from_slice(&issued_at.to_le_bytes());
+    message.extend_from_slice(&expires_at.to_le_bytes());
+    message
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+/// AgentDid - anchors a W3C DID document to an agent
+/// The owner creates it; afterwards only the DID controller key can change it
+#[account]
+#[derive(InitSpace)]
+pub struct AgentDid {
+    /// The agent this DID describes
+    pub agent: Pubkey,
+
+    /// Key that controls the DID document (a verification method in the document)
+    pub controller: Pubkey,
+
+    /// DID or resolvable URI of the DID document (e.g. "did:web:agent.example")
+    #[max_len(200)]
+    pub did_uri: String,
+
+    /// SHA256 of the canonical DID document
+    pub did_doc_hash: [u8; 32],
+
+    /// Number of updates since anchoring
+    pub revision: u64,
+
+    /// Unix timestamp when anchored
+    pub created_at: i64,
+
+    /// Unix timestamp of the last update
+    pub updated_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AgentDid {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::AGENT_DID_SEED;
+
+    /// Maximum DID URI length in bytes
+    pub const MAX_URI_LEN: usize = 200;
+}
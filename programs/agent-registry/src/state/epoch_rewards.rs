@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use super::EpochSnapshot;
+
+/// EpochRewards - an epoch's emission and the reputation sharing it
+/// Opened once per epoch by `open_epoch_rewards`, which fixes the emission and thresholds;
+/// every eligible snapshot adds its reputation, so shares are final once the epoch ends.
+/// Shares can be claimed for `CLAIM_WINDOW_EPOCHS`, after which `release_epoch_rewards`
+/// frees whatever is left
+#[account]
+#[derive(InitSpace)]
+pub struct EpochRewards {
+    /// Solana epoch these rewards cover
+    pub epoch: u64,
+
+    /// Lamports emitted for the epoch (from the config schedule)
+    pub emission_lamports: u64,
+
+    /// Minimum snapshot reputation counted toward the epoch
+    pub min_reputation: u32,
+
+    /// Minimum locked snapshot stake counted toward the epoch
+    pub min_stake_lamports: u64,
+
+    /// Sum of eligible snapshot reputation
+    pub total_reputation: u64,
+
+    /// Number of eligible snapshots
+    pub eligible_agents: u32,
+
+    /// Lamports paid out so far
+    pub claimed_lamports: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Unclaimed lamports released from the treasury liabilities after the claim window
+    pub released_lamports: u64,
+}
+
+impl EpochRewards {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::EPOCH_REWARDS_SEED;
+
+    /// Epochs after the rewarded one during which shares can be claimed (~2 months)
+    pub const CLAIM_WINDOW_EPOCHS: u64 = 30;
+
+    /// Whether shares can still be claimed in `current_epoch`
+    pub fn claimable(&self, current_epoch: u64) -> bool {
+        current_epoch <= self.epoch.saturating_add(Self::CLAIM_WINDOW_EPOCHS)
+    }
+
+    /// Emission still held in the treasury liabilities
+    pub fn unclaimed_lamports(&self) -> u64 {
+        self.emission_lamports
+            .saturating_sub(self.claimed_lamports)
+            .saturating_sub(self.released_lamports)
+    }
+
+    /// Verified snapshots at or above the epoch's reputation and locked stake
+    /// thresholds share the emission
+    pub fn is_eligible(&self, snapshot: &EpochSnapshot) -> bool {
+        snapshot.verified
+            && snapshot.locked_stake_lamports > 0
+            && snapshot.locked_stake_lamports >= self.min_stake_lamports
+            && snapshot.reputation_score >= self.min_reputation
+    }
+
+    /// Share of the emission owed to an eligible snapshot
+    pub fn reward_for(&self, snapshot: &EpochSnapshot) -> u64 {
+        if self.total_reputation == 0 {
+            return 0;
+        }
+        ((self.emission_lamports as u128) * (snapshot.reputation_score as u128)
+            / (self.total_reputation as u128)) as u64
+    }
+}
+
+/// EpochRewardClaim - marks an agent's epoch reward as paid
+/// Its `init` is what stops the same snapshot from being claimed twice
+#[account]
+#[derive(InitSpace)]
+pub struct EpochRewardClaim {
+    /// Agent that was paid
+    pub agent: Pubkey,
+
+    /// Epoch the reward was earned in
+    pub epoch: u64,
+
+    /// Lamports paid
+    pub amount: u64,
+
+    /// Unix timestamp of the claim
+    pub claimed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl EpochRewardClaim {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::EPOCH_REWARD_CLAIM_SEED;
+}
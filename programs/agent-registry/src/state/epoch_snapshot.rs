@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use super::AgentAccount;
+
+/// Epoch snapshot - an agent's standing captured once per Solana epoch
+/// Gives analytics and reward programs a historical series that survives RPC log pruning
+#[account]
+#[derive(InitSpace)]
+pub struct EpochSnapshot {
+    /// The agent this snapshot belongs to
+    pub agent: Pubkey,
+
+    /// Solana epoch the snapshot was taken in
+    pub epoch: u64,
+
+    /// Reputation score at capture time
+    pub reputation_score: u32,
+
+    /// Challenges passed at capture time
+    pub challenges_passed: u32,
+
+    /// Challenges failed at capture time
+    pub challenges_failed: u32,
+
+    /// Staked lamports at capture time
+    pub staked_lamports: u64,
+
+    /// Of those, lamports locked through the end of the epoch (what emissions count)
+    pub locked_stake_lamports: u64,
+
+    /// Verification status at capture time
+    pub verified: bool,
+
+    /// Slot of capture
+    pub slot: u64,
+
+    /// Unix timestamp of capture
+    pub captured_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl EpochSnapshot {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::EPOCH_SNAPSHOT_SEED;
+
+    /// Snapshots are only taken this long after the epoch starts, so they capture the
+    /// boundary state rather than a moment the owner picked (1 hour)
+    pub const CAPTURE_WINDOW_SECS: i64 = 3600;
+
+    /// Nominal slot duration, for estimating when an epoch ends
+    pub const MS_PER_SLOT: u64 = 400;
+
+    /// Whether the clock is still within the current epoch's capture window
+    pub fn capture_open(clock: &Clock) -> bool {
+        clock.unix_timestamp <= clock.epoch_start_timestamp.saturating_add(Self::CAPTURE_WINDOW_SECS)
+    }
+
+    /// Estimated Unix timestamp the current epoch ends at
+    pub fn epoch_end(clock: &Clock, slots_per_epoch: u64) -> i64 {
+        let secs = slots_per_epoch.saturating_mul(Self::MS_PER_SLOT) / 1000;
+        clock.epoch_start_timestamp.saturating_add(secs as i64)
+    }
+
+    /// The agent's stake that can't be withdrawn before `epoch_end`
+    pub fn locked_stake(agent: &AgentAccount, epoch_end: i64) -> u64 {
+        if agent.stake_locked_until >= epoch_end {
+            agent.staked_lamports
+        } else {
+            0
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::merkle::verify_batch_proof;
+
+/// Eval batch tag - marks a stored MerkleAuditRoot as a batch of evaluation results
+/// Attached by an active ReputationAuthority (an evaluation service) that vouches for
+/// the batch's leaves, so `prove_capability` can trust them
+/// Leaf: SHA256(0x01 || agent pubkey || capability id (u8)) - "agent passed the eval"
+#[account]
+#[derive(InitSpace)]
+pub struct EvalBatch {
+    /// The tagged MerkleAuditRoot
+    pub audit_root: Pubkey,
+
+    /// The agent the batch belongs to
+    pub agent: Pubkey,
+
+    /// Evaluation service that tagged the batch
+    pub evaluator: Pubkey,
+
+    /// Unix timestamp when the batch was tagged
+    pub tagged_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl EvalBatch {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::EVAL_BATCH_SEED;
+
+    /// Domain separator for capability eval leaves
+    pub const LEAF_PREFIX: u8 = crate::merkle::EVAL_LEAF_PREFIX;
+
+    /// Leaf hash for `agent` passing the eval for `capability_id`
+    pub fn leaf_hash(agent: &Pubkey, capability_id: u8) -> [u8; 32] {
+        solana_sha256_hasher::hashv(&[&[Self::LEAF_PREFIX], agent.as_ref(), &[capability_id]]).to_bytes()
+    }
+
+    /// Whether `proof` places the eval leaf for `agent` / `capability_id` at `index` in
+    /// the batch `root` over `leaf_count` leaves
+    pub fn verify(
+        root: &[u8; 32],
+        leaf_count: u64,
+        agent: &Pubkey,
+        capability_id: u8,
+        index: u64,
+        proof: &[[u8; 32]],
+    ) -> bool {
+        verify_batch_proof(root, Self::leaf_hash(agent, capability_id), index, leaf_count, proof)
+    }
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+/// ReputationOracle - a key the admin trusts to import scores from one off-chain source
+/// (e.g. a benchmark leaderboard); kept (deactivated) on removal
+#[account]
+#[derive(InitSpace)]
+pub struct ReputationOracle {
+    /// The oracle's signing key
+    pub authority: Pubkey,
+
+    /// Source this oracle reports for (one oracle per source slot)
+    pub source_id: u8,
+
+    /// Whether the oracle may currently import scores
+    pub active: bool,
+
+    /// Total scores imported
+    pub imports: u64,
+
+    /// Unix timestamp when the oracle was registered
+    pub registered_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ReputationOracle {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::REPUTATION_ORACLE_SEED;
+}
+
+/// One source's latest score for an agent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub struct ExternalScore {
+    /// Source identifier (ReputationOracle::source_id)
+    pub source_id: u8,
+
+    /// Score on the registry's 0-10000 scale
+    pub score: u32,
+
+    /// Oracle that reported the score
+    pub oracle: Pubkey,
+
+    /// Unix timestamp of the report (0 = empty slot)
+    pub updated_at: i64,
+}
+
+/// ExternalScores - imported off-chain scores for one agent
+/// Kept apart from challenge-based `reputation_score`; blended in by `composite_reputation`
+#[account]
+#[derive(InitSpace)]
+pub struct ExternalScores {
+    /// The agent these scores describe
+    pub agent: Pubkey,
+
+    /// Latest score per source (empty slots have `updated_at == 0`)
+    pub scores: [ExternalScore; ExternalScores::MAX_SOURCES],
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ExternalScores {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::EXTERNAL_SCORES_SEED;
+
+    /// Number of distinct sources tracked per agent
+    pub const MAX_SOURCES: usize = 8;
+
+    /// Record a source's score, replacing its previous one or taking a free slot
+    pub fn upsert(&mut self, score: ExternalScore) -> bool {
+        let slot = self
+            .scores
+            .iter()
+            .position(|s| s.updated_at != 0 && s.source_id == score.source_id)
+            .or_else(|| self.scores.iter().position(|s| s.updated_at == 0));
+        match slot {
+            Some(idx) => {
+                self.scores[idx] = score;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Mean score over reported sources (None if nothing was imported)
+    pub fn average(&self) -> Option<u32> {
+        let (sum, count) = self
+            .scores
+            .iter()
+            .filter(|s| s.updated_at != 0)
+            .fold((0u64, 0u64), |(sum, count), s| (sum + s.score as u64, count + 1));
+        (count > 0).then(|| (sum / count) as u32)
+    }
+}
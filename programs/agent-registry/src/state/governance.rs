@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use crate::instructions::UpdateConfigParams;
+use super::{AgentAccount, RegistryConfig};
+
+/// How a voting agent's weight is derived
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum VoteWeighting {
+    /// Effective (decayed) reputation score
+    Reputation,
+    /// Staked lamports
+    Stake,
+    /// Effective reputation times staked SOL
+    ReputationTimesStake,
+}
+
+/// Governance - thresholds for agent-voted config changes (singleton)
+#[account]
+#[derive(InitSpace)]
+pub struct Governance {
+    /// Whether new proposals are accepted
+    pub active: bool,
+
+    pub weighting: VoteWeighting,
+
+    /// Total weight (for + against) a proposal needs to be decided
+    pub quorum_weight: u64,
+
+    /// Share of the cast weight that must approve (basis points, above 50%)
+    pub majority_bps: u16,
+
+    /// How long proposals stay open for votes
+    pub voting_period_secs: i64,
+
+    /// Number of proposals created (next proposal id)
+    pub proposal_count: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Governance {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::GOVERNANCE_SEED;
+
+    /// Shortest voting period (1 day)
+    pub const MIN_VOTING_PERIOD_SECS: i64 = 24 * 3600;
+
+    /// Longest voting period (30 days)
+    pub const MAX_VOTING_PERIOD_SECS: i64 = 30 * 24 * 3600;
+
+    /// Thresholds are usable: a non-zero quorum, a strict majority and a bounded period
+    pub fn is_valid_config(quorum_weight: u64, majority_bps: u16, voting_period_secs: i64) -> bool {
+        quorum_weight > 0
+            && majority_bps > 5000
+            && (majority_bps as u32) <= RegistryConfig::BPS_DENOMINATOR
+            && (Self::MIN_VOTING_PERIOD_SECS..=Self::MAX_VOTING_PERIOD_SECS).contains(&voting_period_secs)
+    }
+
+    /// Voting weight of an agent under the current weighting
+    pub fn vote_weight(&self, agent: &AgentAccount, config: &RegistryConfig, now: i64) -> u64 {
+        let reputation = config.effective_reputation(agent, now) as u128;
+        let stake = agent.staked_lamports as u128;
+        let weight = match self.weighting {
+            VoteWeighting::Reputation => reputation,
+            VoteWeighting::Stake => stake,
+            VoteWeighting::ReputationTimesStake => reputation * stake / 1_000_000_000,
+        };
+        weight.min(u64::MAX as u128) as u64
+    }
+
+    /// Whether votes are weighed by stake, which is then locked until voting ends
+    pub fn weighs_stake(&self) -> bool {
+        !matches!(self.weighting, VoteWeighting::Reputation)
+    }
+
+    /// Whether an agent may propose and vote: verified, in good standing and
+    /// solely owned by `voter`
+    pub fn is_eligible(agent: &AgentAccount, voter: &Pubkey, now: i64) -> bool {
+        agent.verified && agent.owner == *voter && !agent.has_multisig() && agent.accepts_work(now)
+    }
+}
+
+/// Governance proposal - a config change put to a reputation-weighted agent vote
+/// Thresholds are copied from Governance at creation so later changes don't move the goalposts
+#[account]
+pub struct GovernanceProposal {
+    /// Proposal id (PDA seed)
+    pub proposal_id: u64,
+
+    /// Wallet that created the proposal
+    pub proposer: Pubkey,
+
+    /// Verified agent the proposer created it through
+    pub proposer_agent: Pubkey,
+
+    /// SHA256 of the off-chain rationale
+    pub description_hash: [u8; 32],
+
+    /// Config change applied when the proposal passes (same semantics as `update_config`)
+    pub params: UpdateConfigParams,
+
+    pub quorum_weight: u64,
+    pub majority_bps: u16,
+
+    pub votes_for: u64,
+    pub votes_against: u64,
+
+    /// Number of agents that voted
+    pub voters: u32,
+
+    /// Whether the config change has been applied
+    pub executed: bool,
+
+    /// Unix timestamp when proposed
+    pub created_at: i64,
+
+    /// Votes are accepted until this timestamp
+    pub voting_ends_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl GovernanceProposal {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::GOVERNANCE_PROPOSAL_SEED;
+
+    /// Account size (with discriminator) for a proposal carrying `params`
+    pub fn space(params: &UpdateConfigParams) -> usize {
+        let fixed = 8 + 32 + 32 + 32 + 8 + 2 + 8 + 8 + 4 + 1 + 8 + 8 + 1;
+        8 + fixed + params.try_to_vec().map_or(0, |bytes| bytes.len())
+    }
+
+    /// Whether votes are still accepted
+    pub fn is_open(&self, now: i64) -> bool {
+        now < self.voting_ends_at
+    }
+
+    /// Whether the cast weight meets the quorum and the approving share the majority
+    pub fn passed(&self) -> bool {
+        let total = self.votes_for as u128 + self.votes_against as u128;
+        total >= self.quorum_weight as u128
+            && self.votes_for as u128 * RegistryConfig::BPS_DENOMINATOR as u128
+                >= total * self.majority_bps as u128
+    }
+}
+
+/// Governance vote - one agent's ballot on a proposal (the PDA, keyed by agent ID,
+/// prevents double voting across transfers)
+#[account]
+#[derive(InitSpace)]
+pub struct GovernanceVote {
+    pub proposal: Pubkey,
+
+    /// Agent account that cast the vote
+    pub agent: Pubkey,
+
+    /// Agent ID (shared by an agent and its transfer successors)
+    pub agent_id: u64,
+
+    pub approve: bool,
+
+    /// Weight counted for the agent
+    pub weight: u64,
+
+    /// Unix timestamp of the vote
+    pub voted_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl GovernanceVote {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::GOVERNANCE_VOTE_SEED;
+}
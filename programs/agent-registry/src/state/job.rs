@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use super::{AgentAccount, RegistryConfig};
+
+/// Job lifecycle status
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub enum JobStatus {
+    /// Waiting for the agent owner to accept
+    #[default]
+    Open,
+    /// Accepted; the agent must deliver before the delivery deadline
+    Accepted,
+    /// Delivered; the consumer may settle or dispute during the review window
+    Delivered,
+    /// Consumer disputed the delivery; awaiting admin resolution
+    Disputed,
+    /// Completed: escrow paid to the agent owner
+    Settled,
+    /// Failed (missed deadline or lost dispute): escrow refunded to the consumer
+    Refunded,
+    /// Cancelled by the consumer before acceptance
+    Cancelled,
+}
+
+/// Job - an escrowed unit of work purchased from a service listing
+/// Escrow lamports are held in the job PDA until settlement
+#[account]
+#[derive(InitSpace)]
+pub struct Job {
+    /// Service listing the job was purchased from
+    pub listing: Pubkey,
+
+    /// Agent performing the work
+    pub agent: Pubkey,
+
+    /// Consumer who created and funded the job
+    pub consumer: Pubkey,
+
+    /// Job ID chosen by the consumer (unique per listing + consumer)
+    pub job_id: u64,
+
+    /// Current status
+    pub status: JobStatus,
+
+    /// Lamports escrowed in this PDA
+    pub escrow: u64,
+
+    /// SHA256 commitment to the job request (revealed to the agent off-chain)
+    pub request_hash: [u8; 32],
+
+    /// SHA256 commitment to the deliverable
+    pub deliverable_hash: [u8; 32],
+
+    /// Agent must accept before this timestamp
+    pub accept_deadline: i64,
+
+    /// Seconds the agent has to deliver after accepting
+    pub delivery_window: i64,
+
+    /// Agent must deliver before this timestamp (set on accept)
+    pub delivery_deadline: i64,
+
+    /// Unix timestamp when created
+    pub created_at: i64,
+
+    /// Unix timestamp when delivered
+    pub delivered_at: i64,
+
+    /// Unix timestamp when settled, refunded or cancelled
+    pub closed_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Job {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::JOB_SEED;
+
+    /// Consumer review window after delivery before the owner may self-settle (3 days)
+    pub const REVIEW_WINDOW: i64 = 3 * 24 * 3600;
+
+    /// Longest accept or delivery window (30 days)
+    pub const MAX_WINDOW: i64 = 30 * 24 * 3600;
+
+    /// Smallest escrow a job may be created with (0.001 SOL)
+    pub const MIN_ESCROW_LAMPORTS: u64 = 1_000_000;
+
+    /// Escrow backing each reputation point a settled job earns (0.001 SOL)
+    pub const LAMPORTS_PER_REPUTATION_POINT: u64 = 1_000_000;
+
+    /// Most reputation a single settled job can earn
+    pub const COMPLETED_REPUTATION_DELTA: i32 = 50;
+
+    /// Reputation loss for a missed deadline or lost dispute
+    pub const FAILED_REPUTATION_DELTA: i32 = -100;
+
+    /// Reputation earned by settling a job holding `escrow`: one point per
+    /// LAMPORTS_PER_REPUTATION_POINT, capped at COMPLETED_REPUTATION_DELTA
+    pub fn completed_reputation_delta(escrow: u64) -> i32 {
+        (escrow / Self::LAMPORTS_PER_REPUTATION_POINT).min(Self::COMPLETED_REPUTATION_DELTA as u64) as i32
+    }
+
+    /// Close the job: settled pays the owner and rewards the agent by escrow size,
+    /// refunded returns escrow to the consumer and penalizes the agent
+    /// Returns the amount of escrow moved
+    pub fn finalize<'info>(
+        job: &mut Account<'info, Job>,
+        agent: &mut AgentAccount,
+        config: &RegistryConfig,
+        completed: bool,
+        owner: &AccountInfo<'info>,
+        consumer: &AccountInfo<'info>,
+        now: i64,
+    ) -> Result<u64> {
+        let (status, delta, recipient) = if completed {
+            (JobStatus::Settled, Self::completed_reputation_delta(job.escrow), owner)
+        } else {
+            (JobStatus::Refunded, Self::FAILED_REPUTATION_DELTA, consumer)
+        };
+        job.status = status;
+        job.closed_at = now;
+
+        agent.adjust_reputation(delta, config, now);
+        agent.touch(now);
+
+        Self::release_escrow(job, recipient)
+    }
+
+    /// Move the escrowed lamports out of the job PDA to `recipient`
+    pub fn release_escrow<'info>(job: &mut Account<'info, Job>, recipient: &AccountInfo<'info>) -> Result<u64> {
+        let amount = job.escrow;
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        job.escrow = 0;
+        let job_info = job.to_account_info();
+        **job_info.try_borrow_mut_lamports()? -= amount;
+        **recipient.try_borrow_mut_lamports()? += amount;
+
+        Ok(amount)
+    }
+}
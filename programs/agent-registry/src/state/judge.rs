@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+/// Judge account - a committee member allowed to vote on judged challenges
+/// Registered by the admin; kept (deactivated) on removal for accountability
+#[account]
+#[derive(InitSpace)]
+pub struct Judge {
+    /// The judge's signing key
+    pub authority: Pubkey,
+
+    /// Whether the judge may currently vote
+    pub active: bool,
+
+    /// Total votes cast
+    pub votes_cast: u64,
+
+    /// Votes for a pass verdict
+    pub votes_pass: u64,
+
+    /// Votes for a fail verdict
+    pub votes_fail: u64,
+
+    /// Unix timestamp when the judge was registered
+    pub registered_at: i64,
+
+    /// Unix timestamp of the judge's last vote
+    pub last_vote_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Judge {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::JUDGE_SEED;
+}
+
+/// A single judge's vote on a challenge (per-judge accountability record)
+/// PDA per (challenge, judge) also prevents double voting
+#[account]
+#[derive(InitSpace)]
+pub struct JudgeVote {
+    /// The challenge voted on
+    pub challenge: Pubkey,
+
+    /// The judge who voted
+    pub judge: Pubkey,
+
+    /// The verdict: true = pass, false = fail
+    pub passed: bool,
+
+    /// Unix timestamp of the vote
+    pub voted_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// ChallengeEvidence digest when the vote was cast (zero = no evidence anchored)
+    pub evidence_digest: [u8; 32],
+}
+
+impl JudgeVote {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::JUDGE_VOTE_SEED;
+}
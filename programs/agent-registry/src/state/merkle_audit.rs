@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
 
 /// Merkle audit root - stores a batch of audit entries as a single root hash
 /// This is more gas-efficient than storing each entry individually
@@ -28,7 +29,7 @@ pub struct MerkleAuditRoot {
 }
 
 impl MerkleAuditRoot {
-    pub const SEED_PREFIX: &'static [u8] = b"merkle_audit";
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::MERKLE_AUDIT_SEED;
 }
 
 /// Lightweight summary tracking total batches per agent
@@ -48,10 +49,117 @@ pub struct MerkleAuditSummary {
     /// Timestamp of last batch
     pub last_batch_at: i64,
 
+    /// Running hash over every batch root in order: H(running || root), starting from
+    /// zero. Commits to the whole batch history even after root accounts are closed
+    pub running_root: [u8; 32],
+
     /// PDA bump seed
     pub bump: u8,
 }
 
 impl MerkleAuditSummary {
-    pub const SEED_PREFIX: &'static [u8] = b"merkle_summary";
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::MERKLE_SUMMARY_SEED;
+
+    /// Fold the next batch root into `running_root`
+    pub fn fold_root(&mut self, merkle_root: &[u8; 32]) {
+        self.running_root = hash_pair(&self.running_root, merkle_root);
+    }
 }
+
+/// Depth of the on-chain incremental audit tree (2^20 ≈ 1M leaves per agent)
+pub const AUDIT_TREE_DEPTH: usize = 20;
+
+/// Incremental Merkle tree for per-event audit anchoring
+///
+/// Pattern: append one leaf per tx → root is updated on-chain immediately
+/// Only the rightmost path (`filled_subtrees`) is cached, so the account size
+/// is fixed regardless of how many leaves have been appended.
+/// Empty positions hash as zero leaves ([0u8; 32]) at every level.
+#[account]
+#[derive(InitSpace)]
+pub struct AuditMerkleTree {
+    /// The agent this tree belongs to
+    pub agent: Pubkey,
+
+    /// Current root over all appended leaves
+    pub root: [u8; 32],
+
+    /// Index the next leaf will be written to (= number of leaves appended)
+    pub next_index: u64,
+
+    /// Left-side nodes of the rightmost path, one per level
+    pub filled_subtrees: [[u8; 32]; AUDIT_TREE_DEPTH],
+
+    /// Timestamp of last append
+    pub last_append_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AuditMerkleTree {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::AUDIT_TREE_SEED;
+
+    /// Third seed of the agent's challenge-result stream, a separate tree that
+    /// challenge resolutions append to: [AUDIT_TREE_SEED, agent, CHALLENGE_STREAM]
+    pub const CHALLENGE_STREAM: &'static [u8] = crate::constants::CHALLENGE_STREAM_SEED;
+
+    /// Maximum number of leaves the tree can hold
+    pub const CAPACITY: u64 = 1 << AUDIT_TREE_DEPTH;
+
+    /// Reset the tree to the empty state for `agent`
+    pub fn init(&mut self, agent: Pubkey, bump: u8) {
+        let mut zero = [0u8; 32];
+        for level in 0..AUDIT_TREE_DEPTH {
+            self.filled_subtrees[level] = zero;
+            zero = hash_pair(&zero, &zero);
+        }
+        self.agent = agent;
+        self.root = zero;
+        self.next_index = 0;
+        self.bump = bump;
+    }
+
+    /// Append a leaf and recompute the root along the rightmost path
+    /// Returns the index the leaf was written to, or None if the tree is full
+    pub fn append(&mut self, leaf: [u8; 32]) -> Option<u64> {
+        if self.next_index >= Self::CAPACITY {
+            return None;
+        }
+
+        let leaf_index = self.next_index;
+        let mut index = leaf_index;
+        let mut node = leaf;
+        let mut zero = [0u8; 32];
+
+        for level in 0..AUDIT_TREE_DEPTH {
+            if index & 1 == 0 {
+                // Left child: cache it, pair with the empty subtree on the right
+                self.filled_subtrees[level] = node;
+                node = hash_pair(&node, &zero);
+            } else {
+                // Right child: pair with the cached left sibling
+                node = hash_pair(&self.filled_subtrees[level], &node);
+            }
+            zero = hash_pair(&zero, &zero);
+            index >>= 1;
+        }
+
+        self.root = node;
+        self.next_index = leaf_index + 1;
+        Some(leaf_index)
+    }
+
+    /// Append a leaf at `now`, initializing the tree for `agent` on first use
+    /// Returns the index the leaf was written to
+    pub fn record(&mut self, agent: Pubkey, bump: u8, leaf: [u8; 32], now: i64) -> Result<u64> {
+        if self.agent == Pubkey::default() {
+            self.init(agent, bump);
+        }
+        let leaf_index = self.append(leaf).ok_or(RegistryError::AuditTreeFull)?;
+        self.last_append_at = now;
+        Ok(leaf_index)
+    }
+}
+
+pub use crate::merkle::hash_pair;
@@ -1,11 +1,111 @@
+pub mod admin_log;
 pub mod agent;
+pub mod agent_archive;
+pub mod agent_counters;
+pub mod agent_transfer;
+pub mod allowance;
+pub mod allowlist;
+pub mod attestation;
 pub mod audit;
+pub mod audit_delegate;
+pub mod audit_selection;
+pub mod badge;
+pub mod benchmark;
+pub mod capability;
 pub mod challenge;
+pub mod challenge_evidence;
+pub mod challenge_template;
+pub mod config;
+pub mod credential;
+pub mod did;
+pub mod epoch_rewards;
+pub mod epoch_snapshot;
+pub mod eval_batch;
+pub mod external_reputation;
+pub mod governance;
+pub mod job;
+pub mod judge;
 pub mod merkle_audit;
+pub mod model_index;
+pub mod model_record;
+pub mod moderator;
+pub mod multisig;
+pub mod namespace;
+pub mod owner_counter;
+pub mod owner_index;
+pub mod payment_channel;
+pub mod pyth;
+pub mod referral;
 pub mod registry;
+pub mod registry_migration;
+pub mod registry_snapshot;
+pub mod reputation_authority;
+pub mod reputation_history;
+pub mod reputation_hook;
+pub mod review;
+pub mod service_listing;
+pub mod signed_action;
+pub mod sponsor;
+pub mod tag_index;
+pub mod token_account;
+pub mod unstake_request;
+pub mod verification;
+pub mod verification_queue;
+pub mod watchers;
+pub mod zk_verifier;
 
+pub use admin_log::*;
 pub use agent::*;
+pub use agent_archive::*;
+pub use agent_counters::*;
+pub use agent_transfer::*;
+pub use allowance::*;
+pub use allowlist::*;
+pub use attestation::*;
 pub use audit::*;
+pub use audit_delegate::*;
+pub use audit_selection::*;
+pub use badge::*;
+pub use benchmark::*;
+pub use capability::*;
 pub use challenge::*;
+pub use challenge_evidence::*;
+pub use challenge_template::*;
+pub use config::*;
+pub use credential::*;
+pub use did::*;
+pub use epoch_rewards::*;
+pub use epoch_snapshot::*;
+pub use eval_batch::*;
+pub use external_reputation::*;
+pub use governance::*;
+pub use job::*;
+pub use judge::*;
 pub use merkle_audit::*;
+pub use model_index::*;
+pub use model_record::*;
+pub use moderator::*;
+pub use multisig::*;
+pub use namespace::*;
+pub use owner_counter::*;
+pub use owner_index::*;
+pub use payment_channel::*;
+pub use pyth::*;
+pub use referral::*;
 pub use registry::*;
+pub use registry_migration::*;
+pub use registry_snapshot::*;
+pub use reputation_authority::*;
+pub use reputation_history::*;
+pub use reputation_hook::*;
+pub use review::*;
+pub use service_listing::*;
+pub use signed_action::*;
+pub use sponsor::*;
+pub use tag_index::*;
+pub use token_account::*;
+pub use unstake_request::*;
+pub use verification::*;
+pub use verification_queue::*;
+pub use watchers::*;
+pub use zk_verifier::*;
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// ModelIndex - every indexed agent running one model hash
+/// Lets clients resolve "who runs this model?" with a single account fetch;
+/// kept independently of `unique_models` (forks and duplicates share an index)
+#[account]
+#[derive(InitSpace)]
+pub struct ModelIndex {
+    /// sha256 of the model hash string (same digest as ModelRecord)
+    pub model_digest: [u8; 32],
+
+    /// Agents registered with this model, in indexing order
+    #[max_len(16)]
+    pub agents: Vec<Pubkey>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ModelIndex {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::MODEL_INDEX_SEED;
+
+    /// Maximum agents tracked per model
+    pub const MAX_AGENTS: usize = 16;
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// ModelRecord - claims a model hash for the first agent registered with it
+/// Only created while the registry config has `unique_models` set; a second plain
+/// registration of the same model fails because the PDA already exists
+#[account]
+#[derive(InitSpace, Default)]
+pub struct ModelRecord {
+    /// sha256 of the model hash string (the string itself is too long for a seed)
+    pub model_digest: [u8; 32],
+
+    /// Agent that first registered the model
+    pub agent: Pubkey,
+
+    /// Forks linked to this record by reusing their parent's model
+    pub linked_forks: u32,
+
+    /// Unix timestamp when the model was claimed
+    pub created_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ModelRecord {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::MODEL_RECORD_SEED;
+
+    /// Seed digest for a model hash string
+    pub fn digest(model_hash: &str) -> [u8; 32] {
+        solana_sha256_hasher::hash(model_hash.as_bytes()).to_bytes()
+    }
+}
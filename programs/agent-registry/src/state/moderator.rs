@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Moderator account - a key the admin trusts to suspend, ban and reinstate agents
+/// Kept (deactivated) on removal for accountability
+#[account]
+#[derive(InitSpace)]
+pub struct Moderator {
+    /// The moderator's signing key
+    pub authority: Pubkey,
+
+    /// Whether the moderator may currently act
+    pub active: bool,
+
+    /// Total moderation actions taken
+    pub actions_taken: u64,
+
+    /// Unix timestamp when the moderator was registered
+    pub registered_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Moderator {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::MODERATOR_SEED;
+}
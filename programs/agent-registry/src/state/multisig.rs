@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+
+/// A mutation a team-owned agent can authorize through its multisig
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub enum MultisigAction {
+    /// Same semantics as `update_agent`
+    UpdateMetadata {
+        #[max_len(256)]
+        name: Option<String>,
+        #[max_len(2048)]
+        capabilities: Option<String>,
+    },
+    /// Replace the owner set and threshold
+    SetOwners {
+        #[max_len(10)]
+        owners: Vec<Pubkey>,
+        threshold: u8,
+    },
+    /// Withdraw staked lamports to `recipient` (only while no unstake cooldown is set)
+    Unstake { amount: u64, recipient: Pubkey },
+    /// Same semantics as `set_capability_flags`
+    SetCapabilityFlags { flags: u64 },
+    /// Same semantics as `freeze_fields`
+    FreezeFields { fields: u8 },
+    /// Same semantics as `request_unstake`; the executor funds the request's rent
+    RequestUnstake { amount: u64 },
+    /// Same semantics as `withdraw_unstake`, paying the request out to `recipient`
+    WithdrawUnstake { recipient: Pubkey },
+    /// Same semantics as `retire_agent`
+    Retire,
+    /// Same semantics as `deactivate_agent`
+    Deactivate { until: Option<i64> },
+    /// Same semantics as `reactivate_agent` called by the owner
+    Reactivate,
+    /// Same semantics as `close_agent`, returning rent and stake to `recipient`; the
+    /// multisig account is closed along with the agent
+    Close { recipient: Pubkey },
+    /// Same semantics as `archive_agent`, returning rent and stake to `recipient`; the
+    /// executor funds the archive
+    Archive { recipient: Pubkey },
+}
+
+impl MultisigAction {
+    /// Whether the action needs an agent that hasn't been retired or banned
+    /// Stake already pending withdrawal can still leave a retired agent, which is
+    /// then closed or archived
+    pub fn needs_mutable_agent(&self) -> bool {
+        !matches!(
+            self,
+            Self::WithdrawUnstake { .. } | Self::Close { .. } | Self::Archive { .. }
+        )
+    }
+}
+
+/// Agent multisig - m-of-n authority over a team-owned agent
+/// Once attached, owner-key mutations are disabled and go through this account
+#[account]
+#[derive(InitSpace)]
+pub struct AgentMultisig {
+    /// The agent this multisig controls
+    pub agent: Pubkey,
+
+    /// Owner keys (max 10)
+    #[max_len(10)]
+    pub owners: Vec<Pubkey>,
+
+    /// Approvals required to act
+    pub threshold: u8,
+
+    /// Incremented whenever the owner set changes; stale proposals can't execute
+    pub owner_set_seq: u32,
+
+    /// Number of proposals created (next proposal index)
+    pub proposal_count: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AgentMultisig {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::AGENT_MULTISIG_SEED;
+
+    /// Maximum owners per multisig (approvals are tracked in a u16 bitmask)
+    pub const MAX_OWNERS: usize = 10;
+
+    /// Owner set is non-empty, bounded, duplicate-free and the threshold reachable
+    pub fn is_valid_config(owners: &[Pubkey], threshold: u8) -> bool {
+        let unique = owners
+            .iter()
+            .enumerate()
+            .all(|(i, owner)| !owners[..i].contains(owner));
+        !owners.is_empty()
+            && owners.len() <= Self::MAX_OWNERS
+            && unique
+            && threshold > 0
+            && (threshold as usize) <= owners.len()
+    }
+
+    /// Position of `key` in the owner set
+    pub fn owner_index(&self, key: &Pubkey) -> Option<usize> {
+        self.owners.iter().position(|owner| owner == key)
+    }
+
+    /// Bitmask of owners that signed this transaction among `accounts`
+    pub fn signer_mask(&self, accounts: &[AccountInfo]) -> u16 {
+        accounts
+            .iter()
+            .filter(|info| info.is_signer)
+            .filter_map(|info| self.owner_index(info.key))
+            .fold(0u16, |mask, idx| mask | (1 << idx))
+    }
+
+    /// Whether an approval bitmask meets the threshold
+    pub fn meets_threshold(&self, approvals: u16) -> bool {
+        approvals.count_ones() >= self.threshold as u32
+    }
+}
+
+/// Multisig proposal - an action awaiting owner approvals
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigProposal {
+    /// The multisig this proposal belongs to
+    pub multisig: Pubkey,
+
+    /// Proposal index within the multisig
+    pub index: u64,
+
+    /// Owner who proposed it
+    pub proposer: Pubkey,
+
+    /// Action to execute once approved
+    pub action: MultisigAction,
+
+    /// Approval bitmask (bit i = owners[i] approved)
+    pub approvals: u16,
+
+    /// Owner set the approvals refer to
+    pub owner_set_seq: u32,
+
+    /// Whether the action has been executed
+    pub executed: bool,
+
+    /// Unix timestamp when proposed
+    pub created_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl MultisigProposal {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::MULTISIG_PROPOSAL_SEED;
+}
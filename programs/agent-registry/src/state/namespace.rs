@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+/// Registry namespace - an independent sub-registry (e.g. "trading-agents") under one
+/// deployment, with its own admin, membership policy and counters
+/// Agents opt in by tagging themselves with the namespace via `join_namespace`
+#[account]
+#[derive(InitSpace)]
+pub struct RegistryNamespace {
+    /// Human-readable name (lowercase alphanumerics and '-')
+    #[max_len(32)]
+    pub name: String,
+
+    /// sha256 of `name` - PDA seed and the tag stored on member agents
+    pub name_hash: [u8; 32],
+
+    /// Namespace admin (sets the policy below; independent of the registry admin)
+    pub admin: Pubkey,
+
+    /// Lamports a member pays the namespace admin on joining
+    pub join_fee_lamports: u64,
+
+    /// Minimum reputation to join
+    pub min_reputation: u32,
+
+    /// Only verified agents may join
+    pub verified_only: bool,
+
+    /// Joining requires the namespace admin's signature
+    pub approval_required: bool,
+
+    /// Agents currently tagged with this namespace
+    pub member_count: u64,
+
+    /// Agents that ever joined
+    pub total_joined: u64,
+
+    /// Unix timestamp when the namespace was created
+    pub created_at: i64,
+
+    /// When the namespace was sunset by `deprecate_registry` (0 = active)
+    pub deprecated_at: i64,
+
+    /// Successor RegistryNamespace for members once deprecated (default pubkey = none named)
+    pub successor: Pubkey,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl RegistryNamespace {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::NAMESPACE_SEED;
+
+    /// Longest namespace name
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Whether the namespace has been sunset (no new members)
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated_at != 0
+    }
+
+    /// Digest identifying a namespace name
+    pub fn hash(name: &str) -> [u8; 32] {
+        solana_sha256_hasher::hash(name.as_bytes()).to_bytes()
+    }
+
+    /// Non-empty, at most MAX_NAME_LEN, lowercase ASCII alphanumerics and '-'
+    pub fn is_valid_name(name: &str) -> bool {
+        !name.is_empty()
+            && name.len() <= Self::MAX_NAME_LEN
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// OwnerCounter - registrations made by one owner wallet, for the per-owner
+/// registration rate limit (`RegistryConfig::max_registrations_per_day`)
+/// Counts every registration path, including forks and sponsored registrations
+#[account]
+#[derive(InitSpace, Default)]
+pub struct OwnerCounter {
+    pub owner: Pubkey,
+
+    /// Start of the current rate-limit window
+    pub window_start: i64,
+
+    /// Registrations within the current window
+    pub window_registrations: u16,
+
+    /// Registrations counted since the counter was created
+    pub total_registrations: u64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl OwnerCounter {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::OWNER_COUNTER_SEED;
+
+    /// Length of a rate-limit window (1 day)
+    pub const WINDOW_SECS: i64 = 24 * 3600;
+
+    /// Count one registration, initializing a freshly created counter
+    /// A new window starts once the current one has elapsed; `limit` 0 counts without limiting
+    pub fn record(&mut self, owner: Pubkey, limit: u16, bump: u8, now: i64) -> Result<()> {
+        if self.owner == Pubkey::default() {
+            self.owner = owner;
+            self.bump = bump;
+        }
+
+        if now >= self.window_start + Self::WINDOW_SECS {
+            self.window_start = now;
+            self.window_registrations = 0;
+        }
+        require!(
+            limit == 0 || self.window_registrations < limit,
+            RegistryError::RegistrationRateLimited
+        );
+
+        self.window_registrations = self.window_registrations.saturating_add(1);
+        self.total_registrations = self.total_registrations.saturating_add(1);
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// OwnerIndex - the agent IDs registered under one owner wallet
+/// Lets wallets list "my agents" by deriving AgentAccount PDAs from (owner, id)
+/// instead of scanning program accounts. Maintained by `register_agent`,
+/// `fork_agent`, `close_agent` and `archive_agent` when passed; other
+/// registration paths are backfilled with `index_owner_agent`
+#[account]
+#[derive(InitSpace)]
+pub struct OwnerIndex {
+    pub owner: Pubkey,
+
+    /// Indexed agent IDs, in indexing order
+    #[max_len(64)]
+    pub agent_ids: Vec<u64>,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl OwnerIndex {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::OWNER_INDEX_SEED;
+
+    /// Maximum agents tracked per owner
+    pub const MAX_AGENTS: usize = 64;
+
+    /// Add an agent ID, initializing a freshly created index
+    pub fn add(&mut self, owner: Pubkey, agent_id: u64, bump: u8) -> Result<()> {
+        if self.owner == Pubkey::default() {
+            self.owner = owner;
+            self.bump = bump;
+        }
+
+        require!(!self.agent_ids.contains(&agent_id), RegistryError::AgentAlreadyIndexed);
+        require!(self.agent_ids.len() < Self::MAX_AGENTS, RegistryError::OwnerIndexFull);
+        self.agent_ids.push(agent_id);
+        Ok(())
+    }
+
+    /// Drop an agent ID; returns whether it was indexed
+    pub fn remove(&mut self, agent_id: u64) -> bool {
+        let before = self.agent_ids.len();
+        self.agent_ids.retain(|id| *id != agent_id);
+        self.agent_ids.len() != before
+    }
+}
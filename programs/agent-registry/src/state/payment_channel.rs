@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+/// Payment channel - a consumer's lamport deposit for a long-running agent session
+/// The consumer signs cumulative vouchers off-chain (`channel_voucher_message`) as the
+/// session progresses; the agent owner redeems the latest one whenever it likes, paying
+/// one transaction for many calls. The deposit is held in this PDA.
+///
+/// Closing: the owner may close at any time (forfeiting anything unredeemed), while the
+/// consumer must request a close and wait out CHALLENGE_PERIOD, during which the owner
+/// can still redeem its latest voucher. The rest of the deposit returns to the consumer
+#[account]
+#[derive(InitSpace)]
+pub struct PaymentChannel {
+    pub consumer: Pubkey,
+
+    /// The agent serving the session
+    pub agent: Pubkey,
+
+    /// Agent owner at opening (receives redeemed amounts)
+    pub owner: Pubkey,
+
+    /// Channel ID chosen by the consumer (unique per agent and consumer)
+    pub channel_id: u64,
+
+    /// Total lamports deposited
+    pub deposit: u64,
+
+    /// Cumulative amount redeemed by the owner (the highest voucher seen)
+    pub redeemed: u64,
+
+    /// Consumer's close becomes final at this timestamp (0 = open)
+    pub closes_at: i64,
+
+    /// Unix timestamp of opening
+    pub opened_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PaymentChannel {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::PAYMENT_CHANNEL_SEED;
+
+    /// Window after a consumer close request in which the owner can still redeem (24h)
+    pub const CHALLENGE_PERIOD: i64 = 24 * 3600;
+
+    /// Whether a close has been requested
+    pub fn is_closing(&self) -> bool {
+        self.closes_at != 0
+    }
+
+    /// Whether vouchers can still be redeemed
+    pub fn accepts_vouchers(&self, now: i64) -> bool {
+        !self.is_closing() || now < self.closes_at
+    }
+}
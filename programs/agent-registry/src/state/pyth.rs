@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// Pyth Solana Receiver program (owner of PriceUpdateV2 accounts)
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey =
+    pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// Pyth SOL/USD price feed ID
+pub const SOL_USD_FEED_ID: [u8; 32] = [
+    0xef, 0x0d, 0x8b, 0x6f, 0xda, 0x2c, 0xeb, 0xa4, 0x1d, 0xa1, 0x5d, 0x40, 0x95, 0xd1, 0xda, 0x39,
+    0x2a, 0x0d, 0x2f, 0x8e, 0xd0, 0xc6, 0xc7, 0xbc, 0x0f, 0x4c, 0xfa, 0xc8, 0xc2, 0x80, 0xb5, 0x6d,
+];
+
+/// Anchor discriminator of `PriceUpdateV2`: SHA256("account:PriceUpdateV2")[..8]
+pub const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// Micro-USD per USD (config USD values are stored in micro-USD)
+pub const MICRO_USD: u128 = 1_000_000;
+
+// Mirrors of the Pyth receiver account layout (pyth-solana-receiver-sdk)
+
+#[derive(AnchorDeserialize)]
+enum VerificationLevel {
+    Partial {
+        #[allow(dead_code)]
+        num_signatures: u8,
+    },
+    Full,
+}
+
+#[derive(AnchorDeserialize)]
+struct PriceFeedMessage {
+    feed_id: [u8; 32],
+    price: i64,
+    conf: u64,
+    exponent: i32,
+    publish_time: i64,
+    _prev_publish_time: i64,
+    _ema_price: i64,
+    _ema_conf: u64,
+}
+
+#[derive(AnchorDeserialize)]
+struct PriceUpdateV2 {
+    _write_authority: Pubkey,
+    verification_level: VerificationLevel,
+    price_message: PriceFeedMessage,
+    _posted_slot: u64,
+}
+
+/// A validated Pyth price (fully verified, fresh, expected feed)
+pub struct PythPrice {
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+impl PythPrice {
+    /// Load a PriceUpdateV2 account and check owner, discriminator, verification,
+    /// feed ID and staleness
+    pub fn load(info: &AccountInfo, feed_id: &[u8; 32], now: i64, max_age_secs: u64) -> Result<Self> {
+        require_keys_eq!(*info.owner, PYTH_RECEIVER_PROGRAM_ID, RegistryError::InvalidPriceFeed);
+
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() > 8 && data[..8] == PRICE_UPDATE_V2_DISCRIMINATOR,
+            RegistryError::InvalidPriceFeed
+        );
+
+        let update = PriceUpdateV2::deserialize(&mut &data[8..])
+            .map_err(|_| error!(RegistryError::InvalidPriceFeed))?;
+        require!(
+            matches!(update.verification_level, VerificationLevel::Full),
+            RegistryError::InvalidPriceFeed
+        );
+
+        let message = update.price_message;
+        require!(message.feed_id == *feed_id, RegistryError::InvalidPriceFeed);
+        require!(
+            message.publish_time.saturating_add(max_age_secs as i64) >= now,
+            RegistryError::PriceStale
+        );
+        require!(message.price > 0, RegistryError::InvalidPriceFeed);
+
+        Ok(Self {
+            price: message.price,
+            conf: message.conf,
+            exponent: message.exponent,
+            publish_time: message.publish_time,
+        })
+    }
+
+    /// Reject prices whose confidence interval is wider than `max_conf_bps` of the price
+    pub fn check_confidence(&self, max_conf_bps: u16) -> Result<()> {
+        let conf_bps = (self.conf as u128) * 10000 / (self.price as u128);
+        require!(conf_bps <= max_conf_bps as u128, RegistryError::PriceConfidenceTooWide);
+        Ok(())
+    }
+
+    /// Convert a micro-USD amount into lamports at this SOL/USD price
+    /// lamports = usd_micros / 1e6 * 1e9 / (price * 10^exponent)
+    pub fn usd_to_lamports(&self, usd_micros: u64) -> Result<u64> {
+        let mut numerator = (usd_micros as u128) * 1_000_000_000;
+        let mut denominator = (self.price as u128) * MICRO_USD;
+
+        // Apply 10^exponent to the denominator (exponent is negative for SOL/USD)
+        let scale = 10u128
+            .checked_pow(self.exponent.unsigned_abs())
+            .ok_or(RegistryError::InvalidPriceFeed)?;
+        if self.exponent < 0 {
+            numerator = numerator.checked_mul(scale).ok_or(RegistryError::InvalidPriceFeed)?;
+        } else {
+            denominator = denominator.checked_mul(scale).ok_or(RegistryError::InvalidPriceFeed)?;
+        }
+
+        u64::try_from(numerator / denominator).map_err(|_| error!(RegistryError::InvalidPriceFeed))
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// Referrer stats - registrations referred and the fee-share rewards they earned
+/// Rewards accrue here and stay in the treasury until claimed
+#[account]
+#[derive(InitSpace)]
+pub struct ReferrerStats {
+    /// Wallet credited with referrals
+    pub referrer: Pubkey,
+
+    /// Agents registered with this referrer
+    pub referrals: u64,
+
+    /// Total registration fees paid by referred agents (lamports)
+    pub fees_referred: u64,
+
+    /// Total rewards ever accrued (lamports)
+    pub rewards_accrued: u64,
+
+    /// Accrued rewards not yet claimed (lamports)
+    pub rewards_claimable: u64,
+
+    /// Unix timestamp of the most recent referral
+    pub last_referral_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ReferrerStats {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::REFERRER_SEED;
+}
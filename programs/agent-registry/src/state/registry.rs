@@ -17,7 +17,9 @@ pub struct RegistryState {
 }
 
 impl RegistryState {
-    pub const SEED_PREFIX: &'static [u8] = b"registry";
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::REGISTRY_SEED;
+    /// Seed of the treasury PDA (system account collecting registry fees)
+    pub const TREASURY_SEED: &'static [u8] = crate::constants::TREASURY_SEED;
     pub const COLLECTION_NAME: &'static str = "Agent PoI Identity";
     pub const COLLECTION_URI: &'static str = "https://arweave.net/agent-poi-collection";
 }
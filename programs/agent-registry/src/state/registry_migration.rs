@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+/// RegistryMigration - provenance link left by `migrate_agent_to_registry`
+/// Records what the agent carried out of a deprecated registry so the successor
+/// (a namespace here, or another program reading this account) can honour it
+#[account]
+#[derive(InitSpace)]
+pub struct RegistryMigration {
+    /// Agent that migrated
+    pub agent: Pubkey,
+
+    /// Owner at migration time
+    pub owner: Pubkey,
+
+    /// Agent ID carried over
+    pub agent_id: u64,
+
+    /// Deprecated source: its RegistryNamespace, or this program's ID for the deployment
+    pub from: Pubkey,
+
+    /// Successor: a RegistryNamespace, or the successor program ID
+    pub to: Pubkey,
+
+    /// Reputation score carried over
+    pub reputation_score: u32,
+
+    /// Verification carried over
+    pub verified: bool,
+
+    /// How the agent was verified (AgentAccount::VERIFICATION_*)
+    pub verification_level: u8,
+
+    /// Challenges passed at migration
+    pub challenges_passed: u32,
+
+    /// Challenges failed at migration
+    pub challenges_failed: u32,
+
+    /// Unix timestamp of the migration
+    pub migrated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RegistryMigration {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::REGISTRY_MIGRATION_SEED;
+}
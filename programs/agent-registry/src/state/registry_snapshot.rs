@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+/// Registry-wide Merkle snapshot of agent standings
+///
+/// Pattern: off-chain indexer builds a tree over every agent's leaf → admin commits the root
+/// Light clients (and other chains) verify one agent's standing with a single Merkle proof
+/// Leaf: SHA256(0x00 || agent pubkey || reputation_score (u32 LE) || verified (u8))
+/// Node: SHA256(0xff || left || right), odd nodes are paired with themselves
+#[account]
+#[derive(InitSpace)]
+pub struct RegistrySnapshot {
+    /// Sequential snapshot index
+    pub index: u64,
+
+    /// Merkle root over all agent leaves
+    pub merkle_root: [u8; 32],
+
+    /// Number of agents (leaves) covered by the root
+    pub agent_count: u64,
+
+    /// Slot the snapshot reflects
+    pub slot: u64,
+
+    /// Unix timestamp of commit
+    pub committed_at: i64,
+
+    /// Who committed the snapshot
+    pub committed_by: Pubkey,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RegistrySnapshot {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::REGISTRY_SNAPSHOT_SEED;
+
+    /// Domain separator for agent standing leaves
+    pub const LEAF_PREFIX: u8 = crate::merkle::STANDING_LEAF_PREFIX;
+
+    /// Canonical leaf hash for an agent's standing
+    pub fn leaf_hash(agent: &Pubkey, reputation_score: u32, verified: bool) -> [u8; 32] {
+        solana_sha256_hasher::hashv(&[
+            &[Self::LEAF_PREFIX],
+            agent.as_ref(),
+            &reputation_score.to_le_bytes(),
+            &[verified as u8],
+        ])
+        .to_bytes()
+    }
+
+    /// Verify a proof for `leaf` at `index` against this snapshot's root
+    pub fn verify_proof(&self, leaf: [u8; 32], index: u64, proof: &[[u8; 32]]) -> bool {
+        crate::merkle::verify_batch_proof(&self.merkle_root, leaf, index, self.agent_count, proof)
+    }
+}
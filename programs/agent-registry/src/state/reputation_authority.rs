@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// ReputationAuthority - an evaluation service the admin trusts to call `update_reputation`
+/// Each authority may move reputation by at most `epoch_budget` (sum of |delta|) per
+/// Solana epoch, bounding what a single compromised key can do. Kept (deactivated)
+/// on removal for accountability
+#[account]
+#[derive(InitSpace)]
+pub struct ReputationAuthority {
+    /// The authority's signing key
+    pub authority: Pubkey,
+
+    /// Whether the authority may currently update reputation
+    pub active: bool,
+
+    /// Most total |delta| the authority may apply per epoch
+    pub epoch_budget: u32,
+
+    /// Epoch `spent` was accumulated in
+    pub epoch: u64,
+
+    /// Total |delta| applied during `epoch`
+    pub spent: u32,
+
+    /// Reputation updates applied since registration
+    pub updates: u64,
+
+    /// Total |delta| applied since registration
+    pub total_delta: u64,
+
+    /// Unix timestamp when the authority was registered
+    pub registered_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ReputationAuthority {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::REPUTATION_AUTHORITY_SEED;
+
+    /// Budget left in `epoch`
+    pub fn remaining(&self, epoch: u64) -> u32 {
+        let spent = if epoch == self.epoch { self.spent } else { 0 };
+        self.epoch_budget.saturating_sub(spent)
+    }
+
+    /// Charge `delta` against the budget for `epoch`, starting a fresh budget when the
+    /// epoch has rolled over
+    pub fn charge(&mut self, delta: i32, epoch: u64) -> Result<()> {
+        require!(self.active, RegistryError::NotReputationAuthority);
+        let cost = delta.unsigned_abs();
+        require!(cost <= self.remaining(epoch), RegistryError::ReputationBudgetExceeded);
+
+        if epoch != self.epoch {
+            self.epoch = epoch;
+            self.spent = 0;
+        }
+        self.spent += cost;
+        self.updates = self.updates.saturating_add(1);
+        self.total_delta = self.total_delta.saturating_add(cost as u64);
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+
+/// What caused a reputation change
+/// Derived from the instruction and its signer, never chosen by the caller, so
+/// consumers can weigh earned reputation above manually assigned reputation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum ReputationSource {
+    /// `update_reputation` (or its fast path) by the admin
+    AdminManual,
+    /// Challenge verdicts and expiries, including the challenge program's CPI
+    ChallengeProgram,
+    /// Job settlement and refunds
+    JobSettlement,
+    /// `update_reputation` by a whitelisted ReputationAuthority (evaluation oracle)
+    Oracle,
+    /// Job dispute resolution
+    DisputeResolution,
+}
+
+impl ReputationSource {
+    /// Number of sources (length of `AgentAccount::reputation_by_source`)
+    pub const COUNT: usize = 5;
+
+    /// Index into per-source arrays
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// One reputation change (24 bytes, no padding holes for zero-copy)
+#[zero_copy]
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct ReputationChange {
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+
+    /// Applied delta (after clamping and suspension gating)
+    pub delta: i32,
+
+    /// Reputation score after the change
+    pub new_score: u32,
+
+    /// What caused the change (`ReputationSource as u8`)
+    pub source: u8,
+
+    pub _padding: [u8; 7],
+}
+
+/// ReputationHistory - ring buffer of an agent's most recent reputation changes
+/// Zero-copy so appending doesn't round-trip the whole buffer through Borsh
+/// Instructions that change reputation append to it when it is passed in
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct ReputationHistory {
+    /// The agent this history belongs to
+    pub agent: Pubkey,
+
+    /// Changes, oldest overwritten first (`head` is the next slot to write)
+    pub entries: [ReputationChange; ReputationHistory::CAPACITY],
+
+    /// Next slot to write
+    pub head: u32,
+
+    /// Number of valid entries (saturates at CAPACITY)
+    pub count: u32,
+
+    /// Total changes ever recorded
+    pub total_recorded: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    pub _padding: [u8; 7],
+}
+
+impl ReputationHistory {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::REPUTATION_HISTORY_SEED;
+
+    /// Number of changes retained
+    pub const CAPACITY: usize = 32;
+
+    /// Account size (with discriminator)
+    pub const SPACE: usize = 8 + std::mem::size_of::<ReputationHistory>();
+
+    /// Append a change, overwriting the oldest entry once full
+    pub fn push(&mut self, change: ReputationChange) {
+        self.entries[self.head as usize] = change;
+        self.head = (self.head + 1) % Self::CAPACITY as u32;
+        self.count = (self.count + 1).min(Self::CAPACITY as u32);
+        self.total_recorded = self.total_recorded.saturating_add(1);
+    }
+
+    /// Valid entries, newest first
+    pub fn recent(&self) -> impl Iterator<Item = &ReputationChange> {
+        (1..=self.count as usize).map(move |back| {
+            &self.entries[(self.head as usize + Self::CAPACITY - back) % Self::CAPACITY]
+        })
+    }
+
+    /// Record the change from `old_score` to `new_score` if a history account was passed
+    /// No-op when the score didn't move
+    pub fn record(
+        history: Option<&AccountLoader<ReputationHistory>>,
+        old_score: u32,
+        new_score: u32,
+        source: ReputationSource,
+        now: i64,
+    ) -> Result<()> {
+        let Some(history) = history else {
+            return Ok(());
+        };
+        if old_score == new_score {
+            return Ok(());
+        }
+
+        history.load_mut()?.push(ReputationChange {
+            timestamp: now,
+            delta: new_score as i32 - old_score as i32,
+            new_score,
+            source: source as u8,
+            _padding: [0; 7],
+        });
+        Ok(())
+    }
+}
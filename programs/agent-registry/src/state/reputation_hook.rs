@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+
+use super::AgentAccount;
+
+/// One registered callback program and its delivery state
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq, Eq)]
+pub struct ReputationHook {
+    /// Callback program
+    pub program: Pubkey,
+
+    /// Latest change (`ReputationHooks::seq`) delivered to the hook
+    pub delivered_seq: u64,
+
+    /// Changes not delivered in the updating transaction (program not passed, compute
+    /// budget too low or hook over budget); `dispatch_reputation_hook` catches up
+    pub missed: u32,
+
+    /// The last delivery used more than MAX_COMPUTE_PER_HOOK; the hook is skipped in
+    /// updates until a `dispatch_reputation_hook` delivery stays within budget
+    pub over_budget: bool,
+}
+
+/// ReputationHooks - programs notified via CPI when an agent's reputation changes
+/// Hooks are registered by the integrating program (signing with its
+/// `[HOOK_AUTHORITY_SEED]` PDA), not the agent owner, so an owner can't drop a hook
+/// ahead of a reputation loss. Once the account exists every reputation update records
+/// the change here and calls each hook whose program is passed as a remaining account
+#[account]
+#[derive(InitSpace)]
+pub struct ReputationHooks {
+    /// Agent whose reputation changes are broadcast
+    pub agent: Pubkey,
+
+    /// Callback programs, invoked in registration order
+    #[max_len(4)]
+    pub hooks: Vec<ReputationHook>,
+
+    /// Reputation changes recorded since the account was created
+    pub seq: u64,
+
+    /// Score before the latest change
+    pub old_score: u32,
+
+    /// Score after the latest change
+    pub new_score: u32,
+
+    /// Unix timestamp of the latest change
+    pub changed_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+/// Arguments passed to a hook's `on_reputation_change` instruction
+/// Hooks should treat the (read-only) agent account as the source of truth;
+/// anyone can call a hook directly with arbitrary arguments. A `seq` gap means
+/// intermediate changes were superseded before they were delivered
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReputationHookArgs {
+    pub agent: Pubkey,
+    pub old_score: u32,
+    pub new_score: u32,
+    pub seq: u64,
+}
+
+impl ReputationHooks {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::REPUTATION_HOOKS_SEED;
+
+    /// Maximum hooks per agent
+    pub const MAX_HOOKS: usize = 4;
+
+    /// Compute units a hook may use per delivery; hooks that use more are flagged
+    /// `over_budget` and left to `dispatch_reputation_hook`
+    pub const MAX_COMPUTE_PER_HOOK: u64 = 30_000;
+
+    /// Compute units that must remain before a hook is invoked (the hook's budget plus
+    /// headroom for the rest of the update); below it the hook is recorded as missed
+    pub const MIN_COMPUTE_PER_HOOK: u64 = 40_000;
+
+    /// Anchor instruction name hooks must implement
+    pub const CALLBACK: &'static str = "on_reputation_change";
+
+    /// Index of `program` among the registered hooks
+    pub fn position(&self, program: &Pubkey) -> Option<usize> {
+        self.hooks.iter().position(|hook| hook.program == *program)
+    }
+
+    /// Record a reputation change on the agent's hooks account, when it exists, and
+    /// deliver it to every hook whose program is among `hook_programs`. `agent` must
+    /// already hold the new score on chain (flushed), since hooks read it directly
+    ///
+    /// A failing hook aborts the transaction it runs in, so the updater can always leave
+    /// its program out: the change is then recorded as missed and delivered later by
+    /// `dispatch_reputation_hook`. Returns the number of hooks invoked
+    pub fn notify<'info>(
+        hooks: &AccountInfo<'info>,
+        agent: &AccountInfo<'info>,
+        hook_programs: &[AccountInfo<'info>],
+        old_score: u32,
+        new_score: u32,
+        now: i64,
+    ) -> Result<u8> {
+        if old_score == new_score || hooks.data_is_empty() {
+            return Ok(0);
+        }
+
+        require_keys_eq!(*hooks.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        let mut state = Self::try_deserialize(&mut &hooks.try_borrow_data()?[..])?;
+        state.seq = state.seq.saturating_add(1);
+        state.old_score = old_score;
+        state.new_score = new_score;
+        state.changed_at = now;
+
+        let mut invoked = 0u8;
+        for index in 0..state.hooks.len() {
+            let hook = state.hooks[index];
+            let program = hook_programs
+                .iter()
+                .find(|info| *info.key == hook.program && info.executable);
+            match program {
+                Some(program)
+                    if !hook.over_budget && remaining_compute_units() >= Self::MIN_COMPUTE_PER_HOOK =>
+                {
+                    state.deliver(index, agent, program)?;
+                    invoked += 1;
+                }
+                _ => {
+                    let hook = &mut state.hooks[index];
+                    hook.missed = hook.missed.saturating_add(1);
+                    msg!("Reputation hook pending: {}", hook.program);
+                }
+            }
+        }
+
+        state.try_serialize(&mut &mut hooks.try_borrow_mut_data()?[..])?;
+        msg!("Reputation hooks notified: {}/{}", invoked, state.hooks.len());
+        Ok(invoked)
+    }
+
+    /// `notify` for an agent held as an Account: flushes the new score first
+    pub fn notify_agent<'info>(
+        hooks: &AccountInfo<'info>,
+        agent: &Account<'info, AgentAccount>,
+        hook_programs: &[AccountInfo<'info>],
+        old_score: u32,
+        now: i64,
+    ) -> Result<u8> {
+        if old_score == agent.reputation_score {
+            return Ok(0);
+        }
+        agent.exit(&crate::ID)?;
+        Self::notify(
+            hooks,
+            &agent.to_account_info(),
+            hook_programs,
+            old_score,
+            agent.reputation_score,
+            now,
+        )
+    }
+
+    /// CPI the latest change into the hook at `index`, marking it delivered and
+    /// flagging it `over_budget` when the call used more than MAX_COMPUTE_PER_HOOK
+    pub fn deliver<'info>(
+        &mut self,
+        index: usize,
+        agent: &AccountInfo<'info>,
+        program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let mut data = solana_sha256_hasher::hash(format!("global:{}", Self::CALLBACK).as_bytes())
+            .to_bytes()[..8]
+            .to_vec();
+        ReputationHookArgs {
+            agent: self.agent,
+            old_score: self.old_score,
+            new_score: self.new_score,
+            seq: self.seq,
+        }
+        .serialize(&mut data)?;
+
+        let before = remaining_compute_units();
+        invoke(
+            &Instruction {
+                program_id: *program.key,
+                accounts: vec![AccountMeta::new_readonly(agent.key(), false)],
+                data,
+            },
+            &[agent.clone(), program.clone()],
+        )?;
+        let used = before.saturating_sub(remaining_compute_units());
+
+        let hook = &mut self.hooks[index];
+        hook.delivered_seq = self.seq;
+        hook.over_budget = used > Self::MAX_COMPUTE_PER_HOOK;
+        if hook.over_budget {
+            msg!("Reputation hook over budget: {} used {} CU", hook.program, used);
+        }
+        Ok(())
+    }
+}
+
+fn remaining_compute_units() -> u64 {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        solana_define_syscall::definitions::sol_remaining_compute_units()
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    u64::MAX
+}
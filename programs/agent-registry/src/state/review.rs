@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+/// Review - a consumer's rating of a closed job, with one optional owner response
+#[account]
+#[derive(InitSpace)]
+pub struct Review {
+    /// The job being reviewed (one review per job)
+    pub job: Pubkey,
+
+    /// The agent that performed the job
+    pub agent: Pubkey,
+
+    /// Consumer who wrote the review
+    pub reviewer: Pubkey,
+
+    /// Rating from 1 to 5
+    pub rating: u8,
+
+    /// SHA256 of the review text (stored off-chain)
+    pub comment_hash: [u8; 32],
+
+    /// Unix timestamp when reviewed
+    pub created_at: i64,
+
+    /// SHA256 of the owner's response text (zero = no response)
+    pub response_hash: [u8; 32],
+
+    /// Unix timestamp of the owner's response (0 = no response)
+    pub responded_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Review {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::REVIEW_SEED;
+
+    pub const MIN_RATING: u8 = 1;
+    pub const MAX_RATING: u8 = 5;
+
+    /// Whether the owner has already responded
+    pub fn has_response(&self) -> bool {
+        self.responded_at != 0
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+/// How a listed service is billed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum PricingModel {
+    /// `price_lamports` per call
+    PerCall,
+    /// `price_lamports` per `period_secs` of access
+    PerPeriod { period_secs: i64 },
+}
+
+/// Service listing - a priced service an agent offers (owner managed)
+/// Marketplaces read these straight from chain state
+#[account]
+#[derive(InitSpace)]
+pub struct ServiceListing {
+    /// The agent offering the service
+    pub agent: Pubkey,
+
+    /// Listing ID chosen by the owner (unique per agent)
+    pub listing_id: u64,
+
+    /// Capabilities the service exercises (subset of the agent's capability flags)
+    pub capability_flags: u64,
+
+    /// Billing model
+    pub pricing: PricingModel,
+
+    /// Price in lamports per call or per period
+    pub price_lamports: u64,
+
+    /// SHA256 commitment to the service endpoint (URL + API spec), revealed off-chain
+    pub endpoint_hash: [u8; 32],
+
+    /// Key that meters prepaid Allowances (default pubkey = the agent owner)
+    pub meter: Pubkey,
+
+    /// Whether the service is currently offered
+    pub active: bool,
+
+    /// Unix timestamp when listed
+    pub created_at: i64,
+
+    /// Unix timestamp of the last change
+    pub updated_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ServiceListing {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::SERVICE_LISTING_SEED;
+
+    /// Shortest billable period (1 hour)
+    pub const MIN_PERIOD_SECS: i64 = 3600;
+
+    /// Pricing model is well formed
+    pub fn is_valid_pricing(pricing: &PricingModel) -> bool {
+        match pricing {
+            PricingModel::PerCall => true,
+            PricingModel::PerPeriod { period_secs } => *period_secs >= Self::MIN_PERIOD_SECS,
+        }
+    }
+
+    /// Whether `key` may meter this listing's allowances
+    pub fn is_meter(&self, key: &Pubkey, owner: &Pubkey) -> bool {
+        if self.meter == Pubkey::default() {
+            key == owner
+        } else {
+            *key == self.meter
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// Domain separator for owner-signed `update_agent_with_signature` payloads
+pub const UPDATE_AGENT_DOMAIN: &[u8] = b"agent-registry:update_agent:v1";
+
+/// Domain separator for consumer-signed payment channel vouchers
+pub const CHANNEL_VOUCHER_DOMAIN: &[u8] = b"agent-registry:channel_voucher:v1";
+
+/// Size of the ed25519 program's per-signature offsets record
+const ED25519_OFFSETS_LEN: usize = 14;
+
+/// Offsets start after `num_signatures: u8` and one padding byte
+const ED25519_OFFSETS_START: usize = 2;
+
+/// `instruction_index` value meaning "data lives in the ed25519 instruction itself"
+const ED25519_CURRENT_IX: u16 = u16::MAX;
+
+/// Message an owner signs to authorize a relayed metadata update
+/// domain || program id || agent || nonce (LE) || borsh(name) || borsh(capabilities)
+pub fn update_agent_message(
+    agent: &Pubkey,
+    nonce: u64,
+    name: &Option<String>,
+    capabilities: &Option<String>,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(128);
+    message.extend_from_slice(UPDATE_AGENT_DOMAIN);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.extend_from_slice(agent.as_ref());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    // Writing into a Vec cannot fail
+    name.serialize(&mut message).unwrap();
+    capabilities.serialize(&mut message).unwrap();
+    message
+}
+
+/// Message a consumer signs to owe a channel's agent `cumulative` lamports in total
+/// domain || program id || channel || opened_at (LE) || cumulative (LE)
+/// `opened_at` keeps vouchers for a closed channel from redeeming against a later
+/// channel reopened at the same address
+pub fn channel_voucher_message(channel: &Pubkey, opened_at: i64, cumulative: u64) -> Vec<u8> {
+    let mut message = Vec::with_capacity(CHANNEL_VOUCHER_DOMAIN.len() + 80);
+    message.extend_from_slice(CHANNEL_VOUCHER_DOMAIN);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.extend_from_slice(channel.as_ref());
+    message.extend_from_slice(&opened_at.to_le_bytes());
+    message.extend_from_slice(&cumulative.to_le_bytes());
+    message
+}
+
+/// Require that the instruction immediately before this one is an ed25519 program
+/// verification of exactly one `signer` signature over `message`
+/// The runtime has already checked the signature; we only check what was signed and by whom
+/// Returns the verified signature
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<[u8; 64]> {
+    let ix = solana_instructions_sysvar::get_instruction_relative(-1, instructions_sysvar)
+        .map_err(|_| RegistryError::InvalidSignature)?;
+    require_keys_eq!(
+        ix.program_id,
+        solana_sdk_ids::ed25519_program::ID,
+        RegistryError::InvalidSignature
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= ED25519_OFFSETS_START + ED25519_OFFSETS_LEN && data[0] == 1,
+        RegistryError::InvalidSignature
+    );
+
+    let offsets = &data[ED25519_OFFSETS_START..ED25519_OFFSETS_START + ED25519_OFFSETS_LEN];
+    let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+    let signature_offset = read_u16(0) as usize;
+    let signature_ix = read_u16(2);
+    let pubkey_offset = read_u16(4) as usize;
+    let pubkey_ix = read_u16(6);
+    let message_offset = read_u16(8) as usize;
+    let message_len = read_u16(10) as usize;
+    let message_ix = read_u16(12);
+
+    // All parts must come from the ed25519 instruction itself, not some other instruction
+    require!(
+        signature_ix == ED25519_CURRENT_IX
+            && pubkey_ix == ED25519_CURRENT_IX
+            && message_ix == ED25519_CURRENT_IX,
+        RegistryError::InvalidSignature
+    );
+
+    let signature = data
+        .get(signature_offset..signature_offset + 64)
+        .ok_or(RegistryError::InvalidSignature)?;
+    let signed_pubkey = data
+        .get(pubkey_offset..pubkey_offset + 32)
+        .ok_or(RegistryError::InvalidSignature)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_len)
+        .ok_or(RegistryError::InvalidSignature)?;
+
+    require!(
+        signed_pubkey == signer.as_ref() && signed_message == message,
+        RegistryError::InvalidSignature
+    );
+
+    let mut verified = [0u8; 64];
+    verified.copy_from_slice(signature);
+    Ok(verified)
+}
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::merkle::verify_batch_proof;
+use super::RegistrySnapshot;
+
+/// Merkle whitelist of registrants a sponsor pays for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RegistrantWhitelist {
+    /// Batch root over `SponsorPool::registrant_leaf` leaves (zero = no whitelist)
+    pub root: [u8; 32],
+
+    /// Number of leaves under `root`
+    pub count: u32,
+}
+
+/// Sponsorship pool - a sponsor prepays registrations for whitelisted registrants
+/// Lamports live in a separate system-owned vault PDA so it can fund account creation
+#[account]
+#[derive(InitSpace)]
+pub struct SponsorPool {
+    /// Wallet that created and funds the pool
+    pub sponsor: Pubkey,
+
+    /// Merkle root over whitelisted registrant pubkeys (zero = explicit allowances only)
+    pub registrant_root: [u8; 32],
+
+    /// Number of registrants (leaves) under `registrant_root`
+    pub registrant_count: u32,
+
+    /// Registrations granted to each registrant proven against `registrant_root`
+    pub registrations_per_registrant: u16,
+
+    /// Agents registered through this pool
+    pub registrations: u64,
+
+    /// Lamports drawn from the vault for rent and fees
+    pub lamports_spent: u64,
+
+    /// Unix timestamp when the pool was created
+    pub created_at: i64,
+
+    /// Bump seed of the vault PDA
+    pub vault_bump: u8,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SponsorPool {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::SPONSOR_POOL_SEED;
+
+    /// Seed of the system-owned vault holding the pool's lamports
+    pub const VAULT_SEED: &'static [u8] = crate::constants::SPONSOR_VAULT_SEED;
+
+    /// Leaf hash for a whitelisted registrant (same domain byte as registry snapshots)
+    pub fn registrant_leaf(registrant: &Pubkey) -> [u8; 32] {
+        solana_sha256_hasher::hashv(&[&[RegistrySnapshot::LEAF_PREFIX], registrant.as_ref()]).to_bytes()
+    }
+
+    /// Whether `registrant` is in the Merkle whitelist at `index`
+    pub fn is_whitelisted(&self, registrant: &Pubkey, index: u64, proof: &[[u8; 32]]) -> bool {
+        self.registrant_root != [0u8; 32]
+            && verify_batch_proof(
+                &self.registrant_root,
+                Self::registrant_leaf(registrant),
+                index,
+                self.registrant_count as u64,
+                proof,
+            )
+    }
+
+    /// Replace the whitelist
+    pub fn set_whitelist(&mut self, whitelist: RegistrantWhitelist) {
+        self.registrant_root = whitelist.root;
+        self.registrant_count = whitelist.count;
+    }
+
+    /// Create a program-owned account at `target` funded by the vault
+    /// Both the vault and the target PDA sign, so the registrant pays nothing
+    pub fn create_funded_account<'info>(
+        pool: &Account<'info, SponsorPool>,
+        vault: &AccountInfo<'info>,
+        target: &AccountInfo<'info>,
+        target_seeds: &[&[u8]],
+        space: usize,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<u64> {
+        let lamports = Rent::get()?.minimum_balance(space);
+        let pool_key = pool.key();
+        let vault_bump = [pool.vault_bump];
+        let vault_seeds: &[&[u8]] = &[Self::VAULT_SEED, pool_key.as_ref(), &vault_bump];
+
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: vault.clone(),
+                    to: target.clone(),
+                },
+                &[vault_seeds, target_seeds],
+            ),
+            lamports,
+            space as u64,
+            &crate::ID,
+        )?;
+
+        Ok(lamports)
+    }
+
+    /// Move lamports out of the vault (signed by the vault PDA)
+    pub fn pay_from_vault<'info>(
+        pool: &Account<'info, SponsorPool>,
+        vault: &AccountInfo<'info>,
+        recipient: &AccountInfo<'info>,
+        amount: u64,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let pool_key = pool.key();
+        let vault_bump = [pool.vault_bump];
+        let vault_seeds: &[&[u8]] = &[Self::VAULT_SEED, pool_key.as_ref(), &vault_bump];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: vault.clone(),
+                    to: recipient.clone(),
+                },
+                &[vault_seeds],
+            ),
+            amount,
+        )
+    }
+}
+
+/// Sponsor allowance - how many pool-funded registrations a registrant has left
+/// Created by the sponsor for explicit whitelisting, or on first use from a Merkle proof
+#[account]
+#[derive(InitSpace)]
+pub struct SponsorAllowance {
+    /// The pool this allowance draws from
+    pub pool: Pubkey,
+
+    /// Registrant allowed to register agents through the pool
+    pub registrant: Pubkey,
+
+    /// Sponsored registrations remaining
+    pub remaining: u16,
+
+    /// Sponsored registrations used
+    pub used: u16,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SponsorAllowance {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::SPONSOR_ALLOWANCE_SEED;
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// TagIndex - every indexed agent carrying one discovery tag
+/// Lets marketplaces list a category ("trading", "code-gen") with a single account
+/// fetch; membership is checked against the agent's `tags` on every write
+#[account]
+#[derive(InitSpace)]
+pub struct TagIndex {
+    /// The tag (PDA seed; at most 16 bytes)
+    #[max_len(16)]
+    pub tag: String,
+
+    /// Agents carrying the tag, in indexing order
+    #[max_len(32)]
+    pub agents: Vec<Pubkey>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl TagIndex {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::TAG_INDEX_SEED;
+
+    /// Maximum agents tracked per tag
+    pub const MAX_AGENTS: usize = 32;
+
+    /// Add an agent, failing if it is already indexed or the index is full
+    pub fn add(&mut self, agent: Pubkey) -> Result<()> {
+        require!(!self.agents.contains(&agent), RegistryError::AgentAlreadyIndexed);
+        require!(self.agents.len() < Self::MAX_AGENTS, RegistryError::TagIndexFull);
+        self.agents.push(agent);
+        Ok(())
+    }
+
+    /// Drop an agent; returns whether it was indexed
+    pub fn remove(&mut self, agent: &Pubkey) -> bool {
+        let before = self.agents.len();
+        self.agents.retain(|member| member != agent);
+        self.agents.len() != before
+    }
+}
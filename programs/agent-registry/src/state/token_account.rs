@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// SPL Token program (owner of classic token accounts)
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Token-2022 program (shares the base token account layout)
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Associated token account program
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+// Offsets into the SPL token account layout (spl-token state::Account)
+const MINT_OFFSET: usize = 0;
+const OWNER_OFFSET: usize = 32;
+const AMOUNT_OFFSET: usize = 64;
+const STATE_OFFSET: usize = 108;
+const BASE_LEN: usize = 165;
+
+/// A validated SPL token account (either token program, initialized)
+pub struct TokenHolding {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+impl TokenHolding {
+    /// Load a token account owned by either token program and check it is initialized
+    pub fn load(info: &AccountInfo) -> Result<Self> {
+        require!(
+            *info.owner == TOKEN_PROGRAM_ID || *info.owner == TOKEN_2022_PROGRAM_ID,
+            RegistryError::InvalidTokenAccount
+        );
+
+        let data = info.try_borrow_data()?;
+        // Token-2022 pads mints to BASE_LEN; their zero state byte rejects them here
+        require!(
+            data.len() >= BASE_LEN && data[STATE_OFFSET] != 0,
+            RegistryError::InvalidTokenAccount
+        );
+
+        let read_key = |offset: usize| {
+            let mut key = [0u8; 32];
+            key.copy_from_slice(&data[offset..offset + 32]);
+            Pubkey::new_from_array(key)
+        };
+        let mut amount = [0u8; 8];
+        amount.copy_from_slice(&data[AMOUNT_OFFSET..AMOUNT_OFFSET + 8]);
+
+        Ok(Self {
+            mint: read_key(MINT_OFFSET),
+            owner: read_key(OWNER_OFFSET),
+            amount: u64::from_le_bytes(amount),
+        })
+    }
+}
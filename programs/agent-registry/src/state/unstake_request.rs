@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::errors::RegistryError;
+
+/// UnstakeRequest - a pending stake withdrawal waiting out the unstake cooldown
+/// The lamports stay in the agent PDA (no longer counted in `staked_lamports`) until
+/// `withdraw_unstake`, so they can still be slashed while the cooldown runs
+#[account]
+#[derive(InitSpace)]
+pub struct UnstakeRequest {
+    /// Agent the stake is withdrawn from
+    pub agent: Pubkey,
+
+    /// Lamports pending withdrawal
+    pub amount: u64,
+
+    /// Unix timestamp of the request
+    pub requested_at: i64,
+
+    /// Earliest time `withdraw_unstake` can pay out
+    pub available_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl UnstakeRequest {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::UNSTAKE_REQUEST_SEED;
+
+    /// Lamports pending in the agent's UnstakeRequest PDA `info`, 0 when there is none
+    pub fn pending(info: &AccountInfo) -> Result<u64> {
+        if info.data_is_empty() {
+            return Ok(0);
+        }
+
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        Ok(Self::try_deserialize(&mut &info.try_borrow_data()?[..])?.amount)
+    }
+
+    /// Create `agent`'s request PDA `info`, held as a raw account, with `payer` funding
+    /// the rent (lamports already at the address count towards it). Returns the bump
+    pub fn create<'info>(
+        info: &AccountInfo<'info>,
+        agent: &Pubkey,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<u8> {
+        require!(info.data_is_empty(), RegistryError::UnstakePending);
+        let (address, bump) =
+            Pubkey::find_program_address(&[Self::SEED_PREFIX, agent.as_ref()], &crate::ID);
+        require_keys_eq!(address, info.key(), ErrorCode::ConstraintSeeds);
+
+        let space = 8 + Self::INIT_SPACE;
+        let shortfall = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+
+        let bump_seed = [bump];
+        let seeds: &[&[&[u8]]] = &[&[Self::SEED_PREFIX, agent.as_ref(), &bump_seed]];
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Allocate {
+                    account_to_allocate: info.clone(),
+                },
+                seeds,
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Assign {
+                    account_to_assign: info.clone(),
+                },
+                seeds,
+            ),
+            &crate::ID,
+        )?;
+        Ok(bump)
+    }
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+/// Outcome of a verification request
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub enum VerificationStatus {
+    /// Waiting for the admin
+    #[default]
+    Pending,
+    /// Admin verified the agent
+    Approved,
+    /// Admin declined; the owner may request again
+    Rejected,
+    /// Admin withdrew an approved verification; the owner may request again
+    Revoked,
+}
+
+/// VerificationRequest - an owner's paid request for admin verification (one per agent)
+/// A rejected request can be re-submitted, which pays the fee again and bumps `attempts`
+/// The request account also escrows the owner's verification bond while it is held
+#[account]
+#[derive(InitSpace)]
+pub struct VerificationRequest {
+    /// The agent to be verified
+    pub agent: Pubkey,
+
+    /// Owner who submitted (and paid for) the latest request
+    pub requester: Pubkey,
+
+    /// Current state of the request
+    pub status: VerificationStatus,
+
+    /// Fee paid to the treasury for the latest request (lamports)
+    pub fee_paid: u64,
+
+    /// Number of times verification was requested
+    pub attempts: u16,
+
+    /// Unix timestamp of the latest request
+    pub requested_at: i64,
+
+    /// Unix timestamp the admin processed the latest request (0 = pending)
+    pub processed_at: i64,
+
+    /// Admin who processed the latest request
+    pub processed_by: Pubkey,
+
+    /// Admin's reason for the decision (empty when approved without comment)
+    #[max_len(200)]
+    pub reason: String,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// VerificationQueue ticket of the pending request (0 = not queued)
+    pub queue_ticket: u64,
+
+    /// Bond held in this account (lamports, 0 = none held)
+    pub bond_lamports: u64,
+
+    /// Unix timestamp from which an approved request's bond can be released
+    pub bond_releases_at: i64,
+}
+
+impl VerificationRequest {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::VERIFICATION_REQUEST_SEED;
+
+    /// Maximum reason length in bytes
+    pub const MAX_REASON_LEN: usize = 200;
+
+    /// Whether the owner may (re-)submit: a fresh account reads as Pending with zero attempts,
+    /// and only a rejection or revocation reopens the queue
+    pub fn can_request(&self) -> bool {
+        self.attempts == 0
+            || matches!(self.status, VerificationStatus::Rejected | VerificationStatus::Revoked)
+    }
+
+    /// Whether the held bond can go back to the requester: approved and through the clean period
+    pub fn bond_releasable(&self, now: i64) -> bool {
+        self.bond_lamports > 0
+            && self.status == VerificationStatus::Approved
+            && now >= self.bond_releases_at
+    }
+
+    /// Move the held bond out of this account to `to`, returning the amount moved
+    pub fn pay_out_bond(request: &mut Account<VerificationRequest>, to: &AccountInfo) -> Result<u64> {
+        let bond = request.bond_lamports;
+        if bond > 0 {
+            // The request PDA is program-owned, so lamports can be moved directly
+            **request.to_account_info().try_borrow_mut_lamports()? -= bond;
+            **to.try_borrow_mut_lamports()? += bond;
+            request.bond_lamports = 0;
+            request.bond_releases_at = 0;
+        }
+        Ok(bond)
+    }
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// One queued verification request (56 bytes, no padding holes for zero-copy)
+#[zero_copy]
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct QueueEntry {
+    /// Agent whose VerificationRequest is waiting
+    pub agent: Pubkey,
+
+    /// Stake plus fee paid when the request was queued (lamports)
+    pub priority: u64,
+
+    /// Arrival order (1-based, never reused)
+    pub ticket: u64,
+
+    /// Unix timestamp the request was queued
+    pub enqueued_at: i64,
+}
+
+impl QueueEntry {
+    /// Whether the request has waited long enough to bypass priority ordering
+    pub fn is_overdue(&self, now: i64) -> bool {
+        now.saturating_sub(self.enqueued_at) >= VerificationQueue::STARVATION_SECS
+    }
+
+    /// Queue order: overdue requests first (oldest first), then higher priority, then
+    /// earlier ticket. Tickets are unique, so this is a strict total order
+    pub fn outranks(&self, other: &QueueEntry, now: i64) -> bool {
+        let (overdue, other_overdue) = (self.is_overdue(now), other.is_overdue(now));
+        if overdue != other_overdue {
+            return overdue;
+        }
+        if !overdue && self.priority != other.priority {
+            return self.priority > other.priority;
+        }
+        self.ticket < other.ticket
+    }
+}
+
+/// VerificationQueue - pending verification requests in processing order (singleton)
+/// Admins must process the head (see `QueueEntry::outranks`), so stake and fees buy
+/// precedence while STARVATION_SECS bounds how long any request can be passed over
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct VerificationQueue {
+    /// Queued requests, unordered (`len` valid entries from the start)
+    pub entries: [QueueEntry; VerificationQueue::CAPACITY],
+
+    /// Ticket the next request receives
+    pub next_ticket: u64,
+
+    /// Requests taken off the queue (approved, rejected or pruned)
+    pub total_processed: u64,
+
+    /// Number of valid entries
+    pub len: u32,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    pub _padding: [u8; 3],
+}
+
+impl VerificationQueue {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::VERIFICATION_QUEUE_SEED;
+
+    /// Most requests waiting at once
+    pub const CAPACITY: usize = 64;
+
+    /// Account size (with discriminator)
+    pub const SPACE: usize = 8 + std::mem::size_of::<VerificationQueue>();
+
+    /// Wait after which a request outranks every fresher one (7 days)
+    pub const STARVATION_SECS: i64 = 7 * 24 * 3600;
+
+    /// Queued entries
+    pub fn queued(&self) -> &[QueueEntry] {
+        &self.entries[..self.len as usize]
+    }
+
+    /// Queue a request and return its ticket
+    pub fn push(&mut self, agent: Pubkey, priority: u64, now: i64) -> Result<u64> {
+        require!((self.len as usize) < Self::CAPACITY, RegistryError::VerificationQueueFull);
+
+        let ticket = self.next_ticket.max(1);
+        self.next_ticket = ticket + 1;
+        self.entries[self.len as usize] = QueueEntry {
+            agent,
+            priority,
+            ticket,
+            enqueued_at: now,
+        };
+        self.len += 1;
+        Ok(ticket)
+    }
+
+    /// The request that must be processed next
+    pub fn head(&self, now: i64) -> Option<&QueueEntry> {
+        self.head_index(now).map(|index| &self.entries[index])
+    }
+
+    fn head_index(&self, now: i64) -> Option<usize> {
+        (0..self.len as usize).reduce(|best, index| {
+            if self.entries[index].outranks(&self.entries[best], now) {
+                index
+            } else {
+                best
+            }
+        })
+    }
+
+    /// Take the head off the queue; fails unless it holds `ticket`
+    pub fn pop_head(&mut self, ticket: u64, now: i64) -> Result<QueueEntry> {
+        let index = self
+            .head_index(now)
+            .filter(|&index| self.entries[index].ticket == ticket)
+            .ok_or(RegistryError::NotQueueHead)?;
+
+        Ok(self.remove(index))
+    }
+
+    /// Take `agent`'s request off the queue wherever it stands
+    pub fn remove_agent(&mut self, agent: &Pubkey) -> Result<QueueEntry> {
+        let index = self
+            .queued()
+            .iter()
+            .position(|entry| entry.agent == *agent)
+            .ok_or(RegistryError::NotQueued)?;
+        Ok(self.remove(index))
+    }
+
+    fn remove(&mut self, index: usize) -> QueueEntry {
+        let entry = self.entries[index];
+        self.len -= 1;
+        self.entries[index] = self.entries[self.len as usize];
+        self.entries[self.len as usize] = QueueEntry::default();
+        self.total_processed = self.total_processed.saturating_add(1);
+        entry
+    }
+}
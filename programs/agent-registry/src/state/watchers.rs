@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// One subscriber's view of an agent's changes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct WatchSlot {
+    /// Wallet (or service key) that subscribed
+    pub watcher: Pubkey,
+
+    /// Change categories the watcher cares about (AgentWatchers::FLAG_* bits)
+    pub mask: u16,
+
+    /// Categories that changed since the watcher last cleared them
+    pub dirty: u16,
+}
+
+/// AgentWatchers - subscribers to an agent's changes, each with a dirty-flag bitmap
+/// Monitoring services poll this small account instead of diffing the full agent and
+/// clear their bits once handled. Every instruction that changes the agent takes this
+/// PDA (address-checked, possibly empty) and flags it once it exists
+#[account]
+#[derive(InitSpace)]
+pub struct AgentWatchers {
+    /// The watched agent
+    pub agent: Pubkey,
+
+    /// Subscribers, in subscription order
+    #[max_len(8)]
+    pub slots: Vec<WatchSlot>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AgentWatchers {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::WATCHERS_SEED;
+
+    /// Maximum subscribers per agent
+    pub const MAX_WATCHERS: usize = 8;
+
+    /// Name, capabilities, URI or published keys
+    pub const FLAG_METADATA: u16 = 1 << 0;
+
+    /// Lifecycle status (suspension, ban, retirement, deactivation)
+    pub const FLAG_STATUS: u16 = 1 << 1;
+
+    /// Reputation score
+    pub const FLAG_REPUTATION: u16 = 1 << 2;
+
+    /// Staked lamports or unstake requests
+    pub const FLAG_STAKE: u16 = 1 << 3;
+
+    /// Verification granted or revoked
+    pub const FLAG_VERIFICATION: u16 = 1 << 4;
+
+    /// Every defined flag
+    pub const ALL_FLAGS: u16 = (1 << 5) - 1;
+
+    /// Subscribe `watcher` to the `mask` categories, or re-scope an existing
+    /// subscription (its pending flags outside the new mask are dropped)
+    pub fn watch(&mut self, agent: Pubkey, bump: u8, watcher: Pubkey, mask: u16) -> Result<()> {
+        if self.agent == Pubkey::default() {
+            self.agent = agent;
+            self.bump = bump;
+        }
+
+        if let Some(slot) = self.slots.iter_mut().find(|slot| slot.watcher == watcher) {
+            slot.mask = mask;
+            slot.dirty &= mask;
+            return Ok(());
+        }
+        require!(self.slots.len() < Self::MAX_WATCHERS, RegistryError::WatchersFull);
+        self.slots.push(WatchSlot {
+            watcher,
+            mask,
+            dirty: 0,
+        });
+        Ok(())
+    }
+
+    /// Drop `watcher`'s subscription; returns whether it was subscribed
+    pub fn unwatch(&mut self, watcher: &Pubkey) -> bool {
+        let before = self.slots.len();
+        self.slots.retain(|slot| slot.watcher != *watcher);
+        self.slots.len() != before
+    }
+
+    /// Clear `flags` from `watcher`'s bitmap; returns the flags that were set
+    pub fn clear(&mut self, watcher: &Pubkey, flags: u16) -> Result<u16> {
+        let slot = self
+            .slots
+            .iter_mut()
+            .find(|slot| slot.watcher == *watcher)
+            .ok_or(RegistryError::NotWatching)?;
+        let cleared = slot.dirty & flags;
+        slot.dirty &= !flags;
+        Ok(cleared)
+    }
+
+    /// Mark `flags` dirty for every subscriber whose mask includes them
+    pub fn flag(&mut self, flags: u16) {
+        for slot in &mut self.slots {
+            slot.dirty |= flags & slot.mask;
+        }
+    }
+
+    /// Mark `flags` dirty on the agent's watchers PDA, when it exists
+    pub fn notify(watchers: &AccountInfo, flags: u16) -> Result<()> {
+        if watchers.data_is_empty() {
+            return Ok(());
+        }
+
+        require_keys_eq!(*watchers.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        let mut state = Self::try_deserialize(&mut &watchers.try_borrow_data()?[..])?;
+        state.flag(flags);
+        state.try_serialize(&mut &mut watchers.try_borrow_mut_data()?[..])
+    }
+}
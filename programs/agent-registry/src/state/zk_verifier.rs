@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+
+/// Public inputs every inference circuit exposes: [model commitment, output commitment]
+pub const ZK_PUBLIC_INPUTS: usize = 2;
+
+/// Groth16 verifying key over BN254, in the big-endian alt_bn128 encoding
+/// (G1 = x || y, G2 = x_im || x_re || y_im || y_re, as exported by snarkjs / groth16-solana)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ZkVerifyingKey {
+    pub alpha_g1: [u8; 64],
+    pub beta_g2: [u8; 128],
+    pub gamma_g2: [u8; 128],
+    pub delta_g2: [u8; 128],
+
+    /// IC[0] plus one point per public input
+    pub ic: [[u8; 64]; ZK_PUBLIC_INPUTS + 1],
+}
+
+/// Groth16 proof (A and C in G1, B in G2), same encoding as the verifying key
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Groth16Proof {
+    pub a: [u8; 64],
+    pub b: [u8; 128],
+    pub c: [u8; 64],
+}
+
+/// ZkVerifier - an admin-registered inference circuit (one per `circuit_id`)
+/// Proofs show that the model behind the agent's `model_hash` produced a committed output
+#[account]
+#[derive(InitSpace)]
+pub struct ZkVerifier {
+    /// Circuit identifier (PDA seed)
+    pub circuit_id: u32,
+
+    pub vk: ZkVerifyingKey,
+
+    /// Whether proofs are currently accepted
+    pub active: bool,
+
+    /// Proofs accepted against this circuit
+    pub verified_proofs: u64,
+
+    /// Unix timestamp of registration
+    pub registered_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// ZkInference - one verified inference, keyed by circuit and output commitment so a
+/// proof can only be counted once, and only for the first agent that records it
+#[account]
+#[derive(InitSpace)]
+pub struct ZkInference {
+    pub agent: Pubkey,
+
+    /// Circuit the proof was verified against
+    pub circuit_id: u32,
+
+    /// Public inputs the proof was verified with
+    pub model_commitment: [u8; 32],
+    pub output_commitment: [u8; 32],
+
+    /// Unix timestamp of verification
+    pub verified_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ZkInference {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::ZK_INFERENCE_SEED;
+}
+
+/// alt_bn128 syscall operations
+const ALT_BN128_ADD: u64 = 0;
+const ALT_BN128_MUL: u64 = 2;
+const ALT_BN128_PAIRING: u64 = 3;
+
+/// BN254 base field modulus (big-endian)
+const FIELD_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+/// BN254 scalar field modulus (big-endian); public inputs must be below it
+const SCALAR_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+impl ZkVerifier {
+    pub const SEED_PREFIX: &'static [u8] = crate::constants::ZK_VERIFIER_SEED;
+
+    /// Public input binding a proof to `model_hash`: SHA256 of the string with the top
+    /// byte cleared, so it is always a BN254 scalar
+    pub fn model_commitment(model_hash: &str) -> [u8; 32] {
+        let mut commitment = solana_sha256_hasher::hash(model_hash.as_bytes()).to_bytes();
+        commitment[0] = 0;
+        commitment
+    }
+
+    /// Whether a public input is a canonical scalar
+    pub fn is_scalar(value: &[u8; 32]) -> bool {
+        value.as_slice() < SCALAR_MODULUS.as_slice()
+    }
+
+    /// Groth16 check e(-A, B) · e(alpha, beta) · e(vk_x, gamma) · e(C, delta) == 1,
+    /// with vk_x = IC[0] + Σ input_i · IC[i+1]. Malformed points fail verification
+    pub fn verify(&self, proof: &Groth16Proof, public_inputs: &[[u8; 32]; ZK_PUBLIC_INPUTS]) -> bool {
+        if !public_inputs.iter().all(Self::is_scalar) {
+            return false;
+        }
+
+        let vk = &self.vk;
+        let mut vk_x = vk.ic[0];
+        for (input, ic) in public_inputs.iter().zip(&vk.ic[1..]) {
+            let mut mul_input = [0u8; 96];
+            mul_input[..64].copy_from_slice(ic);
+            mul_input[64..].copy_from_slice(input);
+            let mut term = [0u8; 64];
+            if !alt_bn128(ALT_BN128_MUL, &mul_input, &mut term) {
+                return false;
+            }
+
+            let mut add_input = [0u8; 128];
+            add_input[..64].copy_from_slice(&vk_x);
+            add_input[64..].copy_from_slice(&term);
+            if !alt_bn128(ALT_BN128_ADD, &add_input, &mut vk_x) {
+                return false;
+            }
+        }
+
+        let pairs: [(&[u8; 64], &[u8; 128]); 4] = [
+            (&negate_g1(&proof.a), &proof.b),
+            (&vk.alpha_g1, &vk.beta_g2),
+            (&vk_x, &vk.gamma_g2),
+            (&proof.c, &vk.delta_g2),
+        ];
+        let mut pairing_input = [0u8; 4 * 192];
+        for (chunk, (g1, g2)) in pairing_input.chunks_exact_mut(192).zip(pairs) {
+            chunk[..64].copy_from_slice(g1);
+            chunk[64..].copy_from_slice(g2);
+        }
+
+        let mut result = [0u8; 32];
+        alt_bn128(ALT_BN128_PAIRING, &pairing_input, &mut result)
+            && result[..31].iter().all(|&b| b == 0)
+            && result[31] == 1
+    }
+}
+
+/// -P = (x, p - y); the point at infinity (all zeros) is its own negation
+fn negate_g1(point: &[u8; 64]) -> [u8; 64] {
+    let mut negated = *point;
+    if point.iter().all(|&b| b == 0) {
+        return negated;
+    }
+
+    let mut borrow = 0i16;
+    for i in (0..32).rev() {
+        let diff = FIELD_MODULUS[i] as i16 - point[32 + i] as i16 - borrow;
+        negated[32 + i] = diff.rem_euclid(256) as u8;
+        borrow = (diff < 0) as i16;
+    }
+    negated
+}
+
+/// Run an alt_bn128 group operation; false when the syscall rejects the input
+/// Off-chain builds have no syscall, so nothing verifies there
+fn alt_bn128(op: u64, input: &[u8], output: &mut [u8]) -> bool {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        solana_define_syscall::definitions::sol_alt_bn128_group_op(
+            op,
+            input.as_ptr(),
+            input.len() as u64,
+            output.as_mut_ptr(),
+        ) == 0
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        let _ = (op, input, output);
+        false
+    }
+}
@@ -0,0 +1,261 @@
+use anchor_lang::prelude::*;
+use crate::error_context::{require_within, ErrorField};
+use crate::errors::RegistryError;
+
+/// Model hash schemes accepted by `parse_model_hash`, with their digest size in bytes
+/// (bounded by `AgentAccount::model_hash`'s 72-byte allocation)
+pub const MODEL_HASH_SCHEMES: &[(&str, usize)] = &[("sha256", 32)];
+
+/// Characters that render as nothing or reorder the text around them: zero-width
+/// spaces and joiners, bidi embeddings/overrides/isolates, word joiners, BOM, soft hyphen
+/// They let one name display exactly like another
+pub fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{180E}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{FEFF}'
+    )
+}
+
+/// Whether `input` is free of control and invisible characters
+/// Whitespace other than a plain space (tabs, newlines) counts as control
+pub fn is_printable(input: &str) -> bool {
+    !input.chars().any(|c| c.is_control() || is_invisible(c))
+}
+
+/// Trim the ends and collapse every whitespace run (any Unicode whitespace) into a single space
+pub fn normalize_whitespace(input: &str) -> String {
+    input.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Validate an agent name and return its normalized form
+/// Rejects control and invisible characters and names that are blank once normalized.
+/// `max_bytes` is a UTF-8 byte limit (what the account stores), so names with
+/// multi-byte characters fit fewer characters
+pub fn agent_name(name: &str, max_bytes: usize) -> Result<String> {
+    require!(is_printable(name), RegistryError::InvalidName);
+    let name = normalize_whitespace(name);
+    require!(!name.is_empty(), RegistryError::InvalidName);
+    require_within(ErrorField::Name, name.len(), max_bytes, RegistryError::NameTooLong)?;
+    Ok(name)
+}
+
+/// Validate a comma-separated capabilities string and return its normalized form:
+/// entries whitespace-normalized, blank entries dropped. `max_bytes` is a UTF-8 byte limit
+pub fn agent_capabilities(capabilities: &str, max_bytes: usize) -> Result<String> {
+    require!(is_printable(capabilities), RegistryError::InvalidCapabilities);
+    let capabilities = capabilities
+        .split(',')
+        .map(normalize_whitespace)
+        .filter(|entry| !entry.is_empty())
+        .collect::<Vec<_>>()
+        .join(",");
+    require_within(
+        ErrorField::Capabilities,
+        capabilities.len(),
+        max_bytes,
+        RegistryError::CapabilitiesTooLong,
+    )?;
+    Ok(capabilities)
+}
+
+/// Parse a `scheme:hex` model hash into its digest bytes
+/// The scheme must be one of MODEL_HASH_SCHEMES and the hex its full digest in lowercase;
+/// other spellings are rejected rather than normalized so each model has exactly one
+/// (ModelRecord PDAs are derived from the string)
+pub fn parse_model_hash(model_hash: &str) -> Result<Vec<u8>> {
+    let (scheme, hex) = model_hash.split_once(':').ok_or(RegistryError::InvalidModelHash)?;
+    let (_, digest_len) = MODEL_HASH_SCHEMES
+        .iter()
+        .find(|(name, _)| *name == scheme)
+        .ok_or(RegistryError::InvalidModelHash)?;
+    require!(hex.len() == digest_len * 2, RegistryError::InvalidModelHash);
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| Some((hex_nibble(pair[0])? << 4) | hex_nibble(pair[1])?))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(|| RegistryError::InvalidModelHash.into())
+}
+
+/// Value of a lowercase hex digit
+fn hex_nibble(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::AgentAccount;
+
+    const NAME_MAX: usize = AgentAccount::BASE_NAME_LEN;
+    const CAPABILITIES_MAX: usize = AgentAccount::BASE_CAPABILITIES_LEN;
+
+    fn sha256(hex: &str) -> String {
+        format!("sha256:{}", hex)
+    }
+
+    #[test]
+    fn name_at_byte_limit() {
+        let name = "a".repeat(NAME_MAX);
+        assert_eq!(agent_name(&name, NAME_MAX).unwrap(), name);
+        assert_eq!(
+            agent_name(&"a".repeat(NAME_MAX + 1), NAME_MAX).unwrap_err(),
+            RegistryError::NameTooLong.into()
+        );
+    }
+
+    #[test]
+    fn name_limit_applies_after_normalizing() {
+        let padded = format!("  {}  ", "a".repeat(NAME_MAX));
+        assert_eq!(agent_name(&padded, NAME_MAX).unwrap(), "a".repeat(NAME_MAX));
+    }
+
+    #[test]
+    fn empty_or_blank_name_rejected() {
+        for name in ["", " ", "   ", "\u{3000}"] {
+            assert_eq!(
+                agent_name(name, NAME_MAX).unwrap_err(),
+                RegistryError::InvalidName.into(),
+                "{:?}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn name_whitespace_collapsed() {
+        assert_eq!(agent_name(" Research \u{3000} Agent ", NAME_MAX).unwrap(), "Research Agent");
+    }
+
+    #[test]
+    fn non_ascii_name_limited_by_bytes() {
+        // 'é' is two bytes, so the limit fits half as many characters
+        let name = "é".repeat(NAME_MAX / 2);
+        assert_eq!(agent_name(&name, NAME_MAX).unwrap(), name);
+        assert_eq!(
+            agent_name(&"é".repeat(NAME_MAX / 2 + 1), NAME_MAX).unwrap_err(),
+            RegistryError::NameTooLong.into()
+        );
+        assert_eq!(agent_name("エージェント", NAME_MAX).unwrap(), "エージェント");
+    }
+
+    #[test]
+    fn control_and_invisible_characters_rejected() {
+        for name in [
+            "agent\tone",
+            "agent\none",
+            "ag\u{200B}ent",
+            "agent\u{202E}",
+            "\u{FEFF}agent",
+            "ag\u{00AD}ent",
+        ] {
+            assert_eq!(
+                agent_name(name, NAME_MAX).unwrap_err(),
+                RegistryError::InvalidName.into(),
+                "{:?}",
+                name
+            );
+        }
+        assert_eq!(
+            agent_capabilities("coding,\u{2060}trading", CAPABILITIES_MAX).unwrap_err(),
+            RegistryError::InvalidCapabilities.into()
+        );
+    }
+
+    #[test]
+    fn capabilities_at_byte_limit() {
+        let capabilities = "a".repeat(CAPABILITIES_MAX);
+        assert_eq!(agent_capabilities(&capabilities, CAPABILITIES_MAX).unwrap(), capabilities);
+        assert_eq!(
+            agent_capabilities(&"a".repeat(CAPABILITIES_MAX + 1), CAPABILITIES_MAX).unwrap_err(),
+            RegistryError::CapabilitiesTooLong.into()
+        );
+        let non_ascii = "é".repeat(CAPABILITIES_MAX / 2 + 1);
+        assert_eq!(
+            agent_capabilities(&non_ascii, CAPABILITIES_MAX).unwrap_err(),
+            RegistryError::CapabilitiesTooLong.into()
+        );
+    }
+
+    #[test]
+    fn capabilities_blank_entries_dropped() {
+        assert_eq!(
+            agent_capabilities(" analysis , ,code  review,,", CAPABILITIES_MAX).unwrap(),
+            "analysis,code review"
+        );
+        assert_eq!(agent_capabilities("", CAPABILITIES_MAX).unwrap(), "");
+        assert_eq!(agent_capabilities(" , ,", CAPABILITIES_MAX).unwrap(), "");
+    }
+
+    #[test]
+    fn model_hash_parsed_to_digest() {
+        let hex = "00112233445566778899aabbccddeeff".repeat(2);
+        let digest = parse_model_hash(&sha256(&hex)).unwrap();
+        assert_eq!(digest.len(), 32);
+        assert_eq!(&digest[..4], &[0x00, 0x11, 0x22, 0x33]);
+        assert_eq!(&digest[28..], &[0xcc, 0xdd, 0xee, 0xff]);
+    }
+
+    #[test]
+    fn model_hash_needs_full_lowercase_digest() {
+        let hex = "ab".repeat(32);
+        for model_hash in [
+            String::new(),
+            sha256(""),
+            sha256(&hex[..63]),
+            sha256(&format!("{}a", hex)),
+            sha256(&hex.to_uppercase()),
+            sha256(&format!("{}g", &hex[..63])),
+            format!(" {}", sha256(&hex)),
+        ] {
+            assert_eq!(
+                parse_model_hash(&model_hash).unwrap_err(),
+                RegistryError::InvalidModelHash.into(),
+                "{:?}",
+                model_hash
+            );
+        }
+    }
+
+    #[test]
+    fn model_hash_scheme_must_be_known() {
+        let hex = "ab".repeat(32);
+        for model_hash in [
+            hex.clone(),
+            format!("md5:{}", hex),
+            format!("SHA256:{}", hex),
+            format!(":{}", hex),
+        ] {
+            assert_eq!(
+                parse_model_hash(&model_hash).unwrap_err(),
+                RegistryError::InvalidModelHash.into(),
+                "{:?}",
+                model_hash
+            );
+        }
+    }
+
+    #[test]
+    fn non_ascii_model_hash_rejected() {
+        // 64 bytes long, but not 64 hex digits
+        let model_hash = sha256(&"é".repeat(32));
+        assert_eq!(
+            parse_model_hash(&model_hash).unwrap_err(),
+            RegistryError::InvalidModelHash.into()
+        );
+        assert_eq!(
+            parse_model_hash(&sha256(&"٣".repeat(32))).unwrap_err(),
+            RegistryError::InvalidModelHash.into()
+        );
+    }
+}
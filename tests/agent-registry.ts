@@ -5,7 +5,7 @@ import { readFileSync } from "fs";
 import { fileURLToPath } from "url";
 import { dirname, join } from "path";
 import BN from "bn.js";
-import { createHash } from "crypto";
+import { createHash, randomBytes } from "crypto";
 
 // ESM compatible __dirname
 const __filename = fileURLToPath(import.meta.url);
@@ -26,6 +26,59 @@ describe("agent-registry", () => {
     [Buffer.from("registry")],
     programId
   );
+  const [configPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    programId
+  );
+  const [adminLogPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("admin_log")],
+    programId
+  );
+  const [treasuryPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    programId
+  );
+  const [eventAuthorityPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority")],
+    programId
+  );
+  // Upgradeable-loader data account; `initialize` requires its upgrade authority
+  const [programDataPda] = PublicKey.findProgramAddressSync(
+    [programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
+
+  // Per-agent PDAs required by mutating instructions
+  const hooksPda = (agent: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("reputation_hooks"), agent.toBuffer()],
+      programId
+    )[0];
+  const watchersPda = (agent: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("watchers"), agent.toBuffer()],
+      programId
+    )[0];
+  const unstakeRequestPda = (agent: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("unstake_request"), agent.toBuffer()],
+      programId
+    )[0];
+  const challengeStreamPda = (agent: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("audit_tree"), agent.toBuffer(), Buffer.from("challenges")],
+      programId
+    )[0];
+  const challengePdaFor = (agent: PublicKey, challenger: PublicKey, nonce: BN) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("challenge"),
+        agent.toBuffer(),
+        challenger.toBuffer(),
+        nonce.toArrayLike(Buffer, "le", 8),
+      ],
+      programId
+    )[0];
 
   // Test data
   const testModelHash = "sha256:1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
@@ -39,10 +92,20 @@ describe("agent-registry", () => {
   it("Initialize registry", async () => {
     try {
       const tx = await program.methods
-        .initialize()
+        .initialize({
+          config: {},
+          moderator: PublicKey.default,
+          attester: PublicKey.default,
+          attesterKind: { tee: {} },
+        })
         .accounts({
           admin: provider.wallet.publicKey,
           registry: registryPda,
+          config: configPda,
+          adminLog: adminLogPda,
+          programData: programDataPda,
+          moderator: null,
+          attester: null,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -114,6 +177,8 @@ describe("agent-registry", () => {
       .accounts({
         owner: provider.wallet.publicKey,
         registry: registryPda,
+        config: configPda,
+        treasury: treasuryPda,
         agent: agentPda,
         nftMint: mockNft.publicKey,
         systemProgram: SystemProgram.programId,
@@ -161,6 +226,8 @@ describe("agent-registry", () => {
       .accounts({
         owner: provider.wallet.publicKey,
         agent: agentPda,
+        watchers: watchersPda(agentPda),
+        unstakeRequest: unstakeRequestPda(agentPda),
       })
       .rpc();
 
@@ -177,6 +244,65 @@ describe("agent-registry", () => {
     });
   });
 
+  it("Fully unstake after growing a staked agent's metadata", async () => {
+    const agentId = new BN(0);
+
+    const [agentPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("agent"),
+        provider.wallet.publicKey.toBuffer(),
+        agentId.toArrayLike(Buffer, "le", 8),
+      ],
+      programId
+    );
+
+    const stake = new BN(anchor.web3.LAMPORTS_PER_SOL);
+    await program.methods
+      .stakeAgent(stake)
+      .accounts({
+        owner: provider.wallet.publicKey,
+        agent: agentPda,
+        config: configPda,
+        watchers: watchersPda(agentPda),
+      })
+      .rpc();
+
+    // Past the base allocation, so the account is resized; the owner pays its rent
+    const before = await provider.connection.getAccountInfo(agentPda);
+    const longName = "Staked agent " + "x".repeat(200);
+    await program.methods
+      .updateAgent(longName, null)
+      .accounts({
+        owner: provider.wallet.publicKey,
+        agent: agentPda,
+        watchers: watchersPda(agentPda),
+        unstakeRequest: unstakeRequestPda(agentPda),
+      })
+      .rpc();
+    const grown = await provider.connection.getAccountInfo(agentPda);
+    expect(grown!.data.length).to.be.greaterThan(before!.data.length);
+
+    await program.methods
+      .unstakeAgent(stake)
+      .accounts({
+        owner: provider.wallet.publicKey,
+        agent: agentPda,
+        config: configPda,
+        watchers: watchersPda(agentPda),
+        unstakeRequest: unstakeRequestPda(agentPda),
+      })
+      .rpc();
+
+    const after = await provider.connection.getAccountInfo(agentPda);
+    const rent = await provider.connection.getMinimumBalanceForRentExemption(
+      after!.data.length
+    );
+    const agentAccount = await program.account.agentAccount.fetch(agentPda);
+    expect(agentAccount.stakedLamports.toNumber()).to.equal(0);
+    expect(after!.lamports).to.be.at.least(rent);
+    expect(grown!.lamports - after!.lamports).to.equal(stake.toNumber());
+  });
+
   it("Verify agent (admin only)", async () => {
     const agentId = new BN(0);
 
@@ -194,7 +320,9 @@ describe("agent-registry", () => {
       .accounts({
         admin: provider.wallet.publicKey,
         registry: registryPda,
+        adminLog: adminLogPda,
         agent: agentPda,
+        watchers: watchersPda(agentPda),
       })
       .rpc();
 
@@ -229,7 +357,12 @@ describe("agent-registry", () => {
       .accounts({
         authority: provider.wallet.publicKey,
         registry: registryPda,
+        config: configPda,
         agent: agentPda,
+        watchers: watchersPda(agentPda),
+        hooks: hooksPda(agentPda),
+        eventAuthority: eventAuthorityPda,
+        program: programId,
       })
       .rpc();
 
@@ -251,8 +384,15 @@ describe("agent-registry", () => {
   // Day 3: Challenge-Response System Tests
   // ============================================
 
+  // Challenges commit to the expected answer: the challenger stores
+  // hex SHA256(expectedHash || salt) and reveals both after the agent responds
+  const commitAnswer = (hash: string, salt: Buffer) =>
+    createHash("sha256").update(Buffer.concat([Buffer.from(hash), salt])).digest("hex");
+
   const challengeAnswer = "The answer to life, the universe, and everything is 42";
   const expectedHash = createHash("sha256").update(challengeAnswer).digest("hex");
+  const challengeSalt = randomBytes(32);
+  const challengeNonce = new BN(0);
 
   it("Create a challenge for an agent", async () => {
     const agentId = new BN(0);
@@ -266,22 +406,17 @@ describe("agent-registry", () => {
       programId
     );
 
-    const [challengePda] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("challenge"),
-        agentPda.toBuffer(),
-        provider.wallet.publicKey.toBuffer(),
-      ],
-      programId
-    );
+    const challengePda = challengePdaFor(agentPda, provider.wallet.publicKey, challengeNonce);
 
     const question = "What is the meaning of life?";
+    const answerCommitment = commitAnswer(expectedHash, challengeSalt);
 
     const tx = await program.methods
-      .createChallenge(question, expectedHash)
+      .createChallenge(question, answerCommitment, challengeNonce, { accuracy: {} }, false)
       .accounts({
         challenger: provider.wallet.publicKey,
         agent: agentPda,
+        config: configPda,
         challenge: challengePda,
         systemProgram: SystemProgram.programId,
       })
@@ -292,13 +427,13 @@ describe("agent-registry", () => {
     // Fetch challenge state
     const challengeAccount = await program.account.challenge.fetch(challengePda);
     expect(challengeAccount.question).to.equal(question);
-    expect(challengeAccount.expectedHash).to.equal(expectedHash);
+    expect(challengeAccount.answerCommitment).to.equal(answerCommitment);
     expect(challengeAccount.status).to.deep.equal({ pending: {} });
     expect(challengeAccount.agent.toString()).to.equal(agentPda.toString());
 
     console.log("Challenge created:", {
       question: challengeAccount.question,
-      expectedHash: challengeAccount.expectedHash.substring(0, 16) + "...",
+      answerCommitment: challengeAccount.answerCommitment.substring(0, 16) + "...",
       status: "Pending",
       expiresAt: new Date(challengeAccount.expiresAt.toNumber() * 1000).toISOString(),
     });
@@ -316,14 +451,7 @@ describe("agent-registry", () => {
       programId
     );
 
-    const [challengePda] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("challenge"),
-        agentPda.toBuffer(),
-        provider.wallet.publicKey.toBuffer(),
-      ],
-      programId
-    );
+    const challengePda = challengePdaFor(agentPda, provider.wallet.publicKey, challengeNonce);
 
     // Get agent state before
     const agentBefore = await program.account.agentAccount.fetch(agentPda);
@@ -333,7 +461,7 @@ describe("agent-registry", () => {
     const responseHash = createHash("sha256").update(challengeAnswer).digest("hex");
 
     const tx = await program.methods
-      .submitResponse(responseHash)
+      .submitResponse(responseHash, challengeNonce)
       .accounts({
         owner: provider.wallet.publicKey,
         registry: registryPda,
@@ -344,8 +472,31 @@ describe("agent-registry", () => {
 
     console.log("Submit response tx:", tx);
 
+    // The response is recorded until the challenger reveals the committed answer
+    let challengeAccount = await program.account.challenge.fetch(challengePda);
+    expect(challengeAccount.status).to.deep.equal({ awaitingReveal: {} });
+
+    const revealTx = await program.methods
+      .revealChallengeAnswer(challengeNonce, expectedHash, Array.from(challengeSalt))
+      .accounts({
+        challenger: provider.wallet.publicKey,
+        config: configPda,
+        agent: agentPda,
+        challenge: challengePda,
+        owner: provider.wallet.publicKey,
+        challengeStream: challengeStreamPda(agentPda),
+        systemProgram: SystemProgram.programId,
+        watchers: watchersPda(agentPda),
+        hooks: hooksPda(agentPda),
+        eventAuthority: eventAuthorityPda,
+        program: programId,
+      })
+      .rpc();
+
+    console.log("Reveal answer tx:", revealTx);
+
     // Verify challenge status
-    const challengeAccount = await program.account.challenge.fetch(challengePda);
+    challengeAccount = await program.account.challenge.fetch(challengePda);
     expect(challengeAccount.status).to.deep.equal({ passed: {} });
 
     // Verify agent reputation increased
@@ -382,25 +533,21 @@ describe("agent-registry", () => {
     );
     await provider.connection.confirmTransaction(airdropSig);
 
-    const [challengePda2] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("challenge"),
-        agentPda.toBuffer(),
-        challenger2.publicKey.toBuffer(),
-      ],
-      programId
-    );
+    const nonce2 = new BN(0);
+    const challengePda2 = challengePdaFor(agentPda, challenger2.publicKey, nonce2);
 
     const question2 = "What is 2 + 2?";
     const correctAnswer = "4";
     const expectedHash2 = createHash("sha256").update(correctAnswer).digest("hex");
+    const salt2 = randomBytes(32);
 
     // Create challenge with challenger2
     const createTx = await program.methods
-      .createChallenge(question2, expectedHash2)
+      .createChallenge(question2, commitAnswer(expectedHash2, salt2), nonce2, { accuracy: {} }, false)
       .accounts({
         challenger: challenger2.publicKey,
         agent: agentPda,
+        config: configPda,
         challenge: challengePda2,
         systemProgram: SystemProgram.programId,
       })
@@ -418,7 +565,7 @@ describe("agent-registry", () => {
     const wrongHash = createHash("sha256").update(wrongAnswer).digest("hex");
 
     const submitTx = await program.methods
-      .submitResponse(wrongHash)
+      .submitResponse(wrongHash, nonce2)
       .accounts({
         owner: provider.wallet.publicKey,
         registry: registryPda,
@@ -429,6 +576,26 @@ describe("agent-registry", () => {
 
     console.log("Submit wrong response tx:", submitTx);
 
+    const revealTx = await program.methods
+      .revealChallengeAnswer(nonce2, expectedHash2, Array.from(salt2))
+      .accounts({
+        challenger: challenger2.publicKey,
+        config: configPda,
+        agent: agentPda,
+        challenge: challengePda2,
+        owner: provider.wallet.publicKey,
+        challengeStream: challengeStreamPda(agentPda),
+        systemProgram: SystemProgram.programId,
+        watchers: watchersPda(agentPda),
+        hooks: hooksPda(agentPda),
+        eventAuthority: eventAuthorityPda,
+        program: programId,
+      })
+      .signers([challenger2])
+      .rpc();
+
+    console.log("Reveal answer 2 tx:", revealTx);
+
     // Verify challenge status
     const challengeAccount = await program.account.challenge.fetch(challengePda2);
     expect(challengeAccount.status).to.deep.equal({ failed: {} });
@@ -445,6 +612,70 @@ describe("agent-registry", () => {
     });
   });
 
+  it("Should reject a reveal that does not open the commitment", async () => {
+    const agentId = new BN(0);
+
+    const [agentPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("agent"),
+        provider.wallet.publicKey.toBuffer(),
+        agentId.toArrayLike(Buffer, "le", 8),
+      ],
+      programId
+    );
+
+    const nonce = new BN(1);
+    const challengePda = challengePdaFor(agentPda, provider.wallet.publicKey, nonce);
+    const salt = randomBytes(32);
+
+    await program.methods
+      .createChallenge("What is 3 + 3?", commitAnswer(expectedHash, salt), nonce, { accuracy: {} }, false)
+      .accounts({
+        challenger: provider.wallet.publicKey,
+        agent: agentPda,
+        config: configPda,
+        challenge: challengePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await program.methods
+      .submitResponse(expectedHash, nonce)
+      .accounts({
+        owner: provider.wallet.publicKey,
+        registry: registryPda,
+        agent: agentPda,
+        challenge: challengePda,
+      })
+      .rpc();
+
+    // Revealing a different expected hash under the same salt must not settle
+    const otherHash = createHash("sha256").update("6").digest("hex");
+    try {
+      await program.methods
+        .revealChallengeAnswer(nonce, otherHash, Array.from(salt))
+        .accounts({
+          challenger: provider.wallet.publicKey,
+          config: configPda,
+          agent: agentPda,
+          challenge: challengePda,
+          owner: provider.wallet.publicKey,
+          challengeStream: challengeStreamPda(agentPda),
+          systemProgram: SystemProgram.programId,
+          watchers: watchersPda(agentPda),
+          hooks: hooksPda(agentPda),
+          eventAuthority: eventAuthorityPda,
+          program: programId,
+        })
+        .rpc();
+      throw new Error("Should have failed with AnswerCommitmentMismatch");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("AnswerCommitmentMismatch");
+      console.log("Correctly rejected a reveal that does not match the commitment");
+    }
+  });
+
   // ============================================
   // Error Path Tests (Critical Security)
   // ============================================
@@ -471,6 +702,8 @@ describe("agent-registry", () => {
           .accounts({
             owner: provider.wallet.publicKey,
             registry: registryPda,
+            config: configPda,
+            treasury: treasuryPda,
             agent: agentPda,
             nftMint: Keypair.generate().publicKey,
             systemProgram: SystemProgram.programId,
@@ -510,7 +743,9 @@ describe("agent-registry", () => {
           .accounts({
             admin: nonAdmin.publicKey,
             registry: registryPda,
+            adminLog: adminLogPda,
             agent: agentPda,
+            watchers: watchersPda(agentPda),
           })
           .signers([nonAdmin])
           .rpc();
@@ -548,6 +783,8 @@ describe("agent-registry", () => {
           .accounts({
             owner: nonOwner.publicKey,
             agent: agentPda,
+            watchers: watchersPda(agentPda),
+            unstakeRequest: unstakeRequestPda(agentPda),
           })
           .signers([nonOwner])
           .rpc();
@@ -580,7 +817,12 @@ describe("agent-registry", () => {
           .accounts({
             authority: provider.wallet.publicKey,
             registry: registryPda,
+            config: configPda,
             agent: agentPda,
+            watchers: watchersPda(agentPda),
+            hooks: hooksPda(agentPda),
+            eventAuthority: eventAuthorityPda,
+            program: programId,
           })
           .rpc();
         throw new Error("Should have failed with ReputationDeltaTooLarge");
@@ -612,6 +854,8 @@ describe("agent-registry", () => {
           .accounts({
             owner: provider.wallet.publicKey,
             registry: registryPda,
+            config: configPda,
+            treasury: treasuryPda,
             agent: agentPda,
             nftMint: Keypair.generate().publicKey,
             systemProgram: SystemProgram.programId,
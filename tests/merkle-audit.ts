@@ -5,6 +5,7 @@
  * 1. Register an agent
  * 2. Store Merkle audit root on-chain
  * 3. Verify the stored data
+ * 4. Append single leaves to the on-chain incremental tree
  */
 
 import * as anchor from "@coral-xyz/anchor";
@@ -23,6 +24,9 @@ describe("Merkle Audit", () => {
 
   // PDAs
   let registryPda: PublicKey;
+  let configPda: PublicKey;
+  let treasuryPda: PublicKey;
+  let eventAuthorityPda: PublicKey;
   let agentPda: PublicKey;
   let merkleAuditSummaryPda: PublicKey;
   let merkleAuditRootPda: PublicKey;
@@ -32,12 +36,45 @@ describe("Merkle Audit", () => {
   const testModelHash = "sha256:" + crypto.randomBytes(32).toString("hex");
   const testCapabilities = "merkle,audit,testing";
 
+  // Incremental tree helpers (must match AuditMerkleTree on-chain)
+  const TREE_DEPTH = 20;
+  const sha256 = (...parts: Buffer[]) =>
+    crypto.createHash("sha256").update(Buffer.concat(parts)).digest();
+  // Internal nodes carry the 0xff domain separator (merkle::NODE_PREFIX)
+  const hashPair = (left: Buffer, right: Buffer) => sha256(Buffer.from([0xff]), left, right);
+
+  const computeTreeRoot = (leaves: Buffer[]): Buffer => {
+    let level = leaves;
+    let zero = Buffer.alloc(32);
+    for (let depth = 0; depth < TREE_DEPTH; depth++) {
+      const next: Buffer[] = [];
+      for (let i = 0; i < level.length; i += 2) {
+        next.push(hashPair(level[i], level[i + 1] ?? zero));
+      }
+      level = next.length > 0 ? next : [hashPair(zero, zero)];
+      zero = hashPair(zero, zero);
+    }
+    return level[0];
+  };
+
   before(async () => {
     // Find registry PDA
     [registryPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("registry")],
       program.programId
     );
+    [configPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("config")],
+      program.programId
+    );
+    [treasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury")],
+      program.programId
+    );
+    [eventAuthorityPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("__event_authority")],
+      program.programId
+    );
 
     console.log("\n=== Merkle Audit Test ===");
     console.log("Program ID:", program.programId.toBase58());
@@ -51,10 +88,27 @@ describe("Merkle Audit", () => {
     } catch {
       console.log("Registry not found, initializing...");
       await program.methods
-        .initialize()
+        .initialize({
+          config: {},
+          moderator: PublicKey.default,
+          attester: PublicKey.default,
+          attesterKind: { tee: {} },
+        })
         .accounts({
           admin: owner,
           registry: registryPda,
+          config: configPda,
+          adminLog: PublicKey.findProgramAddressSync(
+            [Buffer.from("admin_log")],
+            program.programId
+          )[0],
+          // Only the upgrade authority may initialize
+          programData: PublicKey.findProgramAddressSync(
+            [program.programId.toBuffer()],
+            new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+          )[0],
+          moderator: null,
+          attester: null,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -90,6 +144,8 @@ describe("Merkle Audit", () => {
       .accounts({
         owner,
         registry: registryPda,
+        config: configPda,
+        treasury: treasuryPda,
         agent: agentPda,
         nftMint,
         systemProgram: SystemProgram.programId,
@@ -134,11 +190,15 @@ describe("Merkle Audit", () => {
     const tx = await program.methods
       .storeMerkleAudit(merkleRoot, entriesCount)
       .accounts({
-        owner,
+        submitter: owner,
         agent: agentPda,
+        config: configPda,
+        treasury: treasuryPda,
         auditSummary: merkleAuditSummaryPda,
         auditRoot: merkleAuditRootPda,
         systemProgram: SystemProgram.programId,
+        eventAuthority: eventAuthorityPda,
+        program: program.programId,
       })
       .rpc();
 
@@ -180,11 +240,15 @@ describe("Merkle Audit", () => {
     await program.methods
       .storeMerkleAudit(merkleRoot, entriesCount)
       .accounts({
-        owner,
+        submitter: owner,
         agent: agentPda,
+        config: configPda,
+        treasury: treasuryPda,
         auditSummary: merkleAuditSummaryPda,
         auditRoot: merkleAuditRootPda,
         systemProgram: SystemProgram.programId,
+        eventAuthority: eventAuthorityPda,
+        program: program.programId,
       })
       .rpc();
 
@@ -196,4 +260,36 @@ describe("Merkle Audit", () => {
     console.log("  Total batches:", summaryAfter.totalBatches.toNumber());
     console.log("  Total entries:", summaryAfter.totalEntries.toNumber());
   });
+
+  it("Appends leaves to the incremental audit tree", async () => {
+    const [auditTreePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("audit_tree"), agentPda.toBuffer()],
+      program.programId
+    );
+
+    const leaves = [crypto.randomBytes(32), crypto.randomBytes(32), crypto.randomBytes(32)];
+
+    for (const leaf of leaves) {
+      await program.methods
+        .appendAuditLeaf(Array.from(leaf))
+        .accounts({
+          submitter: owner,
+          agent: agentPda,
+          config: configPda,
+          treasury: treasuryPda,
+          auditTree: auditTreePda,
+          systemProgram: SystemProgram.programId,
+          eventAuthority: eventAuthorityPda,
+          program: program.programId,
+        })
+        .rpc();
+    }
+
+    const tree = await program.account.auditMerkleTree.fetch(auditTreePda);
+    expect(tree.nextIndex.toNumber()).to.equal(leaves.length);
+    expect(Buffer.from(tree.root).toString("hex")).to.equal(
+      computeTreeRoot(leaves).toString("hex")
+    );
+    console.log("  ✓ Incremental root matches off-chain computation");
+  });
 });